@@ -2,7 +2,13 @@
 
 //! Shared backend-adapter helper types for the Twill ecosystem.
 
-use twill_core::tokens::Color;
+use std::borrow::Cow;
+
+use twill_core::style::Style;
+use twill_core::tokens::{
+    Blur, Color, LetterSpacing, LineHeight, TextOverflow, TextShadow, TextTransform, WhiteSpace,
+    WordBreak,
+};
 
 /// Explicit shadow color selection for backend conversion helpers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -26,3 +32,182 @@ impl From<Option<Color>> for ShadowColor {
         }
     }
 }
+
+/// Box-shadow blur radius of a shadow that looks like `filter: blur(<blur>)`.
+///
+/// `blur()` is a Gaussian with the blur radius as its standard deviation, while a
+/// box-shadow blur is twice the standard deviation, so adapters can blur a solid fill
+/// by drawing it as an unoffset shadow of this radius.
+pub fn filter_blur_shadow_radius(blur: Blur) -> f32 {
+    2.0 * f32::from(blur.radius_px())
+}
+
+/// Background alpha that stands in for `backdrop-blur` in renderers that cannot read the
+/// pixels behind an element.
+///
+/// A blurred backdrop shows less of what lies behind a translucent panel, so the panel
+/// background gets more opaque instead: its transparency shrinks by
+/// `radius / (radius + 24)`, a third for `backdrop-blur-md` and half for `-xl`.
+pub fn frosted_alpha(alpha: f32, backdrop_blur: Blur) -> f32 {
+    let radius = f32::from(backdrop_blur.radius_px());
+    let alpha = alpha.clamp(0.0, 1.0);
+    alpha + (1.0 - alpha) * radius / (radius + 24.0)
+}
+
+/// How an adapter should lay out the text of a [`Style`]: its `whitespace-*`, `break-*`,
+/// `line-clamp-*` and `text-ellipsis`/`text-clip` utilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLayout {
+    /// Whether lines wrap at the width of the box (`false` for `whitespace-nowrap` and
+    /// `whitespace-pre`).
+    pub wrap: bool,
+    /// Where wrapped lines may break inside words.
+    pub word_break: WordBreak,
+    /// The `line-clamp-*` line count.
+    pub max_lines: Option<usize>,
+    /// What ends text cut off at the edge of the box. A line clamp implies an ellipsis.
+    pub overflow: Option<TextOverflow>,
+}
+
+/// The [`TextLayout`] of `style`.
+pub fn text_layout(style: &Style) -> TextLayout {
+    let max_lines = style
+        .line_clamp_value()
+        .filter(|&lines| lines > 0)
+        .map(usize::from);
+    TextLayout {
+        wrap: !matches!(
+            style.white_space_value(),
+            Some(WhiteSpace::NoWrap | WhiteSpace::Pre)
+        ),
+        word_break: style.word_break_value().unwrap_or(WordBreak::Normal),
+        max_lines,
+        overflow: style
+            .text_overflow_value()
+            .or(max_lines.map(|_| TextOverflow::Ellipsis)),
+    }
+}
+
+/// `text` with white space collapsed the way `white_space` does in CSS.
+///
+/// `normal` and `nowrap` collapse every run of white space, newlines included, to one
+/// space and drop it at the start and end of lines; `pre-line` does the same but keeps
+/// newlines; `pre`, `pre-wrap` and no white-space mode keep the text as written.
+pub fn white_space_text(text: &str, white_space: Option<WhiteSpace>) -> Cow<'_, str> {
+    let keep_newlines = match white_space {
+        Some(WhiteSpace::Normal | WhiteSpace::NoWrap) => false,
+        Some(WhiteSpace::PreLine) => true,
+        Some(WhiteSpace::Pre | WhiteSpace::PreWrap) | None => return Cow::Borrowed(text),
+    };
+    let collapses = |c: char| c.is_whitespace() && !(keep_newlines && c == '\n');
+
+    let mut collapsed = String::with_capacity(text.len());
+    let mut pending_space = false;
+    for c in text.chars() {
+        if collapses(c) {
+            pending_space = true;
+            continue;
+        }
+        // Spaces around a kept newline are removed, like in CSS.
+        if pending_space && c != '\n' && !collapsed.is_empty() && !collapsed.ends_with('\n') {
+            collapsed.push(' ');
+        }
+        pending_space = false;
+        collapsed.push(c);
+    }
+    if collapsed == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(collapsed)
+    }
+}
+
+/// `text` as a [`Style`] displays it: white space collapsed by `whitespace-*` (see
+/// [`white_space_text`]), then cased by `uppercase`, `lowercase` or `capitalize`.
+pub fn display_text<'a>(text: &'a str, style: &Style) -> Cow<'a, str> {
+    match white_space_text(text, style.white_space_value()) {
+        Cow::Borrowed(text) => transform_text(text, style.text_transform_value()),
+        Cow::Owned(text) => {
+            Cow::Owned(transform_text(&text, style.text_transform_value()).into_owned())
+        }
+    }
+}
+
+/// `text` cased by a `text-transform`; `capitalize` uppercases the first letter of
+/// every word and leaves the rest as written, like in CSS.
+pub fn transform_text(text: &str, transform: Option<TextTransform>) -> Cow<'_, str> {
+    match transform {
+        Some(TextTransform::Uppercase) => Cow::Owned(text.to_uppercase()),
+        Some(TextTransform::Lowercase) => Cow::Owned(text.to_lowercase()),
+        Some(TextTransform::Capitalize) => {
+            let mut capitalized = String::with_capacity(text.len());
+            let mut word_start = true;
+            for c in text.chars() {
+                if !c.is_alphanumeric() {
+                    capitalized.push(c);
+                    word_start |= c.is_whitespace();
+                } else if word_start {
+                    capitalized.extend(c.to_uppercase());
+                    word_start = false;
+                } else {
+                    capitalized.push(c);
+                }
+            }
+            Cow::Owned(capitalized)
+        }
+        Some(TextTransform::None) | None => Cow::Borrowed(text),
+    }
+}
+
+/// Line height of a [`Style`] as a multiple of its font size: its `leading-*`, or the
+/// line height a `text-*` font size sets. `None` for custom-property values.
+///
+/// Spacing-scale heights such as `leading-6` are divided by the font size of the style,
+/// or by 16px without one.
+pub fn line_height_multiplier(style: &Style) -> Option<f32> {
+    match style.line_height_value() {
+        Some(LineHeight::Tight) => Some(1.25),
+        Some(LineHeight::Snug) => Some(1.375),
+        Some(LineHeight::Normal) => Some(1.5),
+        Some(LineHeight::Relaxed) => Some(1.625),
+        Some(LineHeight::Loose) => Some(2.0),
+        Some(LineHeight::Number(number)) => Some(number),
+        Some(LineHeight::Spacing(spacing)) => {
+            let font_px = style
+                .font_size_value()
+                .and_then(|size| size.resolve_px(&[]))
+                .unwrap_or(16.0);
+            let px = spacing.to_px()? as f32;
+            (font_px > 0.0).then(|| px / font_px)
+        }
+        Some(LineHeight::Var(_)) => None,
+        None => style.font_size_value().map(|size| size.line_height()),
+    }
+}
+
+/// Letter spacing in `em`, or `None` for custom-property values.
+pub fn letter_spacing_em(spacing: LetterSpacing) -> Option<f32> {
+    match spacing {
+        LetterSpacing::Tighter => Some(-0.05),
+        LetterSpacing::Tight => Some(-0.025),
+        LetterSpacing::Normal => Some(0.0),
+        LetterSpacing::Wide => Some(0.025),
+        LetterSpacing::Wider => Some(0.05),
+        LetterSpacing::Widest => Some(0.1),
+        LetterSpacing::Var(_) => None,
+        LetterSpacing::Em(em) => Some(em),
+    }
+}
+
+/// The layers of a text shadow as `(y offset, blur radius, black alpha)`, bottom-most
+/// last, like the CSS `text-shadow` lists of the tokens.
+pub fn text_shadow_layers(shadow: TextShadow) -> &'static [(f32, f32, f32)] {
+    match shadow {
+        TextShadow::Xs2 => &[(1.0, 0.0, 0.15)],
+        TextShadow::Xs => &[(1.0, 1.0, 0.2)],
+        TextShadow::Sm => &[(1.0, 0.0, 0.075), (1.0, 1.0, 0.075), (2.0, 2.0, 0.075)],
+        TextShadow::Md => &[(1.0, 1.0, 0.1), (1.0, 2.0, 0.1), (2.0, 4.0, 0.1)],
+        TextShadow::Lg => &[(1.0, 2.0, 0.1), (3.0, 2.0, 0.1), (4.0, 8.0, 0.1)],
+        TextShadow::None => &[],
+    }
+}
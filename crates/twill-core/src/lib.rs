@@ -47,34 +47,42 @@ pub mod tokens;
 pub mod traits;
 pub mod utilities;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 /// Canonical import surface for day-to-day twill usage.
 pub mod prelude {
     /// Narrow, recommended import set for most applications.
     pub mod core {
-        pub use crate::style::{AriaAttr, DataAttr, DataState, Style};
+        pub use crate::style::{
+            AriaAttr, DataAttr, DataState, InteractionState, ResolveContext, Style,
+        };
         pub use crate::tokens::{
-            BorderRadius, BorderStyle, BorderWidth, Breakpoint, Color, Container, DropShadow,
-            Easing, FontFamily, FontSize, FontWeight, InsetShadow, LetterSpacing, LineHeight,
-            OutlineStyle, Percentage, Perspective, RingWidth, Scale, Shadow, Spacing, TextAlign,
-            TextDecoration, TextTransform, TransitionDuration, TransitionProperty,
+            BorderRadius, BorderStyle, BorderWidth, Breakpoint, Color, Container, DivideWidth,
+            DropShadow, Easing, FontFamily, FontSize, FontWeight, GradientInterpolation,
+            GradientKind, InsetShadow, LetterSpacing, LineHeight, LinearDirection, OutlineStyle,
+            Percentage, Perspective, RingWidth, Scale, Shadow, Spacing, TextAlign, TextDecoration,
+            TextTransform, TransitionDuration, TransitionProperty,
         };
         pub use crate::utilities::{
-            AlignItems, Columns, Display, FlexContainer, FlexDirection, GridContainer,
-            GridTemplate, Height, JustifyContent, Margin, Overflow, Padding, Position,
-            SizeConstraints, Width, ZIndex,
+            AlignItems, BorderRadii, BorderWidths, Columns, Display, FlexContainer, FlexDirection,
+            GridContainer, GridTemplate, Height, Inset, InsetValue, JustifyContent, Margin,
+            Overflow, Padding, Position, SizeConstraints, Transform, TransformOrigin, Width,
+            ZIndex,
         };
     }
 
     /// Semantic theme tokens and aliases.
     pub mod theme {
         pub use crate::tokens::{
-            DynamicSemanticTheme, SemanticColor, SemanticThemeSource, SemanticThemeVars,
-            ThemeVariant,
+            CssSemanticTheme, DynamicSemanticTheme, SemanticColor, SemanticThemeSource,
+            SemanticThemeVars, ThemeCssError, ThemeVariant, TokenExport,
         };
     }
 
     /// Typed escape hatches for arbitrary values and custom properties.
     pub mod arbitrary {
+        pub use crate::style::CustomPropertyValue;
         pub use crate::tokens::{
             BackgroundColor, BackgroundColorVar, BorderColor, BorderColorVar, ColorFamily,
             ColorValue, ColorValueToken, FontSizeVar, LetterSpacingVar, LineHeightVar,
@@ -92,10 +100,16 @@ pub mod prelude {
         pub use crate::traits::{ComputeValue, IntoStyle, Merge, Responsive, ThemedStyle};
     }
 
+    pub use crate::style::{Keyframe, Keyframes};
     pub use crate::tokens::{
-        AnimationToken, DivideWidth, MotionDefaults, TextOverflow, WhiteSpace, WordBreak,
+        AnimationToken, ColorStop, Filter, FontFace, FontLoadError, FontRegistry, FontSource,
+        Gradient, GradientStop, MotionDefaults, RegisteredFamily, ResolvedGradient, TextOverflow,
+        WhiteSpace, WordBreak,
+    };
+    pub use crate::utilities::{
+        AlignSelf, Flex, FlexWrap, GridFlow, GridLine, GridPlacement, TrackBreadth, TrackList,
+        TrackSize,
     };
-    pub use crate::utilities::{AlignSelf, Flex, FlexWrap};
     pub use arbitrary::*;
     pub use core::*;
     pub use theme::*;
@@ -103,7 +117,7 @@ pub mod prelude {
 }
 
 // Style
-pub use style::{AriaAttr, DataAttr, DataState, Style};
+pub use style::{AriaAttr, DataAttr, DataState, InteractionState, Style};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,780 @@
+//! `serde` support, enabled by the `serde` cargo feature.
+//!
+//! Tokens serialize as the names used in Tailwind class suffixes (`Spacing::S4` as `"4"`,
+//! `Color::blue(Scale::S500)` as `"blue-500"`), with Tailwind's arbitrary-value syntax for
+//! escape hatches (`"[13px]"`, `"(--gutter)"`, `"[#1e293b]"`). Styles and utility containers
+//! serialize as maps that omit unset fields.
+//!
+//! Custom-property names (`"--gutter"`, `"(--gutter)"`) and theme radii deserialize into
+//! `&'static str` tokens. Malformed ones are rejected; every other distinct name is
+//! interned and leaked once for the lifetime of the process, so data with an unbounded
+//! number of distinct names should not be deserialized in a long-running process.
+
+use std::collections::HashMap;
+
+use serde::de::{Error as _, Unexpected};
+use serde::ser::{Error as _, SerializeMap, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::style::class_string::{
+    Axis, arbitrary, color_suffix, columns_suffix, duration_suffix, flex_suffix,
+    gradient_kind_suffix, grid_line_suffix, grid_template, inset_suffix, margin_suffix,
+    padding_suffix, palette_suffix, size_suffix, transform_origin_suffix,
+};
+use crate::style::parse::{
+    self, ColorSpec, Value, bracketed, color_token, custom_property, line_height_spacing,
+};
+use crate::tokens::names::{self, intern, lookup, name_of};
+use crate::tokens::{
+    AnimationToken, AspectRatio, BackgroundColor, BackgroundColorVar, Blur, BorderColor,
+    BorderColorVar, BorderRadius, BorderStyle, BorderWidth, Breakpoint, Color, ColorFamily,
+    ColorValue, ColorValueToken, Container, Cursor, DivideWidth, DropShadow, DynamicSemanticTheme,
+    Easing, FontFamily, FontSize, FontSizeVar, FontWeight, GradientInterpolation, GradientKind,
+    InsetShadow, LetterSpacing, LetterSpacingVar, LineHeight, LineHeightVar, OutlineColor,
+    OutlineColorVar, OutlineStyle, Percentage, Perspective, RingColor, RingColorVar, RingWidth,
+    Scale, SemanticColor, SemanticThemeVars, Shadow, ShadowColorToken, ShadowColorVar, Spacing,
+    SpecialColor, TextAlign, TextColor, TextColorVar, TextDecoration, TextOverflow, TextShadow,
+    TextTransform, ThemeVariant, TransitionDuration, TransitionProperty, WhiteSpace, WordBreak,
+};
+use crate::traits::ComputeValue;
+use crate::utilities::{
+    AlignItems, AlignSelf, Columns, Display, Flex, FlexDirection, FlexGrow, FlexWrap, GridFlow,
+    GridLine, GridTemplate, Height, HeightSize, HeightVar, InsetValue, JustifyContent,
+    JustifyItems, JustifySelf, MarginValue, MarginVar, ObjectFit, Overflow, PaddingValue,
+    PaddingVar, PlaceContent, PlaceItems, Position, Size, SizeConstraints, TransformOrigin,
+    Visibility, Width, WidthSize, WidthVar, ZIndex,
+};
+
+/// Implement `Serialize`/`Deserialize` through a string name.
+///
+/// `to` maps a value to its name (or `None` when it has none) and `from` parses a name.
+macro_rules! string_serde {
+    ($($ty:ty, $expecting:literal, |$value:ident| $to:expr, |$raw:ident| $from:expr;)*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $value = self;
+                let name: Option<String> = $to;
+                match name {
+                    Some(name) => serializer.serialize_str(&name),
+                    None => Err(S::Error::custom(format_args!(
+                        "{self:?} has no {} name",
+                        $expecting
+                    ))),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let raw = String::deserialize(deserializer)?;
+                let $raw = raw.as_str();
+                let parsed: Option<Self> = $from;
+                parsed.ok_or_else(|| D::Error::invalid_value(Unexpected::Str($raw), &$expecting))
+            }
+        }
+    )*};
+}
+
+/// Implement `string_serde!` for keyword-only tokens backed by a name table.
+macro_rules! table_serde {
+    ($($ty:ty => $table:expr, $expecting:literal;)*) => {
+        string_serde! {$(
+            $ty,
+            $expecting,
+            |value| name_of($table, value).map(str::to_owned),
+            |raw| lookup($table, raw);
+        )*}
+    };
+}
+
+/// Implement `string_serde!` for `&'static str` custom-property wrappers.
+///
+/// Deserialized names are interned, leaking each distinct name once.
+macro_rules! var_serde {
+    ($($ty:ty),* $(,)?) => {
+        string_serde! {$(
+            $ty,
+            "a custom property name like `--gutter`",
+            |value| Some(value.as_str().to_owned()),
+            |raw| custom_property(raw).map(<$ty>::new);
+        )*}
+    };
+}
+
+/// Implement `string_serde!` for the palette-backed color token families.
+macro_rules! color_serde {
+    ($($ty:ident, $var:ident;)*) => {
+        string_serde! {$(
+            $ty,
+            "a color like `blue-500`, `muted`, `(--brand)` or `[#1e293b]`",
+            |value| Some(color_suffix!(*value, $ty)),
+            |raw| parse::color(raw).ok().map(|spec| color_token!(spec, $ty, $var));
+        )*}
+    };
+}
+
+const ALIGN_SELF: &[(&str, AlignSelf)] = &[
+    ("auto", AlignSelf::Auto),
+    ("start", AlignSelf::Start),
+    ("end", AlignSelf::End),
+    ("center", AlignSelf::Center),
+    ("baseline", AlignSelf::Baseline),
+    ("stretch", AlignSelf::Stretch),
+];
+
+const FLEX_GROW: &[(&str, FlexGrow)] = &[("0", FlexGrow::S0), ("1", FlexGrow::S1)];
+
+const THEME_VARIANT: &[(&str, ThemeVariant)] =
+    &[("light", ThemeVariant::Light), ("dark", ThemeVariant::Dark)];
+
+const SPECIAL_COLOR: &[(&str, SpecialColor)] = &[
+    ("transparent", SpecialColor::Transparent),
+    ("current", SpecialColor::Current),
+    ("black", SpecialColor::Black),
+    ("white", SpecialColor::White),
+];
+
+table_serde! {
+    Scale => names::SCALE, "a color scale like `500`";
+    ColorFamily => names::COLOR_FAMILY, "a color family like `blue`";
+    Spacing => names::SPACING, "a spacing token like `4` or `px`";
+    Percentage => names::PERCENTAGE, "a fraction like `1/2` or `full`";
+    Container => names::CONTAINER, "a container size like `md`";
+    Breakpoint => names::BREAKPOINT, "a breakpoint like `md`";
+    FontFamily => names::FONT_FAMILY, "a font family like `sans`";
+    FontWeight => names::FONT_WEIGHT, "a font weight like `semibold`";
+    TextAlign => names::TEXT_ALIGN, "a text alignment like `center`";
+    TextDecoration => names::TEXT_DECORATION, "a text decoration like `underline`";
+    TextTransform => names::TEXT_TRANSFORM, "a text transform like `uppercase`";
+    TextOverflow => names::TEXT_OVERFLOW, "a text overflow like `ellipsis`";
+    WhiteSpace => names::WHITE_SPACE, "a white-space mode like `nowrap`";
+    WordBreak => names::WORD_BREAK, "a word-break mode like `words`";
+    BorderRadius => names::BORDER_RADIUS, "a border radius like `lg`";
+    BorderWidth => names::BORDER_WIDTH, "a border width like `2`";
+    BorderStyle => names::BORDER_STYLE, "a border style like `dashed`";
+    OutlineStyle => names::OUTLINE_STYLE, "an outline style like `dashed`";
+    RingWidth => names::RING_WIDTH, "a ring width like `2`";
+    DivideWidth => names::DIVIDE_WIDTH, "a divide width like `2`";
+    GradientInterpolation => names::GRADIENT_INTERPOLATION, "`oklch` or `srgb`";
+    Shadow => names::SHADOW, "a shadow like `md`";
+    InsetShadow => names::INSET_SHADOW, "an inset shadow like `sm`";
+    TextShadow => names::TEXT_SHADOW, "a text shadow like `sm`";
+    Easing => names::EASING, "an easing like `in-out`";
+    AnimationToken => names::ANIMATION, "an animation like `spin`";
+    Cursor => names::CURSOR, "a cursor like `pointer`";
+    Display => names::DISPLAY, "a display class like `flex`";
+    Position => names::POSITION, "a position class like `absolute`";
+    Visibility => names::VISIBILITY, "a visibility class like `invisible`";
+    ZIndex => names::Z_INDEX, "a z-index like `10`";
+    Overflow => names::OVERFLOW, "an overflow mode like `hidden`";
+    ObjectFit => names::OBJECT_FIT, "an object-fit mode like `cover`";
+    FlexDirection => names::FLEX_DIRECTION, "a flex direction like `col`";
+    FlexWrap => names::FLEX_WRAP, "a flex wrap mode like `wrap`";
+    JustifyContent => names::JUSTIFY_CONTENT, "a justify-content value like `between`";
+    AlignItems => names::ALIGN_ITEMS, "an align-items value like `center`";
+    AlignSelf => ALIGN_SELF, "an align-self value like `center`";
+    FlexGrow => FLEX_GROW, "a flex-grow value of `0` or `1`";
+    JustifyItems => names::JUSTIFY_ITEMS, "a justify-items value like `center`";
+    JustifySelf => names::JUSTIFY_SELF, "a justify-self value like `center`";
+    PlaceContent => names::PLACE_CONTENT, "a place-content value like `center`";
+    PlaceItems => names::PLACE_ITEMS, "a place-items value like `center`";
+    GridFlow => names::GRID_FLOW, "a grid flow like `row` or `col-dense`";
+    ThemeVariant => THEME_VARIANT, "`light` or `dark`";
+    SpecialColor => SPECIAL_COLOR, "`transparent`, `current`, `black` or `white`";
+}
+
+var_serde!(
+    FontSizeVar,
+    LetterSpacingVar,
+    LineHeightVar,
+    BackgroundColorVar,
+    BorderColorVar,
+    OutlineColorVar,
+    RingColorVar,
+    ShadowColorVar,
+    TextColorVar,
+    PaddingVar,
+    MarginVar,
+    WidthVar,
+    HeightVar,
+);
+
+color_serde! {
+    BackgroundColor, BackgroundColorVar;
+    BorderColor, BorderColorVar;
+    OutlineColor, OutlineColorVar;
+    RingColor, RingColorVar;
+    ShadowColorToken, ShadowColorVar;
+    TextColor, TextColorVar;
+}
+
+/// Parse `[<n>px]` into whole logical pixels.
+fn bracketed_px(raw: &str) -> Option<u16> {
+    bracketed(raw).and_then(parse::px_u16)
+}
+
+/// Parse `(--name)` into an interned custom-property name.
+fn var_name(raw: &str) -> Option<&'static str> {
+    match parse::value(raw) {
+        Value::Var { hint: _, name } => custom_property(name),
+        _ => None,
+    }
+}
+
+string_serde! {
+    Color,
+    "a palette color like `blue-500`",
+    |value| Some(palette_suffix(*value)),
+    |raw| match parse::color(raw) {
+        Ok(ColorSpec::Palette(color)) => Some(color),
+        _ => None,
+    };
+
+    ColorValue,
+    "a CSS color like `oklch(0.63 0.26 29.2)` or `#1e293b`",
+    |value| Some(value.to_string()),
+    |raw| ColorValue::from_css(raw);
+
+    ColorValueToken,
+    "a hex color like `#1e293b`",
+    |value| Some(value.to_hex()),
+    |raw| ColorValueToken::from_hex(raw);
+
+    SemanticColor,
+    "a semantic color like `muted-foreground`",
+    |value| Some(value.var_name().to_owned()),
+    |raw| SemanticColor::ALL.into_iter().find(|token| token.var_name() == raw);
+
+    FontSize,
+    "a font size like `sm`, `[14px]` or `(--text)`",
+    |value| match value {
+        FontSize::Px(px) => Some(format!("[{px}px]")),
+        FontSize::Var(var) => Some(format!("({var})")),
+        size => name_of(names::FONT_SIZE, size).map(str::to_owned),
+    },
+    |raw| lookup(names::FONT_SIZE, raw)
+        .or_else(|| bracketed_px(raw).map(FontSize::Px))
+        .or_else(|| var_name(raw).map(|name| FontSize::Var(FontSizeVar::new(name))));
+
+    LetterSpacing,
+    "a letter spacing like `wide`, `[0.05em]` or `(--tracking)`",
+    |value| match value {
+        LetterSpacing::Em(em) => Some(format!("[{em}em]")),
+        LetterSpacing::Var(var) => Some(format!("({var})")),
+        spacing => name_of(names::LETTER_SPACING, spacing).map(str::to_owned),
+    },
+    |raw| lookup(names::LETTER_SPACING, raw)
+        .or_else(|| {
+            let em = bracketed(raw)?.strip_suffix("em")?.parse().ok()?;
+            Some(LetterSpacing::Em(em))
+        })
+        .or_else(|| var_name(raw).map(|name| LetterSpacing::Var(LetterSpacingVar::new(name))));
+
+    LineHeight,
+    "a line height like `snug`, `6`, `[1.3]` or `(--leading)`",
+    |value| match value {
+        LineHeight::Var(var) => Some(format!("({var})")),
+        LineHeight::Spacing(spacing) => name_of(names::SPACING, spacing).map(str::to_owned),
+        LineHeight::Number(number) => Some(
+            name_of(names::LINE_HEIGHT, value)
+                .map(str::to_owned)
+                .unwrap_or_else(|| format!("[{number}]")),
+        ),
+        line_height => name_of(names::LINE_HEIGHT, line_height).map(str::to_owned),
+    },
+    |raw| lookup(names::LINE_HEIGHT, raw)
+        .or_else(|| line_height_spacing(raw))
+        .or_else(|| bracketed(raw)?.parse().ok().map(LineHeight::Number))
+        .or_else(|| var_name(raw).map(|name| LineHeight::Var(LineHeightVar::new(name))));
+
+    Blur,
+    "a blur like `md`, `base` or `[6px]`",
+    |value| match value {
+        Blur::Base => Some("base".to_owned()),
+        Blur::Custom(px) => Some(format!("[{px}px]")),
+        blur => name_of(names::BLUR, blur).map(str::to_owned),
+    },
+    |raw| match raw {
+        "base" => Some(Blur::Base),
+        _ => lookup(names::BLUR, raw).or_else(|| bracketed_px(raw).map(Blur::Custom)),
+    };
+
+    DropShadow,
+    "a drop shadow like `md` or `base`",
+    |value| match value {
+        DropShadow::Base => Some("base".to_owned()),
+        shadow => name_of(names::DROP_SHADOW, shadow).map(str::to_owned),
+    },
+    |raw| match raw {
+        "base" => Some(DropShadow::Base),
+        _ => lookup(names::DROP_SHADOW, raw),
+    };
+
+    Perspective,
+    "a perspective like `normal` or `[640px]`",
+    |value| match value {
+        Perspective::CustomPx(px) => Some(format!("[{px}px]")),
+        perspective => name_of(names::PERSPECTIVE, perspective).map(str::to_owned),
+    },
+    |raw| lookup(names::PERSPECTIVE, raw)
+        .or_else(|| bracketed_px(raw).map(Perspective::CustomPx));
+
+    TransformOrigin,
+    "a transform origin like `top-left` or `[25%_75%]`",
+    |value| Some(transform_origin_suffix(*value)),
+    |raw| parse::transform_origin(raw).ok();
+
+    GradientKind,
+    "a gradient like `linear-to-r`, `linear-45`, `radial` or `conic-180`",
+    |value| Some(gradient_kind_suffix(*value)),
+    |raw| parse::gradient_kind(raw, false).ok();
+
+    GridLine,
+    "a grid line like `auto`, `2`, `-1` or `span-2`",
+    |value| Some(match value {
+        GridLine::Span(span) => format!("span-{span}"),
+        line => grid_line_suffix(*line),
+    }),
+    |raw| parse::grid_line(raw).ok();
+
+    AspectRatio,
+    "an aspect ratio like `video` or `[4/3]`",
+    |value| match value {
+        AspectRatio::Custom(width, height) => Some(format!("[{width}/{height}]")),
+        ratio => name_of(names::ASPECT_RATIO, ratio).map(str::to_owned),
+    },
+    |raw| lookup(names::ASPECT_RATIO, raw).or_else(|| {
+        let (width, height) = bracketed(raw)?.split_once('/')?;
+        Some(AspectRatio::Custom(width.parse().ok()?, height.parse().ok()?))
+    });
+
+    TransitionDuration,
+    "a duration like `150` or `[350ms]`",
+    |value| Some(duration_suffix(*value)),
+    |raw| parse::duration(raw).ok();
+
+    TransitionProperty,
+    "a transition property like `colors` or `[width,height]`",
+    |value| Some(match value {
+        TransitionProperty::None => "none".to_owned(),
+        TransitionProperty::All => "all".to_owned(),
+        TransitionProperty::Default => "default".to_owned(),
+        TransitionProperty::Colors => "colors".to_owned(),
+        TransitionProperty::Opacity => "opacity".to_owned(),
+        TransitionProperty::Shadow => "shadow".to_owned(),
+        TransitionProperty::Transform => "transform".to_owned(),
+        TransitionProperty::Custom(value) => arbitrary(value),
+    }),
+    |raw| match raw {
+        "none" => Some(TransitionProperty::None),
+        "all" => Some(TransitionProperty::All),
+        "default" => Some(TransitionProperty::Default),
+        "colors" => Some(TransitionProperty::Colors),
+        "opacity" => Some(TransitionProperty::Opacity),
+        "shadow" => Some(TransitionProperty::Shadow),
+        "transform" => Some(TransitionProperty::Transform),
+        _ => bracketed(raw).map(|value| TransitionProperty::Custom(value.replace('_', " "))),
+    };
+
+    PaddingValue,
+    "a padding value like `4`, `[13px]` or `(--gutter)`",
+    |value| Some(padding_suffix(*value)),
+    |raw| parse::padding_value(raw).ok();
+
+    MarginValue,
+    "a margin value like `4`, `-2`, `auto`, `[13px]` or `(--gutter)`",
+    |value| {
+        let (negative, suffix) = margin_suffix(*value);
+        Some(if negative { format!("-{suffix}") } else { suffix })
+    },
+    |raw| match raw.strip_prefix('-') {
+        Some(negative) => parse::margin_value(negative, true).ok(),
+        None => parse::margin_value(raw, false).ok(),
+    };
+
+    InsetValue,
+    "an inset value like `4`, `-2`, `1/2`, `full`, `auto`, `[13px]` or `[-25%]`",
+    |value| {
+        let (negative, suffix) = inset_suffix(*value);
+        Some(if negative { format!("-{suffix}") } else { suffix })
+    },
+    |raw| match raw.strip_prefix('-') {
+        Some(negative) => parse::inset_value(negative, true).ok(),
+        None => parse::inset_value(raw, false).ok(),
+    };
+
+    WidthSize,
+    "a width like `64`, `1/2`, `md`, `full`, `[240px]` or `(--width)`",
+    |value| Some(size_suffix((*value).into(), Axis::Width)),
+    |raw| parse::width_size(raw).ok();
+
+    HeightSize,
+    "a height like `64`, `1/2`, `screen`, `[240px]` or `(--height)`",
+    |value| Some(size_suffix((*value).into(), Axis::Height)),
+    |raw| parse::height_size(raw).ok();
+
+    Size,
+    "a size like `64`, `1/2`, `md`, `full`, `[240px]` or `(--size)`",
+    |value| Some(size_suffix(*value, Axis::Width)),
+    |raw| parse::width_size(raw).ok().map(Size::from);
+
+    Flex,
+    "a flex value like `1`, `1/2`, `auto`, `none` or `[2_2_0%]`",
+    |value| Some(flex_suffix(value)),
+    |raw| parse::flex_value(raw).ok();
+
+    GridTemplate,
+    "a grid template like `3`, `none`, `subgrid` or `[200px_1fr]`",
+    |value| Some(grid_template(value)),
+    |raw| parse::grid_template(raw).ok();
+
+    Columns,
+    "a columns value like `3`, `md`, `auto` or `[280px]`",
+    |value| Some(columns_suffix(*value)),
+    |raw| parse::columns_value(raw).ok();
+}
+
+impl Serialize for Width {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.size() {
+            Some(size) => serializer.serialize_some(&size_suffix(size, Axis::Width)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Width {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<WidthSize>::deserialize(deserializer)?
+            .map(Width::new)
+            .unwrap_or_default())
+    }
+}
+
+impl Serialize for Height {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.size() {
+            Some(size) => serializer.serialize_some(&size_suffix(size, Axis::Height)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Height {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<HeightSize>::deserialize(deserializer)?
+            .map(Height::new)
+            .unwrap_or_default())
+    }
+}
+
+/// Wire shape of [`SizeConstraints`], keeping the width/height axis of each bound.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct SizeConstraintsRepr {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_width: Option<WidthSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_width: Option<WidthSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_height: Option<HeightSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_height: Option<HeightSize>,
+}
+
+impl Serialize for SizeConstraints {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bounds = [
+            ("min_width", self.min_width, Axis::Width),
+            ("max_width", self.max_width, Axis::Width),
+            ("min_height", self.min_height, Axis::Height),
+            ("max_height", self.max_height, Axis::Height),
+        ];
+        let mut state = serializer.serialize_struct("SizeConstraints", 4)?;
+        for (key, bound, axis) in bounds {
+            match bound {
+                Some(size) => state.serialize_field(key, &size_suffix(size, axis))?,
+                None => state.skip_field(key)?,
+            }
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SizeConstraints {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = SizeConstraintsRepr::deserialize(deserializer)?;
+        Ok(SizeConstraints {
+            min_width: repr.min_width.map(Size::from),
+            max_width: repr.max_width.map(Size::from),
+            min_height: repr.min_height.map(Size::from),
+            max_height: repr.max_height.map(Size::from),
+        })
+    }
+}
+
+/// Serialize `(token, value)` pairs as a map keyed by semantic token name.
+fn serialize_semantic_map<S, T>(
+    entries: &[(SemanticColor, T)],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut map = serializer.serialize_map(Some(entries.len()))?;
+    for (token, value) in entries {
+        map.serialize_entry(token, value)?;
+    }
+    map.end()
+}
+
+/// Order a deserialized semantic map like [`SemanticColor::ALL`], requiring every token.
+fn ordered_semantic_map<E, T>(
+    mut map: HashMap<SemanticColor, T>,
+) -> Result<Vec<(SemanticColor, T)>, E>
+where
+    E: serde::de::Error,
+{
+    SemanticColor::ALL
+        .into_iter()
+        .map(|token| match map.remove(&token) {
+            Some(value) => Ok((token, value)),
+            None => Err(E::custom(format_args!(
+                "missing semantic token `{}`",
+                token.var_name()
+            ))),
+        })
+        .collect()
+}
+
+struct SemanticMap<'a, T>(&'a [(SemanticColor, T)]);
+
+impl<T: Serialize> Serialize for SemanticMap<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_semantic_map(self.0, serializer)
+    }
+}
+
+impl Serialize for SemanticThemeVars {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SemanticThemeVars", 5)?;
+        state.serialize_field("radius", self.radius)?;
+        state.serialize_field("light", &SemanticMap(&self.light))?;
+        state.serialize_field("dark", &SemanticMap(&self.dark))?;
+        state.serialize_field("light_values", &SemanticMap(&self.light_values))?;
+        state.serialize_field("dark_values", &SemanticMap(&self.dark_values))?;
+        state.end()
+    }
+}
+
+/// Wire shape of [`SemanticThemeVars`]; `*_values` default to the palette values.
+#[derive(Deserialize)]
+struct SemanticThemeVarsRepr {
+    radius: String,
+    light: HashMap<SemanticColor, Color>,
+    dark: HashMap<SemanticColor, Color>,
+    #[serde(default)]
+    light_values: Option<HashMap<SemanticColor, ColorValue>>,
+    #[serde(default)]
+    dark_values: Option<HashMap<SemanticColor, ColorValue>>,
+}
+
+/// The radius must be a length like `0.625rem`; it is interned, leaking each distinct
+/// radius once.
+impl<'de> Deserialize<'de> for SemanticThemeVars {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = SemanticThemeVarsRepr::deserialize(deserializer)?;
+        if parse::length(&repr.radius).is_none() {
+            return Err(D::Error::invalid_value(
+                Unexpected::Str(&repr.radius),
+                &"a radius like `0.625rem` or `8px`",
+            ));
+        }
+        let light = ordered_semantic_map::<D::Error, _>(repr.light)?;
+        let dark = ordered_semantic_map::<D::Error, _>(repr.dark)?;
+        let values = |explicit: Option<HashMap<SemanticColor, ColorValue>>,
+                      palette: &[(SemanticColor, Color)]| match explicit {
+            Some(explicit) => ordered_semantic_map::<D::Error, _>(explicit),
+            None => Ok(palette
+                .iter()
+                .map(|(token, color)| (*token, color.compute()))
+                .collect()),
+        };
+
+        Ok(SemanticThemeVars {
+            radius: intern(&repr.radius),
+            light_values: values(repr.light_values, &light)?,
+            dark_values: values(repr.dark_values, &dark)?,
+            light,
+            dark,
+        })
+    }
+}
+
+impl Serialize for DynamicSemanticTheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DynamicSemanticTheme", 2)?;
+        state.serialize_field("light", &SemanticMap(&self.light))?;
+        state.serialize_field("dark", &SemanticMap(&self.dark))?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct DynamicSemanticThemeRepr {
+    light: HashMap<SemanticColor, ColorValue>,
+    dark: HashMap<SemanticColor, ColorValue>,
+}
+
+impl<'de> Deserialize<'de> for DynamicSemanticTheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DynamicSemanticThemeRepr::deserialize(deserializer)?;
+        Ok(DynamicSemanticTheme {
+            light: ordered_semantic_map::<D::Error, _>(repr.light)?,
+            dark: ordered_semantic_map::<D::Error, _>(repr.dark)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+    use crate::style::DataState;
+    use serde_json::json;
+
+    #[test]
+    fn test_tokens_serialize_as_tailwind_names() {
+        assert_eq!(serde_json::to_value(Spacing::S4).unwrap(), json!("4"));
+        assert_eq!(serde_json::to_value(Spacing::S0_5).unwrap(), json!("0.5"));
+        assert_eq!(
+            serde_json::to_value(Color::blue(Scale::S500)).unwrap(),
+            json!("blue-500")
+        );
+        assert_eq!(serde_json::to_value(Shadow::Lg).unwrap(), json!("lg"));
+        assert_eq!(
+            serde_json::to_value(FontSize::Px(14)).unwrap(),
+            json!("[14px]")
+        );
+        assert_eq!(
+            serde_json::to_value(MarginValue::NegativeScale(Spacing::S2)).unwrap(),
+            json!("-2")
+        );
+        assert_eq!(
+            serde_json::from_value::<Breakpoint>(json!("2xl")).unwrap(),
+            Breakpoint::S2xl
+        );
+    }
+
+    #[test]
+    fn test_unknown_token_name_is_rejected() {
+        let error = serde_json::from_value::<Spacing>(json!("s4")).unwrap_err();
+        assert!(error.to_string().contains("spacing token"));
+    }
+
+    #[test]
+    fn test_malformed_custom_property_and_radius_are_rejected() {
+        for raw in ["--", "--a b", "--gap)", "gutter"] {
+            assert!(
+                serde_json::from_value::<WidthVar>(json!(raw)).is_err(),
+                "{raw}"
+            );
+        }
+        assert!(serde_json::from_value::<WidthVar>(json!("--gutter_2")).is_ok());
+
+        let mut theme = serde_json::to_value(SemanticThemeVars::shadcn_neutral()).unwrap();
+        theme["radius"] = json!("wide");
+        let error = serde_json::from_value::<SemanticThemeVars>(theme).unwrap_err();
+        assert!(error.to_string().contains("a radius like"));
+    }
+
+    #[test]
+    fn test_style_round_trips_and_omits_unset_fields() {
+        let style = Style::new()
+            .p(Spacing::S4)
+            .mt_px(12.0)
+            .w_var(WidthVar::new("--panel-width"))
+            .bg(Color::blue(Scale::S500))
+            .text_color_token(TextColor::Semantic(SemanticColor::MutedForeground))
+            .rounded(BorderRadius::Lg)
+            .rounded_b(BorderRadius::None)
+            .border_b(BorderWidth::S2)
+            .min_h(HeightSize::ScreenHeight)
+            .hover(|s| s.bg(Color::blue(Scale::S600)))
+            .data_attr(DataState::Open, |s| s.shadow(Shadow::Lg))
+            .md(|s| s.display(Display::Flex).gap(Spacing::S2));
+
+        let value = serde_json::to_value(&style).unwrap();
+        assert_eq!(value["background_color"], json!("blue-500"));
+        assert_eq!(value["text_color"], json!("muted-foreground"));
+        assert_eq!(value["constraints"], json!({ "min_height": "screen" }));
+        assert_eq!(value["border_widths"], json!({ "bottom": "2" }));
+        assert_eq!(value["responsive"]["md"]["display"], json!("flex"));
+        assert!(value.get("opacity").is_none());
+
+        let decoded: Style = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded, style);
+    }
+
+    #[test]
+    fn test_gradient_round_trips_as_tailwind_names() {
+        let style = Style::new()
+            .bg_linear(crate::tokens::LinearDirection::angle(45))
+            .gradient_from(Color::sky(Scale::S500))
+            .gradient_to_position(80);
+
+        let value = serde_json::to_value(&style).unwrap();
+        assert_eq!(
+            value["background_gradient"],
+            json!({ "kind": "linear-45", "from": { "color": "sky-500" }, "to": { "position": 80 } })
+        );
+        assert_eq!(serde_json::from_value::<Style>(value).unwrap(), style);
+        assert_eq!(
+            serde_json::from_value::<GradientKind>(json!("conic")).unwrap(),
+            GradientKind::Conic(0)
+        );
+    }
+
+    #[test]
+    fn test_grid_placement_round_trips_as_tailwind_names() {
+        let style = Style::new().col_span(2).row_start(-1);
+
+        let value = serde_json::to_value(&style).unwrap();
+        assert_eq!(
+            value,
+            json!({ "grid_column": { "start": "span-2", "end": "span-2" }, "grid_row": { "start": "-1" } })
+        );
+        assert_eq!(serde_json::from_value::<Style>(value).unwrap(), style);
+        assert_eq!(
+            serde_json::from_value::<GridFlow>(json!("col-dense")).unwrap(),
+            GridFlow::ColDense
+        );
+    }
+
+    #[test]
+    fn test_semantic_themes_round_trip() {
+        let theme = SemanticThemeVars::shadcn_neutral();
+        let value = serde_json::to_value(theme).unwrap();
+        assert_eq!(value["light"]["background"], json!("white"));
+
+        let decoded: SemanticThemeVars = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.radius(), theme.radius());
+        assert_eq!(decoded.light_tokens(), theme.light_tokens());
+        assert_eq!(decoded.dark_values(), theme.dark_values());
+
+        let dynamic = DynamicSemanticTheme::from_brand_oklch(0.628, 0.258, 29.234);
+        let decoded: DynamicSemanticTheme =
+            serde_json::from_value(serde_json::to_value(&dynamic).unwrap()).unwrap();
+        assert_eq!(decoded.light_values(), dynamic.light_values());
+    }
+
+    #[test]
+    fn test_semantic_theme_requires_every_token() {
+        let mut value = serde_json::to_value(SemanticThemeVars::shadcn_neutral()).unwrap();
+        value["light"].as_object_mut().unwrap().remove("ring");
+
+        let error = serde_json::from_value::<SemanticThemeVars>(value).unwrap_err();
+        assert!(error.to_string().contains("missing semantic token `ring`"));
+    }
+}
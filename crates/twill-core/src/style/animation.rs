@@ -0,0 +1,406 @@
+//! Keyframe animations sampled into [`Style`] deltas.
+
+use std::time::Duration;
+
+use super::Style;
+use crate::tokens::motion::cubic_bezier;
+use crate::tokens::{AnimationToken, Easing, TransitionDuration};
+use crate::utilities::InsetValue;
+
+/// Timing function of the interval that starts at a keyframe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Timing {
+    Easing(Easing),
+    /// Control points of timing functions that have no [`Easing`] token.
+    CubicBezier([f32; 4]),
+}
+
+impl Timing {
+    fn sample(self, progress: f32) -> f32 {
+        match self {
+            Self::Easing(easing) => easing.sample(progress),
+            Self::CubicBezier(points) => cubic_bezier(points, progress),
+        }
+    }
+}
+
+/// The values of one keyframe in a [`Keyframes`] set.
+///
+/// A keyframe only animates the values it sets. Like CSS, each value is interpolated
+/// between the keyframes that set it, and from its initial value (fully opaque, not
+/// moved, rotated or scaled) when no keyframe sets it at `0%` or `100%`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Keyframe {
+    pub(crate) opacity: Option<f32>,
+    pub(crate) translate_x: Option<InsetValue>,
+    pub(crate) translate_y: Option<InsetValue>,
+    pub(crate) rotate: Option<i16>,
+    pub(crate) scale: Option<i16>,
+    pub(crate) timing: Option<Timing>,
+}
+
+impl Keyframe {
+    pub const fn new() -> Self {
+        Self {
+            opacity: None,
+            translate_x: None,
+            translate_y: None,
+            rotate: None,
+            scale: None,
+            timing: None,
+        }
+    }
+
+    /// Opacity from `0.0` to `1.0`, like [`Style::opacity`].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Horizontal translation, like [`Style::translate_x`].
+    pub fn translate_x(mut self, value: impl Into<InsetValue>) -> Self {
+        self.translate_x = Some(value.into());
+        self
+    }
+
+    /// Vertical translation, like [`Style::translate_y`].
+    pub fn translate_y(mut self, value: impl Into<InsetValue>) -> Self {
+        self.translate_y = Some(value.into());
+        self
+    }
+
+    /// Rotation in degrees, like [`Style::rotate`].
+    pub fn rotate(mut self, degrees: i16) -> Self {
+        self.rotate = Some(degrees);
+        self
+    }
+
+    /// Scale of both axes in percent, like [`Style::scale`].
+    pub fn scale(mut self, percent: i16) -> Self {
+        self.scale = Some(percent);
+        self
+    }
+
+    /// Timing function from this keyframe to the next one (`animation-timing-function`
+    /// inside a keyframe).
+    pub fn ease(mut self, easing: Easing) -> Self {
+        self.timing = Some(Timing::Easing(easing));
+        self
+    }
+}
+
+/// A keyframe animation (`@keyframes` plus `animation-duration`,
+/// `animation-timing-function` and `animation-iteration-count`).
+///
+/// [`Keyframes::sample`] evaluates the animation at a time since it started into a
+/// [`Style`] holding only the animated opacity and transform, to be merged over the
+/// element's style. Backends keep the start time and repaint while
+/// [`Keyframes::is_running`]:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twill_core::prelude::*;
+///
+/// let fade_in = Keyframes::new(TransitionDuration::Ms300, Easing::Out)
+///     .at(0, Keyframe::new().opacity(0.0).translate_y(Spacing::S2))
+///     .iterations(1);
+///
+/// let card = Style::new().bg(Color::white());
+/// let halfway = card.clone().merged(fade_in.sample(Duration::from_millis(150)));
+/// assert!(halfway.opacity_value().is_some_and(|opacity| opacity > 0.5));
+/// assert_eq!(fade_in.sample(Duration::from_millis(300)), Style::new());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    pub(crate) frames: Vec<(u8, Keyframe)>,
+    pub(crate) duration: TransitionDuration,
+    pub(crate) timing: Timing,
+    pub(crate) iterations: Option<u32>,
+}
+
+impl Keyframes {
+    /// An empty animation repeating forever, each iteration lasting `duration` and
+    /// eased with `easing` between keyframes that set no timing of their own.
+    pub fn new(duration: TransitionDuration, easing: Easing) -> Self {
+        Self {
+            frames: Vec::new(),
+            duration,
+            timing: Timing::Easing(easing),
+            iterations: None,
+        }
+    }
+
+    /// Place `keyframe` at `percent` of each iteration (`0` is `from`, `100` is `to`),
+    /// replacing any keyframe already there.
+    pub fn at(mut self, percent: u8, keyframe: Keyframe) -> Self {
+        let percent = percent.min(100);
+        match self
+            .frames
+            .binary_search_by_key(&percent, |(offset, _)| *offset)
+        {
+            Ok(index) => self.frames[index].1 = keyframe,
+            Err(index) => self.frames.insert(index, (percent, keyframe)),
+        }
+        self
+    }
+
+    /// Stop after `count` iterations instead of repeating forever.
+    pub fn iterations(mut self, count: u32) -> Self {
+        self.iterations = Some(count);
+        self
+    }
+
+    pub const fn duration_value(&self) -> TransitionDuration {
+        self.duration
+    }
+
+    /// Number of iterations, `None` when the animation repeats forever.
+    pub const fn iterations_value(&self) -> Option<u32> {
+        self.iterations
+    }
+
+    /// Whether the animation still changes `elapsed` after it started.
+    pub fn is_running(&self, elapsed: Duration) -> bool {
+        let iteration = Duration::from_millis(u64::from(self.duration.as_millis()));
+        !iteration.is_zero()
+            && !self.frames.is_empty()
+            && self
+                .iterations
+                .is_none_or(|count| elapsed < iteration * count)
+    }
+
+    /// The animated values `elapsed` after the animation started, as a style that only
+    /// sets them.
+    ///
+    /// A finished animation returns an empty style, so the element goes back to its own
+    /// values like CSS's default `animation-fill-mode: none`. Rotations and scales are
+    /// rounded to whole degrees and percents.
+    pub fn sample(&self, elapsed: Duration) -> Style {
+        if !self.is_running(elapsed) {
+            return Style::new();
+        }
+        let iteration = f64::from(self.duration.as_millis()) / 1000.0;
+        let progress = (elapsed.as_secs_f64() / iteration).fract() as f32 * 100.0;
+
+        let mut style = Style::new();
+        if let Some(opacity) = self.track(progress, |frame| frame.opacity, 1.0, lerp) {
+            style = style.opacity(opacity);
+        }
+        let origin = InsetValue::Px(0.0);
+        if let Some(x) = self.track(progress, |frame| frame.translate_x, origin, lerp_inset) {
+            style = style.translate_x(x);
+        }
+        if let Some(y) = self.track(progress, |frame| frame.translate_y, origin, lerp_inset) {
+            style = style.translate_y(y);
+        }
+        let degrees = |frame: &Keyframe| frame.rotate.map(f32::from);
+        if let Some(degrees) = self.track(progress, degrees, 0.0, lerp) {
+            style = style.rotate(degrees.round() as i16);
+        }
+        let percent = |frame: &Keyframe| frame.scale.map(f32::from);
+        if let Some(percent) = self.track(progress, percent, 100.0, lerp) {
+            style = style.scale(percent.round() as i16);
+        }
+        style
+    }
+
+    /// Interpolate one value at `progress` percent of an iteration, or `None` when no
+    /// keyframe sets it.
+    fn track<T: Copy>(
+        &self,
+        progress: f32,
+        value: impl Fn(&Keyframe) -> Option<T>,
+        initial: T,
+        lerp: impl Fn(T, T, f32) -> T,
+    ) -> Option<T> {
+        let mut points: Vec<(f32, T, Option<Timing>)> = self
+            .frames
+            .iter()
+            .filter_map(|(offset, frame)| {
+                value(frame).map(|value| (f32::from(*offset), value, frame.timing))
+            })
+            .collect();
+        let (first, last) = (points.first()?.0, points.last()?.0);
+        if first > 0.0 {
+            points.insert(0, (0.0, initial, None));
+        }
+        if last < 100.0 {
+            points.push((100.0, initial, None));
+        }
+
+        let next = points
+            .iter()
+            .position(|(offset, ..)| *offset > progress)
+            .unwrap_or(points.len() - 1)
+            .max(1);
+        let (from_offset, from, timing) = points[next - 1];
+        let (to_offset, to, _) = points[next];
+        if to_offset <= from_offset {
+            return Some(to);
+        }
+        let local = (progress - from_offset) / (to_offset - from_offset);
+        let eased = timing.unwrap_or(self.timing).sample(local);
+        Some(lerp(from, to, eased))
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+/// Interpolate translations of the same unit; a zero takes the unit of the other side,
+/// and translations mixing px and percentages switch halfway.
+fn lerp_inset(from: InsetValue, to: InsetValue, t: f32) -> InsetValue {
+    let length = |value: InsetValue| match value {
+        InsetValue::Scale(_) | InsetValue::NegativeScale(_) | InsetValue::Px(_) => {
+            value.to_px(0.0).map(|px| (px, false))
+        }
+        InsetValue::Fraction(_) | InsetValue::NegativeFraction(_) | InsetValue::Percent(_) => {
+            value.to_px(100.0).map(|percent| (percent, true))
+        }
+        InsetValue::Auto => None,
+    };
+    let (Some((from_value, from_percent)), Some((to_value, to_percent))) =
+        (length(from), length(to))
+    else {
+        return if t < 0.5 { from } else { to };
+    };
+    let percent = if from_percent == to_percent || to_value == 0.0 {
+        from_percent
+    } else if from_value == 0.0 {
+        to_percent
+    } else {
+        return if t < 0.5 { from } else { to };
+    };
+    let value = lerp(from_value, to_value, t);
+    if percent {
+        InsetValue::Percent(value)
+    } else {
+        InsetValue::Px(value)
+    }
+}
+
+impl AnimationToken {
+    /// The keyframes of Tailwind's `animate-*` utility, or `None` for `animate-none`.
+    pub fn keyframes(&self) -> Option<Keyframes> {
+        let keyframes = match self {
+            Self::None => return None,
+            Self::Spin => Keyframes::new(TransitionDuration::Ms1000, Easing::Linear)
+                .at(100, Keyframe::new().rotate(360)),
+            Self::Ping => {
+                let expanded = Keyframe::new().scale(200).opacity(0.0);
+                Keyframes::new(TransitionDuration::Ms1000, Easing::Out)
+                    .at(75, expanded)
+                    .at(100, expanded)
+            }
+            Self::Pulse => Keyframes {
+                timing: Timing::CubicBezier([0.4, 0.0, 0.6, 1.0]),
+                ..Keyframes::new(TransitionDuration::CustomMs(2000), Easing::InOut)
+            }
+            .at(50, Keyframe::new().opacity(0.5)),
+            Self::Bounce => {
+                let up = Keyframe {
+                    timing: Some(Timing::CubicBezier([0.8, 0.0, 1.0, 1.0])),
+                    ..Keyframe::new().translate_y(InsetValue::Percent(-25.0))
+                };
+                let down = Keyframe::new()
+                    .translate_y(InsetValue::Px(0.0))
+                    .ease(Easing::Out);
+                Keyframes::new(TransitionDuration::Ms1000, Easing::InOut)
+                    .at(0, up)
+                    .at(50, down)
+                    .at(100, up)
+            }
+        };
+        Some(keyframes)
+    }
+}
+
+impl Style {
+    /// This style with its `animate-*` token sampled `elapsed` after the animation
+    /// started merged on top; see [`Keyframes::sample`].
+    pub fn animated(&self, elapsed: Duration) -> Self {
+        match self
+            .animation_value()
+            .and_then(|animation| animation.keyframes())
+        {
+            Some(keyframes) => self.clone().merged(keyframes.sample(elapsed)),
+            None => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::Spacing;
+
+    fn at(keyframes: &Keyframes, millis: u64) -> Style {
+        keyframes.sample(Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn test_keyframes_interpolate_each_value_between_its_keyframes() {
+        let slide = Keyframes::new(TransitionDuration::Ms1000, Easing::Linear)
+            .at(50, Keyframe::new().opacity(0.0))
+            .at(100, Keyframe::new().translate_x(Spacing::S4).scale(50))
+            .iterations(2);
+
+        let quarter = at(&slide, 250);
+        assert_eq!(quarter.opacity_value(), Some(0.5));
+        let transform = quarter
+            .transform_value()
+            .expect("the transform is animated");
+        assert_eq!(transform.translate_x(), Some(InsetValue::Px(4.0)));
+        assert_eq!(transform.scale_x(), Some(88));
+        assert_eq!(transform.rotate(), None);
+
+        // The second iteration starts over, and the animation then stops.
+        assert_eq!(at(&slide, 1750).opacity_value(), Some(0.5));
+        assert!(slide.is_running(Duration::from_millis(1999)));
+        assert!(!slide.is_running(Duration::from_secs(2)));
+        assert_eq!(at(&slide, 2000), Style::new());
+    }
+
+    #[test]
+    fn test_animation_tokens_sample_tailwind_keyframes() {
+        assert_eq!(AnimationToken::None.keyframes(), None);
+
+        let spin = AnimationToken::Spin.keyframes().expect("spin animates");
+        let rotation = |millis| at(&spin, millis).transform_value().and_then(|t| t.rotate());
+        assert_eq!(rotation(250), Some(90));
+        assert_eq!(rotation(1500), Some(180));
+        assert!(spin.is_running(Duration::from_secs(3600)));
+
+        let pulse = AnimationToken::Pulse.keyframes().expect("pulse animates");
+        assert_eq!(at(&pulse, 0).opacity_value(), Some(1.0));
+        assert_eq!(at(&pulse, 1000).opacity_value(), Some(0.5));
+        let fading = at(&pulse, 500)
+            .opacity_value()
+            .expect("opacity is animated");
+        assert!(fading > 0.5 && fading < 1.0);
+
+        let ping = AnimationToken::Ping.keyframes().expect("ping animates");
+        let expanded = at(&ping, 900);
+        assert_eq!(expanded.opacity_value(), Some(0.0));
+        assert_eq!(
+            expanded.transform_value().and_then(|t| t.scale_y()),
+            Some(200)
+        );
+
+        let bounce = AnimationToken::Bounce.keyframes().expect("bounce animates");
+        let lift = |millis| {
+            at(&bounce, millis)
+                .transform_value()
+                .and_then(|t| t.translate_y())
+        };
+        assert_eq!(lift(0), Some(InsetValue::Percent(-25.0)));
+        assert_eq!(lift(500), Some(InsetValue::Percent(0.0)));
+        assert!(matches!(lift(250), Some(InsetValue::Percent(p)) if p > -25.0 && p < 0.0));
+
+        let spinner = Style::new().opacity(0.8).animate(AnimationToken::Spin);
+        let turned = spinner.animated(Duration::from_millis(500));
+        assert_eq!(turned.transform_value().and_then(|t| t.rotate()), Some(180));
+        assert_eq!(turned.opacity_value(), Some(0.8));
+    }
+}
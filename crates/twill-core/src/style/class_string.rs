@@ -0,0 +1,1168 @@
+//! Serialization of [`Style`] back into Tailwind class strings.
+
+use std::fmt;
+
+use super::Style;
+use crate::tokens::names::{self, name_of};
+use crate::tokens::{
+    AspectRatio, BackgroundColor, Blur, BorderColor, BorderWidth, Color, ColorFamily, DivideWidth,
+    DropShadow, Filter, FontSize, GradientKind, GradientStop, LetterSpacing, LineHeight,
+    LinearDirection, OutlineColor, Perspective, RingColor, RingWidth, ShadowColorToken, TextColor,
+    TransitionDuration, TransitionProperty,
+};
+use crate::traits::ComputeValue;
+use crate::utilities::{
+    BorderRadii, BorderWidths, Columns, Flex, GridLine, GridPlacement, GridTemplate, InsetValue,
+    MarginValue, PaddingValue, Size, Transform, TransformOrigin,
+};
+
+/// Convert a color token into its class suffix (`blue-500`, `muted`, `(--x)`, `[#fff]`).
+macro_rules! color_suffix {
+    ($token:expr, $ty:ident) => {
+        match $token {
+            $ty::Inherit => "inherit".to_owned(),
+            $ty::Current => "current".to_owned(),
+            $ty::Transparent => "transparent".to_owned(),
+            $ty::Palette(color) => palette_suffix(color),
+            $ty::Semantic(color) => color.var_name().to_owned(),
+            $ty::CustomProperty(var) => format!("({})", var.as_str()),
+            $ty::Arbitrary(value) => format!("[{}]", value.to_hex()),
+        }
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use color_suffix;
+
+/// [`fmt::Display`] adapter returned by [`Style::classes`].
+#[derive(Debug, Clone, Copy)]
+pub struct StyleClasses<'a>(&'a Style);
+
+impl fmt::Display for StyleClasses<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, class) in self.0.class_list().iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(class)?;
+        }
+        Ok(())
+    }
+}
+
+impl Style {
+    /// Serialize this style to a canonical Tailwind class string.
+    ///
+    /// Base utilities come first in field order, followed by state variants
+    /// (`hover:`, `data-[state=open]:`, `aria-selected:`) and then breakpoint
+    /// layers (`sm:` ... `2xl:`). Escape hatches use Tailwind's arbitrary-value
+    /// syntax (`p-[13px]`, `bg-[#1e293b]`, `text-(--fg)`).
+    ///
+    /// Twill-only settings without a Tailwind utility (`column_gap`,
+    /// `columns_max_count`) are not emitted.
+    ///
+    /// ```rust
+    /// use twill_core::prelude::core::*;
+    ///
+    /// let style = Style::new()
+    ///     .p(Spacing::S4)
+    ///     .bg(Color::blue(Scale::S500))
+    ///     .hover(|s| s.bg(Color::blue(Scale::S600)))
+    ///     .md(|s| s.display(Display::Flex));
+    ///
+    /// assert_eq!(style.to_class_string(), "p-4 bg-blue-500 hover:bg-blue-600 md:flex");
+    /// ```
+    pub fn to_class_string(&self) -> String {
+        self.classes().to_string()
+    }
+
+    /// Display adapter that writes the same output as [`Style::to_class_string`].
+    pub fn classes(&self) -> StyleClasses<'_> {
+        StyleClasses(self)
+    }
+
+    /// The individual classes emitted by [`Style::to_class_string`], in order.
+    pub fn class_list(&self) -> Vec<String> {
+        let mut classes = ClassWriter {
+            prefix: String::new(),
+            out: Vec::new(),
+        };
+        classes.style(self);
+        classes.out
+    }
+}
+
+struct ClassWriter {
+    prefix: String,
+    out: Vec<String>,
+}
+
+impl ClassWriter {
+    fn push(&mut self, class: impl fmt::Display) {
+        let class = format!("{}{class}", self.prefix);
+        if !self.out.contains(&class) {
+            self.out.push(class);
+        }
+    }
+
+    fn utility(&mut self, name: &str, suffix: impl fmt::Display) {
+        self.push(format_args!("{name}-{suffix}"));
+    }
+
+    fn keyword<T: PartialEq>(&mut self, name: &str, table: &[(&'static str, T)], value: &T) {
+        if let Some(suffix) = name_of(table, value) {
+            self.utility(name, suffix);
+        }
+    }
+
+    fn class<T: PartialEq>(&mut self, table: &[(&'static str, T)], value: &T) {
+        if let Some(class) = name_of(table, value) {
+            self.push(class);
+        }
+    }
+
+    fn variant(&mut self, variant: &str, style: &Style) {
+        let outer = self.prefix.len();
+        self.prefix.push_str(variant);
+        self.prefix.push(':');
+        self.style(style);
+        self.prefix.truncate(outer);
+    }
+
+    fn style(&mut self, style: &Style) {
+        self.layout(style);
+        self.flex_and_grid(style);
+        self.spacing(style);
+        self.sizing(style);
+        self.effects(style);
+        self.borders(style);
+        self.typography(style);
+        self.motion(style);
+        self.layers(style);
+    }
+
+    fn layout(&mut self, style: &Style) {
+        if let Some(display) = &style.display {
+            self.class(names::DISPLAY, display);
+        }
+        if let Some(visibility) = &style.visibility {
+            self.class(names::VISIBILITY, visibility);
+        }
+        if let Some(position) = &style.position {
+            self.class(names::POSITION, position);
+        }
+        if let Some(inset) = &style.inset {
+            self.inset([inset.top, inset.right, inset.bottom, inset.left]);
+        }
+        if let Some(z_index) = &style.z_index {
+            self.keyword("z", names::Z_INDEX, z_index);
+        }
+        if let Some(overflow) = &style.overflow {
+            self.keyword("overflow", names::OVERFLOW, overflow);
+        }
+        if let Some(overflow) = &style.overflow_x {
+            self.keyword("overflow-x", names::OVERFLOW, overflow);
+        }
+        if let Some(overflow) = &style.overflow_y {
+            self.keyword("overflow-y", names::OVERFLOW, overflow);
+        }
+        match style.aspect_ratio {
+            Some(AspectRatio::Custom(width, height)) => {
+                self.utility("aspect", format_args!("[{width}/{height}]"));
+            }
+            Some(ratio) => self.keyword("aspect", names::ASPECT_RATIO, &ratio),
+            None => {}
+        }
+        if let Some(object_fit) = &style.object_fit {
+            self.keyword("object", names::OBJECT_FIT, object_fit);
+        }
+        if let Some(columns) = style.columns {
+            self.utility("columns", columns_suffix(columns));
+        }
+    }
+
+    fn flex_and_grid(&mut self, style: &Style) {
+        if let Some(flex) = &style.flex {
+            if let Some(direction) = &flex.direction {
+                self.keyword("flex", names::FLEX_DIRECTION, direction);
+            }
+            if let Some(wrap) = &flex.wrap {
+                self.keyword("flex", names::FLEX_WRAP, wrap);
+            }
+            if let Some(justify) = &flex.justify {
+                self.keyword("justify", names::JUSTIFY_CONTENT, justify);
+            }
+            if let Some(align) = &flex.align {
+                self.keyword("items", names::ALIGN_ITEMS, align);
+            }
+            self.gaps(flex.gap, flex.col_gap, flex.row_gap);
+        }
+        if let Some(flex) = &style.flex_item {
+            self.utility("flex", flex_suffix(flex));
+        }
+        if let Some(grid) = &style.grid {
+            if let Some(columns) = &grid.columns {
+                self.utility("grid-cols", grid_template(columns));
+            }
+            if let Some(rows) = &grid.rows {
+                self.utility("grid-rows", grid_template(rows));
+            }
+            if let Some(justify) = &grid.justify {
+                self.keyword("justify", names::JUSTIFY_CONTENT, justify);
+            }
+            if let Some(align) = &grid.align {
+                self.keyword("items", names::ALIGN_ITEMS, align);
+            }
+            if let Some(flow) = &grid.flow {
+                self.keyword("grid-flow", names::GRID_FLOW, flow);
+            }
+            self.gaps(grid.gap, grid.col_gap, grid.row_gap);
+        }
+        if let Some(place_content) = &style.place_content {
+            self.keyword("place-content", names::PLACE_CONTENT, place_content);
+        }
+        if let Some(place_items) = &style.place_items {
+            self.keyword("place-items", names::PLACE_ITEMS, place_items);
+        }
+        if let Some(justify_items) = &style.justify_items {
+            self.keyword("justify-items", names::JUSTIFY_ITEMS, justify_items);
+        }
+        if let Some(justify_self) = &style.justify_self {
+            self.keyword("justify-self", names::JUSTIFY_SELF, justify_self);
+        }
+        if let Some(column) = style.grid_column {
+            self.grid_placement("col", column);
+        }
+        if let Some(row) = style.grid_row {
+            self.grid_placement("row", row);
+        }
+    }
+
+    fn grid_placement(&mut self, axis: &str, placement: GridPlacement) {
+        match (placement.start, placement.end) {
+            (Some(GridLine::Span(start)), Some(GridLine::Span(end))) if start == end => {
+                self.push(format_args!("{axis}-span-{start}"));
+            }
+            (Some(GridLine::Auto), Some(GridLine::Auto)) => self.push(format_args!("{axis}-auto")),
+            _ if placement == GridPlacement::full() => self.push(format_args!("{axis}-span-full")),
+            (start, end) => {
+                for (edge, line) in [("start", start), ("end", end)] {
+                    match line {
+                        Some(GridLine::Line(line)) if line < 0 => {
+                            self.push(format_args!("-{axis}-{edge}-{}", line.unsigned_abs()));
+                        }
+                        Some(line) => {
+                            self.push(format_args!("{axis}-{edge}-{}", grid_line_suffix(line)));
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn gaps(
+        &mut self,
+        gap: Option<crate::tokens::Spacing>,
+        col_gap: Option<crate::tokens::Spacing>,
+        row_gap: Option<crate::tokens::Spacing>,
+    ) {
+        for (name, value) in [("gap", gap), ("gap-x", col_gap), ("gap-y", row_gap)] {
+            if let Some(value) = value {
+                self.keyword(name, names::SPACING, &value);
+            }
+        }
+    }
+
+    fn spacing(&mut self, style: &Style) {
+        if let Some(padding) = &style.padding {
+            let sides = [padding.top, padding.right, padding.bottom, padding.left];
+            self.sides("p", sides, |value| (false, padding_suffix(value)));
+        }
+        if let Some(margin) = &style.margin {
+            let sides = [margin.top, margin.right, margin.bottom, margin.left];
+            self.sides("m", sides, margin_suffix);
+        }
+        for (name, value) in [("space-x", style.space_x), ("space-y", style.space_y)] {
+            if let Some(value) = value {
+                self.keyword(name, names::SPACING, &value);
+            }
+        }
+    }
+
+    /// Emit the shortest `p`/`px`/`py`/`pt`... combination for physical sides.
+    fn sides<T: Copy + PartialEq>(
+        &mut self,
+        name: &str,
+        [top, right, bottom, left]: [Option<T>; 4],
+        suffix: impl Fn(T) -> (bool, String),
+    ) {
+        let mut emit = |axis: &str, value: Option<T>| {
+            if let Some(value) = value {
+                let (negative, suffix) = suffix(value);
+                let sign = if negative { "-" } else { "" };
+                self.push(format_args!("{sign}{name}{axis}-{suffix}"));
+            }
+        };
+
+        if top.is_some() && top == right && top == bottom && top == left {
+            return emit("", top);
+        }
+
+        let (x, y) = (
+            left.is_some() && left == right,
+            top.is_some() && top == bottom,
+        );
+        if x {
+            emit("x", left);
+        }
+        if y {
+            emit("y", top);
+        } else {
+            emit("t", top);
+        }
+        if !x {
+            emit("r", right);
+        }
+        if !y {
+            emit("b", bottom);
+        }
+        if !x {
+            emit("l", left);
+        }
+    }
+
+    /// Emit `border-x`/`border-y` for matching side pairs, then single sides.
+    fn border_widths(&mut self, widths: BorderWidths) {
+        let [top, right, bottom, left] = [
+            widths.top_side(),
+            widths.right_side(),
+            widths.bottom_side(),
+            widths.left_side(),
+        ];
+        let mut emit = |name: &str, width: Option<BorderWidth>| match width {
+            Some(BorderWidth::S1) => self.push(name),
+            Some(width) => self.keyword(name, names::BORDER_WIDTH, &width),
+            None => {}
+        };
+
+        let (x, y) = (
+            left.is_some() && left == right,
+            top.is_some() && top == bottom,
+        );
+        if x {
+            emit("border-x", left);
+        }
+        if y {
+            emit("border-y", top);
+        } else {
+            emit("border-t", top);
+        }
+        if !x {
+            emit("border-r", right);
+        }
+        if !y {
+            emit("border-b", bottom);
+        }
+        if !x {
+            emit("border-l", left);
+        }
+    }
+
+    /// Emit `rounded-t`/`rounded-b` (or `rounded-l`/`rounded-r`) for matching corner
+    /// pairs, then single corners.
+    fn border_radii(&mut self, radii: BorderRadii) {
+        let corners = [
+            radii.top_left_corner(),
+            radii.top_right_corner(),
+            radii.bottom_right_corner(),
+            radii.bottom_left_corner(),
+        ];
+        let mut done = corners.map(|radius| radius.is_none());
+        let mut emit = |name: &str, indices: &[usize]| {
+            let radius = corners[indices[0]];
+            let free = indices.iter().all(|&index| !done[index]);
+            if free && indices.iter().all(|&index| corners[index] == radius) {
+                if let Some(radius) = radius {
+                    self.keyword(name, names::BORDER_RADIUS, &radius);
+                }
+                for &index in indices {
+                    done[index] = true;
+                }
+            }
+        };
+
+        emit("rounded-t", &[0, 1]);
+        emit("rounded-b", &[2, 3]);
+        emit("rounded-l", &[0, 3]);
+        emit("rounded-r", &[1, 2]);
+        emit("rounded-tl", &[0]);
+        emit("rounded-tr", &[1]);
+        emit("rounded-br", &[2]);
+        emit("rounded-bl", &[3]);
+    }
+
+    /// Emit the shortest `inset`/`inset-x`/`inset-y`/`top`... combination.
+    fn inset(&mut self, [top, right, bottom, left]: [Option<InsetValue>; 4]) {
+        let mut emit = |name: &str, value: Option<InsetValue>| {
+            if let Some(value) = value {
+                let (negative, suffix) = inset_suffix(value);
+                let sign = if negative { "-" } else { "" };
+                self.push(format_args!("{sign}{name}-{suffix}"));
+            }
+        };
+
+        if top.is_some() && top == right && top == bottom && top == left {
+            return emit("inset", top);
+        }
+
+        let (x, y) = (
+            left.is_some() && left == right,
+            top.is_some() && top == bottom,
+        );
+        if x {
+            emit("inset-x", left);
+        }
+        if y {
+            emit("inset-y", top);
+        } else {
+            emit("top", top);
+        }
+        if !x {
+            emit("right", right);
+        }
+        if !y {
+            emit("bottom", bottom);
+        }
+        if !x {
+            emit("left", left);
+        }
+    }
+
+    fn sizing(&mut self, style: &Style) {
+        let width = style.width.and_then(|width| width.size());
+        let height = style.height.and_then(|height| height.size());
+        let width = width.map(|size| size_suffix(size, Axis::Width));
+        let height = height.map(|size| size_suffix(size, Axis::Height));
+
+        match (width, height) {
+            (Some(width), Some(height)) if width == height => self.utility("size", width),
+            (width, height) => {
+                if let Some(width) = width {
+                    self.utility("w", width);
+                }
+                if let Some(height) = height {
+                    self.utility("h", height);
+                }
+            }
+        }
+
+        if let Some(constraints) = &style.constraints {
+            let bounds = [
+                ("min-w", constraints.min_width, Axis::Width),
+                ("max-w", constraints.max_width, Axis::Width),
+                ("min-h", constraints.min_height, Axis::Height),
+                ("max-h", constraints.max_height, Axis::Height),
+            ];
+            for (name, size, axis) in bounds {
+                if let Some(size) = size {
+                    self.utility(name, size_suffix(size, axis));
+                }
+            }
+        }
+    }
+
+    fn effects(&mut self, style: &Style) {
+        if let Some(background) = style.background_color {
+            self.utility("bg", color_suffix!(background, BackgroundColor));
+        }
+        if let Some(gradient) = style.background_gradient {
+            if let Some(kind) = gradient.kind {
+                match gradient.interpolation {
+                    Some(interpolation) => self.utility(
+                        "bg",
+                        format_args!(
+                            "{}/{}",
+                            gradient_kind_suffix(kind),
+                            name_of(names::GRADIENT_INTERPOLATION, &interpolation).unwrap_or("")
+                        ),
+                    ),
+                    None => self.utility("bg", gradient_kind_suffix(kind)),
+                }
+            }
+            self.gradient_stop("from", gradient.from);
+            self.gradient_stop("via", gradient.via);
+            self.gradient_stop("to", gradient.to);
+        }
+        if let Some(opacity) = style.opacity {
+            let percent = opacity * 100.0;
+            if (percent - percent.round()).abs() < 1e-3 && (0.0..=100.0).contains(&percent) {
+                self.utility("opacity", percent.round() as u8);
+            } else {
+                self.utility("opacity", format_args!("[{opacity}]"));
+            }
+        }
+        if style.filter == Some(Filter::NONE)
+            && style.blur == Some(Blur::None)
+            && style.drop_shadow == Some(DropShadow::None)
+        {
+            self.push("filter-none");
+        } else {
+            self.blur("blur", style.blur);
+            self.filter(style.filter);
+            match style.drop_shadow {
+                Some(DropShadow::Base) => self.push("drop-shadow"),
+                Some(shadow) => self.keyword("drop-shadow", names::DROP_SHADOW, &shadow),
+                None => {}
+            }
+        }
+        self.blur("backdrop-blur", style.backdrop_blur);
+        match style.perspective {
+            Some(Perspective::CustomPx(px)) => {
+                self.utility("perspective", format_args!("[{px}px]"));
+            }
+            Some(perspective) => self.keyword("perspective", names::PERSPECTIVE, &perspective),
+            None => {}
+        }
+        match style.transform {
+            Some(Transform::NONE) => self.push("transform-none"),
+            Some(transform) => self.transform(transform),
+            None => {}
+        }
+        if let Some(origin) = style.transform_origin {
+            self.utility("origin", transform_origin_suffix(origin));
+        }
+    }
+
+    /// Emit the transform functions; `translate-*` and `scale-*` cover both axes when they
+    /// match.
+    fn transform(&mut self, transform: Transform) {
+        let mut signed = |name: &str, value: Option<(bool, String)>| {
+            if let Some((negative, suffix)) = value {
+                let sign = if negative { "-" } else { "" };
+                self.push(format_args!("{sign}{name}-{suffix}"));
+            }
+        };
+        let number =
+            |value: Option<i16>| value.map(|value| (value < 0, value.unsigned_abs().to_string()));
+
+        if transform.translate_x.is_some() && transform.translate_x == transform.translate_y {
+            signed("translate", transform.translate_x.map(inset_suffix));
+        } else {
+            signed("translate-x", transform.translate_x.map(inset_suffix));
+            signed("translate-y", transform.translate_y.map(inset_suffix));
+        }
+        signed("rotate", number(transform.rotate));
+        if transform.scale_x.is_some() && transform.scale_x == transform.scale_y {
+            signed("scale", number(transform.scale_x));
+        } else {
+            signed("scale-x", number(transform.scale_x));
+            signed("scale-y", number(transform.scale_y));
+        }
+        signed("skew-x", number(transform.skew_x));
+        signed("skew-y", number(transform.skew_y));
+    }
+
+    fn blur(&mut self, name: &str, blur: Option<Blur>) {
+        match blur {
+            Some(Blur::Base) => self.push(name),
+            Some(Blur::Custom(px)) => self.utility(name, format_args!("[{px}px]")),
+            Some(blur) => self.keyword(name, names::BLUR, &blur),
+            None => {}
+        }
+    }
+
+    /// Emit the color filter functions in Tailwind's order. Full-strength `grayscale`,
+    /// `invert` and `sepia` use the bare class.
+    fn filter(&mut self, filter: Option<Filter>) {
+        let Some(filter) = filter else {
+            return;
+        };
+        self.filter_amount("brightness", filter.brightness, false);
+        self.filter_amount("contrast", filter.contrast, false);
+        self.filter_amount("grayscale", filter.grayscale, true);
+        match filter.hue_rotate {
+            Some(degrees) if degrees < 0 => {
+                self.push(format_args!("-hue-rotate-{}", degrees.unsigned_abs()));
+            }
+            Some(degrees) => self.push(format_args!("hue-rotate-{degrees}")),
+            None => {}
+        }
+        self.filter_amount("invert", filter.invert, true);
+        self.filter_amount("saturate", filter.saturate, false);
+        self.filter_amount("sepia", filter.sepia, true);
+    }
+
+    fn filter_amount(&mut self, name: &str, amount: Option<u16>, bare: bool) {
+        match amount {
+            Some(100) if bare => self.push(name),
+            Some(amount) => self.push(format_args!("{name}-{amount}")),
+            None => {}
+        }
+    }
+
+    fn gradient_stop(&mut self, name: &str, stop: Option<GradientStop>) {
+        let Some(stop) = stop else {
+            return;
+        };
+        if let Some(color) = stop.color {
+            self.utility(name, color_suffix!(color, BackgroundColor));
+        }
+        if let Some(percent) = stop.position {
+            self.utility(name, format_args!("{percent}%"));
+        }
+    }
+
+    fn borders(&mut self, style: &Style) {
+        if let Some(radius) = &style.border_radius {
+            self.keyword("rounded", names::BORDER_RADIUS, radius);
+        }
+        if let Some(radii) = style.border_radii {
+            self.border_radii(radii);
+        }
+        match style.border_width {
+            Some(BorderWidth::S1) => self.push("border"),
+            Some(width) => self.keyword("border", names::BORDER_WIDTH, &width),
+            None => {}
+        }
+        if let Some(widths) = style.border_widths {
+            self.border_widths(widths);
+        }
+        if let Some(border_style) = &style.border_style {
+            self.keyword("border", names::BORDER_STYLE, border_style);
+        }
+        if let Some(color) = style.border_color {
+            self.utility("border", color_suffix!(color, BorderColor));
+        }
+        for (name, width) in [("divide-x", style.divide_x), ("divide-y", style.divide_y)] {
+            match width {
+                Some(DivideWidth::S1) => self.push(name),
+                Some(width) => self.keyword(name, names::DIVIDE_WIDTH, &width),
+                None => {}
+            }
+        }
+        if let Some(color) = style.divide_color {
+            self.utility("divide", color_suffix!(color, BorderColor));
+        }
+        match style.outline_width {
+            Some(BorderWidth::S1) => self.push("outline"),
+            Some(width) => self.keyword("outline", names::BORDER_WIDTH, &width),
+            None => {}
+        }
+        if let Some(outline_style) = &style.outline_style {
+            self.keyword("outline", names::OUTLINE_STYLE, outline_style);
+        }
+        if let Some(color) = style.outline_color {
+            self.utility("outline", color_suffix!(color, OutlineColor));
+        }
+        match style.ring_width {
+            Some(RingWidth::S1) => self.push("ring"),
+            Some(width) => self.keyword("ring", names::RING_WIDTH, &width),
+            None => {}
+        }
+        if let Some(color) = style.ring_color {
+            self.utility("ring", color_suffix!(color, RingColor));
+        }
+        if let Some(shadow) = &style.box_shadow {
+            self.keyword("shadow", names::SHADOW, shadow);
+        }
+        if let Some(shadow) = &style.inset_shadow {
+            self.keyword("inset-shadow", names::INSET_SHADOW, shadow);
+        }
+        if let Some(color) = style.shadow_color {
+            self.utility("shadow", color_suffix!(color, ShadowColorToken));
+        }
+    }
+
+    fn typography(&mut self, style: &Style) {
+        if let Some(family) = &style.font_family {
+            self.keyword("font", names::FONT_FAMILY, family);
+        }
+        match style.font_size {
+            Some(FontSize::Px(px)) => self.utility("text", format_args!("[{px}px]")),
+            Some(FontSize::Var(var)) => self.utility("text", format_args!("(length:{var})")),
+            Some(size) => self.keyword("text", names::FONT_SIZE, &size),
+            None => {}
+        }
+        if let Some(weight) = &style.font_weight {
+            self.keyword("font", names::FONT_WEIGHT, weight);
+        }
+        match style.letter_spacing {
+            Some(LetterSpacing::Em(em)) => self.utility("tracking", format_args!("[{em}em]")),
+            Some(LetterSpacing::Var(var)) => self.utility("tracking", format_args!("({var})")),
+            Some(spacing) => self.keyword("tracking", names::LETTER_SPACING, &spacing),
+            None => {}
+        }
+        match style.line_height {
+            Some(LineHeight::Var(var)) => self.utility("leading", format_args!("({var})")),
+            Some(LineHeight::Spacing(spacing)) => self.keyword("leading", names::SPACING, &spacing),
+            Some(line_height) => match name_of(names::LINE_HEIGHT, &line_height) {
+                Some(name) => self.utility("leading", name),
+                None => {
+                    if let LineHeight::Number(number) = line_height {
+                        self.utility("leading", format_args!("[{number}]"));
+                    }
+                }
+            },
+            None => {}
+        }
+        if let Some(align) = &style.text_align {
+            self.keyword("text", names::TEXT_ALIGN, align);
+        }
+        if let Some(decoration) = &style.text_decoration {
+            self.class(names::TEXT_DECORATION, decoration);
+        }
+        if let Some(transform) = &style.text_transform {
+            self.class(names::TEXT_TRANSFORM, transform);
+        }
+        if let Some(overflow) = &style.text_overflow {
+            self.keyword("text", names::TEXT_OVERFLOW, overflow);
+        }
+        if let Some(white_space) = &style.white_space {
+            self.keyword("whitespace", names::WHITE_SPACE, white_space);
+        }
+        if let Some(word_break) = &style.word_break {
+            self.keyword("break", names::WORD_BREAK, word_break);
+        }
+        match style.line_clamp {
+            Some(0) => self.push("line-clamp-none"),
+            Some(lines) => self.utility("line-clamp", lines),
+            None => {}
+        }
+        if let Some(color) = style.text_color {
+            self.utility("text", color_suffix!(color, TextColor));
+        }
+        if let Some(shadow) = &style.text_shadow {
+            self.keyword("text-shadow", names::TEXT_SHADOW, shadow);
+        }
+    }
+
+    fn motion(&mut self, style: &Style) {
+        let defaults = crate::tokens::MotionDefaults::default();
+        let default_property = TransitionProperty::Default.value();
+        let preset = style.transition_property.as_deref() == Some(default_property)
+            && style.transition_duration == Some(defaults.duration)
+            && style.transition_timing_function == Some(defaults.easing);
+
+        if let Some(property) = style.transition_property.as_deref() {
+            let keyword = [
+                TransitionProperty::None,
+                TransitionProperty::All,
+                TransitionProperty::Colors,
+                TransitionProperty::Opacity,
+                TransitionProperty::Shadow,
+                TransitionProperty::Transform,
+            ]
+            .into_iter()
+            .find(|token| token.value() == property);
+
+            match keyword {
+                _ if property == default_property => self.push("transition"),
+                Some(TransitionProperty::None) => self.push("transition-none"),
+                Some(TransitionProperty::All) => self.push("transition-all"),
+                Some(TransitionProperty::Colors) => self.push("transition-colors"),
+                Some(TransitionProperty::Opacity) => self.push("transition-opacity"),
+                Some(TransitionProperty::Shadow) => self.push("transition-shadow"),
+                Some(TransitionProperty::Transform) => self.push("transition-transform"),
+                _ => self.utility("transition", arbitrary(property)),
+            }
+        }
+        if !preset {
+            if let Some(duration) = style.transition_duration {
+                self.utility("duration", duration_suffix(duration));
+            }
+            if let Some(easing) = &style.transition_timing_function {
+                self.keyword("ease", names::EASING, easing);
+            }
+        }
+        if let Some(delay) = style.transition_delay {
+            self.utility("delay", duration_suffix(delay));
+        }
+        if let Some(animation) = &style.animation {
+            self.keyword("animate", names::ANIMATION, animation);
+        }
+        if let Some(cursor) = &style.cursor {
+            self.keyword("cursor", names::CURSOR, cursor);
+        }
+    }
+
+    fn layers(&mut self, style: &Style) {
+        if let Some(states) = &style.states {
+            let named = [
+                ("hover", &states.hover),
+                ("focus", &states.focus),
+                ("focus-visible", &states.focus_visible),
+                ("active", &states.active),
+                ("disabled", &states.disabled),
+                ("selected", &states.selected),
+                ("checked", &states.checked),
+                ("open", &states.open),
+                ("closed", &states.closed),
+            ];
+            for (variant, layer) in named {
+                if let Some(layer) = layer {
+                    self.variant(variant, layer);
+                }
+            }
+            for (selector, layer) in &states.data {
+                self.variant(&attribute_variant("data", selector), layer);
+            }
+            for (name, layer) in &states.aria {
+                self.variant(&attribute_variant("aria", name), layer);
+            }
+        }
+        if let Some(responsive) = &style.responsive {
+            for (breakpoint, layer) in responsive {
+                if let Some(variant) = name_of(names::BREAKPOINT, breakpoint) {
+                    self.variant(variant, layer);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn palette_suffix(color: Color) -> String {
+    match color.family() {
+        ColorFamily::Black | ColorFamily::White => color.family().as_str().to_owned(),
+        family => match name_of(names::SCALE, &color.scale()) {
+            Some(scale) => format!("{family}-{scale}"),
+            None => family.as_str().to_owned(),
+        },
+    }
+}
+
+/// Wrap a raw value in `[...]`, encoding spaces as underscores.
+pub(crate) fn arbitrary(value: &str) -> String {
+    format!("[{}]", value.replace(' ', "_"))
+}
+
+pub(crate) fn attribute_variant(prefix: &str, key: &str) -> String {
+    let simple = key
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-');
+    if simple {
+        format!("{prefix}-{key}")
+    } else {
+        format!("{prefix}-{}", arbitrary(key))
+    }
+}
+
+pub(crate) fn flex_suffix(flex: &Flex) -> String {
+    match flex {
+        Flex::Number(number) => number.to_string(),
+        Flex::Fraction {
+            numerator,
+            denominator,
+        } => format!("{numerator}/{denominator}"),
+        Flex::Auto => "auto".to_owned(),
+        Flex::Initial => "initial".to_owned(),
+        Flex::None => "none".to_owned(),
+        Flex::CustomProperty(name) => format!("({name})"),
+        Flex::Arbitrary(value) => arbitrary(value),
+    }
+}
+
+pub(crate) fn columns_suffix(columns: Columns) -> String {
+    match columns {
+        Columns::Count(count) => count.to_string(),
+        Columns::Width(container) => name_of(names::CONTAINER, &container)
+            .map(str::to_owned)
+            .unwrap_or_else(|| arbitrary(container.value())),
+        Columns::WidthPx(px) => format!("[{px}px]"),
+        Columns::Auto => "auto".to_owned(),
+    }
+}
+
+/// `linear-to-r`, `linear-45`, `radial` or `conic-180`, as in `bg-linear-to-r`.
+pub(crate) fn gradient_kind_suffix(kind: GradientKind) -> String {
+    match kind {
+        GradientKind::Linear(LinearDirection::Angle(degrees)) => format!("linear-{degrees}"),
+        GradientKind::Linear(direction) => format!(
+            "linear-{}",
+            name_of(names::LINEAR_DIRECTION, &direction).unwrap_or_default()
+        ),
+        GradientKind::Radial => "radial".to_owned(),
+        GradientKind::Conic(0) => "conic".to_owned(),
+        GradientKind::Conic(degrees) => format!("conic-{degrees}"),
+    }
+}
+
+/// `top-left` or `[25%_75%]`, as in `origin-top-left`.
+pub(crate) fn transform_origin_suffix(origin: TransformOrigin) -> String {
+    match origin {
+        TransformOrigin::Percent(x, y) => format!("[{x}%_{y}%]"),
+        origin => name_of(names::TRANSFORM_ORIGIN, &origin)
+            .unwrap_or_default()
+            .to_owned(),
+    }
+}
+
+/// `auto`, `3` or `[span_2]`, as in `col-start-3`.
+pub(crate) fn grid_line_suffix(line: GridLine) -> String {
+    match line {
+        GridLine::Auto => "auto".to_owned(),
+        GridLine::Line(line) => line.to_string(),
+        GridLine::Span(span) => format!("[span_{span}]"),
+    }
+}
+
+pub(crate) fn grid_template(template: &GridTemplate) -> String {
+    match template {
+        GridTemplate::Count(count) => count.to_string(),
+        GridTemplate::None => "none".to_owned(),
+        GridTemplate::Subgrid => "subgrid".to_owned(),
+        GridTemplate::CustomProperty(name) => format!("({name})"),
+        GridTemplate::Arbitrary(value) => arbitrary(value),
+    }
+}
+
+pub(crate) fn duration_suffix(duration: TransitionDuration) -> String {
+    match duration {
+        TransitionDuration::CustomMs(ms) => format!("[{ms}ms]"),
+        duration => name_of(names::TRANSITION_DURATION, &duration)
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("[{}ms]", duration.as_millis())),
+    }
+}
+
+pub(crate) fn padding_suffix(value: PaddingValue) -> String {
+    match value {
+        PaddingValue::Scale(spacing) => spacing_suffix(spacing),
+        PaddingValue::Px(px) => format!("[{px}px]"),
+        PaddingValue::Rem(rem) => format!("[{rem}rem]"),
+        PaddingValue::Var(var) => format!("({var})"),
+    }
+}
+
+pub(crate) fn margin_suffix(value: MarginValue) -> (bool, String) {
+    match value {
+        MarginValue::Scale(spacing) => (false, spacing_suffix(spacing)),
+        MarginValue::NegativeScale(spacing) => (true, spacing_suffix(spacing)),
+        MarginValue::Px(px) => (false, format!("[{px}px]")),
+        MarginValue::Rem(rem) => (false, format!("[{rem}rem]")),
+        MarginValue::Var(var) => (false, format!("({var})")),
+        MarginValue::Auto => (false, "auto".to_owned()),
+    }
+}
+
+pub(crate) fn inset_suffix(value: InsetValue) -> (bool, String) {
+    let fraction = |fraction| {
+        name_of(names::PERCENTAGE, &fraction)
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("[{}]", fraction.value()))
+    };
+    match value {
+        InsetValue::Scale(spacing) => (false, spacing_suffix(spacing)),
+        InsetValue::NegativeScale(spacing) => (true, spacing_suffix(spacing)),
+        InsetValue::Fraction(value) => (false, fraction(value)),
+        InsetValue::NegativeFraction(value) => (true, fraction(value)),
+        InsetValue::Px(px) => (false, format!("[{px}px]")),
+        InsetValue::Percent(percent) => (false, format!("[{percent}%]")),
+        InsetValue::Auto => (false, "auto".to_owned()),
+    }
+}
+
+pub(crate) fn spacing_suffix(spacing: crate::tokens::Spacing) -> String {
+    name_of(names::SPACING, &spacing)
+        .map(str::to_owned)
+        .unwrap_or_else(|| format!("[{}]", spacing.compute()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Axis {
+    Width,
+    Height,
+}
+
+pub(crate) fn size_suffix(size: Size, axis: Axis) -> String {
+    let keyword = match size {
+        Size::Spacing(spacing) => return spacing_suffix(spacing),
+        Size::Percentage(percentage) => name_of(names::PERCENTAGE, &percentage),
+        Size::Container(container) => name_of(names::CONTAINER, &container),
+        Size::Auto => Some("auto"),
+        Size::Full => Some("full"),
+        Size::Prose => Some("prose"),
+        Size::ScreenWidth if axis == Axis::Width => Some("screen"),
+        Size::ScreenHeight if axis == Axis::Height => Some("screen"),
+        Size::ScreenWidth | Size::ScreenHeight => None,
+        Size::Dvw => Some("dvw"),
+        Size::Dvh => Some("dvh"),
+        Size::Lvw => Some("lvw"),
+        Size::Lvh => Some("lvh"),
+        Size::Svw => Some("svw"),
+        Size::Svh => Some("svh"),
+        Size::MinContent => Some("min"),
+        Size::MaxContent => Some("max"),
+        Size::Fit => Some("fit"),
+        Size::Lh => Some("lh"),
+        Size::Var(var) => return format!("({var})"),
+        Size::HeightVar(var) => return format!("({var})"),
+        Size::Px(px) => return format!("[{px}px]"),
+    };
+    keyword
+        .map(str::to_owned)
+        .unwrap_or_else(|| arbitrary(&size.value()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::DataState;
+    use crate::tokens::{
+        BorderRadius, Breakpoint, ColorValueToken, Easing, FontWeight, Percentage, Scale,
+        SemanticColor, Shadow, Spacing, TextColorVar,
+    };
+    use crate::utilities::{Display, FlexDirection, JustifyContent, Position};
+
+    #[test]
+    fn test_class_string_orders_base_states_and_breakpoints() {
+        let style = Style::new()
+            .md(|s| s.display(Display::Flex))
+            .hover(|s| s.bg(Color::blue(Scale::S600)))
+            .bg(Color::blue(Scale::S500))
+            .p(Spacing::S4);
+
+        assert_eq!(
+            style.to_class_string(),
+            "p-4 bg-blue-500 hover:bg-blue-600 md:flex"
+        );
+        assert_eq!(style.classes().to_string(), style.to_class_string());
+    }
+
+    #[test]
+    fn test_class_string_collapses_sides() {
+        let style = Style::new().px(Spacing::S4).py(Spacing::S2).mt(Spacing::S1);
+        assert_eq!(style.to_class_string(), "px-4 py-2 mt-1");
+
+        let style = Style::new()
+            .pt(Spacing::S1)
+            .pl(Spacing::S3)
+            .neg_mx(Spacing::S2);
+        assert_eq!(style.to_class_string(), "pt-1 pl-3 -mx-2");
+    }
+
+    #[test]
+    fn test_class_string_positions_with_insets() {
+        let style = Style::new()
+            .position(Position::Absolute)
+            .inset_x(Spacing::S0)
+            .top(Percentage::S1_2)
+            .bottom(InsetValue::neg_scale(Spacing::S2));
+        let classes = style.to_class_string();
+
+        assert_eq!(classes, "absolute inset-x-0 top-1/2 -bottom-2");
+        assert_eq!(Style::parse(&classes), Ok(style));
+        assert_eq!(Style::new().inset(Spacing::S4).to_class_string(), "inset-4");
+    }
+
+    #[test]
+    fn test_class_string_compacts_border_sides_and_corners() {
+        let style = Style::new()
+            .rounded(BorderRadius::Md)
+            .rounded_t(BorderRadius::Lg)
+            .rounded_bl(BorderRadius::None)
+            .border_x(BorderWidth::S1)
+            .border_b(BorderWidth::S2);
+        let classes = style.to_class_string();
+
+        assert_eq!(
+            classes,
+            "rounded-md rounded-t-lg rounded-bl-none border-x border-b-2"
+        );
+        assert_eq!(Style::parse(&classes), Ok(style));
+
+        let segment = Style::new()
+            .rounded_l(BorderRadius::Sm)
+            .border_y(BorderWidth::S0);
+        assert_eq!(segment.to_class_string(), "rounded-l-sm border-y-0");
+    }
+
+    #[test]
+    fn test_class_string_uses_arbitrary_and_custom_property_syntax() {
+        let style = Style::new()
+            .p_px(13.0)
+            .bg_arbitrary(ColorValueToken::from_rgb8(0x1e, 0x29, 0x3b))
+            .text_color_var(TextColorVar::new("--my-var"))
+            .w_px_value(240);
+
+        assert_eq!(
+            style.to_class_string(),
+            "p-[13px] w-[240px] bg-[#1e293b] text-(--my-var)"
+        );
+    }
+
+    #[test]
+    fn test_class_string_nested_variants() {
+        let style = Style::new()
+            .data_attr(DataState::Open, |s| s.shadow(Shadow::Lg))
+            .aria_state("selected", |s| s.font_weight(FontWeight::Bold))
+            .responsive(Breakpoint::Lg, |s| s.hover(|s| s.opacity(0.9)));
+
+        assert_eq!(
+            style.to_class_string(),
+            "data-[state=open]:shadow-lg aria-selected:font-bold lg:hover:opacity-90"
+        );
+    }
+
+    #[test]
+    fn test_class_string_round_trips_through_parse() {
+        let style = Style::new()
+            .display(Display::Flex)
+            .flex_direction(FlexDirection::Col)
+            .justify_content(JustifyContent::Between)
+            .gap(Spacing::S2)
+            .width(crate::utilities::WidthSize::Full)
+            .rounded(BorderRadius::Lg)
+            .border_width(BorderWidth::S1)
+            .text_color_token(TextColor::Semantic(SemanticColor::MutedForeground))
+            .transition_default()
+            .transition_ease(Easing::Out)
+            .focus_visible(|s| s.ring_width(RingWidth::S2))
+            .sm(|s| s.p(Spacing::S6));
+
+        let classes = style.to_class_string();
+        assert_eq!(Style::parse(&classes), Ok(style));
+    }
+
+    #[test]
+    fn test_class_string_writes_grid_placement() {
+        let tile = Style::new().col_span(2).row_start(2).row_end(-1);
+        assert_eq!(tile.to_class_string(), "col-span-2 row-start-2 -row-end-1");
+        assert_eq!(Style::parse("col-span-2 row-start-2 -row-end-1"), Ok(tile));
+
+        let grid = Style::new()
+            .grid_cols_count(3)
+            .grid_flow(crate::utilities::GridFlow::Dense)
+            .col_span_full()
+            .row_start(GridLine::Span(2));
+        assert_eq!(
+            grid.to_class_string(),
+            "grid-cols-3 grid-flow-dense col-span-full row-start-[span_2]"
+        );
+        assert_eq!(Style::parse(&grid.to_class_string()), Ok(grid));
+    }
+
+    #[test]
+    fn test_class_string_writes_text_layout() {
+        let file_name = Style::new().truncate();
+        assert_eq!(
+            file_name.to_class_string(),
+            "overflow-hidden text-ellipsis whitespace-nowrap"
+        );
+        assert_eq!(Style::parse("truncate"), Ok(file_name));
+
+        let excerpt = Style::new().line_clamp(3).break_words();
+        assert_eq!(excerpt.to_class_string(), "break-words line-clamp-3");
+        assert_eq!(Style::parse("line-clamp-3 break-words"), Ok(excerpt));
+        assert_eq!(
+            Style::parse("md:line-clamp-none whitespace-pre-line"),
+            Ok(Style::new()
+                .whitespace(crate::tokens::WhiteSpace::PreLine)
+                .md(|s| s.line_clamp_none()))
+        );
+    }
+
+    #[test]
+    fn test_empty_style_serializes_to_empty_string() {
+        assert_eq!(Style::new().to_class_string(), "");
+    }
+}
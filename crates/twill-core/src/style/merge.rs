@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
 
 use super::Style;
-use crate::tokens::Breakpoint;
+use crate::tokens::{Breakpoint, Filter, Gradient, GradientStop};
 use crate::traits::{IntoStyle, Merge};
 use crate::utilities::{
-    Display, FlexContainer, GridContainer, Height, Margin, Padding, SizeConstraints, Width,
+    BorderRadii, BorderWidths, Display, FlexContainer, GridContainer, GridPlacement, Height, Inset,
+    Margin, Padding, SizeConstraints, Transform, Width,
 };
 
 fn merge_flex_container(
@@ -40,6 +41,22 @@ fn merge_grid_container(
             col_gap: override_value.col_gap.or(base.col_gap),
             justify: override_value.justify.or(base.justify),
             align: override_value.align.or(base.align),
+            flow: override_value.flow.or(base.flow),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_grid_placement(
+    base: Option<GridPlacement>,
+    override_value: Option<GridPlacement>,
+) -> Option<GridPlacement> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(GridPlacement {
+            start: override_value.start.or(base.start),
+            end: override_value.end.or(base.end),
         }),
         (None, Some(override_value)) => Some(override_value),
         (Some(base), None) => Some(base),
@@ -75,6 +92,121 @@ fn merge_margin(base: Option<Margin>, override_value: Option<Margin>) -> Option<
     }
 }
 
+fn merge_inset(base: Option<Inset>, override_value: Option<Inset>) -> Option<Inset> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(Inset {
+            top: override_value.top.or(base.top),
+            right: override_value.right.or(base.right),
+            bottom: override_value.bottom.or(base.bottom),
+            left: override_value.left.or(base.left),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_border_widths(
+    base: Option<BorderWidths>,
+    override_value: Option<BorderWidths>,
+) -> Option<BorderWidths> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(BorderWidths {
+            top: override_value.top.or(base.top),
+            right: override_value.right.or(base.right),
+            bottom: override_value.bottom.or(base.bottom),
+            left: override_value.left.or(base.left),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_gradient(base: Option<Gradient>, override_value: Option<Gradient>) -> Option<Gradient> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(Gradient {
+            kind: override_value.kind.or(base.kind),
+            interpolation: override_value.interpolation.or(base.interpolation),
+            from: merge_gradient_stop(base.from, override_value.from),
+            via: merge_gradient_stop(base.via, override_value.via),
+            to: merge_gradient_stop(base.to, override_value.to),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_gradient_stop(
+    base: Option<GradientStop>,
+    override_value: Option<GradientStop>,
+) -> Option<GradientStop> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(GradientStop {
+            color: override_value.color.or(base.color),
+            position: override_value.position.or(base.position),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_filter(base: Option<Filter>, override_value: Option<Filter>) -> Option<Filter> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(Filter {
+            brightness: override_value.brightness.or(base.brightness),
+            contrast: override_value.contrast.or(base.contrast),
+            grayscale: override_value.grayscale.or(base.grayscale),
+            hue_rotate: override_value.hue_rotate.or(base.hue_rotate),
+            invert: override_value.invert.or(base.invert),
+            saturate: override_value.saturate.or(base.saturate),
+            sepia: override_value.sepia.or(base.sepia),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_transform(
+    base: Option<Transform>,
+    override_value: Option<Transform>,
+) -> Option<Transform> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(Transform {
+            translate_x: override_value.translate_x.or(base.translate_x),
+            translate_y: override_value.translate_y.or(base.translate_y),
+            rotate: override_value.rotate.or(base.rotate),
+            scale_x: override_value.scale_x.or(base.scale_x),
+            scale_y: override_value.scale_y.or(base.scale_y),
+            skew_x: override_value.skew_x.or(base.skew_x),
+            skew_y: override_value.skew_y.or(base.skew_y),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
+fn merge_border_radii(
+    base: Option<BorderRadii>,
+    override_value: Option<BorderRadii>,
+) -> Option<BorderRadii> {
+    match (base, override_value) {
+        (Some(base), Some(override_value)) => Some(BorderRadii {
+            top_left: override_value.top_left.or(base.top_left),
+            top_right: override_value.top_right.or(base.top_right),
+            bottom_right: override_value.bottom_right.or(base.bottom_right),
+            bottom_left: override_value.bottom_left.or(base.bottom_left),
+        }),
+        (None, Some(override_value)) => Some(override_value),
+        (Some(base), None) => Some(base),
+        (None, None) => None,
+    }
+}
+
 fn merge_constraints(
     base: Option<SizeConstraints>,
     override_value: Option<SizeConstraints>,
@@ -140,6 +272,7 @@ impl Style {
             display: other.display.or(self.display),
             visibility: other.visibility.or(self.visibility),
             position: other.position.or(self.position),
+            inset: merge_inset(self.inset, other.inset),
             z_index: other.z_index.or(self.z_index),
             overflow: other.overflow.or(self.overflow),
             overflow_x: other.overflow_x.or(self.overflow_x),
@@ -156,20 +289,43 @@ impl Style {
             place_items: other.place_items.or(self.place_items),
             justify_items: other.justify_items.or(self.justify_items),
             justify_self: other.justify_self.or(self.justify_self),
+            grid_column: merge_grid_placement(self.grid_column, other.grid_column),
+            grid_row: merge_grid_placement(self.grid_row, other.grid_row),
             padding: merge_padding(self.padding, other.padding),
             margin: merge_margin(self.margin, other.margin),
+            space_x: other.space_x.or(self.space_x),
+            space_y: other.space_y.or(self.space_y),
             width: other.width.or(self.width),
             height: other.height.or(self.height),
             constraints: merge_constraints(self.constraints, other.constraints),
             background_color: other.background_color.or(self.background_color),
+            background_gradient: merge_gradient(
+                self.background_gradient,
+                other.background_gradient,
+            ),
             opacity: other.opacity.or(self.opacity),
             blur: other.blur.or(self.blur),
+            filter: merge_filter(self.filter, other.filter),
+            backdrop_blur: other.backdrop_blur.or(self.backdrop_blur),
             drop_shadow: other.drop_shadow.or(self.drop_shadow),
             perspective: other.perspective.or(self.perspective),
+            transform: merge_transform(self.transform, other.transform),
+            transform_origin: other.transform_origin.or(self.transform_origin),
             border_radius: other.border_radius.or(self.border_radius),
+            border_radii: merge_border_radii(
+                self.border_radii.filter(|_| other.border_radius.is_none()),
+                other.border_radii,
+            ),
             border_width: other.border_width.or(self.border_width),
+            border_widths: merge_border_widths(
+                self.border_widths.filter(|_| other.border_width.is_none()),
+                other.border_widths,
+            ),
             border_style: other.border_style.or(self.border_style),
             border_color: other.border_color.or(self.border_color),
+            divide_x: other.divide_x.or(self.divide_x),
+            divide_y: other.divide_y.or(self.divide_y),
+            divide_color: other.divide_color.or(self.divide_color),
             outline_width: other.outline_width.or(self.outline_width),
             outline_style: other.outline_style.or(self.outline_style),
             outline_color: other.outline_color.or(self.outline_color),
@@ -186,6 +342,10 @@ impl Style {
             text_align: other.text_align.or(self.text_align),
             text_decoration: other.text_decoration.or(self.text_decoration),
             text_transform: other.text_transform.or(self.text_transform),
+            text_overflow: other.text_overflow.or(self.text_overflow),
+            white_space: other.white_space.or(self.white_space),
+            word_break: other.word_break.or(self.word_break),
+            line_clamp: other.line_clamp.or(self.line_clamp),
             text_color: other.text_color.or(self.text_color),
             text_shadow: other.text_shadow.or(self.text_shadow),
             transition_property: other
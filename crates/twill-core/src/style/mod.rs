@@ -1,12 +1,20 @@
 //! Style module.
 
+mod animation;
+pub(crate) mod class_string;
 mod layers;
 mod merge;
+pub(crate) mod parse;
 mod presets;
+mod resolve;
 pub mod state;
 #[allow(clippy::module_inception)]
 mod style;
 mod theme;
 
-pub use state::{AriaAttr, DataAttr, DataState, StateStyles};
+pub use animation::{Keyframe, Keyframes};
+pub use class_string::StyleClasses;
+pub use parse::{ClassDiagnostic, ClassDiagnosticReason, ParseStyleError};
+pub use resolve::{CustomPropertyValue, ResolveContext};
+pub use state::{AriaAttr, DataAttr, DataState, InteractionState, StateStyles};
 pub use style::Style;
@@ -0,0 +1,1870 @@
+//! Tailwind class-string parsing into [`Style`].
+
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+
+use super::{AriaAttr, DataAttr, Style};
+use crate::tokens::names::{self, intern, lookup};
+use crate::tokens::{
+    AspectRatio, BackgroundColor, BackgroundColorVar, Blur, BorderColor, BorderColorVar,
+    BorderRadius, BorderWidth, Color, ColorValue, ColorValueToken, DivideWidth, DropShadow,
+    FontSize, FontSizeVar, GradientKind, LetterSpacing, LetterSpacingVar, LineHeight,
+    LineHeightVar, LinearDirection, OutlineColor, OutlineColorVar, Perspective, RingColor,
+    RingColorVar, RingWidth, SemanticColor, ShadowColorToken, ShadowColorVar, Spacing, TextColor,
+    TextColorVar, TransitionDuration, TransitionProperty,
+};
+use crate::utilities::{
+    Columns, Flex, GridLine, GridTemplate, HeightSize, HeightVar, InsetValue, MarginValue,
+    MarginVar, PaddingValue, PaddingVar, TransformOrigin, WidthSize, WidthVar,
+};
+
+/// Why a class could not be applied by [`Style::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClassDiagnosticReason {
+    /// The variant prefix (for example `group-hover:`) is not supported.
+    UnknownVariant(String),
+    /// The utility name is not recognized.
+    UnknownUtility,
+    /// The utility is recognized but its value is not.
+    InvalidValue(String),
+}
+
+impl fmt::Display for ClassDiagnosticReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVariant(variant) => write!(f, "unknown variant `{variant}:`"),
+            Self::UnknownUtility => f.write_str("unknown utility"),
+            Self::InvalidValue(value) => write!(f, "invalid value `{value}`"),
+        }
+    }
+}
+
+/// A class from a class string that could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassDiagnostic {
+    class: String,
+    span: Range<usize>,
+    reason: ClassDiagnosticReason,
+}
+
+impl ClassDiagnostic {
+    /// The rejected class, including its variant prefixes.
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+
+    /// Byte range of the class within the parsed input.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Why the class was rejected.
+    pub fn reason(&self) -> &ClassDiagnosticReason {
+        &self.reason
+    }
+}
+
+impl fmt::Display for ClassDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at {}..{}: {}",
+            self.class, self.span.start, self.span.end, self.reason
+        )
+    }
+}
+
+/// Error returned by [`Style::parse`] when one or more classes were rejected.
+///
+/// The style built from the accepted classes is kept and available through
+/// [`ParseStyleError::partial_style`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseStyleError {
+    style: Box<Style>,
+    diagnostics: Vec<ClassDiagnostic>,
+}
+
+impl ParseStyleError {
+    /// The rejected classes, in the order they appear in the input.
+    pub fn diagnostics(&self) -> &[ClassDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Style built from every class that was accepted.
+    pub fn partial_style(&self) -> &Style {
+        &self.style
+    }
+
+    /// Split the error into the partial style and the diagnostics, like
+    /// [`Style::parse_lossy`] returns them.
+    pub fn into_parts(self) -> (Style, Vec<ClassDiagnostic>) {
+        (*self.style, self.diagnostics)
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} unsupported class(es)", self.diagnostics.len())?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "; {diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseStyleError {}
+
+impl Style {
+    /// Parse a Tailwind class string such as `"p-4 bg-blue-500 hover:bg-blue-600 md:flex"`.
+    ///
+    /// State variants (`hover:`, `focus-visible:`, `data-[state=open]:`, `aria-selected:`)
+    /// land in the state layers and breakpoint variants (`sm:` ... `2xl:`) in the
+    /// responsive layers. Later classes win, like in a Tailwind class list.
+    ///
+    /// Opacity modifiers such as `bg-blue-500/50` apply to palette colors only: a semantic
+    /// color is only known once a theme resolves it, so `bg-primary/50` is rejected.
+    ///
+    /// Custom properties such as `p-(--gutter)` become `&'static str` tokens: each
+    /// distinct name is interned and leaked once for the lifetime of the process, so
+    /// class strings with an unbounded number of distinct names should not be parsed in a
+    /// long-running process.
+    ///
+    /// ```rust
+    /// use twill_core::prelude::core::*;
+    ///
+    /// let style = Style::parse("p-4 bg-blue-500 hover:bg-blue-600 md:flex").unwrap();
+    ///
+    /// assert_eq!(style, Style::new()
+    ///     .p(Spacing::S4)
+    ///     .bg(Color::blue(Scale::S500))
+    ///     .hover(|s| s.bg(Color::blue(Scale::S600)))
+    ///     .md(|s| s.display(Display::Flex)));
+    /// assert!(Style::parse("p-4 group-hover:p-2").is_err());
+    /// ```
+    pub fn parse(classes: &str) -> Result<Self, ParseStyleError> {
+        let (style, diagnostics) = Self::parse_lossy(classes);
+        if diagnostics.is_empty() {
+            Ok(style)
+        } else {
+            Err(ParseStyleError {
+                style: Box::new(style),
+                diagnostics,
+            })
+        }
+    }
+
+    /// Parse a class string, keeping every accepted class and reporting the rest.
+    ///
+    /// Like [`Style::parse`], this interns and leaks each distinct `(--name)` custom
+    /// property for the lifetime of the process.
+    pub fn parse_lossy(classes: &str) -> (Self, Vec<ClassDiagnostic>) {
+        let mut style = Style::new();
+        let mut diagnostics = Vec::new();
+        let mut offset = 0;
+
+        for class in classes.split_whitespace() {
+            let start = offset + classes[offset..].find(class).unwrap_or(0);
+            offset = start + class.len();
+
+            match apply_class(style.clone(), class) {
+                Ok(next) => style = next,
+                Err(reason) => diagnostics.push(ClassDiagnostic {
+                    class: class.to_owned(),
+                    span: start..offset,
+                    reason,
+                }),
+            }
+        }
+
+        (style, diagnostics)
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(classes: &str) -> Result<Self, Self::Err> {
+        Self::parse(classes)
+    }
+}
+
+pub(crate) type Reason = ClassDiagnosticReason;
+
+pub(crate) fn invalid(value: &str) -> Reason {
+    Reason::InvalidValue(value.to_owned())
+}
+
+/// Apply one class (variants included) the way the equivalent builder chain would.
+fn apply_class(style: Style, class: &str) -> Result<Style, Reason> {
+    let mut parts = split_top_level(class, ':');
+    let utility = parts.pop().unwrap_or_default();
+    apply_variants(style, &parts, utility)
+}
+
+/// Split on `separator`, ignoring separators nested in `[...]` or `(...)`.
+fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, ch) in input.char_indices() {
+        match ch {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            _ if ch == separator && depth == 0 => {
+                parts.push(&input[start..index]);
+                start = index + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&input[start..]);
+    parts
+}
+
+/// Apply `utility` inside the layer selected by `variants`, outermost variant first.
+fn apply_variants(style: Style, variants: &[&str], utility: &str) -> Result<Style, Reason> {
+    let Some((&variant, rest)) = variants.split_first() else {
+        return apply_utility(style, utility);
+    };
+
+    let mut outcome = Ok(());
+    let nested = |current: Style| {
+        apply_variants(current, rest, utility).unwrap_or_else(|reason| {
+            outcome = Err(reason);
+            Style::new()
+        })
+    };
+
+    let style = match variant {
+        "hover" => style.hover(nested),
+        "focus" => style.focus(nested),
+        "focus-visible" => style.focus_visible(nested),
+        "active" => style.active(nested),
+        "disabled" => style.disabled(nested),
+        "selected" => style.selected(nested),
+        "checked" => style.checked(nested),
+        "open" => style.open(nested),
+        "closed" => style.closed(nested),
+        _ => {
+            if let Some(breakpoint) = lookup(names::BREAKPOINT, variant) {
+                style.responsive(breakpoint, nested)
+            } else if let Some(selector) = variant.strip_prefix("data-") {
+                let selector = bracketed(selector).unwrap_or(selector);
+                style.data_attr(DataAttr::custom(selector), nested)
+            } else if let Some(name) = variant.strip_prefix("aria-") {
+                style.aria_attr(aria_attr(name), nested)
+            } else {
+                return Err(Reason::UnknownVariant(variant.to_owned()));
+            }
+        }
+    };
+
+    outcome.map(|()| style)
+}
+
+/// The `aria-*` hook of an `aria-expanded` or `aria-[expanded=true]` variant.
+///
+/// `aria-<name>` matches `aria-<name>="true"` in Tailwind, so both spellings select the
+/// same typed hook; other bracketed selectors such as `aria-[sort=ascending]` stay custom.
+fn aria_attr(raw: &str) -> AriaAttr {
+    let name = bracketed(raw).map_or(raw, |selector| {
+        selector
+            .strip_suffix("=true")
+            .or_else(|| selector.strip_suffix("=\"true\""))
+            .unwrap_or(selector)
+    });
+    match name {
+        "selected" => AriaAttr::Selected,
+        "checked" => AriaAttr::Checked,
+        "expanded" => AriaAttr::Expanded,
+        "pressed" => AriaAttr::Pressed,
+        "disabled" => AriaAttr::Disabled,
+        "hidden" => AriaAttr::Hidden,
+        "invalid" => AriaAttr::Invalid,
+        "current" => AriaAttr::Current,
+        _ => AriaAttr::custom(name),
+    }
+}
+
+pub(crate) fn bracketed(raw: &str) -> Option<&str> {
+    raw.strip_prefix('[')?.strip_suffix(']')
+}
+
+/// Utility value syntax: `p-4`, `p-[13px]` or `p-(--gutter)`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Value<'a> {
+    Named(&'a str),
+    Arbitrary(&'a str),
+    Var {
+        hint: Option<&'a str>,
+        name: &'a str,
+    },
+}
+
+pub(crate) fn value(raw: &str) -> Value<'_> {
+    if let Some(inner) = bracketed(raw) {
+        return Value::Arbitrary(inner);
+    }
+    if let Some(inner) = raw.strip_prefix('(').and_then(|raw| raw.strip_suffix(')')) {
+        let (hint, name) = match inner.split_once(':') {
+            Some((hint, name)) => (Some(hint), name),
+            None => (None, inner),
+        };
+        return Value::Var { hint, name };
+    }
+    Value::Named(raw)
+}
+
+/// Resolve a `(--name)` value to an interned custom-property name.
+///
+/// Only `--` followed by letters, digits, `-` and `_` is a name; anything else is
+/// rejected before it is interned.
+pub(crate) fn custom_property(name: &str) -> Option<&'static str> {
+    let ident = name.strip_prefix("--")?;
+    let valid = !ident.is_empty()
+        && ident
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_'));
+    valid.then(|| intern(name))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Length {
+    Px(f32),
+    Rem(f32),
+}
+
+impl Length {
+    pub(crate) fn to_px(self) -> f32 {
+        match self {
+            Self::Px(px) => px,
+            Self::Rem(rem) => rem * 16.0,
+        }
+    }
+}
+
+pub(crate) fn length(raw: &str) -> Option<Length> {
+    let parse = |number: &str| number.parse::<f32>().ok().filter(|value| value.is_finite());
+    if let Some(number) = raw.strip_suffix("px") {
+        parse(number).map(Length::Px)
+    } else if let Some(number) = raw.strip_suffix("rem") {
+        parse(number).map(Length::Rem)
+    } else if raw == "0" {
+        Some(Length::Px(0.0))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn px_u16(raw: &str) -> Option<u16> {
+    let px = length(raw)?.to_px();
+    (0.0..=u16::MAX as f32)
+        .contains(&px)
+        .then(|| px.round() as u16)
+}
+
+pub(crate) fn number<T: FromStr>(raw: &str) -> Result<T, Reason> {
+    raw.parse().map_err(|_| invalid(raw))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ColorSpec {
+    Inherit,
+    Current,
+    Transparent,
+    Palette(Color),
+    Semantic(SemanticColor),
+    Var(&'static str),
+    Arbitrary(ColorValueToken),
+}
+
+pub(crate) fn color(raw: &str) -> Result<ColorSpec, Reason> {
+    match value(raw) {
+        Value::Arbitrary(inner) => {
+            let inner = inner.strip_prefix("color:").unwrap_or(inner);
+            ColorValueToken::from_hex(inner)
+                .map(ColorSpec::Arbitrary)
+                .ok_or_else(|| invalid(raw))
+        }
+        Value::Var { hint, name } => match hint {
+            None | Some("color") => custom_property(name)
+                .map(ColorSpec::Var)
+                .ok_or_else(|| invalid(raw)),
+            Some(_) => Err(invalid(raw)),
+        },
+        Value::Named(named) => {
+            let (named, alpha) = match named.split_once('/') {
+                Some((named, alpha)) => (named, Some(number::<u8>(alpha)?.min(100))),
+                None => (named, None),
+            };
+            let spec = named_color(named).ok_or_else(|| invalid(raw))?;
+            match (spec, alpha) {
+                (spec, None) => Ok(spec),
+                (ColorSpec::Palette(color), Some(alpha)) => {
+                    let value = ColorValue::from_color(color).with_alpha(alpha as f32 / 100.0);
+                    Ok(ColorSpec::Arbitrary(value.into()))
+                }
+                _ => Err(invalid(raw)),
+            }
+        }
+    }
+}
+
+fn named_color(named: &str) -> Option<ColorSpec> {
+    match named {
+        "inherit" => return Some(ColorSpec::Inherit),
+        "current" => return Some(ColorSpec::Current),
+        "transparent" => return Some(ColorSpec::Transparent),
+        "black" => return Some(ColorSpec::Palette(Color::black())),
+        "white" => return Some(ColorSpec::Palette(Color::white())),
+        _ => {}
+    }
+
+    if let Some(semantic) = SemanticColor::ALL
+        .into_iter()
+        .find(|semantic| semantic.var_name() == named)
+    {
+        return Some(ColorSpec::Semantic(semantic));
+    }
+
+    let (family, scale) = named.rsplit_once('-')?;
+    Some(ColorSpec::Palette(Color::new(
+        lookup(names::COLOR_FAMILY, family)?,
+        lookup(names::SCALE, scale)?,
+    )))
+}
+
+macro_rules! color_token {
+    ($spec:expr, $token:ident, $var:ident) => {
+        match $spec {
+            ColorSpec::Inherit => $token::inherit(),
+            ColorSpec::Current => $token::current(),
+            ColorSpec::Transparent => $token::transparent(),
+            ColorSpec::Palette(color) => $token::palette(color),
+            ColorSpec::Semantic(color) => $token::semantic(color),
+            ColorSpec::Var(name) => $token::custom_property($var::new(name)),
+            ColorSpec::Arbitrary(value) => $token::arbitrary(value),
+        }
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use color_token;
+
+/// Parse the `linear-to-r`, `linear-45`, `radial` or `conic-180` part of a gradient class,
+/// negating angles for `-bg-linear-45`.
+pub(crate) fn gradient_kind(raw: &str, negative: bool) -> Result<GradientKind, Reason> {
+    let angle = |degrees: &str| {
+        let degrees = i32::from(number::<u16>(degrees)?);
+        Ok(if negative { -degrees } else { degrees })
+    };
+    if let Some(direction) = raw.strip_prefix("linear-") {
+        if let Some(direction) = lookup(names::LINEAR_DIRECTION, direction)
+            && !negative
+        {
+            return Ok(GradientKind::Linear(direction));
+        }
+        return Ok(GradientKind::Linear(LinearDirection::angle(angle(
+            direction,
+        )?)));
+    }
+    if let Some(degrees) = raw.strip_prefix("conic-") {
+        return Ok(GradientKind::conic(angle(degrees)?));
+    }
+    match raw {
+        "radial" if !negative => Ok(GradientKind::Radial),
+        "conic" if !negative => Ok(GradientKind::Conic(0)),
+        _ => Err(invalid(raw)),
+    }
+}
+
+/// A filter amount in percent: `brightness-125`, `brightness-[125%]` or
+/// `brightness-[1.25]`.
+pub(crate) fn filter_amount(raw: &str) -> Result<u16, Reason> {
+    match value(raw) {
+        Value::Named(named) => number(named),
+        Value::Arbitrary(inner) => match inner.strip_suffix('%') {
+            Some(percent) => number(percent).map_err(|_| invalid(raw)),
+            None => {
+                let amount = number::<f32>(inner).map_err(|_| invalid(raw))? * 100.0;
+                (0.0..=f32::from(u16::MAX))
+                    .contains(&amount)
+                    .then(|| amount.round() as u16)
+                    .ok_or_else(|| invalid(raw))
+            }
+        },
+        Value::Var { .. } => Err(invalid(raw)),
+    }
+}
+
+/// An angle in degrees: `hue-rotate-90`, `rotate-45` or `skew-x-[30deg]`.
+pub(crate) fn degrees(raw: &str, negative: bool) -> Result<i16, Reason> {
+    let degrees: i16 = match value(raw) {
+        Value::Named(named) => number(named)?,
+        Value::Arbitrary(inner) => {
+            number(inner.strip_suffix("deg").unwrap_or(inner)).map_err(|_| invalid(raw))?
+        }
+        Value::Var { .. } => return Err(invalid(raw)),
+    };
+    Ok(if negative { -degrees } else { degrees })
+}
+
+/// A scale in percent: `scale-105`, `scale-x-[150%]` or `scale-[1.5]`.
+pub(crate) fn scale_amount(raw: &str, negative: bool) -> Result<i16, Reason> {
+    let percent = i16::try_from(filter_amount(raw)?).map_err(|_| invalid(raw))?;
+    Ok(if negative { -percent } else { percent })
+}
+
+/// A translation: the inset values without `auto`.
+pub(crate) fn translate_value(raw: &str, negative: bool) -> Result<InsetValue, Reason> {
+    match inset_value(raw, negative)? {
+        InsetValue::Auto => Err(invalid(raw)),
+        value => Ok(value),
+    }
+}
+
+/// A `leading-<number>` height on the spacing scale, such as `leading-6`.
+pub(crate) fn line_height_spacing(raw: &str) -> Option<LineHeight> {
+    lookup(names::SPACING, raw)
+        .filter(|spacing| !matches!(spacing, Spacing::Auto))
+        .map(LineHeight::Spacing)
+}
+
+/// A transform origin: `origin-top-left` or `origin-[25%_75%]`.
+pub(crate) fn transform_origin(raw: &str) -> Result<TransformOrigin, Reason> {
+    match value(raw) {
+        Value::Named(named) => table(names::TRANSFORM_ORIGIN, named),
+        Value::Arbitrary(inner) => {
+            let percent = |raw: &str| raw.strip_suffix('%').and_then(|raw| raw.parse().ok());
+            inner
+                .split_once('_')
+                .and_then(|(x, y)| Some(TransformOrigin::Percent(percent(x)?, percent(y)?)))
+                .ok_or_else(|| invalid(raw))
+        }
+        Value::Var { .. } => Err(invalid(raw)),
+    }
+}
+
+pub(crate) fn padding_value(raw: &str) -> Result<PaddingValue, Reason> {
+    let parsed = match value(raw) {
+        Value::Named("auto") => None,
+        Value::Named(named) => lookup(names::SPACING, named).map(PaddingValue::Scale),
+        Value::Arbitrary(inner) => match length(inner) {
+            Some(Length::Px(px)) => Some(PaddingValue::Px(px)),
+            Some(Length::Rem(rem)) => Some(PaddingValue::Rem(rem)),
+            None => None,
+        },
+        Value::Var { hint: None, name } => {
+            custom_property(name).map(|name| PaddingValue::Var(PaddingVar::new(name)))
+        }
+        Value::Var { .. } => None,
+    };
+    parsed.ok_or_else(|| invalid(raw))
+}
+
+pub(crate) fn margin_value(raw: &str, negative: bool) -> Result<MarginValue, Reason> {
+    let parsed = match value(raw) {
+        Value::Named("auto") if !negative => Some(MarginValue::Auto),
+        Value::Named("auto") => None,
+        Value::Named(named) => lookup(names::SPACING, named).map(|spacing| {
+            if negative {
+                MarginValue::NegativeScale(spacing)
+            } else {
+                MarginValue::Scale(spacing)
+            }
+        }),
+        Value::Arbitrary(inner) => {
+            let sign = if negative { -1.0 } else { 1.0 };
+            match length(inner) {
+                Some(Length::Px(px)) => Some(MarginValue::Px(px * sign)),
+                Some(Length::Rem(rem)) => Some(MarginValue::Rem(rem * sign)),
+                None => None,
+            }
+        }
+        Value::Var { hint: None, name } if !negative => {
+            custom_property(name).map(|name| MarginValue::Var(MarginVar::new(name)))
+        }
+        Value::Var { .. } => None,
+    };
+    parsed.ok_or_else(|| invalid(raw))
+}
+
+pub(crate) fn inset_value(raw: &str, negative: bool) -> Result<InsetValue, Reason> {
+    let parsed = match value(raw) {
+        Value::Named("auto") if !negative => Some(InsetValue::Auto),
+        Value::Named("auto") => None,
+        Value::Named(named) => {
+            if let Some(spacing) = lookup(names::SPACING, named) {
+                Some(if negative {
+                    InsetValue::NegativeScale(spacing)
+                } else {
+                    InsetValue::Scale(spacing)
+                })
+            } else {
+                lookup(names::PERCENTAGE, named)
+                    .filter(|fraction| fraction.to_ratio().is_some())
+                    .map(|fraction| {
+                        if negative {
+                            InsetValue::NegativeFraction(fraction)
+                        } else {
+                            InsetValue::Fraction(fraction)
+                        }
+                    })
+            }
+        }
+        Value::Arbitrary(inner) => {
+            let sign = if negative { -1.0 } else { 1.0 };
+            match inner.strip_suffix('%') {
+                Some(percent) => percent
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|percent| percent.is_finite())
+                    .map(|percent| InsetValue::Percent(percent * sign)),
+                None => length(inner).map(|length| InsetValue::Px(length.to_px() * sign)),
+            }
+        }
+        Value::Var { .. } => None,
+    };
+    parsed.ok_or_else(|| invalid(raw))
+}
+
+pub(crate) fn width_size(raw: &str) -> Result<WidthSize, Reason> {
+    let parsed = match value(raw) {
+        Value::Named(named) => match named {
+            "auto" => Some(WidthSize::Auto),
+            "full" => Some(WidthSize::Full),
+            "prose" => Some(WidthSize::Prose),
+            "screen" => Some(WidthSize::ScreenWidth),
+            "dvw" => Some(WidthSize::Dvw),
+            "dvh" => Some(WidthSize::Dvh),
+            "lvw" => Some(WidthSize::Lvw),
+            "lvh" => Some(WidthSize::Lvh),
+            "svw" => Some(WidthSize::Svw),
+            "svh" => Some(WidthSize::Svh),
+            "min" => Some(WidthSize::MinContent),
+            "max" => Some(WidthSize::MaxContent),
+            "fit" => Some(WidthSize::Fit),
+            "lh" => Some(WidthSize::Lh),
+            _ => lookup(names::SPACING, named)
+                .map(WidthSize::Spacing)
+                .or_else(|| lookup(names::PERCENTAGE, named).map(WidthSize::Percentage))
+                .or_else(|| lookup(names::CONTAINER, named).map(WidthSize::Container)),
+        },
+        Value::Arbitrary(inner) => px_u16(inner).map(WidthSize::Px),
+        Value::Var { hint: None, name } => {
+            custom_property(name).map(|name| WidthSize::Var(WidthVar::new(name)))
+        }
+        Value::Var { .. } => None,
+    };
+    parsed.ok_or_else(|| invalid(raw))
+}
+
+pub(crate) fn height_size(raw: &str) -> Result<HeightSize, Reason> {
+    let parsed = match value(raw) {
+        Value::Named(named) => match named {
+            "auto" => Some(HeightSize::Auto),
+            "full" => Some(HeightSize::Full),
+            "prose" => Some(HeightSize::Prose),
+            "screen" => Some(HeightSize::ScreenHeight),
+            "dvw" => Some(HeightSize::Dvw),
+            "dvh" => Some(HeightSize::Dvh),
+            "lvw" => Some(HeightSize::Lvw),
+            "lvh" => Some(HeightSize::Lvh),
+            "svw" => Some(HeightSize::Svw),
+            "svh" => Some(HeightSize::Svh),
+            "min" => Some(HeightSize::MinContent),
+            "max" => Some(HeightSize::MaxContent),
+            "fit" => Some(HeightSize::Fit),
+            "lh" => Some(HeightSize::Lh),
+            _ => lookup(names::SPACING, named)
+                .map(HeightSize::Spacing)
+                .or_else(|| lookup(names::PERCENTAGE, named).map(HeightSize::Percentage))
+                .or_else(|| lookup(names::CONTAINER, named).map(HeightSize::Container)),
+        },
+        Value::Arbitrary(inner) => px_u16(inner).map(HeightSize::Px),
+        Value::Var { hint: None, name } => {
+            custom_property(name).map(|name| HeightSize::Var(HeightVar::new(name)))
+        }
+        Value::Var { .. } => None,
+    };
+    parsed.ok_or_else(|| invalid(raw))
+}
+
+pub(crate) fn duration(raw: &str) -> Result<TransitionDuration, Reason> {
+    if let Some(duration) = lookup(names::TRANSITION_DURATION, raw) {
+        return Ok(duration);
+    }
+    let millis = match value(raw) {
+        Value::Named(named) => named,
+        Value::Arbitrary(inner) => inner.strip_suffix("ms").ok_or_else(|| invalid(raw))?,
+        Value::Var { .. } => return Err(invalid(raw)),
+    };
+    Ok(TransitionDuration::CustomMs(
+        number(millis).map_err(|_| invalid(raw))?,
+    ))
+}
+
+pub(crate) fn flex_value(raw: &str) -> Result<Flex, Reason> {
+    match (raw, value(raw)) {
+        ("auto", _) => Ok(Flex::Auto),
+        ("initial", _) => Ok(Flex::Initial),
+        ("none", _) => Ok(Flex::None),
+        (_, Value::Arbitrary(inner)) => Ok(Flex::arbitrary(inner)),
+        (_, Value::Var { hint: None, name }) if name.starts_with("--") => {
+            Ok(Flex::custom_property(name))
+        }
+        (_, Value::Var { .. }) => Err(invalid(raw)),
+        (_, Value::Named(named)) => match named.split_once('/') {
+            Some((numerator, denominator)) => {
+                Ok(Flex::fraction(number(numerator)?, number(denominator)?))
+            }
+            None => Ok(Flex::number(number(named)?)),
+        },
+    }
+}
+
+pub(crate) fn columns_value(raw: &str) -> Result<Columns, Reason> {
+    if raw == "auto" {
+        return Ok(Columns::auto());
+    }
+    if let Some(container) = lookup(names::CONTAINER, raw) {
+        return Ok(Columns::width(container));
+    }
+    if let Some(inner) = bracketed(raw) {
+        let px = length(inner).ok_or_else(|| invalid(raw))?.to_px();
+        return Ok(Columns::width_px(px));
+    }
+    Ok(Columns::count(number(raw)?))
+}
+
+/// A grid line: `auto`, `3`, `span-2` or `[span_2]`. Line `0` does not exist.
+pub(crate) fn grid_line(raw: &str) -> Result<GridLine, Reason> {
+    if raw == "auto" {
+        return Ok(GridLine::Auto);
+    }
+    let line = bracketed(raw).unwrap_or(raw);
+    if let Some(span) = line
+        .strip_prefix("span-")
+        .or_else(|| line.strip_prefix("span_"))
+    {
+        return match number::<u16>(span).map_err(|_| invalid(raw))? {
+            0 => Err(invalid(raw)),
+            span => Ok(GridLine::Span(span)),
+        };
+    }
+    match number::<i16>(line).map_err(|_| invalid(raw))? {
+        0 => Err(invalid(raw)),
+        line => Ok(GridLine::Line(line)),
+    }
+}
+
+/// A placement span: `col-span-2` or `col-span-full`.
+fn grid_span(raw: &str) -> Result<Option<u16>, Reason> {
+    match raw {
+        "full" => Ok(None),
+        _ => match number::<u16>(raw)? {
+            0 => Err(invalid(raw)),
+            span => Ok(Some(span)),
+        },
+    }
+}
+
+pub(crate) fn grid_template(raw: &str) -> Result<GridTemplate, Reason> {
+    match (raw, value(raw)) {
+        ("none", _) => Ok(GridTemplate::none()),
+        ("subgrid", _) => Ok(GridTemplate::subgrid()),
+        (_, Value::Arbitrary(inner)) => Ok(GridTemplate::arbitrary(inner)),
+        (_, Value::Var { hint: None, name }) if name.starts_with("--") => {
+            Ok(GridTemplate::custom_property(name))
+        }
+        (_, Value::Var { .. }) => Err(invalid(raw)),
+        (_, Value::Named(named)) => match number::<u16>(named)? {
+            0 => Err(invalid(raw)),
+            count => Ok(GridTemplate::count(count)),
+        },
+    }
+}
+
+pub(crate) fn table<T: Copy>(table: &[(&'static str, T)], raw: &str) -> Result<T, Reason> {
+    lookup(table, raw).ok_or_else(|| invalid(raw))
+}
+
+type PaddingSetter = fn(Style, PaddingValue) -> Style;
+type MarginSetter = fn(Style, MarginValue) -> Style;
+type InsetSetter = fn(Style, InsetValue) -> Style;
+type BorderSideSetter = fn(Style, BorderWidth) -> Style;
+type BorderCornerSetter = fn(Style, BorderRadius) -> Style;
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const PADDING_PREFIXES: &[(&str, PaddingSetter)] = &[
+    ("pbs", Style::pbs_value),
+    ("pbe", Style::pbe_value),
+    ("px", Style::px_value),
+    ("py", Style::py_value),
+    ("ps", Style::ps_value),
+    ("pe", Style::pe_value),
+    ("pt", Style::pt_value),
+    ("pr", Style::pr_value),
+    ("pb", Style::pb_value),
+    ("pl", Style::pl_value),
+    ("p", Style::p_value),
+];
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const INSET_PREFIXES: &[(&str, InsetSetter)] = &[
+    ("inset-x", |style, value| style.inset_x(value)),
+    ("inset-y", |style, value| style.inset_y(value)),
+    ("inset", |style, value| style.inset(value)),
+    ("top", |style, value| style.top(value)),
+    ("right", |style, value| style.right(value)),
+    ("bottom", |style, value| style.bottom(value)),
+    ("left", |style, value| style.left(value)),
+];
+
+const BORDER_SIDE_PREFIXES: &[(&str, BorderSideSetter)] = &[
+    ("border-x", Style::border_x),
+    ("border-y", Style::border_y),
+    ("border-t", Style::border_t),
+    ("border-r", Style::border_r),
+    ("border-b", Style::border_b),
+    ("border-l", Style::border_l),
+];
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const BORDER_CORNER_PREFIXES: &[(&str, BorderCornerSetter)] = &[
+    ("rounded-tl", Style::rounded_tl),
+    ("rounded-tr", Style::rounded_tr),
+    ("rounded-br", Style::rounded_br),
+    ("rounded-bl", Style::rounded_bl),
+    ("rounded-t", Style::rounded_t),
+    ("rounded-r", Style::rounded_r),
+    ("rounded-b", Style::rounded_b),
+    ("rounded-l", Style::rounded_l),
+    ("rounded-s", Style::rounded_s),
+    ("rounded-e", Style::rounded_e),
+];
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const MARGIN_PREFIXES: &[(&str, MarginSetter)] = &[
+    ("mbs", Style::mbs_value),
+    ("mbe", Style::mbe_value),
+    ("mx", Style::mx_value),
+    ("my", Style::my_value),
+    ("ms", Style::ms_value),
+    ("me", Style::me_value),
+    ("mt", Style::mt_value),
+    ("mr", Style::mr_value),
+    ("mb", Style::mb_value),
+    ("ml", Style::ml_value),
+    ("m", Style::m_value),
+];
+
+type GradientColorSetter = fn(Style, BackgroundColor) -> Style;
+type GradientPositionSetter = fn(Style, u8) -> Style;
+
+const GRADIENT_STOP_PREFIXES: &[(&str, GradientColorSetter, GradientPositionSetter)] = &[
+    (
+        "from",
+        Style::gradient_from_token,
+        Style::gradient_from_position,
+    ),
+    (
+        "via",
+        Style::gradient_via_token,
+        Style::gradient_via_position,
+    ),
+    ("to", Style::gradient_to_token, Style::gradient_to_position),
+];
+
+type FilterSetter = fn(Style, u16) -> Style;
+
+const FILTER_PREFIXES: &[(&str, FilterSetter)] = &[
+    ("brightness", Style::brightness),
+    ("contrast", Style::contrast),
+    ("grayscale", Style::grayscale),
+    ("invert", Style::invert),
+    ("saturate", Style::saturate),
+    ("sepia", Style::sepia),
+];
+
+type AngleSetter = fn(Style, i16) -> Style;
+
+const ANGLE_PREFIXES: &[(&str, AngleSetter)] = &[
+    ("hue-rotate", Style::hue_rotate),
+    ("rotate", Style::rotate),
+    ("skew-x", Style::skew_x),
+    ("skew-y", Style::skew_y),
+];
+
+type GridLineSetter = fn(Style, GridLine) -> Style;
+
+const GRID_LINE_PREFIXES: &[(&str, GridLineSetter)] = &[
+    ("col-start", |style, line| style.col_start(line)),
+    ("col-end", |style, line| style.col_end(line)),
+    ("row-start", |style, line| style.row_start(line)),
+    ("row-end", |style, line| style.row_end(line)),
+];
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const TRANSLATE_PREFIXES: &[(&str, InsetSetter)] = &[
+    ("translate-x", |style, value| style.translate_x(value)),
+    ("translate-y", |style, value| style.translate_y(value)),
+    ("translate", |style, value| {
+        style.translate_x(value).translate_y(value)
+    }),
+];
+
+type ScaleSetter = fn(Style, i16) -> Style;
+
+/// Ordered so that longer prefixes are tried before their shorter counterparts.
+const SCALE_PREFIXES: &[(&str, ScaleSetter)] = &[
+    ("scale-x", Style::scale_x),
+    ("scale-y", Style::scale_y),
+    ("scale", Style::scale),
+];
+
+fn utility_value<'a>(utility: &'a str, prefix: &str) -> Option<&'a str> {
+    utility.strip_prefix(prefix)?.strip_prefix('-')
+}
+
+fn apply_negative(style: Style, utility: &str) -> Result<Style, Reason> {
+    if let Some(raw) = utility_value(utility, "bg")
+        && let Some(applied) = apply_gradient_kind(style.clone(), raw, true)
+    {
+        return applied;
+    }
+    for (prefix, setter) in MARGIN_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, margin_value(raw, true)?));
+        }
+    }
+    for (prefix, setter) in INSET_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, inset_value(raw, true)?));
+        }
+    }
+    for (prefix, setter) in ANGLE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, degrees(raw, true)?));
+        }
+    }
+    for (prefix, setter) in TRANSLATE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, translate_value(raw, true)?));
+        }
+    }
+    for (prefix, setter) in SCALE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, scale_amount(raw, true)?));
+        }
+    }
+    for (prefix, setter) in GRID_LINE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return match grid_line(raw)? {
+                GridLine::Line(line) if line > 0 => Ok(setter(style, GridLine::Line(-line))),
+                _ => Err(invalid(raw)),
+            };
+        }
+    }
+    Err(Reason::UnknownUtility)
+}
+
+/// Apply `bg-linear-*`, `bg-radial` or `bg-conic-*` with an optional `/srgb` or `/oklch`
+/// modifier, or return `None` when `raw` is not a gradient shape.
+fn apply_gradient_kind(style: Style, raw: &str, negative: bool) -> Option<Result<Style, Reason>> {
+    let (kind, interpolation) = match raw.split_once('/') {
+        Some((kind, interpolation)) => (kind, Some(interpolation)),
+        None => (raw, None),
+    };
+    if !["linear", "radial", "conic"]
+        .into_iter()
+        .any(|name| kind == name || utility_value(kind, name).is_some())
+    {
+        return None;
+    }
+    let apply = || {
+        let style = style.bg_gradient(gradient_kind(kind, negative)?);
+        match interpolation {
+            Some(raw) => {
+                Ok(style.bg_gradient_interpolation(table(names::GRADIENT_INTERPOLATION, raw)?))
+            }
+            None => Ok(style),
+        }
+    };
+    Some(apply())
+}
+
+fn apply_utility(mut style: Style, utility: &str) -> Result<Style, Reason> {
+    if let Some(negative) = utility.strip_prefix('-') {
+        return apply_negative(style, negative);
+    }
+
+    // Standalone keyword classes.
+    if let Some(display) = lookup(names::DISPLAY, utility) {
+        return Ok(style.display(display));
+    }
+    if let Some(position) = lookup(names::POSITION, utility) {
+        return Ok(style.position(position));
+    }
+    if let Some(visibility) = lookup(names::VISIBILITY, utility) {
+        return Ok(style.visibility(visibility));
+    }
+    if let Some(decoration) = lookup(names::TEXT_DECORATION, utility) {
+        style.text_decoration = Some(decoration);
+        return Ok(style);
+    }
+    if let Some(transform) = lookup(names::TEXT_TRANSFORM, utility) {
+        style.text_transform = Some(transform);
+        return Ok(style);
+    }
+    match utility {
+        "truncate" => return Ok(style.truncate()),
+        "border" => return Ok(style.border_width(BorderWidth::S1)),
+        "divide-x" => return Ok(style.divide_x(DivideWidth::S1)),
+        "divide-y" => return Ok(style.divide_y(DivideWidth::S1)),
+        "outline" => return Ok(style.outline_width(BorderWidth::S1)),
+        "ring" => return Ok(style.ring_width(RingWidth::S1)),
+        "shadow" => return Ok(style.shadow(crate::tokens::Shadow::DEFAULT)),
+        "rounded" => return Ok(style.rounded(crate::tokens::BorderRadius::Sm)),
+        "blur" => return Ok(style.blur(Blur::Base)),
+        "backdrop-blur" => return Ok(style.backdrop_blur(Blur::Base)),
+        "grayscale" => return Ok(style.grayscale(100)),
+        "invert" => return Ok(style.invert(100)),
+        "sepia" => return Ok(style.sepia(100)),
+        "filter-none" => return Ok(style.filter_none()),
+        "backdrop-filter-none" => return Ok(style.backdrop_filter_none()),
+        "transform-none" => return Ok(style.transform_none()),
+        "drop-shadow" => return Ok(style.drop_shadow(DropShadow::Base)),
+        "transition" => return Ok(style.transition_default()),
+        _ => {}
+    }
+
+    for (prefix, setter) in PADDING_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, padding_value(raw)?));
+        }
+    }
+    for (prefix, setter) in MARGIN_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, margin_value(raw, false)?));
+        }
+    }
+
+    // Sizing.
+    if let Some(raw) = utility_value(utility, "min-w") {
+        return Ok(style.min_w(width_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "max-w") {
+        return Ok(style.max_w(width_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "min-h") {
+        return Ok(style.min_h(height_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "max-h") {
+        return Ok(style.max_h(height_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "w") {
+        return Ok(style.width(width_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "h") {
+        return Ok(style.height(height_size(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "size") {
+        return Ok(style.width(width_size(raw)?).height(height_size(raw)?));
+    }
+
+    // Layout.
+    if let Some(raw) = utility_value(utility, "z") {
+        return Ok(style.z_index(table(names::Z_INDEX, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "overflow-x") {
+        style.overflow_x = Some(table(names::OVERFLOW, raw)?);
+        return Ok(style);
+    }
+    if let Some(raw) = utility_value(utility, "overflow-y") {
+        style.overflow_y = Some(table(names::OVERFLOW, raw)?);
+        return Ok(style);
+    }
+    if let Some(raw) = utility_value(utility, "overflow") {
+        return Ok(style.overflow(table(names::OVERFLOW, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "aspect") {
+        if let Some(ratio) = lookup(names::ASPECT_RATIO, raw) {
+            return Ok(style.aspect_ratio(ratio));
+        }
+        let ratio = bracketed(raw).unwrap_or(raw);
+        let (width, height) = ratio.split_once('/').ok_or_else(|| invalid(raw))?;
+        return Ok(style.aspect_ratio(AspectRatio::Custom(number(width)?, number(height)?)));
+    }
+    if let Some(raw) = utility_value(utility, "object") {
+        return Ok(style.object_fit(table(names::OBJECT_FIT, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "columns") {
+        return Ok(style.columns(columns_value(raw)?));
+    }
+
+    // Flex and grid.
+    if let Some(raw) = utility_value(utility, "flex") {
+        if let Some(direction) = lookup(names::FLEX_DIRECTION, raw) {
+            return Ok(style.flex_direction(direction));
+        }
+        if let Some(wrap) = lookup(names::FLEX_WRAP, raw) {
+            let flex = style.flex.take().unwrap_or_default().wrap(wrap);
+            return Ok(style.flex(flex));
+        }
+        return Ok(style.flex_item(flex_value(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "grid-cols") {
+        return Ok(style.grid_cols(grid_template(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "grid-rows") {
+        return Ok(style.grid_rows(grid_template(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "grid-flow") {
+        return Ok(style.grid_flow(table(names::GRID_FLOW, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "col-span") {
+        return Ok(match grid_span(raw)? {
+            Some(span) => style.col_span(span),
+            None => style.col_span_full(),
+        });
+    }
+    if let Some(raw) = utility_value(utility, "row-span") {
+        return Ok(match grid_span(raw)? {
+            Some(span) => style.row_span(span),
+            None => style.row_span_full(),
+        });
+    }
+    for (prefix, setter) in GRID_LINE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, grid_line(raw)?));
+        }
+    }
+    match utility {
+        "col-auto" => return Ok(style.col_auto()),
+        "row-auto" => return Ok(style.row_auto()),
+        _ => {}
+    }
+    if let Some(raw) = utility_value(utility, "gap-x") {
+        return Ok(style.gap_x(table(names::SPACING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "gap-y") {
+        return Ok(style.gap_y(table(names::SPACING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "gap") {
+        return Ok(style.gap(table(names::SPACING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "space-x") {
+        return Ok(style.space_x(table(names::SPACING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "space-y") {
+        return Ok(style.space_y(table(names::SPACING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "justify-items") {
+        return Ok(style.justify_items(table(names::JUSTIFY_ITEMS, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "justify-self") {
+        return Ok(style.justify_self(table(names::JUSTIFY_SELF, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "justify") {
+        return Ok(style.justify_content(table(names::JUSTIFY_CONTENT, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "items") {
+        return Ok(style.align_items(table(names::ALIGN_ITEMS, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "place-content") {
+        return Ok(style.place_content(table(names::PLACE_CONTENT, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "place-items") {
+        return Ok(style.place_items(table(names::PLACE_ITEMS, raw)?));
+    }
+
+    // Backgrounds and effects.
+    if let Some(raw) = utility_value(utility, "bg") {
+        if let Some(applied) = apply_gradient_kind(style.clone(), raw, false) {
+            return applied;
+        }
+        let token = color_token!(color(raw)?, BackgroundColor, BackgroundColorVar);
+        return Ok(style.background_token(token));
+    }
+    for (prefix, set_color, set_position) in GRADIENT_STOP_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            if let Some(percent) = raw.strip_suffix('%') {
+                let percent = number::<u8>(percent).map_err(|_| invalid(raw))?;
+                if percent > 100 {
+                    return Err(invalid(raw));
+                }
+                return Ok(set_position(style, percent));
+            }
+            let token = color_token!(color(raw)?, BackgroundColor, BackgroundColorVar);
+            return Ok(set_color(style, token));
+        }
+    }
+    if let Some(raw) = utility_value(utility, "opacity") {
+        let opacity = match value(raw) {
+            Value::Named(named) => number::<u8>(named)?.min(100) as f32 / 100.0,
+            Value::Arbitrary(inner) => number::<f32>(inner).map_err(|_| invalid(raw))?,
+            Value::Var { .. } => return Err(invalid(raw)),
+        };
+        return Ok(style.opacity(opacity));
+    }
+    if let Some(raw) = utility_value(utility, "blur") {
+        if let Some(inner) = bracketed(raw) {
+            return Ok(style.blur_px(px_u16(inner).ok_or_else(|| invalid(raw))?));
+        }
+        return Ok(style.blur(table(names::BLUR, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "backdrop-blur") {
+        if let Some(inner) = bracketed(raw) {
+            return Ok(style.backdrop_blur_px(px_u16(inner).ok_or_else(|| invalid(raw))?));
+        }
+        return Ok(style.backdrop_blur(table(names::BLUR, raw)?));
+    }
+    for (prefix, setter) in FILTER_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, filter_amount(raw)?));
+        }
+    }
+    for (prefix, setter) in ANGLE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, degrees(raw, false)?));
+        }
+    }
+    if let Some(raw) = utility_value(utility, "drop-shadow") {
+        return Ok(style.drop_shadow(table(names::DROP_SHADOW, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "perspective") {
+        if let Some(inner) = bracketed(raw) {
+            let px = px_u16(inner).ok_or_else(|| invalid(raw))?;
+            return Ok(style.perspective(Perspective::CustomPx(px)));
+        }
+        return Ok(style.perspective(table(names::PERSPECTIVE, raw)?));
+    }
+    for (prefix, setter) in TRANSLATE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, translate_value(raw, false)?));
+        }
+    }
+    for (prefix, setter) in SCALE_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, scale_amount(raw, false)?));
+        }
+    }
+    if let Some(raw) = utility_value(utility, "origin") {
+        return Ok(style.origin(transform_origin(raw)?));
+    }
+
+    // Borders, outlines and rings.
+    for (prefix, setter) in BORDER_CORNER_PREFIXES {
+        if utility == *prefix {
+            return Ok(setter(style, BorderRadius::Sm));
+        }
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, table(names::BORDER_RADIUS, raw)?));
+        }
+    }
+    for (prefix, setter) in BORDER_SIDE_PREFIXES {
+        if utility == *prefix {
+            return Ok(setter(style, BorderWidth::S1));
+        }
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, table(names::BORDER_WIDTH, raw)?));
+        }
+    }
+    if let Some(raw) = utility_value(utility, "rounded") {
+        return Ok(style.rounded(table(names::BORDER_RADIUS, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "border") {
+        if let Some(width) = lookup(names::BORDER_WIDTH, raw) {
+            return Ok(style.border_width(width));
+        }
+        if let Some(border_style) = lookup(names::BORDER_STYLE, raw) {
+            return Ok(style.border_style(border_style));
+        }
+        let token = color_token!(color(raw)?, BorderColor, BorderColorVar);
+        return Ok(style.border_color_token(token));
+    }
+    if let Some(raw) = utility_value(utility, "divide-x") {
+        return Ok(style.divide_x(table(names::DIVIDE_WIDTH, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "divide-y") {
+        return Ok(style.divide_y(table(names::DIVIDE_WIDTH, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "divide") {
+        let token = color_token!(color(raw)?, BorderColor, BorderColorVar);
+        return Ok(style.divide_color_token(token));
+    }
+    if let Some(raw) = utility_value(utility, "outline") {
+        if let Some(width) = lookup(names::BORDER_WIDTH, raw) {
+            return Ok(style.outline_width(width));
+        }
+        if let Some(outline_style) = lookup(names::OUTLINE_STYLE, raw) {
+            return Ok(style.outline_style(outline_style));
+        }
+        let token = color_token!(color(raw)?, OutlineColor, OutlineColorVar);
+        return Ok(style.outline_color_token(token));
+    }
+    if let Some(raw) = utility_value(utility, "ring") {
+        if let Some(width) = lookup(names::RING_WIDTH, raw) {
+            return Ok(style.ring_width(width));
+        }
+        let token = color_token!(color(raw)?, RingColor, RingColorVar);
+        return Ok(style.ring_color_token(token));
+    }
+
+    // Shadows.
+    if let Some(raw) = utility_value(utility, "inset-shadow") {
+        return Ok(style.inset_shadow(table(names::INSET_SHADOW, raw)?));
+    }
+    for (prefix, setter) in INSET_PREFIXES {
+        if let Some(raw) = utility_value(utility, prefix) {
+            return Ok(setter(style, inset_value(raw, false)?));
+        }
+    }
+    if let Some(raw) = utility_value(utility, "text-shadow") {
+        return Ok(style.text_shadow(table(names::TEXT_SHADOW, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "shadow") {
+        if let Some(shadow) = lookup(names::SHADOW, raw) {
+            return Ok(style.shadow(shadow));
+        }
+        let token = color_token!(color(raw)?, ShadowColorToken, ShadowColorVar);
+        return Ok(style.shadow_color_token(token));
+    }
+
+    // Typography.
+    if let Some(raw) = utility_value(utility, "text") {
+        if let Some(align) = lookup(names::TEXT_ALIGN, raw) {
+            return Ok(style.text_align(align));
+        }
+        if let Some(overflow) = lookup(names::TEXT_OVERFLOW, raw) {
+            return Ok(style.text_overflow(overflow));
+        }
+        if let Some(size) = lookup(names::FONT_SIZE, raw) {
+            return Ok(style.text_size(size));
+        }
+        match value(raw) {
+            Value::Arbitrary(inner) if length(inner).is_some() => {
+                let px = px_u16(inner).ok_or_else(|| invalid(raw))?;
+                return Ok(style.text_size(FontSize::Px(px)));
+            }
+            Value::Var {
+                hint: Some("length"),
+                name,
+            } => {
+                let name = custom_property(name).ok_or_else(|| invalid(raw))?;
+                return Ok(style.text_size_var(FontSizeVar::new(name)));
+            }
+            _ => {}
+        }
+        let token = color_token!(color(raw)?, TextColor, TextColorVar);
+        return Ok(style.text_color_token(token));
+    }
+    if let Some(raw) = utility_value(utility, "whitespace") {
+        return Ok(style.whitespace(table(names::WHITE_SPACE, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "break") {
+        return Ok(style.word_break(table(names::WORD_BREAK, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "line-clamp") {
+        return match (raw, value(raw)) {
+            ("none", _) => Ok(style.line_clamp_none()),
+            (_, Value::Named(lines) | Value::Arbitrary(lines)) => match number::<u16>(lines)? {
+                0 => Err(invalid(raw)),
+                lines => Ok(style.line_clamp(lines)),
+            },
+            _ => Err(invalid(raw)),
+        };
+    }
+    if let Some(raw) = utility_value(utility, "font") {
+        if let Some(family) = lookup(names::FONT_FAMILY, raw) {
+            return Ok(style.font(family));
+        }
+        return Ok(style.font_weight(table(names::FONT_WEIGHT, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "tracking") {
+        let tracking = match value(raw) {
+            Value::Named(named) => table(names::LETTER_SPACING, named)?,
+            Value::Arbitrary(inner) => inner
+                .strip_suffix("em")
+                .and_then(|em| em.parse().ok())
+                .map(LetterSpacing::Em)
+                .ok_or_else(|| invalid(raw))?,
+            Value::Var { hint: None, name } => custom_property(name)
+                .map(|name| LetterSpacing::Var(LetterSpacingVar::new(name)))
+                .ok_or_else(|| invalid(raw))?,
+            Value::Var { .. } => return Err(invalid(raw)),
+        };
+        return Ok(style.tracking(tracking));
+    }
+    if let Some(raw) = utility_value(utility, "leading") {
+        let leading = match value(raw) {
+            Value::Named(named) => lookup(names::LINE_HEIGHT, named)
+                .or_else(|| line_height_spacing(named))
+                .ok_or_else(|| invalid(raw))?,
+            Value::Arbitrary(inner) => LineHeight::Number(number(inner).map_err(|_| invalid(raw))?),
+            Value::Var { hint: None, name } => custom_property(name)
+                .map(|name| LineHeight::Var(LineHeightVar::new(name)))
+                .ok_or_else(|| invalid(raw))?,
+            Value::Var { .. } => return Err(invalid(raw)),
+        };
+        return Ok(style.leading(leading));
+    }
+
+    // Motion and interactivity.
+    if let Some(raw) = utility_value(utility, "transition") {
+        let property = match raw {
+            "none" => TransitionProperty::None,
+            "all" => TransitionProperty::All,
+            "colors" => TransitionProperty::Colors,
+            "opacity" => TransitionProperty::Opacity,
+            "shadow" => TransitionProperty::Shadow,
+            "transform" => TransitionProperty::Transform,
+            _ => match bracketed(raw) {
+                Some(inner) => TransitionProperty::Custom(inner.replace('_', " ")),
+                None => return Err(invalid(raw)),
+            },
+        };
+        return Ok(style.transition(property));
+    }
+    if let Some(raw) = utility_value(utility, "duration") {
+        return Ok(style.transition_duration(duration(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "delay") {
+        return Ok(style.transition_delay(duration(raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "ease") {
+        return Ok(style.transition_ease(table(names::EASING, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "animate") {
+        return Ok(style.animate(table(names::ANIMATION, raw)?));
+    }
+    if let Some(raw) = utility_value(utility, "cursor") {
+        return Ok(style.cursor(table(names::CURSOR, raw)?));
+    }
+
+    Err(Reason::UnknownUtility)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{DataState, InteractionState};
+    use crate::tokens::{
+        Breakpoint, FontWeight, Percentage, Scale, Shadow, Spacing, TextDecoration, TextTransform,
+    };
+    use crate::utilities::{Display, FlexDirection, JustifyContent, Overflow, Position};
+
+    #[test]
+    fn test_parse_base_utilities() {
+        let style = Style::parse("flex flex-col justify-between p-4 bg-blue-500 rounded-lg")
+            .expect("all classes are supported");
+
+        assert_eq!(
+            style,
+            Style::new()
+                .display(Display::Flex)
+                .flex_direction(FlexDirection::Col)
+                .justify_content(JustifyContent::Between)
+                .p(Spacing::S4)
+                .bg(Color::blue(Scale::S500))
+                .rounded(crate::tokens::BorderRadius::Lg)
+        );
+    }
+
+    #[test]
+    fn test_parse_inset_utilities() {
+        let style =
+            Style::parse("absolute -top-2 right-[6px] inset-y-1/2 left-auto inset-shadow-sm")
+                .expect("all classes are supported");
+        let inset = style.inset_value().unwrap();
+
+        assert_eq!(style.position_value(), Some(Position::Absolute));
+        // `inset-y-1/2` comes after `-top-2`, so it wins for the top side.
+        assert_eq!(
+            inset.top_side(),
+            Some(InsetValue::Fraction(Percentage::S1_2))
+        );
+        assert_eq!(inset.right_side(), Some(InsetValue::Px(6.0)));
+        assert_eq!(
+            inset.bottom_side(),
+            Some(InsetValue::Fraction(Percentage::S1_2))
+        );
+        assert_eq!(inset.left_side(), Some(InsetValue::Auto));
+        assert!(style.inset_shadow_value().is_some());
+        assert!(Style::parse("top-min").is_err());
+        assert!(Style::parse("-inset-auto").is_err());
+    }
+
+    #[test]
+    fn test_parse_border_side_and_corner_utilities() {
+        let style =
+            Style::parse("border border-b-2 border-x-0 rounded-lg rounded-b-none rounded-tr")
+                .expect("all classes are supported");
+        let widths = style.resolved_border_widths();
+        let radii = style.resolved_border_radii();
+
+        assert_eq!(widths.to_px(), [1.0, 0.0, 2.0, 0.0]);
+        assert_eq!(
+            radii.top_right_corner(),
+            Some(crate::tokens::BorderRadius::Sm)
+        );
+        assert_eq!(radii.to_px(), [8.0, 4.0, 0.0, 0.0]);
+        assert_eq!(
+            Style::parse("rounded-s-md border-blue-500")
+                .map(|style| style.resolved_border_radii().to_px()),
+            Ok([6.0, 0.0, 0.0, 6.0])
+        );
+        assert!(Style::parse("border-t-3").is_err());
+        assert!(Style::parse("rounded-bl-huge").is_err());
+    }
+
+    #[test]
+    fn test_parse_divide_and_space_utilities() {
+        let style = Style::parse("space-y-2 divide-y divide-x-2 divide-slate-200")
+            .expect("all classes are supported");
+
+        assert_eq!(style.space_y_value(), Some(Spacing::S2));
+        assert_eq!(style.divide_y_value(), Some(DivideWidth::S1));
+        assert_eq!(style.divide_x_value(), Some(DivideWidth::S2));
+        assert_eq!(
+            style.divide_color_value(),
+            Some(Color::slate(crate::tokens::Scale::S200))
+        );
+        assert_eq!(
+            style.to_class_string(),
+            "space-y-2 divide-x-2 divide-y divide-slate-200"
+        );
+        assert_eq!(Style::parse(&style.to_class_string()), Ok(style));
+        assert!(Style::parse("divide-x-3").is_err());
+        assert!(Style::parse("space-x-huge").is_err());
+    }
+
+    #[test]
+    fn test_parse_gradient_utilities() {
+        let style =
+            Style::parse("bg-linear-to-br/srgb from-sky-500 from-10% via-white to-(--accent)")
+                .expect("all classes are supported");
+        let gradient = style.background_gradient_value().expect("gradient is set");
+        assert_eq!(
+            gradient.kind(),
+            Some(GradientKind::Linear(LinearDirection::ToBr))
+        );
+        assert_eq!(
+            gradient.interpolation(),
+            Some(crate::tokens::GradientInterpolation::Srgb)
+        );
+        assert_eq!(
+            gradient.from_stop().and_then(|stop| stop.position()),
+            Some(10)
+        );
+        assert_eq!(
+            style.to_class_string(),
+            "bg-linear-to-br/srgb from-sky-500 from-10% via-white to-(--accent)"
+        );
+        assert_eq!(Style::parse(&style.to_class_string()), Ok(style));
+
+        let conic = Style::parse("bg-conic-180").expect("conic angles are supported");
+        assert_eq!(conic.to_class_string(), "bg-conic-180");
+        let angled = Style::parse("-bg-linear-45").expect("negative angles are supported");
+        assert_eq!(angled.to_class_string(), "bg-linear-315");
+        assert_eq!(
+            Style::parse("bg-radial").map(|style| style.to_class_string()),
+            Ok("bg-radial".to_owned())
+        );
+        assert!(Style::parse("bg-linear-to-x").is_err());
+        assert!(Style::parse("bg-radial/hsl").is_err());
+        assert!(Style::parse("-bg-radial").is_err());
+        assert!(Style::parse("from-120%").is_err());
+    }
+
+    #[test]
+    fn test_parse_filter_utilities() {
+        let style = Style::parse(
+            "grayscale brightness-[1.1] -hue-rotate-15 invert-50 disabled:sepia \
+             backdrop-blur-md",
+        )
+        .expect("all classes are supported");
+        let filter = style.filter_value().expect("filter is set");
+        assert_eq!(filter.grayscale(), Some(100));
+        assert_eq!(filter.brightness(), Some(110));
+        assert_eq!(filter.hue_rotate(), Some(-15));
+        assert_eq!(filter.invert(), Some(50));
+        assert_eq!(style.backdrop_blur_value(), Some(Blur::Md));
+        assert_eq!(
+            style.to_class_string(),
+            "brightness-110 grayscale -hue-rotate-15 invert-50 backdrop-blur-md \
+             disabled:sepia"
+        );
+        assert_eq!(Style::parse(&style.to_class_string()), Ok(style));
+
+        let reset = Style::parse("filter-none backdrop-filter-none").expect("resets parse");
+        assert_eq!(reset.blur_value(), Some(Blur::None));
+        assert_eq!(reset.backdrop_blur_value(), Some(Blur::None));
+        assert_eq!(reset.to_class_string(), "filter-none backdrop-blur-none");
+        assert!(Style::parse("backdrop-saturate-150").is_err());
+        assert!(Style::parse("backdrop-hue-rotate-[30deg]").is_err());
+        assert!(Style::parse("brightness-(--dim)").is_err());
+        assert!(Style::parse("-brightness-50").is_err());
+        assert!(Style::parse("hue-rotate-[1turn]").is_err());
+    }
+
+    #[test]
+    fn test_parse_transform_utilities() {
+        let style = Style::parse(
+            "-translate-x-1/2 translate-y-[6px] rotate-45 scale-x-[1.5] -skew-y-6 \
+             origin-top-right hover:scale-105 active:-rotate-[12deg]",
+        )
+        .expect("all classes are supported");
+        let transform = style.transform_value().expect("transform is set");
+        assert_eq!(
+            transform.translate_x(),
+            Some(InsetValue::NegativeFraction(Percentage::S1_2))
+        );
+        assert_eq!(transform.translate_y(), Some(InsetValue::Px(6.0)));
+        assert_eq!(transform.rotate(), Some(45));
+        assert_eq!(transform.scale_x(), Some(150));
+        assert_eq!(transform.scale_y(), None);
+        assert_eq!(transform.skew_y(), Some(-6));
+        assert_eq!(
+            style.transform_origin_value(),
+            Some(TransformOrigin::TopRight)
+        );
+        assert_eq!(
+            style.to_class_string(),
+            "-translate-x-1/2 translate-y-[6px] rotate-45 scale-x-150 -skew-y-6 \
+             origin-top-right hover:scale-105 active:-rotate-12"
+        );
+        assert_eq!(Style::parse(&style.to_class_string()), Ok(style));
+
+        let reset = Style::parse("transform-none origin-[25%_75%]").expect("resets parse");
+        assert_eq!(reset.to_class_string(), "transform-none origin-[25%_75%]");
+        assert_eq!(
+            reset.transform_origin_value(),
+            Some(TransformOrigin::Percent(25, 75))
+        );
+        let lifted = Style::parse("-translate-y-[25%]").expect("percentages parse");
+        assert_eq!(
+            lifted.transform_value().and_then(|t| t.translate_y()),
+            Some(InsetValue::Percent(-25.0))
+        );
+        assert_eq!(lifted.to_class_string(), "translate-y-[-25%]");
+        assert!(Style::parse("translate-x-auto").is_err());
+        assert!(Style::parse("origin-middle").is_err());
+        assert!(Style::parse("scale-x-[40000%]").is_err());
+    }
+
+    #[test]
+    fn test_parse_state_and_responsive_variants() {
+        let style = Style::parse(
+            "hover:bg-blue-600 focus-visible:ring-2 data-[state=open]:shadow-lg \
+             aria-selected:font-bold md:flex md:hover:opacity-90",
+        )
+        .unwrap();
+
+        assert_eq!(
+            style.hover_style(),
+            Some(&Style::new().bg(Color::blue(Scale::S600)))
+        );
+        assert_eq!(
+            style.focus_visible_style(),
+            Some(&Style::new().ring_width(RingWidth::S2))
+        );
+        assert_eq!(
+            style.data_attr_style(DataState::Open),
+            Some(&Style::new().shadow(Shadow::Lg))
+        );
+        assert_eq!(
+            style.aria_state_style("selected"),
+            Some(&Style::new().font_weight(FontWeight::Bold))
+        );
+
+        let md = style.breakpoint_style(Breakpoint::Md).unwrap();
+        assert_eq!(md.display_mode(), Some(Display::Flex));
+        assert_eq!(md.hover_style(), Some(&Style::new().opacity(0.9)));
+    }
+
+    #[test]
+    fn test_parse_bracketed_aria_variant_selects_typed_hook() {
+        let style = Style::parse("p-4 aria-[expanded=true]:p-2 aria-[sort=ascending]:p-1").unwrap();
+        assert_eq!(
+            style,
+            Style::parse("p-4 aria-expanded:p-2 aria-[sort=ascending]:p-1").unwrap()
+        );
+
+        let expanded = style.resolve_for(&InteractionState::new().aria_attr(AriaAttr::Expanded));
+        assert_eq!(
+            expanded.padding_value(),
+            Style::new().p(Spacing::S2).padding_value()
+        );
+        let collapsed = style.resolve_for(&InteractionState::new());
+        assert_eq!(
+            collapsed.padding_value(),
+            Style::new().p(Spacing::S4).padding_value()
+        );
+        assert!(style.aria_state_style("sort=ascending").is_some());
+    }
+
+    #[test]
+    fn test_parse_arbitrary_values_and_custom_properties() {
+        let style = Style::parse(
+            "p-[13px] -mt-2 mx-auto w-[240px] h-(--panel-h) bg-[#1e293b] text-(--fg) text-[14px]",
+        )
+        .unwrap();
+
+        let padding = style.padding_value().unwrap();
+        assert_eq!(padding.top_side(), Some(PaddingValue::Px(13.0)));
+        let margin = style.margin_value().unwrap();
+        assert_eq!(margin.top, Some(MarginValue::NegativeScale(Spacing::S2)));
+        assert_eq!(margin.left, Some(MarginValue::Auto));
+        assert_eq!(style.width_value(), Some(WidthSize::Px(240).into()));
+        assert_eq!(
+            style.height_value(),
+            Some(HeightSize::Var(HeightVar::new("--panel-h")).into())
+        );
+        assert_eq!(
+            style.background_color_value(),
+            Some(BackgroundColor::Arbitrary(ColorValueToken::from_rgb8(
+                0x1e, 0x29, 0x3b
+            )))
+        );
+        assert_eq!(
+            style.text_color_token_value(),
+            Some(TextColor::CustomProperty(TextColorVar::new("--fg")))
+        );
+        assert_eq!(style.font_size_value(), Some(FontSize::Px(14)));
+    }
+
+    #[test]
+    fn test_parse_typography_and_semantic_colors() {
+        let style = Style::parse("text-sm font-semibold uppercase underline text-muted-foreground")
+            .unwrap();
+
+        assert_eq!(style.font_size_value(), Some(FontSize::Sm));
+        assert_eq!(style.font_weight_value(), Some(FontWeight::SemiBold));
+        assert_eq!(style.text_transform_value(), Some(TextTransform::Uppercase));
+        assert_eq!(
+            style.text_decoration_value(),
+            Some(TextDecoration::Underline)
+        );
+        assert_eq!(
+            style.text_color_token_value(),
+            Some(TextColor::Semantic(SemanticColor::MutedForeground))
+        );
+    }
+
+    #[test]
+    fn test_parse_text_decoration_and_transform_match_builders() {
+        assert_eq!(
+            Style::parse("capitalize line-through"),
+            Ok(Style::new().capitalize().line_through())
+        );
+        assert_eq!(
+            Style::parse("normal-case no-underline"),
+            Ok(Style::new().normal_case().no_underline())
+        );
+        assert_eq!(
+            Style::parse("lowercase overline"),
+            Ok(Style::new().lowercase().overline())
+        );
+    }
+
+    #[test]
+    fn test_later_classes_override_earlier_ones() {
+        let style = Style::parse("overflow-auto p-2 p-4 overflow-x-hidden").unwrap();
+        assert_eq!(
+            style.padding_value().unwrap().top_side(),
+            Some(Spacing::S4.into())
+        );
+        assert_eq!(style.overflow_value(), Some(Overflow::Auto));
+        assert_eq!(style.overflow_x_value(), Some(Overflow::Hidden));
+    }
+
+    #[test]
+    fn test_parse_reports_diagnostics_with_spans() {
+        let input = "p-4 bg-blue-550  group-hover:p-2 frobnicate";
+        let error = Style::parse(input).unwrap_err();
+        let diagnostics = error.diagnostics();
+
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].class(), "bg-blue-550");
+        assert_eq!(&input[diagnostics[0].span()], "bg-blue-550");
+        assert_eq!(
+            diagnostics[0].reason(),
+            &ClassDiagnosticReason::InvalidValue("blue-550".into())
+        );
+        assert_eq!(&input[diagnostics[1].span()], "group-hover:p-2");
+        assert_eq!(
+            diagnostics[1].reason(),
+            &ClassDiagnosticReason::UnknownVariant("group-hover".into())
+        );
+        assert_eq!(diagnostics[2].span(), 33..43);
+        assert_eq!(
+            diagnostics[2].reason(),
+            &ClassDiagnosticReason::UnknownUtility
+        );
+
+        assert_eq!(error.partial_style(), &Style::new().p(Spacing::S4));
+    }
+
+    #[test]
+    fn test_from_str_matches_parse() {
+        let style: Style = "gap-2 items-center cursor-pointer".parse().unwrap();
+        assert_eq!(
+            style,
+            Style::parse("gap-2 items-center cursor-pointer").unwrap()
+        );
+        assert!("".parse::<Style>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_bare_shadow_spacing_leading_and_translate_round_trip() {
+        for (class, expected, canonical) in [
+            ("shadow", Style::new().shadow(Shadow::DEFAULT), "shadow-sm"),
+            (
+                "leading-6",
+                Style::new().leading(LineHeight::Spacing(Spacing::S6)),
+                "leading-6",
+            ),
+            (
+                "translate-full",
+                Style::new()
+                    .translate_x(InsetValue::Fraction(Percentage::Full))
+                    .translate_y(InsetValue::Fraction(Percentage::Full)),
+                "translate-full",
+            ),
+        ] {
+            let style = Style::parse(class).unwrap();
+            assert_eq!(style, expected, "{class}");
+            assert_eq!(style.to_class_string(), canonical, "{class}");
+            assert_eq!(Style::parse(&style.to_class_string()).unwrap(), style);
+        }
+        assert_eq!(
+            Style::new()
+                .leading(LineHeight::Spacing(Spacing::S6))
+                .line_height_value()
+                .map(|h| h.value()),
+            Some("1.5rem".to_owned())
+        );
+        assert!(Style::parse("leading-auto").is_err());
+    }
+
+    #[test]
+    fn test_semantic_color_opacity_modifier_is_rejected() {
+        let error = Style::parse("bg-primary/50").unwrap_err();
+        assert_eq!(
+            error.diagnostics()[0].reason(),
+            &ClassDiagnosticReason::InvalidValue("primary/50".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_palette_opacity_modifier_becomes_arbitrary_color() {
+        let style = Style::parse("bg-black/50").unwrap();
+        match style.background_color_value() {
+            Some(BackgroundColor::Arbitrary(value)) => assert_eq!(value.to_rgba8(), (0, 0, 0, 128)),
+            other => panic!("expected arbitrary background, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,462 @@
+//! One-call resolution of breakpoints, state layers, semantic colors and custom properties.
+
+use std::fmt;
+
+use super::{InteractionState, Style};
+use crate::tokens::{
+    BackgroundColor, BorderColor, Breakpoint, ColorValue, ColorValueToken, FontSize, LetterSpacing,
+    LineHeight, OutlineColor, RingColor, SemanticThemeSource, ShadowColorToken, TextColor,
+    ThemeVariant,
+};
+use crate::utilities::{Height, HeightSize, MarginValue, PaddingValue, Size, Width, WidthSize};
+
+/// Value bound to a CSS custom property (`--name`) for [`Style::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CustomPropertyValue {
+    /// A length in logical pixels.
+    Px(f32),
+    /// A unitless number, used for line heights and `em` letter spacing.
+    Number(f32),
+    /// A color.
+    Color(ColorValue),
+}
+
+/// Everything [`Style::resolve`] needs to turn a layered style into a flat one.
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let context = ResolveContext {
+///     viewport_width_px: 900.0,
+///     state: InteractionState::new().hovered(true),
+///     ..ResolveContext::new()
+/// };
+///
+/// let style = Style::new()
+///     .p(Spacing::S2)
+///     .md(|s| s.p(Spacing::S4).hover(|s| s.opacity(0.9)));
+/// let resolved = style.resolve(&context);
+///
+/// assert_eq!(resolved.padding_value(), Some(&Padding::all(Spacing::S4)));
+/// assert_eq!(resolved.opacity_value(), Some(0.9));
+/// ```
+#[derive(Clone)]
+pub struct ResolveContext<'a> {
+    /// Width of the viewport or window, in logical pixels.
+    pub viewport_width_px: f32,
+    /// Root font size in logical pixels; breakpoints are `rem` based.
+    pub root_font_size: f32,
+    /// Theme used to resolve semantic colors; `None` keeps semantic tokens as-is.
+    pub theme: Option<&'a dyn SemanticThemeSource>,
+    pub variant: ThemeVariant,
+    pub state: InteractionState,
+    /// Values substituted for `*Var` custom-property tokens, keyed by `--name`.
+    pub custom_properties: &'a [(&'a str, CustomPropertyValue)],
+}
+
+impl ResolveContext<'_> {
+    /// Idle, light-themed context for a zero-width viewport and a 16px root font size.
+    pub fn new() -> Self {
+        Self {
+            viewport_width_px: 0.0,
+            root_font_size: 16.0,
+            theme: None,
+            variant: ThemeVariant::Light,
+            state: InteractionState::new(),
+            custom_properties: &[],
+        }
+    }
+
+    /// Breakpoints whose min-width fits the viewport, smallest first.
+    pub fn active_breakpoints(&self) -> impl Iterator<Item = Breakpoint> + '_ {
+        Breakpoint::ALL.into_iter().filter(|breakpoint| {
+            breakpoint.to_rem() * self.root_font_size <= self.viewport_width_px
+        })
+    }
+
+    pub fn custom_property(&self, name: &str) -> Option<CustomPropertyValue> {
+        let name = name.trim_start_matches("--");
+        self.custom_properties
+            .iter()
+            .find(|(property, _)| property.trim_start_matches("--") == name)
+            .map(|(_, value)| *value)
+    }
+
+    fn px(&self, name: &str) -> Option<f32> {
+        match self.custom_property(name)? {
+            CustomPropertyValue::Px(px) => Some(px),
+            _ => None,
+        }
+    }
+
+    fn color(&self, name: &str) -> Option<ColorValueToken> {
+        match self.custom_property(name)? {
+            CustomPropertyValue::Color(color) => Some(color.into()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ResolveContext<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ResolveContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResolveContext")
+            .field("viewport_width_px", &self.viewport_width_px)
+            .field("root_font_size", &self.root_font_size)
+            .field("theme", &self.theme.map(|_| ".."))
+            .field("variant", &self.variant)
+            .field("state", &self.state)
+            .field("custom_properties", &self.custom_properties)
+            .finish()
+    }
+}
+
+/// Round a pixel length into the `u16` range used by pixel size tokens.
+fn clamp_px(px: f32) -> u16 {
+    px.round().clamp(0.0, f32::from(u16::MAX)) as u16
+}
+
+/// Replace a `CustomProperty` color token when the context binds it to a color.
+macro_rules! substitute_color {
+    ($slot:expr, $token:ident, $context:expr) => {
+        if let Some($token::CustomProperty(var)) = $slot {
+            if let Some(color) = $context.color(var.as_str()) {
+                $slot = Some($token::arbitrary(color));
+            }
+        }
+    };
+}
+
+impl Style {
+    /// Resolve this style for a concrete viewport, interaction state, theme and set of
+    /// custom properties.
+    ///
+    /// The pipeline runs in a fixed order:
+    /// 1. every breakpoint layer whose min-width fits `viewport_width_px` is merged,
+    ///    mobile-first and cumulative, together with the state layers it carries;
+    /// 2. the matching state layers are flattened with [`Style::resolve_for`];
+    /// 3. semantic colors are resolved with [`Style::resolved_theme`] when a theme is set;
+    /// 4. `*Var` tokens bound in `custom_properties` are replaced by concrete values.
+    ///
+    /// The result has no responsive or state layers left.
+    pub fn resolve(&self, context: &ResolveContext<'_>) -> Self {
+        let mut resolved = self
+            .resolve_breakpoints(context)
+            .resolve_for(&context.state);
+        resolved.responsive = None;
+        if let Some(theme) = context.theme {
+            resolved = resolved.resolved_theme(theme, context.variant);
+        }
+        resolved.substitute_custom_properties(context);
+        resolved
+    }
+
+    /// Merge the breakpoint layers active in `context` into the base style, mobile-first
+    /// and cumulative, and drop the rest.
+    ///
+    /// This is the first step of [`Style::resolve`]. State layers, including those the
+    /// breakpoint layers carry, are kept for widgets that track interaction themselves.
+    pub fn resolve_breakpoints(&self, context: &ResolveContext<'_>) -> Self {
+        let mut resolved = self.clone();
+        resolved.responsive = None;
+        if let Some(responsive) = &self.responsive {
+            for breakpoint in context.active_breakpoints() {
+                if let Some(layer) = responsive.get(&breakpoint) {
+                    resolved = resolved.merged(layer.clone());
+                }
+            }
+        }
+        resolved
+    }
+
+    fn substitute_custom_properties(&mut self, context: &ResolveContext<'_>) {
+        if context.custom_properties.is_empty() {
+            return;
+        }
+
+        if let Some(padding) = &mut self.padding {
+            for side in [
+                &mut padding.top,
+                &mut padding.right,
+                &mut padding.bottom,
+                &mut padding.left,
+            ] {
+                if let Some(PaddingValue::Var(var)) = side
+                    && let Some(px) = context.px(var.as_str())
+                {
+                    *side = Some(PaddingValue::Px(px));
+                }
+            }
+        }
+        if let Some(margin) = &mut self.margin {
+            for side in [
+                &mut margin.top,
+                &mut margin.right,
+                &mut margin.bottom,
+                &mut margin.left,
+            ] {
+                if let Some(MarginValue::Var(var)) = side
+                    && let Some(px) = context.px(var.as_str())
+                {
+                    *side = Some(MarginValue::Px(px));
+                }
+            }
+        }
+
+        let size = |size: Size| {
+            let name = match size {
+                Size::Var(var) => var.as_str(),
+                Size::HeightVar(var) => var.as_str(),
+                size => return size,
+            };
+            match context.px(name) {
+                Some(px) => Size::Px(clamp_px(px)),
+                None => size,
+            }
+        };
+        if let Some(Size::Var(var)) = self.width.and_then(Width::size)
+            && let Some(px) = context.px(var.as_str())
+        {
+            self.width = Some(Width::new(WidthSize::Px(clamp_px(px))));
+        }
+        if let Some(Size::HeightVar(var)) = self.height.and_then(Height::size)
+            && let Some(px) = context.px(var.as_str())
+        {
+            self.height = Some(Height::new(HeightSize::Px(clamp_px(px))));
+        }
+        if let Some(constraints) = &mut self.constraints {
+            for bound in [
+                &mut constraints.min_width,
+                &mut constraints.max_width,
+                &mut constraints.min_height,
+                &mut constraints.max_height,
+            ] {
+                *bound = bound.map(size);
+            }
+        }
+
+        if let Some(FontSize::Var(var)) = self.font_size
+            && let Some(px) = context.px(var.as_str())
+        {
+            self.font_size = Some(FontSize::Px(clamp_px(px)));
+        }
+        let font_px = self
+            .font_size
+            .and_then(|size| size.resolve_px(&[]))
+            .unwrap_or(context.root_font_size);
+        if let Some(LetterSpacing::Var(var)) = self.letter_spacing {
+            self.letter_spacing = match context.custom_property(var.as_str()) {
+                Some(CustomPropertyValue::Number(em)) => Some(LetterSpacing::Em(em)),
+                Some(CustomPropertyValue::Px(px)) => Some(LetterSpacing::Em(px / font_px)),
+                _ => self.letter_spacing,
+            };
+        }
+        if let Some(LineHeight::Var(var)) = self.line_height {
+            self.line_height = match context.custom_property(var.as_str()) {
+                Some(CustomPropertyValue::Number(number)) => Some(LineHeight::Number(number)),
+                Some(CustomPropertyValue::Px(px)) => Some(LineHeight::Number(px / font_px)),
+                _ => self.line_height,
+            };
+        }
+
+        substitute_color!(self.background_color, BackgroundColor, context);
+        if let Some(gradient) = &mut self.background_gradient {
+            for stop in gradient.stops_mut() {
+                substitute_color!(stop.color, BackgroundColor, context);
+            }
+        }
+        substitute_color!(self.text_color, TextColor, context);
+        substitute_color!(self.border_color, BorderColor, context);
+        substitute_color!(self.divide_color, BorderColor, context);
+        substitute_color!(self.outline_color, OutlineColor, context);
+        substitute_color!(self.ring_color, RingColor, context);
+        substitute_color!(self.shadow_color, ShadowColorToken, context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::DataState;
+    use crate::tokens::{
+        BorderColorVar, Color, LetterSpacingVar, Scale, SemanticColor, SemanticThemeVars, Shadow,
+        Spacing, TextColorVar,
+    };
+    use crate::traits::ComputeValue;
+    use crate::utilities::{Padding, PaddingVar, WidthVar};
+
+    #[test]
+    fn test_resolve_applies_breakpoints_cumulatively_by_viewport() {
+        let style = Style::new()
+            .w(Spacing::S12)
+            .sm(|s| s.w(Spacing::S24))
+            .lg(|s| s.h(Spacing::S32));
+
+        let narrow = style.resolve(&ResolveContext {
+            viewport_width_px: 639.0,
+            ..ResolveContext::new()
+        });
+        assert_eq!(narrow.width_value(), Some(Width::from(Spacing::S12)));
+
+        let wide = style.resolve(&ResolveContext {
+            viewport_width_px: 1024.0,
+            ..ResolveContext::new()
+        });
+        assert_eq!(wide.width_value(), Some(Width::from(Spacing::S24)));
+        assert_eq!(wide.height_value(), Some(Height::from(Spacing::S32)));
+        assert!(wide.responsive_styles().is_none());
+
+        // A larger root font size pushes `lg` (64rem) past a 1024px viewport.
+        let zoomed = style.resolve(&ResolveContext {
+            viewport_width_px: 1024.0,
+            root_font_size: 20.0,
+            ..ResolveContext::new()
+        });
+        assert_eq!(zoomed.height_value(), None);
+    }
+
+    #[test]
+    fn test_resolve_applies_state_layers_from_breakpoints() {
+        let style = Style::new()
+            .hover(|s| s.shadow(Shadow::Sm))
+            .md(|s| s.hover(|s| s.shadow(Shadow::Lg)))
+            .data_attr(DataState::Open, |s| s.opacity(0.8));
+
+        let context = ResolveContext {
+            viewport_width_px: 800.0,
+            state: InteractionState::new()
+                .hovered(true)
+                .data_attr(DataState::Open),
+            ..ResolveContext::new()
+        };
+        let resolved = style.resolve(&context);
+
+        assert_eq!(resolved.box_shadow_value(), Some(Shadow::Lg));
+        assert_eq!(resolved.opacity_value(), Some(0.8));
+        assert!(resolved.hover_style().is_none());
+    }
+
+    #[test]
+    fn test_resolve_substitutes_theme_and_custom_properties() {
+        let theme = SemanticThemeVars::shadcn_neutral();
+        let style = Style::new()
+            .p_value(PaddingValue::var(PaddingVar::new("--gutter")))
+            .w_var(WidthVar::new("--panel"))
+            .text_size_px(20)
+            .tracking_var(LetterSpacingVar::new("--tracking"))
+            .background_token(BackgroundColor::semantic(SemanticColor::Muted))
+            .text_color_token(TextColor::CustomProperty(TextColorVar::new("--ink")));
+
+        let properties = [
+            ("--gutter", CustomPropertyValue::Px(12.0)),
+            ("--panel", CustomPropertyValue::Px(320.4)),
+            ("--tracking", CustomPropertyValue::Px(2.0)),
+            (
+                "--ink",
+                CustomPropertyValue::Color(Color::blue(Scale::S700).compute()),
+            ),
+        ];
+        let resolved = style.resolve(&ResolveContext {
+            theme: Some(theme),
+            variant: ThemeVariant::Dark,
+            custom_properties: &properties,
+            ..ResolveContext::new()
+        });
+
+        assert_eq!(
+            resolved.padding_value().and_then(Padding::top_side),
+            Some(PaddingValue::Px(12.0))
+        );
+        assert_eq!(resolved.width_value(), Some(Width::new(WidthSize::Px(320))));
+        assert_eq!(
+            resolved.letter_spacing_value(),
+            Some(LetterSpacing::Em(0.1))
+        );
+        assert_eq!(
+            resolved.background_color_value(),
+            Some(BackgroundColor::arbitrary(
+                theme
+                    .resolve_dark_value(SemanticColor::Muted)
+                    .unwrap()
+                    .into()
+            ))
+        );
+        assert_eq!(
+            resolved.text_color_token_value(),
+            Some(TextColor::arbitrary(
+                Color::blue(Scale::S700).compute().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve_substitutes_divide_color() {
+        let style = Style::new()
+            .divide_color_var(BorderColorVar::new("--rule"))
+            .md(|s| s.divide_color_token(BorderColor::semantic(SemanticColor::Border)));
+        let properties = [(
+            "--rule",
+            CustomPropertyValue::Color(Color::gray(Scale::S200).compute()),
+        )];
+
+        let narrow = style.resolve(&ResolveContext {
+            custom_properties: &properties,
+            ..ResolveContext::new()
+        });
+        assert_eq!(
+            narrow.divide_color_token_value(),
+            Some(BorderColor::arbitrary(
+                Color::gray(Scale::S200).compute().into()
+            ))
+        );
+
+        let theme = SemanticThemeVars::shadcn_neutral();
+        let wide = style.resolve(&ResolveContext {
+            viewport_width_px: 1024.0,
+            theme: Some(theme),
+            variant: ThemeVariant::Dark,
+            ..ResolveContext::new()
+        });
+        assert_eq!(
+            wide.divide_color_token_value(),
+            Some(BorderColor::arbitrary(
+                theme
+                    .resolve_dark_value(SemanticColor::Border)
+                    .unwrap()
+                    .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve_breakpoints_keeps_state_layers_and_honors_root_font_size() {
+        let style = Style::new()
+            .w(Spacing::S12)
+            .md(|s| s.w(Spacing::S24).hover(|s| s.shadow(Shadow::Md)));
+
+        // 768px is `md` at a 16px root font size but not at 20px.
+        let md = style.resolve_breakpoints(&ResolveContext {
+            viewport_width_px: 768.0,
+            ..ResolveContext::new()
+        });
+        assert_eq!(md.width_value(), Some(Width::from(Spacing::S24)));
+        assert_eq!(
+            md.hover_style().and_then(Style::box_shadow_value),
+            Some(Shadow::Md)
+        );
+        assert!(md.responsive_styles().is_none());
+
+        let scaled = style.resolve_breakpoints(&ResolveContext {
+            viewport_width_px: 768.0,
+            root_font_size: 20.0,
+            ..ResolveContext::new()
+        });
+        assert_eq!(scaled.width_value(), Some(Width::from(Spacing::S12)));
+        assert!(scaled.hover_style().is_none());
+    }
+}
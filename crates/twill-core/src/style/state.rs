@@ -1,4 +1,8 @@
-use std::{borrow::Cow, collections::BTreeMap, fmt};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
 
 use crate::style::Style;
 use crate::traits::Merge;
@@ -165,28 +169,44 @@ impl fmt::Display for AriaAttr {
 
 /// Modifiers for interaction and stateful styling like hover, focus, data, and aria hooks.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct StateStyles {
     /// Styles applied when the user hovers over the element.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) hover: Option<Style>,
     /// Styles applied when the element has programmatic or keyboard focus.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) focus: Option<Style>,
     /// Styles applied when the element matches `:focus-visible`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) focus_visible: Option<Style>,
     /// Styles applied when the element is active (e.g. while being clicked/pressed).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) active: Option<Style>,
     /// Styles applied when the element is disabled and non-interactive.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) disabled: Option<Style>,
     /// Styles applied when the element is selected.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) selected: Option<Style>,
     /// Styles applied when the element is checked.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) checked: Option<Style>,
     /// Styles applied when the element is in an open state.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) open: Option<Style>,
     /// Styles applied when the element is in a closed state.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) closed: Option<Style>,
     /// Styles keyed by arbitrary `data-*` state names.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
     pub(crate) data: BTreeMap<String, Style>,
     /// Styles keyed by arbitrary `aria-*` state names.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
     pub(crate) aria: BTreeMap<String, Style>,
 }
 
@@ -251,6 +271,215 @@ impl StateStyles {
     }
 }
 
+/// Snapshot of an element's interaction state, used by [`Style::resolve_for`].
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let state = InteractionState::new()
+///     .hovered(true)
+///     .open(false)
+///     .data_attr(DataState::Closed)
+///     .aria_attr(AriaAttr::Expanded);
+///
+/// assert!(state.is_hovered());
+/// assert_eq!(state.is_open(), Some(false));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InteractionState {
+    hovered: bool,
+    focused: bool,
+    focus_visible: bool,
+    pressed: bool,
+    disabled: bool,
+    selected: bool,
+    checked: bool,
+    open: Option<bool>,
+    data: BTreeSet<String>,
+    aria: BTreeSet<String>,
+}
+
+impl InteractionState {
+    /// Create an idle state: nothing hovered, focused, pressed or toggled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hovered(mut self, hovered: bool) -> Self {
+        self.hovered = hovered;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Mark keyboard focus (`:focus-visible`), which also counts as focused.
+    pub fn focus_visible(mut self, focus_visible: bool) -> Self {
+        self.focus_visible = focus_visible;
+        self
+    }
+
+    pub fn pressed(mut self, pressed: bool) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Mark a disclosure as open (`true`) or closed (`false`).
+    ///
+    /// Elements that never call this match neither the open nor the closed layer.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// Activate a `data-*` hook such as `data-state=open`.
+    pub fn data_attr(mut self, attr: impl Into<DataAttr>) -> Self {
+        self.data.insert(attr.into().selector().into_owned());
+        self
+    }
+
+    /// Activate an `aria-*` hook such as `aria-selected`.
+    pub fn aria_attr(mut self, attr: impl Into<AriaAttr>) -> Self {
+        self.aria.insert(attr.into().as_str().to_owned());
+        self
+    }
+
+    pub const fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    pub const fn is_focused(&self) -> bool {
+        self.focused || self.focus_visible
+    }
+
+    pub const fn is_focus_visible(&self) -> bool {
+        self.focus_visible
+    }
+
+    pub const fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    pub const fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub const fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    pub const fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    pub const fn is_open(&self) -> Option<bool> {
+        self.open
+    }
+
+    pub fn has_data_attr(&self, attr: impl Into<DataAttr>) -> bool {
+        self.data.contains(attr.into().selector().as_ref())
+    }
+
+    pub fn has_aria_attr(&self, attr: impl Into<AriaAttr>) -> bool {
+        self.aria.contains(attr.into().as_str())
+    }
+}
+
+impl StateStyles {
+    /// Layers that match `state`, in the precedence order used by [`Style::resolve_for`].
+    pub fn matching_layers<'a>(
+        &'a self,
+        state: &'a InteractionState,
+    ) -> impl Iterator<Item = &'a Style> + 'a {
+        let interactive = !state.disabled;
+        let fixed = [
+            (state.open == Some(true), &self.open),
+            (state.open == Some(false), &self.closed),
+            (state.selected, &self.selected),
+            (state.checked, &self.checked),
+            (interactive && state.hovered, &self.hover),
+            (interactive && state.is_focused(), &self.focus),
+            (interactive && state.focus_visible, &self.focus_visible),
+            (interactive && state.pressed, &self.active),
+            (state.disabled, &self.disabled),
+        ];
+        let aria = self
+            .aria
+            .iter()
+            .filter(|(name, _)| state.aria.contains(*name))
+            .map(|(_, style)| style);
+        let data = self
+            .data
+            .iter()
+            .filter(|(selector, _)| state.data.contains(*selector))
+            .map(|(_, style)| style);
+
+        fixed
+            .into_iter()
+            .filter_map(|(active, layer)| active.then_some(layer.as_ref()).flatten())
+            .chain(aria)
+            .chain(data)
+    }
+}
+
+impl Style {
+    /// Flatten the state layers that match `state` into a single style.
+    ///
+    /// Layers are merged over the base style in this order, later layers winning:
+    /// open/closed, selected, checked, hover, focus, focus-visible, active, disabled,
+    /// `aria-*`, then `data-*`. This follows Tailwind's variant order, so the result
+    /// matches what the same classes produce in a browser.
+    ///
+    /// A disabled element is not interactive: its hover, focus, focus-visible and
+    /// active layers are skipped. The returned style has no state layers; responsive
+    /// layers are kept, so it composes with [`Style::at_breakpoint`].
+    ///
+    /// ```rust
+    /// use twill_core::prelude::core::*;
+    ///
+    /// let button = Style::new()
+    ///     .opacity(1.0)
+    ///     .hover(|s| s.opacity(0.9))
+    ///     .disabled(|s| s.opacity(0.5));
+    ///
+    /// let hovered = button.resolve_for(&InteractionState::new().hovered(true));
+    /// assert_eq!(hovered.opacity_value(), Some(0.9));
+    ///
+    /// let disabled = button.resolve_for(&InteractionState::new().hovered(true).disabled(true));
+    /// assert_eq!(disabled.opacity_value(), Some(0.5));
+    /// ```
+    pub fn resolve_for(&self, state: &InteractionState) -> Self {
+        let mut resolved = self.clone();
+        resolved.states = None;
+
+        if let Some(states) = &self.states {
+            for layer in states.matching_layers(state) {
+                resolved = resolved.merged(layer.clone());
+            }
+        }
+
+        resolved.states = None;
+        resolved
+    }
+}
+
 impl Merge<Self> for StateStyles {
     fn merge(&self, other: Self) -> Self {
         self.merge_ref(&other)
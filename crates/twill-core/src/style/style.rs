@@ -5,19 +5,21 @@ use std::{collections::BTreeMap, num::NonZeroU8};
 use crate::tokens::{
     AnimationToken, AspectRatio, BackgroundColor, BackgroundColorVar, Blur, BorderColor,
     BorderColorVar, BorderRadius, BorderStyle, BorderWidth, Breakpoint, Color, ColorValueToken,
-    Container, Cursor, DropShadow, Easing, FontFamily, FontSize, FontSizeVar, FontWeight,
-    InsetShadow, LetterSpacing, LetterSpacingVar, LineHeight, LineHeightVar, MotionDefaults,
-    OutlineColor, OutlineColorVar, OutlineStyle, Percentage, Perspective, RingColor, RingColorVar,
-    RingWidth, Shadow, ShadowColorToken, ShadowColorVar, Spacing, TextAlign, TextColor,
-    TextColorVar, TextDecoration, TextShadow, TextTransform, TransitionDuration,
-    TransitionProperty,
+    Container, Cursor, DivideWidth, DropShadow, Easing, Filter, FontFamily, FontSize, FontSizeVar,
+    FontWeight, Gradient, GradientInterpolation, GradientKind, InsetShadow, LetterSpacing,
+    LetterSpacingVar, LineHeight, LineHeightVar, LinearDirection, MotionDefaults, OutlineColor,
+    OutlineColorVar, OutlineStyle, Percentage, Perspective, RingColor, RingColorVar, RingWidth,
+    Shadow, ShadowColorToken, ShadowColorVar, Spacing, TextAlign, TextColor, TextColorVar,
+    TextDecoration, TextOverflow, TextShadow, TextTransform, TransitionDuration,
+    TransitionProperty, WhiteSpace, WordBreak,
 };
 use crate::traits::IntoStyle;
 use crate::utilities::{
-    AlignItems, Columns, Display, Flex, FlexContainer, FlexDirection, GridContainer, GridTemplate,
-    Height, HeightSize, HeightVar, JustifyContent, JustifyItems, JustifySelf, Margin, MarginValue,
-    MarginVar, ObjectFit, Overflow, Padding, PaddingValue, PaddingVar, PlaceContent, PlaceItems,
-    Position, SizeConstraints, Visibility, Width, WidthSize, WidthVar, ZIndex,
+    AlignItems, BorderRadii, BorderWidths, Columns, Display, Flex, FlexContainer, FlexDirection,
+    GridContainer, GridFlow, GridLine, GridPlacement, GridTemplate, Height, HeightSize, HeightVar,
+    Inset, InsetValue, JustifyContent, JustifyItems, JustifySelf, Margin, MarginValue, MarginVar,
+    ObjectFit, Overflow, Padding, PaddingValue, PaddingVar, PlaceContent, PlaceItems, Position,
+    SizeConstraints, Transform, TransformOrigin, Visibility, Width, WidthSize, WidthVar, ZIndex,
 };
 
 /// A comprehensive style builder for composing native UI styles.
@@ -37,87 +39,189 @@ use crate::utilities::{
 /// assert!(style.data_attr_style(DataState::Open).is_some());
 /// ```
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Style {
     // Layout
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) display: Option<Display>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) visibility: Option<Visibility>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) position: Option<Position>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) inset: Option<Inset>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) z_index: Option<ZIndex>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) overflow: Option<Overflow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) overflow_x: Option<Overflow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) overflow_y: Option<Overflow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) aspect_ratio: Option<AspectRatio>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) object_fit: Option<ObjectFit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) columns: Option<Columns>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) column_gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) columns_max_count: Option<NonZeroU8>,
 
     // Flex/Grid
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) flex: Option<FlexContainer>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) flex_item: Option<Flex>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) grid: Option<GridContainer>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) place_content: Option<PlaceContent>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) place_items: Option<PlaceItems>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) justify_items: Option<JustifyItems>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) justify_self: Option<JustifySelf>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) grid_column: Option<GridPlacement>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) grid_row: Option<GridPlacement>,
 
     // Spacing
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) padding: Option<Padding>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) margin: Option<Margin>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) space_x: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) space_y: Option<Spacing>,
 
     // Size
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) width: Option<Width>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) height: Option<Height>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) constraints: Option<SizeConstraints>,
 
     // Background
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) background_color: Option<BackgroundColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) background_gradient: Option<Gradient>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) opacity: Option<f32>,
 
     // Effects
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) blur: Option<Blur>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) filter: Option<Filter>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) backdrop_blur: Option<Blur>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) drop_shadow: Option<DropShadow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) perspective: Option<Perspective>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) transform: Option<Transform>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) transform_origin: Option<TransformOrigin>,
 
     // Border
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) border_radius: Option<BorderRadius>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) border_radii: Option<BorderRadii>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) border_width: Option<BorderWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) border_widths: Option<BorderWidths>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) border_style: Option<BorderStyle>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) border_color: Option<BorderColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) divide_x: Option<DivideWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) divide_y: Option<DivideWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) divide_color: Option<BorderColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) outline_width: Option<BorderWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) outline_style: Option<OutlineStyle>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) outline_color: Option<OutlineColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) ring_width: Option<RingWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) ring_color: Option<RingColor>,
 
     // Shadow
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) box_shadow: Option<Shadow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) inset_shadow: Option<InsetShadow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) shadow_color: Option<ShadowColorToken>,
 
     // Typography
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) font_family: Option<FontFamily>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) font_size: Option<FontSize>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) font_weight: Option<FontWeight>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) letter_spacing: Option<LetterSpacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) line_height: Option<LineHeight>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) text_align: Option<TextAlign>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) text_decoration: Option<TextDecoration>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) text_transform: Option<TextTransform>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) text_overflow: Option<TextOverflow>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) white_space: Option<WhiteSpace>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) word_break: Option<WordBreak>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) line_clamp: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) text_color: Option<TextColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) text_shadow: Option<TextShadow>,
 
     // Motion (optional)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) transition_property: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) transition_duration: Option<TransitionDuration>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) transition_timing_function: Option<Easing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) transition_delay: Option<TransitionDuration>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) animation: Option<AnimationToken>,
     // Interactivity
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) cursor: Option<Cursor>,
     // State Styles
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) states: Option<Box<crate::style::state::StateStyles>>,
     // Responsive Styles
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) responsive: Option<BTreeMap<Breakpoint, Style>>,
 }
 
@@ -155,6 +259,11 @@ impl Style {
         self.position
     }
 
+    /// Returns the configured `top`/`right`/`bottom`/`left` offsets, if any.
+    pub const fn inset_value(&self) -> Option<&Inset> {
+        self.inset.as_ref()
+    }
+
     /// Returns the configured z-index, if any.
     pub const fn z_index_value(&self) -> Option<ZIndex> {
         self.z_index
@@ -235,6 +344,16 @@ impl Style {
         self.justify_self
     }
 
+    /// Returns the configured grid column placement (`col-*`), if any.
+    pub const fn grid_column_value(&self) -> Option<GridPlacement> {
+        self.grid_column
+    }
+
+    /// Returns the configured grid row placement (`row-*`), if any.
+    pub const fn grid_row_value(&self) -> Option<GridPlacement> {
+        self.grid_row
+    }
+
     /// Returns the configured padding, if any.
     pub const fn padding_value(&self) -> Option<&Padding> {
         self.padding.as_ref()
@@ -245,6 +364,16 @@ impl Style {
         self.margin.as_ref()
     }
 
+    /// Returns the configured horizontal space between children, if any.
+    pub const fn space_x_value(&self) -> Option<Spacing> {
+        self.space_x
+    }
+
+    /// Returns the configured vertical space between children, if any.
+    pub const fn space_y_value(&self) -> Option<Spacing> {
+        self.space_y
+    }
+
     /// Returns the configured width token, if any.
     pub const fn width_value(&self) -> Option<Width> {
         self.width
@@ -265,6 +394,11 @@ impl Style {
         self.background_color
     }
 
+    /// Returns the configured background gradient, if any.
+    pub const fn background_gradient_value(&self) -> Option<Gradient> {
+        self.background_gradient
+    }
+
     /// Returns the configured opacity, if any.
     pub const fn opacity_value(&self) -> Option<f32> {
         self.opacity
@@ -275,6 +409,16 @@ impl Style {
         self.blur
     }
 
+    /// Returns the configured color filters (`brightness`, `grayscale`, ...), if any.
+    pub const fn filter_value(&self) -> Option<Filter> {
+        self.filter
+    }
+
+    /// Returns the configured backdrop blur token, if any.
+    pub const fn backdrop_blur_value(&self) -> Option<Blur> {
+        self.backdrop_blur
+    }
+
     /// Returns the configured drop shadow token, if any.
     pub const fn drop_shadow_value(&self) -> Option<DropShadow> {
         self.drop_shadow
@@ -285,16 +429,48 @@ impl Style {
         self.perspective
     }
 
+    /// Returns the configured transform functions (`translate`, `rotate`, ...), if any.
+    pub const fn transform_value(&self) -> Option<Transform> {
+        self.transform
+    }
+
+    /// Returns the configured transform origin, if any.
+    pub const fn transform_origin_value(&self) -> Option<TransformOrigin> {
+        self.transform_origin
+    }
+
     /// Returns the configured border radius, if any.
     pub const fn border_radius_value(&self) -> Option<BorderRadius> {
         self.border_radius
     }
 
+    /// Returns the per-corner radius overrides, if any.
+    pub const fn border_radii_value(&self) -> Option<&BorderRadii> {
+        self.border_radii.as_ref()
+    }
+
+    /// Returns the radius of every corner, with per-corner overrides applied over
+    /// the uniform `rounded-*` value.
+    pub fn resolved_border_radii(&self) -> BorderRadii {
+        self.border_radii.unwrap_or_default().or(self.border_radius)
+    }
+
     /// Returns the configured border width, if any.
     pub const fn border_width_value(&self) -> Option<BorderWidth> {
         self.border_width
     }
 
+    /// Returns the per-side width overrides, if any.
+    pub const fn border_widths_value(&self) -> Option<&BorderWidths> {
+        self.border_widths.as_ref()
+    }
+
+    /// Returns the width of every side, with per-side overrides applied over the
+    /// uniform `border-*` value.
+    pub fn resolved_border_widths(&self) -> BorderWidths {
+        self.border_widths.unwrap_or_default().or(self.border_width)
+    }
+
     /// Returns the configured border style, if any.
     pub const fn border_style_value(&self) -> Option<BorderStyle> {
         self.border_style
@@ -313,6 +489,29 @@ impl Style {
         self.border_color
     }
 
+    /// Returns the configured width of the dividers between horizontal children, if any.
+    pub const fn divide_x_value(&self) -> Option<DivideWidth> {
+        self.divide_x
+    }
+
+    /// Returns the configured width of the dividers between vertical children, if any.
+    pub const fn divide_y_value(&self) -> Option<DivideWidth> {
+        self.divide_y
+    }
+
+    /// Returns the configured divider color, if any.
+    pub const fn divide_color_value(&self) -> Option<Color> {
+        match self.divide_color {
+            Some(token) => token.palette_value(),
+            None => None,
+        }
+    }
+
+    /// Returns the configured divider color token, if any.
+    pub const fn divide_color_token_value(&self) -> Option<BorderColor> {
+        self.divide_color
+    }
+
     /// Returns the configured outline width, if any.
     pub const fn outline_width_value(&self) -> Option<BorderWidth> {
         self.outline_width
@@ -407,6 +606,26 @@ impl Style {
         self.text_transform
     }
 
+    /// Returns the configured text overflow (`text-ellipsis`/`text-clip`), if any.
+    pub const fn text_overflow_value(&self) -> Option<TextOverflow> {
+        self.text_overflow
+    }
+
+    /// Returns the configured white-space mode, if any.
+    pub const fn white_space_value(&self) -> Option<WhiteSpace> {
+        self.white_space
+    }
+
+    /// Returns the configured word-break mode, if any.
+    pub const fn word_break_value(&self) -> Option<WordBreak> {
+        self.word_break
+    }
+
+    /// Returns the configured line clamp, if any; `0` is `line-clamp-none`.
+    pub const fn line_clamp_value(&self) -> Option<u16> {
+        self.line_clamp
+    }
+
     /// Returns the configured text shadow token, if any.
     pub const fn text_shadow_value(&self) -> Option<TextShadow> {
         self.text_shadow
@@ -455,6 +674,16 @@ impl Style {
         self.transition_delay
     }
 
+    /// Returns `true` when the transition property list covers a CSS property such as
+    /// `"background-color"`, either by name or through `all`.
+    pub fn transitions_property(&self, property: &str) -> bool {
+        self.transition_property.as_deref().is_some_and(|list| {
+            list.split(',')
+                .map(str::trim)
+                .any(|name| name == "all" || name == property)
+        })
+    }
+
     /// Returns the configured animation token, if any.
     pub const fn animation_value(&self) -> Option<AnimationToken> {
         self.animation
@@ -485,6 +714,74 @@ impl Style {
         self
     }
 
+    fn update_inset_value(
+        mut self,
+        update: impl FnOnce(&mut Inset, InsetValue),
+        value: InsetValue,
+    ) -> Self {
+        let mut inset = self.inset.unwrap_or_default();
+        update(&mut inset, value);
+        self.inset = Some(inset);
+        self
+    }
+
+    /// `inset-*` family: offset all sides of a positioned element.
+    ///
+    /// Accepts [`Spacing`], [`Percentage`] fractions or any [`InsetValue`].
+    pub fn inset(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(
+            |inset, value| {
+                inset.top = Some(value);
+                inset.right = Some(value);
+                inset.bottom = Some(value);
+                inset.left = Some(value);
+            },
+            value.into(),
+        )
+    }
+
+    /// `inset-x-*` family: offset the left and right sides.
+    pub fn inset_x(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(
+            |inset, value| {
+                inset.right = Some(value);
+                inset.left = Some(value);
+            },
+            value.into(),
+        )
+    }
+
+    /// `inset-y-*` family: offset the top and bottom sides.
+    pub fn inset_y(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(
+            |inset, value| {
+                inset.top = Some(value);
+                inset.bottom = Some(value);
+            },
+            value.into(),
+        )
+    }
+
+    /// `top-*` family.
+    pub fn top(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(|inset, value| inset.top = Some(value), value.into())
+    }
+
+    /// `right-*` family.
+    pub fn right(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(|inset, value| inset.right = Some(value), value.into())
+    }
+
+    /// `bottom-*` family.
+    pub fn bottom(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(|inset, value| inset.bottom = Some(value), value.into())
+    }
+
+    /// `left-*` family.
+    pub fn left(self, value: impl Into<InsetValue>) -> Self {
+        self.update_inset_value(|inset, value| inset.left = Some(value), value.into())
+    }
+
     /// Set z-index.
     pub fn z_index(mut self, z: ZIndex) -> Self {
         self.z_index = Some(z);
@@ -668,10 +965,11 @@ impl Style {
         self
     }
 
-    /// Set grid template columns.
-    pub fn grid_cols(mut self, cols: GridTemplate) -> Self {
+    /// Set grid template columns, from a [`GridTemplate`] or a typed
+    /// [`TrackList`](crate::utilities::TrackList).
+    pub fn grid_cols(mut self, cols: impl Into<GridTemplate>) -> Self {
         let mut grid = self.grid.unwrap_or_default();
-        grid.columns = Some(cols);
+        grid.columns = Some(cols.into());
         self.grid = Some(grid);
         self
     }
@@ -701,6 +999,28 @@ impl Style {
         self.grid_cols(GridTemplate::arbitrary(value))
     }
 
+    /// Set grid template rows, from a [`GridTemplate`] or a typed
+    /// [`TrackList`](crate::utilities::TrackList).
+    pub fn grid_rows(mut self, rows: impl Into<GridTemplate>) -> Self {
+        let mut grid = self.grid.unwrap_or_default();
+        grid.rows = Some(rows.into());
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Set repeated equal grid rows.
+    pub fn grid_rows_count(self, count: u16) -> Self {
+        self.grid_rows(GridTemplate::count(count))
+    }
+
+    /// Set how auto-placed grid items flow (`grid-flow-*`).
+    pub fn grid_flow(mut self, flow: GridFlow) -> Self {
+        let mut grid = self.grid.unwrap_or_default();
+        grid.flow = Some(flow);
+        self.grid = Some(grid);
+        self
+    }
+
     /// Set gap (for flex/grid).
     pub fn gap(mut self, spacing: Spacing) -> Self {
         if let Some(flex) = self.flex.take() {
@@ -770,6 +1090,20 @@ impl Style {
         self
     }
 
+    /// `space-x-*`: horizontal space between children, like a left margin on every
+    /// child but the first.
+    pub fn space_x(mut self, spacing: Spacing) -> Self {
+        self.space_x = Some(spacing);
+        self
+    }
+
+    /// `space-y-*`: vertical space between children, like a top margin on every child
+    /// but the first.
+    pub fn space_y(mut self, spacing: Spacing) -> Self {
+        self.space_y = Some(spacing);
+        self
+    }
+
     /// Set place content.
     pub fn place_content(mut self, content: PlaceContent) -> Self {
         self.place_content = Some(content);
@@ -794,6 +1128,72 @@ impl Style {
         self
     }
 
+    /// Set where this item sits among the columns of its grid (`grid-column`).
+    pub fn grid_column(mut self, placement: GridPlacement) -> Self {
+        self.grid_column = Some(placement);
+        self
+    }
+
+    /// Set where this item sits among the rows of its grid (`grid-row`).
+    pub fn grid_row(mut self, placement: GridPlacement) -> Self {
+        self.grid_row = Some(placement);
+        self
+    }
+
+    /// `col-span-<count>`: span `count` grid columns.
+    pub fn col_span(self, count: u16) -> Self {
+        self.grid_column(GridPlacement::span(count))
+    }
+
+    /// `col-span-full`: span every column of the explicit grid.
+    pub fn col_span_full(self) -> Self {
+        self.grid_column(GridPlacement::full())
+    }
+
+    /// `col-start-<line>`: start at a column line, counted from the end when negative.
+    pub fn col_start(mut self, line: impl Into<GridLine>) -> Self {
+        self.grid_column = Some(self.grid_column.unwrap_or_default().start(line.into()));
+        self
+    }
+
+    /// `col-end-<line>`: end at a column line, counted from the end when negative.
+    pub fn col_end(mut self, line: impl Into<GridLine>) -> Self {
+        self.grid_column = Some(self.grid_column.unwrap_or_default().end(line.into()));
+        self
+    }
+
+    /// `col-auto`: leave the column to auto-placement.
+    pub fn col_auto(self) -> Self {
+        self.grid_column(GridPlacement::auto())
+    }
+
+    /// `row-span-<count>`: span `count` grid rows.
+    pub fn row_span(self, count: u16) -> Self {
+        self.grid_row(GridPlacement::span(count))
+    }
+
+    /// `row-span-full`: span every row of the explicit grid.
+    pub fn row_span_full(self) -> Self {
+        self.grid_row(GridPlacement::full())
+    }
+
+    /// `row-start-<line>`: start at a row line, counted from the end when negative.
+    pub fn row_start(mut self, line: impl Into<GridLine>) -> Self {
+        self.grid_row = Some(self.grid_row.unwrap_or_default().start(line.into()));
+        self
+    }
+
+    /// `row-end-<line>`: end at a row line, counted from the end when negative.
+    pub fn row_end(mut self, line: impl Into<GridLine>) -> Self {
+        self.grid_row = Some(self.grid_row.unwrap_or_default().end(line.into()));
+        self
+    }
+
+    /// `row-auto`: leave the row to auto-placement.
+    pub fn row_auto(self) -> Self {
+        self.grid_row(GridPlacement::auto())
+    }
+
     // === Spacing ===
 
     fn update_padding_value(
@@ -1939,6 +2339,87 @@ impl Style {
         self.background_token(BackgroundColor::arbitrary(value))
     }
 
+    /// Set the gradient shape, like `bg-linear-to-r`, `bg-radial` or `bg-conic`.
+    pub fn bg_gradient(self, kind: GradientKind) -> Self {
+        self.update_gradient(|gradient| gradient.kind = Some(kind))
+    }
+
+    /// `bg-linear-to-*` / `bg-linear-<angle>` equivalent.
+    pub fn bg_linear(self, direction: LinearDirection) -> Self {
+        self.bg_gradient(GradientKind::Linear(direction))
+    }
+
+    /// `bg-radial` equivalent.
+    pub fn bg_radial(self) -> Self {
+        self.bg_gradient(GradientKind::Radial)
+    }
+
+    /// `bg-conic-<angle>` equivalent; `-90` starts the sweep at the left.
+    pub fn bg_conic(self, degrees: i32) -> Self {
+        self.bg_gradient(GradientKind::conic(degrees))
+    }
+
+    /// Color space of the gradient, like `bg-linear-to-r/srgb`. Defaults to OKLCH.
+    pub fn bg_gradient_interpolation(self, interpolation: GradientInterpolation) -> Self {
+        self.update_gradient(|gradient| gradient.interpolation = Some(interpolation))
+    }
+
+    /// `from-<color>` equivalent.
+    pub fn gradient_from(self, color: Color) -> Self {
+        self.gradient_from_token(BackgroundColor::palette(color))
+    }
+
+    /// Set the `from-*` color token directly.
+    pub fn gradient_from_token(self, token: BackgroundColor) -> Self {
+        self.update_gradient(|gradient| gradient.from.get_or_insert_default().color = Some(token))
+    }
+
+    /// `from-<percent>%` equivalent, clamped to `100`.
+    pub fn gradient_from_position(self, percent: u8) -> Self {
+        self.update_gradient(|gradient| {
+            gradient.from = Some(gradient.from.unwrap_or_default().at(percent));
+        })
+    }
+
+    /// `via-<color>` equivalent.
+    pub fn gradient_via(self, color: Color) -> Self {
+        self.gradient_via_token(BackgroundColor::palette(color))
+    }
+
+    /// Set the `via-*` color token directly.
+    pub fn gradient_via_token(self, token: BackgroundColor) -> Self {
+        self.update_gradient(|gradient| gradient.via.get_or_insert_default().color = Some(token))
+    }
+
+    /// `via-<percent>%` equivalent, clamped to `100`.
+    pub fn gradient_via_position(self, percent: u8) -> Self {
+        self.update_gradient(|gradient| {
+            gradient.via = Some(gradient.via.unwrap_or_default().at(percent));
+        })
+    }
+
+    /// `to-<color>` equivalent.
+    pub fn gradient_to(self, color: Color) -> Self {
+        self.gradient_to_token(BackgroundColor::palette(color))
+    }
+
+    /// Set the `to-*` color token directly.
+    pub fn gradient_to_token(self, token: BackgroundColor) -> Self {
+        self.update_gradient(|gradient| gradient.to.get_or_insert_default().color = Some(token))
+    }
+
+    /// `to-<percent>%` equivalent, clamped to `100`.
+    pub fn gradient_to_position(self, percent: u8) -> Self {
+        self.update_gradient(|gradient| {
+            gradient.to = Some(gradient.to.unwrap_or_default().at(percent));
+        })
+    }
+
+    fn update_gradient(mut self, update: impl FnOnce(&mut Gradient)) -> Self {
+        update(self.background_gradient.get_or_insert_default());
+        self
+    }
+
     /// Set opacity (0.0 - 1.0).
     pub fn opacity(mut self, opacity: f32) -> Self {
         self.opacity = Some(opacity);
@@ -1958,6 +2439,71 @@ impl Style {
         self.blur(Blur::Custom(pixels))
     }
 
+    /// `brightness-<percent>` equivalent.
+    pub fn brightness(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.brightness = Some(percent))
+    }
+
+    /// `contrast-<percent>` equivalent.
+    pub fn contrast(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.contrast = Some(percent))
+    }
+
+    /// `grayscale-<percent>` equivalent; `grayscale` is `100`.
+    pub fn grayscale(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.grayscale = Some(percent))
+    }
+
+    /// `hue-rotate-<degrees>` equivalent; negative degrees rotate counter-clockwise.
+    pub fn hue_rotate(self, degrees: i16) -> Self {
+        self.update_filter(|filter| filter.hue_rotate = Some(degrees))
+    }
+
+    /// `invert-<percent>` equivalent; `invert` is `100`.
+    pub fn invert(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.invert = Some(percent))
+    }
+
+    /// `saturate-<percent>` equivalent.
+    pub fn saturate(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.saturate = Some(percent))
+    }
+
+    /// `sepia-<percent>` equivalent; `sepia` is `100`.
+    pub fn sepia(self, percent: u16) -> Self {
+        self.update_filter(|filter| filter.sepia = Some(percent))
+    }
+
+    /// `filter-none` equivalent: resets the color filters, the blur and the drop shadow,
+    /// including those of the layers below.
+    pub fn filter_none(mut self) -> Self {
+        self.filter = Some(Filter::NONE);
+        self.blur = Some(Blur::None);
+        self.drop_shadow = Some(DropShadow::None);
+        self
+    }
+
+    fn update_filter(mut self, update: impl FnOnce(&mut Filter)) -> Self {
+        update(self.filter.get_or_insert_default());
+        self
+    }
+
+    /// Set backdrop blur, which frosts whatever is drawn behind the element.
+    pub fn backdrop_blur(mut self, blur: Blur) -> Self {
+        self.backdrop_blur = Some(blur);
+        self
+    }
+
+    /// Set backdrop blur from a raw pixel radius.
+    pub fn backdrop_blur_px(self, pixels: u16) -> Self {
+        self.backdrop_blur(Blur::Custom(pixels))
+    }
+
+    /// `backdrop-filter-none` equivalent: resets the backdrop blur.
+    pub fn backdrop_filter_none(self) -> Self {
+        self.backdrop_blur(Blur::None)
+    }
+
     /// Set drop shadow (filter).
     pub fn drop_shadow(mut self, shadow: DropShadow) -> Self {
         self.drop_shadow = Some(shadow);
@@ -1975,11 +2521,125 @@ impl Style {
         self.perspective(Perspective::CustomPx(pixels))
     }
 
+    /// `translate-x-*` family: move the element horizontally.
+    ///
+    /// Accepts [`Spacing`], [`Percentage`] fractions of the element's own width or any
+    /// [`InsetValue`].
+    pub fn translate_x(self, value: impl Into<InsetValue>) -> Self {
+        let value = value.into();
+        self.update_transform(|transform| transform.translate_x = Some(value))
+    }
+
+    /// `translate-y-*` family: move the element vertically.
+    pub fn translate_y(self, value: impl Into<InsetValue>) -> Self {
+        let value = value.into();
+        self.update_transform(|transform| transform.translate_y = Some(value))
+    }
+
+    /// `rotate-<degrees>` equivalent; negative degrees rotate counter-clockwise.
+    pub fn rotate(self, degrees: i16) -> Self {
+        self.update_transform(|transform| transform.rotate = Some(degrees))
+    }
+
+    /// `scale-<percent>` equivalent: scale both axes.
+    pub fn scale(self, percent: i16) -> Self {
+        self.update_transform(|transform| {
+            transform.scale_x = Some(percent);
+            transform.scale_y = Some(percent);
+        })
+    }
+
+    /// `scale-x-<percent>` equivalent; negative percentages mirror the element.
+    pub fn scale_x(self, percent: i16) -> Self {
+        self.update_transform(|transform| transform.scale_x = Some(percent))
+    }
+
+    /// `scale-y-<percent>` equivalent; negative percentages mirror the element.
+    pub fn scale_y(self, percent: i16) -> Self {
+        self.update_transform(|transform| transform.scale_y = Some(percent))
+    }
+
+    /// `skew-x-<degrees>` equivalent.
+    pub fn skew_x(self, degrees: i16) -> Self {
+        self.update_transform(|transform| transform.skew_x = Some(degrees))
+    }
+
+    /// `skew-y-<degrees>` equivalent.
+    pub fn skew_y(self, degrees: i16) -> Self {
+        self.update_transform(|transform| transform.skew_y = Some(degrees))
+    }
+
+    /// `transform-none` equivalent: resets every transform function, including those of
+    /// the layers below.
+    pub fn transform_none(mut self) -> Self {
+        self.transform = Some(Transform::NONE);
+        self
+    }
+
+    fn update_transform(mut self, update: impl FnOnce(&mut Transform)) -> Self {
+        update(self.transform.get_or_insert_default());
+        self
+    }
+
+    /// Set the point transforms apply around (`origin-*`); the center by default.
+    pub fn origin(mut self, origin: TransformOrigin) -> Self {
+        self.transform_origin = Some(origin);
+        self
+    }
+
+    /// `origin-center` equivalent.
+    pub fn origin_center(self) -> Self {
+        self.origin(TransformOrigin::Center)
+    }
+
+    /// `origin-top` equivalent.
+    pub fn origin_top(self) -> Self {
+        self.origin(TransformOrigin::Top)
+    }
+
+    /// `origin-top-right` equivalent.
+    pub fn origin_top_right(self) -> Self {
+        self.origin(TransformOrigin::TopRight)
+    }
+
+    /// `origin-right` equivalent.
+    pub fn origin_right(self) -> Self {
+        self.origin(TransformOrigin::Right)
+    }
+
+    /// `origin-bottom-right` equivalent.
+    pub fn origin_bottom_right(self) -> Self {
+        self.origin(TransformOrigin::BottomRight)
+    }
+
+    /// `origin-bottom` equivalent.
+    pub fn origin_bottom(self) -> Self {
+        self.origin(TransformOrigin::Bottom)
+    }
+
+    /// `origin-bottom-left` equivalent.
+    pub fn origin_bottom_left(self) -> Self {
+        self.origin(TransformOrigin::BottomLeft)
+    }
+
+    /// `origin-left` equivalent.
+    pub fn origin_left(self) -> Self {
+        self.origin(TransformOrigin::Left)
+    }
+
+    /// `origin-top-left` equivalent.
+    pub fn origin_top_left(self) -> Self {
+        self.origin(TransformOrigin::TopLeft)
+    }
+
     // === Border ===
 
     /// Set border radius.
+    ///
+    /// Replaces any per-corner radii set earlier with `rounded_t(...)` and friends.
     pub fn rounded(mut self, radius: BorderRadius) -> Self {
         self.border_radius = Some(radius);
+        self.border_radii = None;
         self
     }
 
@@ -1988,20 +2648,164 @@ impl Style {
         self.rounded(radius)
     }
 
+    fn update_border_radii(
+        mut self,
+        update: impl FnOnce(&mut BorderRadii, BorderRadius),
+        radius: BorderRadius,
+    ) -> Self {
+        let mut radii = self.border_radii.unwrap_or_default();
+        update(&mut radii, radius);
+        self.border_radii = Some(radii);
+        self
+    }
+
+    /// `rounded-t-*`: round the top-left and top-right corners.
+    pub fn rounded_t(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(
+            |radii, radius| {
+                radii.top_left = Some(radius);
+                radii.top_right = Some(radius);
+            },
+            radius,
+        )
+    }
+
+    /// `rounded-r-*`: round the top-right and bottom-right corners.
+    pub fn rounded_r(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(
+            |radii, radius| {
+                radii.top_right = Some(radius);
+                radii.bottom_right = Some(radius);
+            },
+            radius,
+        )
+    }
+
+    /// `rounded-b-*`: round the bottom-right and bottom-left corners.
+    pub fn rounded_b(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(
+            |radii, radius| {
+                radii.bottom_right = Some(radius);
+                radii.bottom_left = Some(radius);
+            },
+            radius,
+        )
+    }
+
+    /// `rounded-l-*`: round the top-left and bottom-left corners.
+    pub fn rounded_l(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(
+            |radii, radius| {
+                radii.top_left = Some(radius);
+                radii.bottom_left = Some(radius);
+            },
+            radius,
+        )
+    }
+
+    /// `rounded-s-*`: round the start corners (left in LTR layouts).
+    pub fn rounded_s(self, radius: BorderRadius) -> Self {
+        self.rounded_l(radius)
+    }
+
+    /// `rounded-e-*`: round the end corners (right in LTR layouts).
+    pub fn rounded_e(self, radius: BorderRadius) -> Self {
+        self.rounded_r(radius)
+    }
+
+    /// `rounded-tl-*`: round the top-left corner.
+    pub fn rounded_tl(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(|radii, radius| radii.top_left = Some(radius), radius)
+    }
+
+    /// `rounded-tr-*`: round the top-right corner.
+    pub fn rounded_tr(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(|radii, radius| radii.top_right = Some(radius), radius)
+    }
+
+    /// `rounded-br-*`: round the bottom-right corner.
+    pub fn rounded_br(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(|radii, radius| radii.bottom_right = Some(radius), radius)
+    }
+
+    /// `rounded-bl-*`: round the bottom-left corner.
+    pub fn rounded_bl(self, radius: BorderRadius) -> Self {
+        self.update_border_radii(|radii, radius| radii.bottom_left = Some(radius), radius)
+    }
+
     /// Set border.
+    ///
+    /// Replaces any per-side widths set earlier with `border_t(...)` and friends.
     pub fn border(mut self, width: BorderWidth, style: BorderStyle, color: Color) -> Self {
         self.border_width = Some(width);
+        self.border_widths = None;
         self.border_style = Some(style);
         self.border_color = Some(BorderColor::palette(color));
         self
     }
 
     /// Set border width without changing style or color.
+    ///
+    /// Replaces any per-side widths set earlier with `border_t(...)` and friends.
     pub fn border_width(mut self, width: BorderWidth) -> Self {
         self.border_width = Some(width);
+        self.border_widths = None;
         self
     }
 
+    fn update_border_widths(
+        mut self,
+        update: impl FnOnce(&mut BorderWidths, BorderWidth),
+        width: BorderWidth,
+    ) -> Self {
+        let mut widths = self.border_widths.unwrap_or_default();
+        update(&mut widths, width);
+        self.border_widths = Some(widths);
+        self
+    }
+
+    /// `border-t-*`: set the top border width.
+    pub fn border_t(self, width: BorderWidth) -> Self {
+        self.update_border_widths(|widths, width| widths.top = Some(width), width)
+    }
+
+    /// `border-r-*`: set the right border width.
+    pub fn border_r(self, width: BorderWidth) -> Self {
+        self.update_border_widths(|widths, width| widths.right = Some(width), width)
+    }
+
+    /// `border-b-*`: set the bottom border width.
+    pub fn border_b(self, width: BorderWidth) -> Self {
+        self.update_border_widths(|widths, width| widths.bottom = Some(width), width)
+    }
+
+    /// `border-l-*`: set the left border width.
+    pub fn border_l(self, width: BorderWidth) -> Self {
+        self.update_border_widths(|widths, width| widths.left = Some(width), width)
+    }
+
+    /// `border-x-*`: set the left and right border widths.
+    pub fn border_x(self, width: BorderWidth) -> Self {
+        self.update_border_widths(
+            |widths, width| {
+                widths.right = Some(width);
+                widths.left = Some(width);
+            },
+            width,
+        )
+    }
+
+    /// `border-y-*`: set the top and bottom border widths.
+    pub fn border_y(self, width: BorderWidth) -> Self {
+        self.update_border_widths(
+            |widths, width| {
+                widths.top = Some(width);
+                widths.bottom = Some(width);
+            },
+            width,
+        )
+    }
+
     /// Set border style without changing width or color.
     pub fn border_style(mut self, style: BorderStyle) -> Self {
         self.border_style = Some(style);
@@ -2030,6 +2834,42 @@ impl Style {
         self.border_color_token(BorderColor::arbitrary(value))
     }
 
+    /// `divide-x-*`: a divider of `width` between horizontal children, like a left
+    /// border on every child but the first.
+    pub fn divide_x(mut self, width: DivideWidth) -> Self {
+        self.divide_x = Some(width);
+        self
+    }
+
+    /// `divide-y-*`: a divider of `width` between vertical children, like a top border
+    /// on every child but the first.
+    pub fn divide_y(mut self, width: DivideWidth) -> Self {
+        self.divide_y = Some(width);
+        self
+    }
+
+    /// Set divider color.
+    pub fn divide_color(mut self, color: Color) -> Self {
+        self.divide_color = Some(BorderColor::palette(color));
+        self
+    }
+
+    /// Set divider color token directly.
+    pub fn divide_color_token(mut self, token: BorderColor) -> Self {
+        self.divide_color = Some(token);
+        self
+    }
+
+    /// `divide-(<custom-property>)` equivalent.
+    pub fn divide_color_var(self, var: BorderColorVar) -> Self {
+        self.divide_color_token(BorderColor::custom_property(var))
+    }
+
+    /// `divide-[<value>]` equivalent.
+    pub fn divide_color_arbitrary(self, value: ColorValueToken) -> Self {
+        self.divide_color_token(BorderColor::arbitrary(value))
+    }
+
     /// Set outline style.
     pub fn outline(mut self, width: BorderWidth, style: OutlineStyle, color: Color) -> Self {
         self.outline_width = Some(width);
@@ -2317,12 +3157,113 @@ impl Style {
         self
     }
 
+    /// Set the text decoration line (`underline`, `overline`, `line-through`,
+    /// `no-underline`).
+    pub fn text_decoration(mut self, decoration: TextDecoration) -> Self {
+        self.text_decoration = Some(decoration);
+        self
+    }
+
+    /// `line-through`.
+    pub fn line_through(self) -> Self {
+        self.text_decoration(TextDecoration::LineThrough)
+    }
+
+    /// `overline`.
+    pub fn overline(self) -> Self {
+        self.text_decoration(TextDecoration::Overline)
+    }
+
+    /// `no-underline`: remove a text decoration.
+    pub fn no_underline(self) -> Self {
+        self.text_decoration(TextDecoration::None)
+    }
+
     /// Set text transform.
     pub fn uppercase(mut self) -> Self {
         self.text_transform = Some(TextTransform::Uppercase);
         self
     }
 
+    /// Set how text is cased (`uppercase`, `lowercase`, `capitalize`, `normal-case`).
+    pub fn text_transform(mut self, transform: TextTransform) -> Self {
+        self.text_transform = Some(transform);
+        self
+    }
+
+    /// `lowercase`.
+    pub fn lowercase(self) -> Self {
+        self.text_transform(TextTransform::Lowercase)
+    }
+
+    /// `capitalize`: uppercase the first letter of every word.
+    pub fn capitalize(self) -> Self {
+        self.text_transform(TextTransform::Capitalize)
+    }
+
+    /// `normal-case`: undo a text transform.
+    pub fn normal_case(self) -> Self {
+        self.text_transform(TextTransform::None)
+    }
+
+    /// `truncate`: keep text on one line and end it with an ellipsis when it does not
+    /// fit (`overflow-hidden text-ellipsis whitespace-nowrap`).
+    pub fn truncate(self) -> Self {
+        self.overflow(Overflow::Hidden)
+            .text_ellipsis()
+            .whitespace(WhiteSpace::NoWrap)
+    }
+
+    /// Set what happens to text cut off at the edge of its box.
+    pub fn text_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.text_overflow = Some(overflow);
+        self
+    }
+
+    /// `text-ellipsis`.
+    pub fn text_ellipsis(self) -> Self {
+        self.text_overflow(TextOverflow::Ellipsis)
+    }
+
+    /// `text-clip`.
+    pub fn text_clip(self) -> Self {
+        self.text_overflow(TextOverflow::Clip)
+    }
+
+    /// `line-clamp-<lines>`: show at most `lines` lines, ending the last with an
+    /// ellipsis. `0` is `line-clamp-none`.
+    pub fn line_clamp(mut self, lines: u16) -> Self {
+        self.line_clamp = Some(lines);
+        self
+    }
+
+    /// `line-clamp-none`: undo a line clamp.
+    pub fn line_clamp_none(self) -> Self {
+        self.line_clamp(0)
+    }
+
+    /// Set how white space collapses and whether lines wrap (`whitespace-*`).
+    pub fn whitespace(mut self, white_space: WhiteSpace) -> Self {
+        self.white_space = Some(white_space);
+        self
+    }
+
+    /// Set where lines may break inside words (`break-*`).
+    pub fn word_break(mut self, word_break: WordBreak) -> Self {
+        self.word_break = Some(word_break);
+        self
+    }
+
+    /// `break-words`: break long words that do not fit on a line.
+    pub fn break_words(self) -> Self {
+        self.word_break(WordBreak::BreakWord)
+    }
+
+    /// `break-all`: break between any two characters.
+    pub fn break_all(self) -> Self {
+        self.word_break(WordBreak::BreakAll)
+    }
+
     /// Set text color.
     pub fn text_color(mut self, color: Color) -> Self {
         self.text_color = Some(TextColor::palette(color));
@@ -2431,12 +3372,12 @@ impl Style {
 mod tests {
     use super::*;
     use crate::tokens::{
-        AnimationToken, BorderColor, BorderColorVar, ColorValueToken, Easing, LetterSpacingVar,
-        LineHeightVar, OutlineColor, Perspective, RingColor, RingColorVar, Scale, SemanticColor,
-        ShadowColorToken, TextColor, TransitionDuration,
+        AnimationToken, BorderColor, BorderColorVar, ColorValueToken, Easing, GradientStop,
+        LetterSpacingVar, LineHeightVar, OutlineColor, Perspective, RingColor, RingColorVar, Scale,
+        SemanticColor, ShadowColorToken, TextColor, TransitionDuration,
     };
     use crate::traits::Merge;
-    use crate::{AriaAttr, DataState};
+    use crate::{AriaAttr, DataState, InteractionState};
 
     #[test]
     fn test_justify_content_builder_fields() {
@@ -2644,6 +3585,75 @@ mod tests {
         assert_eq!(sm.height_value(), Some(Height::from(Spacing::S12)));
     }
 
+    #[test]
+    fn test_resolve_for_merges_matching_layers_in_order() {
+        let style = Style::new()
+            .bg(Color::gray(Scale::S100))
+            .opacity(1.0)
+            .hover(|s| s.bg(Color::gray(Scale::S200)).shadow(Shadow::Md))
+            .active(|s| s.bg(Color::gray(Scale::S300)))
+            .checked(|s| s.bg(Color::blue(Scale::S500)))
+            .aria_attr(AriaAttr::Selected, |s| s.text_color(Color::white()))
+            .data_attr(DataState::Open, |s| s.bg(Color::blue(Scale::S700)));
+
+        let idle = style.resolve_for(&InteractionState::new());
+        assert_eq!(
+            idle.background_color_value(),
+            Some(BackgroundColor::palette(Color::gray(Scale::S100)))
+        );
+        assert!(idle.states.is_none());
+
+        let pressed = style.resolve_for(
+            &InteractionState::new()
+                .hovered(true)
+                .pressed(true)
+                .checked(true),
+        );
+        assert_eq!(
+            pressed.background_color_value(),
+            Some(BackgroundColor::palette(Color::gray(Scale::S300)))
+        );
+        assert_eq!(pressed.box_shadow_value(), Some(Shadow::Md));
+
+        let open = style.resolve_for(
+            &InteractionState::new()
+                .hovered(true)
+                .data_attr(DataState::Open)
+                .aria_attr(AriaAttr::Selected),
+        );
+        assert_eq!(
+            open.background_color_value(),
+            Some(BackgroundColor::palette(Color::blue(Scale::S700)))
+        );
+        assert_eq!(open.text_color_value(), Some(Color::white()));
+    }
+
+    #[test]
+    fn test_resolve_for_disabled_skips_interactive_layers() {
+        let style = Style::new()
+            .hover(|s| s.shadow(Shadow::Md))
+            .focus_visible(|s| s.ring_width(RingWidth::S2))
+            .disabled(|s| s.opacity(0.5))
+            .open(|s| s.rounded(BorderRadius::Lg))
+            .closed(|s| s.rounded(BorderRadius::None));
+
+        let resolved = style.resolve_for(
+            &InteractionState::new()
+                .hovered(true)
+                .focus_visible(true)
+                .disabled(true)
+                .open(false),
+        );
+        assert_eq!(resolved.opacity_value(), Some(0.5));
+        assert_eq!(resolved.box_shadow_value(), None);
+        assert_eq!(resolved.ring_width_value(), None);
+        assert_eq!(resolved.border_radius_value(), Some(BorderRadius::None));
+
+        let focused = style.resolve_for(&InteractionState::new().focus_visible(true));
+        assert_eq!(focused.ring_width_value(), Some(RingWidth::S2));
+        assert_eq!(focused.border_radius_value(), None);
+    }
+
     #[test]
     fn test_responsive_trait_resolves_cascading_layers() {
         let style = Style::new()
@@ -2738,6 +3748,180 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_inset_builders_merge_per_side() {
+        let base = Style::new()
+            .position(Position::Absolute)
+            .inset(Spacing::S2)
+            .top(Percentage::S1_2);
+        let merged = base.merged(Style::new().right(InsetValue::px(-4.0)));
+        let inset = merged.inset_value().unwrap();
+
+        assert_eq!(
+            inset.top_side(),
+            Some(InsetValue::Fraction(Percentage::S1_2))
+        );
+        assert_eq!(inset.right_side(), Some(InsetValue::Px(-4.0)));
+        assert_eq!(inset.bottom_side(), Some(InsetValue::Scale(Spacing::S2)));
+        assert_eq!(inset.top_side().unwrap().to_px(200.0), Some(100.0));
+        assert_eq!(InsetValue::neg_scale(Spacing::S2).to_px(200.0), Some(-8.0));
+        assert_eq!(InsetValue::auto().to_px(200.0), None);
+    }
+
+    #[test]
+    fn test_border_side_and_corner_builders_merge() {
+        let tab = Style::new()
+            .border_width(BorderWidth::S1)
+            .border_b(BorderWidth::S2)
+            .rounded(BorderRadius::Md)
+            .rounded_b(BorderRadius::None);
+        let widths = tab.resolved_border_widths();
+        let radii = tab.resolved_border_radii();
+
+        assert_eq!(widths.top_side(), Some(BorderWidth::S1));
+        assert_eq!(widths.bottom_side(), Some(BorderWidth::S2));
+        assert_eq!(widths.to_px(), [1.0, 1.0, 2.0, 1.0]);
+        assert_eq!(radii.to_px(), [6.0, 6.0, 0.0, 0.0]);
+
+        // Per-side values layer over the base; uniform values replace them.
+        let selected = tab.clone().merged(Style::new().border_t(BorderWidth::S4));
+        assert_eq!(
+            selected.resolved_border_widths().to_px(),
+            [4.0, 1.0, 2.0, 1.0]
+        );
+        let reset = tab
+            .clone()
+            .merged(Style::new().border_width(BorderWidth::S0));
+        assert_eq!(reset.border_widths_value(), None);
+        assert!(reset.resolved_border_widths().is_uniform());
+        let pill = tab.merged(Style::new().rounded(BorderRadius::Full));
+        assert_eq!(pill.border_radii_value(), None);
+
+        let segment = Style::new()
+            .rounded_s(BorderRadius::Lg)
+            .rounded_tr(BorderRadius::Sm);
+        assert_eq!(
+            segment.resolved_border_radii().to_px(),
+            [8.0, 4.0, 0.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn test_divide_and_space_builders_merge() {
+        let menu = Style::new()
+            .space_y(Spacing::S1)
+            .divide_y(DivideWidth::S1)
+            .divide_color(Color::gray(Scale::S200));
+        let merged = menu.merged(Style::new().space_x(Spacing::S2).divide_y(DivideWidth::S2));
+
+        assert_eq!(merged.space_x_value(), Some(Spacing::S2));
+        assert_eq!(merged.space_y_value(), Some(Spacing::S1));
+        assert_eq!(merged.divide_x_value(), None);
+        assert_eq!(merged.divide_y_value(), Some(DivideWidth::S2));
+        assert_eq!(merged.divide_color_value(), Some(Color::gray(Scale::S200)));
+    }
+
+    #[test]
+    fn test_gradient_builders_merge_per_stop() {
+        let card = Style::new()
+            .bg_linear(LinearDirection::ToR)
+            .gradient_from(Color::sky(Scale::S500))
+            .gradient_from_position(10)
+            .gradient_to(Color::indigo(Scale::S500));
+        let hovered = card.merged(
+            Style::new()
+                .gradient_from(Color::sky(Scale::S600))
+                .bg_gradient_interpolation(GradientInterpolation::Srgb),
+        );
+        let gradient = hovered
+            .background_gradient_value()
+            .expect("gradient is set");
+
+        assert_eq!(
+            gradient.kind(),
+            Some(GradientKind::Linear(LinearDirection::ToR))
+        );
+        assert_eq!(gradient.interpolation(), Some(GradientInterpolation::Srgb));
+        assert_eq!(
+            gradient.from_stop(),
+            Some(GradientStop::new(BackgroundColor::palette(Color::sky(Scale::S600))).at(10))
+        );
+        assert_eq!(gradient.via_stop(), None);
+        assert_eq!(
+            gradient.to_stop().and_then(|stop| stop.color()),
+            Some(BackgroundColor::palette(Color::indigo(Scale::S500)))
+        );
+        assert_eq!(
+            Style::new().bg_conic(-90).background_gradient_value(),
+            Some(Gradient::new(GradientKind::Conic(270)))
+        );
+        assert_eq!(
+            Style::new()
+                .gradient_via_position(150)
+                .background_gradient_value()
+                .and_then(|gradient| gradient.via_stop())
+                .and_then(|stop| stop.position()),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn test_filter_builders_merge_per_function() {
+        let disabled = Style::new()
+            .grayscale(100)
+            .brightness(90)
+            .backdrop_blur(Blur::Md);
+        let merged = disabled.merged(Style::new().brightness(110));
+        let filter = merged.filter_value().expect("filter is set");
+
+        assert_eq!(filter.grayscale(), Some(100));
+        assert_eq!(filter.brightness(), Some(110));
+        assert_eq!(filter.hue_rotate(), None);
+        assert_eq!(merged.backdrop_blur_value(), Some(Blur::Md));
+
+        let reset = merged.merged(Style::new().filter_none().backdrop_filter_none());
+        assert_eq!(reset.filter_value(), Some(Filter::NONE));
+        assert_eq!(reset.blur_value(), Some(Blur::None));
+        assert_eq!(reset.drop_shadow_value(), Some(DropShadow::None));
+        assert_eq!(reset.backdrop_blur_value(), Some(Blur::None));
+    }
+
+    #[test]
+    fn test_transform_builders_merge_per_function() {
+        let button = Style::new()
+            .translate_y(Spacing::S1)
+            .rotate(3)
+            .origin_top_left()
+            .hover(|s| s.scale(105))
+            .active(|s| s.scale_x(95).translate_y(Spacing::S0));
+
+        let hovered = button.resolve_for(&InteractionState::new().hovered(true));
+        let transform = hovered.transform_value().expect("transform is set");
+        assert_eq!(transform.scale(), (1.05, 1.05));
+        assert_eq!(transform.rotate(), Some(3));
+        assert_eq!(
+            transform.translate_y(),
+            Some(InsetValue::Scale(Spacing::S1))
+        );
+        assert_eq!(
+            hovered.transform_origin_value(),
+            Some(TransformOrigin::TopLeft)
+        );
+
+        let pressed = button.resolve_for(&InteractionState::new().hovered(true).pressed(true));
+        let transform = pressed.transform_value().expect("transform is set");
+        assert_eq!(transform.scale_x(), Some(95));
+        assert_eq!(transform.scale_y(), Some(105));
+        assert_eq!(
+            transform.translate_y(),
+            Some(InsetValue::Scale(Spacing::S0))
+        );
+        assert!(!transform.is_translate_and_scale());
+
+        let reset = pressed.merged(Style::new().transform_none());
+        assert_eq!(reset.transform_value(), Some(Transform::NONE));
+    }
+
     #[test]
     fn test_style_getters_expose_public_state_without_public_fields() {
         let style = Style::new()
@@ -2954,6 +4138,13 @@ mod tests {
             style.transition_delay_value(),
             Some(TransitionDuration::CustomMs(60))
         );
+        assert!(style.transitions_property("transform"));
+        assert!(!style.transitions_property("opacity"));
+        assert!(
+            Style::new()
+                .transition(TransitionProperty::All)
+                .transitions_property("opacity")
+        );
     }
 
     #[test]
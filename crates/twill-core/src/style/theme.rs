@@ -165,12 +165,22 @@ impl Style {
         resolved.background_color = resolved
             .background_color
             .map(|token| resolve_background_token(token, theme, variant));
+        if let Some(gradient) = &mut resolved.background_gradient {
+            for stop in gradient.stops_mut() {
+                stop.color = stop
+                    .color
+                    .map(|token| resolve_background_token(token, theme, variant));
+            }
+        }
         resolved.text_color = resolved
             .text_color
             .map(|token| resolve_text_token(token, theme, variant));
         resolved.border_color = resolved
             .border_color
             .map(|token| resolve_border_token(token, theme, variant));
+        resolved.divide_color = resolved
+            .divide_color
+            .map(|token| resolve_border_token(token, theme, variant));
         resolved.outline_color = resolved
             .outline_color
             .map(|token| resolve_outline_token(token, theme, variant));
@@ -300,6 +310,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolved_theme_resolves_divide_color() {
+        let divide = BorderColor::semantic(SemanticColor::Primary);
+        let style = Style::new()
+            .divide_color_token(divide)
+            .hover(|style| style.divide_color_token(divide))
+            .at_md(|style| style.divide_color_token(divide));
+
+        let resolved = style.resolved_dark_theme(&PartialTheme);
+        let expected = Some(BorderColor::arbitrary(ColorValueToken::from(
+            Color::blue(crate::tokens::Scale::S500).compute(),
+        )));
+
+        assert_eq!(resolved.divide_color_token_value(), expected);
+        assert_eq!(
+            resolved
+                .hover_style()
+                .and_then(Style::divide_color_token_value),
+            expected
+        );
+        assert_eq!(
+            resolved
+                .breakpoint_style(crate::tokens::Breakpoint::Md)
+                .and_then(Style::divide_color_token_value),
+            expected
+        );
+    }
+
     #[test]
     fn test_resolved_theme_preserves_unresolved_tokens() {
         let style = Style::new()
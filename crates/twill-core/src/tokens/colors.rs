@@ -392,6 +392,116 @@ impl ColorValue {
         let (l, c, h) = get_palette_oklch(color.family, color.scale);
         Self::from_oklch(l, c, h)
     }
+
+    /// Parse a CSS color written as `oklch()`, `rgb()`/`rgba()`, `hsl()`/`hsla()` or hex.
+    ///
+    /// Both the space-separated (`rgb(30 41 59 / 50%)`) and legacy comma-separated
+    /// (`hsl(222, 47%, 11%)`) argument forms are accepted.
+    pub fn from_css(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.starts_with('#') {
+            return ColorValueToken::from_hex(value).map(Self::from);
+        }
+        if value.eq_ignore_ascii_case("transparent") {
+            return Some(Self::TRANSPARENT);
+        }
+
+        let (function, args) = value.strip_suffix(')')?.split_once('(')?;
+        let ([first, second, third], alpha) = css_arguments(args)?;
+        let a = match alpha {
+            Some(alpha) => css_fraction(alpha)?,
+            None => 1.0,
+        };
+
+        let color = match function.trim().to_ascii_lowercase().as_str() {
+            "oklch" => Self::from_oklch(
+                css_fraction(first)?,
+                second.parse::<f32>().ok()?,
+                css_hue(third)?,
+            ),
+            "rgb" | "rgba" => {
+                let channel = |raw: &str| match raw.strip_suffix('%') {
+                    Some(percent) => percent.parse::<f32>().ok().map(|p| p / 100.0),
+                    None => raw.parse::<f32>().ok().map(|c| c / 255.0),
+                };
+                let (l, c, h) = crate::tokens::oklch::OklchConverter::from_srgb(
+                    channel(first)?,
+                    channel(second)?,
+                    channel(third)?,
+                );
+                Self::from_oklch(l, c, h)
+            }
+            "hsl" | "hsla" => {
+                let percent = |raw: &str| {
+                    let raw = raw.strip_suffix('%').unwrap_or(raw);
+                    raw.parse::<f32>().ok().map(|p| p / 100.0)
+                };
+                let (l, c, h) = crate::tokens::oklch::OklchConverter::from_hsl(
+                    css_hue(first)?,
+                    percent(second)?,
+                    percent(third)?,
+                );
+                Self::from_oklch(l, c, h)
+            }
+            _ => return None,
+        };
+
+        Some(color.with_alpha(a))
+    }
+}
+
+/// Split CSS color function arguments into three channels and an optional alpha.
+fn css_arguments(args: &str) -> Option<([&str; 3], Option<&str>)> {
+    let mut alpha = None;
+    let parts: Vec<&str> = if args.contains(',') {
+        args.split(',').map(str::trim).collect()
+    } else {
+        let channels = match args.split_once('/') {
+            Some((channels, value)) => {
+                alpha = Some(value.trim());
+                channels
+            }
+            None => args,
+        };
+        channels.split_whitespace().collect()
+    };
+
+    match parts.as_slice() {
+        [first, second, third] => Some(([first, second, third], alpha)),
+        [first, second, third, value] if alpha.is_none() => {
+            Some(([first, second, third], Some(value)))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a CSS `<number>` or `<percentage>` into a `0.0..=1.0`-style fraction.
+fn css_fraction(raw: &str) -> Option<f32> {
+    let value = match raw.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None => raw.parse::<f32>().ok()?,
+    };
+    value.is_finite().then_some(value)
+}
+
+/// Parse a CSS hue in degrees, accepting the `deg` unit and `none`.
+fn css_hue(raw: &str) -> Option<f32> {
+    if raw == "none" {
+        return Some(0.0);
+    }
+    let hue = raw.strip_suffix("deg").unwrap_or(raw).parse::<f32>().ok()?;
+    hue.is_finite().then_some(hue)
+}
+
+/// Formats as CSS `oklch(L C H)`, adding `/ A` when the color is translucent.
+impl fmt::Display for ColorValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklch({} {} {}", self.l, self.c, self.h)?;
+        if self.a < 1.0 {
+            write!(f, " / {}", self.a)?;
+        }
+        f.write_str(")")
+    }
 }
 
 /// Typed background-color token family (`bg-*` in Tailwind reference semantics).
@@ -621,6 +731,38 @@ impl ColorValueToken {
     pub const fn to_rgba8(self) -> (u8, u8, u8, u8) {
         (self.0, self.1, self.2, self.3)
     }
+
+    /// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` hex notation.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#')?;
+        if !digits.is_ascii() {
+            return None;
+        }
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&digits[range], 16).ok();
+        let short = |index: usize| channel(index..index + 1).map(|value| value * 17);
+
+        match digits.len() {
+            3 => Some(Self(short(0)?, short(1)?, short(2)?, 255)),
+            4 => Some(Self(short(0)?, short(1)?, short(2)?, short(3)?)),
+            6 => Some(Self(channel(0..2)?, channel(2..4)?, channel(4..6)?, 255)),
+            8 => Some(Self(
+                channel(0..2)?,
+                channel(2..4)?,
+                channel(4..6)?,
+                channel(6..8)?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Format as `#rrggbb`, or `#rrggbbaa` when the color is not fully opaque.
+    pub fn to_hex(self) -> String {
+        if self.3 == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.0, self.1, self.2, self.3)
+        }
+    }
 }
 
 impl From<ColorValue> for ColorValueToken {
@@ -1160,6 +1302,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_color_value_token_from_hex() {
+        assert_eq!(
+            ColorValueToken::from_hex("#1e293b").map(ColorValueToken::to_rgba8),
+            Some((0x1e, 0x29, 0x3b, 255))
+        );
+        assert_eq!(
+            ColorValueToken::from_hex("#f008").map(ColorValueToken::to_rgba8),
+            Some((255, 0, 0, 0x88))
+        );
+        assert_eq!(ColorValueToken::from_hex("1e293b"), None);
+        assert_eq!(ColorValueToken::from_hex("#12345"), None);
+    }
+
+    #[test]
+    fn test_color_value_css_round_trip() {
+        let value = ColorValue::new(0.628, 0.258, 29.234, 0.5);
+        assert_eq!(value.to_string(), "oklch(0.628 0.258 29.234 / 0.5)");
+        assert_eq!(ColorValue::from_css(&value.to_string()), Some(value));
+        assert_eq!(
+            ColorValue::from_css("oklch(62.8% 0.258 29.234deg)"),
+            Some(ColorValue::from_oklch(0.628, 0.258, 29.234))
+        );
+        assert_eq!(ColorValue::from_css("oklch(0.5 0.1)"), None);
+    }
+
+    #[test]
+    fn test_color_value_from_css_rgb_and_hsl() {
+        let slate = ColorValue::from_rgb(0x1e, 0x29, 0x3b);
+        for css in [
+            "#1e293b",
+            "rgb(30 41 59)",
+            "rgb(30, 41, 59)",
+            "rgba(30, 41, 59, 1)",
+        ] {
+            assert_eq!(
+                ColorValue::from_css(css).map(|c| c.to_rgb8()),
+                Some((30, 41, 59))
+            );
+        }
+        assert_eq!(
+            ColorValue::from_css("rgb(30 41 59 / 50%)").map(|c| c.alpha()),
+            Some(0.5)
+        );
+        assert_eq!(
+            ColorValue::from_css("hsl(217.2 32.6% 17.5%)").map(|c| c.to_rgb8()),
+            Some(slate.to_rgb8())
+        );
+        assert_eq!(
+            ColorValue::from_css("hsla(0, 0%, 100%, 0.1)").map(|c| (c.to_rgb8(), c.alpha())),
+            Some(((255, 255, 255), 0.1))
+        );
+        assert_eq!(ColorValue::from_css("lab(50 20 30)"), None);
+    }
+
+    #[test]
+    fn test_color_value_token_to_hex() {
+        assert_eq!(
+            ColorValueToken::from_rgb8(0x1e, 0x29, 0x3b).to_hex(),
+            "#1e293b"
+        );
+        assert_eq!(
+            ColorValueToken::from_rgba8(255, 0, 0, 0x88).to_hex(),
+            "#ff000088"
+        );
+    }
+
     #[test]
     fn test_tailwind_palette_reference_entries_match_exact_oklch_values() {
         let cases = [
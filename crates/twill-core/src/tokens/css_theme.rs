@@ -0,0 +1,383 @@
+//! Semantic themes loaded from shadcn/ui-style CSS variable files.
+
+use std::{error::Error, fmt};
+
+use crate::tokens::{ColorValue, SemanticColor, SemanticThemeSource, ThemeVariant};
+
+/// Semantic theme read from a shadcn/ui `globals.css`.
+///
+/// Colors come from the `:root` block (light) and the `.dark` block (dark), and the
+/// corner radius from `--radius`. As in the browser, `.dark` inherits every variable
+/// it does not redefine from `:root`.
+///
+/// ```rust
+/// use twill_core::prelude::theme::*;
+///
+/// let theme = CssSemanticTheme::from_css(
+///     ":root { --radius: 0.5rem; --background: oklch(1 0 0); --primary: #1e293b; }
+///      .dark { --background: hsl(222.2 84% 4.9%); }",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(theme.radius(), Some("0.5rem"));
+/// assert!(theme.resolve_dark(SemanticColor::Primary).is_some());
+/// assert!(theme.missing_tokens(ThemeVariant::Light).contains(&SemanticColor::Ring));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssSemanticTheme {
+    radius: Option<String>,
+    light: Vec<(SemanticColor, ColorValue)>,
+    dark: Vec<(SemanticColor, ColorValue)>,
+    missing_light: Vec<SemanticColor>,
+    missing_dark: Vec<SemanticColor>,
+}
+
+/// Error returned by [`CssSemanticTheme::from_css`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThemeCssError {
+    /// The stylesheet has no `:root` rule.
+    MissingRoot,
+    /// A semantic variable holds a value that is not a supported CSS color.
+    InvalidColor { variable: String, value: String },
+}
+
+impl fmt::Display for ThemeCssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRoot => f.write_str("stylesheet has no `:root` rule"),
+            Self::InvalidColor { variable, value } => {
+                write!(f, "`--{variable}` is not a supported color: `{value}`")
+            }
+        }
+    }
+}
+
+impl Error for ThemeCssError {}
+
+/// Maximum `var()` indirection followed when resolving a variable.
+const MAX_VAR_DEPTH: usize = 8;
+
+impl CssSemanticTheme {
+    /// Parse the `:root` and `.dark` variable blocks of a shadcn/ui `globals.css`.
+    ///
+    /// Colors may be written as `oklch()`, `hsl()`, `rgb()` or hex, as bare shadcn v3
+    /// HSL channels (`222.2 84% 4.9%`), or as `var(--other)` references. Rules nested in
+    /// at-rules such as `@layer base` are read too. Semantic tokens that are not defined
+    /// are reported through [`CssSemanticTheme::missing_tokens`] instead of failing.
+    pub fn from_css(css: &str) -> Result<Self, ThemeCssError> {
+        let css = strip_comments(css);
+        let mut root = None::<Vec<(&str, &str)>>;
+        let mut dark = Vec::new();
+        collect_rules(&css, &mut |selector, body| {
+            let selectors = || selector.split(',').map(str::trim);
+            if selectors().any(|s| s == ":root") {
+                root.get_or_insert_with(Vec::new).extend(declarations(body));
+            } else if selectors().any(|s| s == ".dark") {
+                dark.extend(declarations(body));
+            }
+        });
+        let root = root.ok_or(ThemeCssError::MissingRoot)?;
+
+        // `.dark` declarations come last so they override `:root` when looked up in reverse.
+        let dark_scope = root.iter().chain(&dark).copied().collect::<Vec<_>>();
+        let radius = lookup(&root, "radius", 0).map(str::to_owned);
+        let (light, missing_light) = resolve_tokens(&root)?;
+        let (dark, missing_dark) = resolve_tokens(&dark_scope)?;
+
+        Ok(Self {
+            radius,
+            light,
+            dark,
+            missing_light,
+            missing_dark,
+        })
+    }
+
+    /// The `--radius` value, such as `"0.625rem"`.
+    pub fn radius(&self) -> Option<&str> {
+        self.radius.as_deref()
+    }
+
+    pub fn light_values(&self) -> &[(SemanticColor, ColorValue)] {
+        &self.light
+    }
+
+    pub fn dark_values(&self) -> &[(SemanticColor, ColorValue)] {
+        &self.dark
+    }
+
+    /// Semantic tokens the stylesheet does not define for `variant`.
+    pub fn missing_tokens(&self, variant: ThemeVariant) -> &[SemanticColor] {
+        if variant.is_dark() {
+            &self.missing_dark
+        } else {
+            &self.missing_light
+        }
+    }
+
+    /// Returns `true` when every semantic token is defined for both variants.
+    pub fn is_complete(&self) -> bool {
+        self.missing_light.is_empty() && self.missing_dark.is_empty()
+    }
+
+    pub fn resolve(&self, token: SemanticColor, variant: ThemeVariant) -> Option<ColorValue> {
+        let source = if variant.is_dark() {
+            &self.dark
+        } else {
+            &self.light
+        };
+        source
+            .iter()
+            .find(|(t, _)| *t == token)
+            .map(|(_, color)| *color)
+    }
+
+    pub fn resolve_light(&self, token: SemanticColor) -> Option<ColorValue> {
+        self.resolve(token, ThemeVariant::Light)
+    }
+
+    pub fn resolve_dark(&self, token: SemanticColor) -> Option<ColorValue> {
+        self.resolve(token, ThemeVariant::Dark)
+    }
+}
+
+impl SemanticThemeSource for CssSemanticTheme {
+    fn resolve_value(&self, semantic: SemanticColor, variant: ThemeVariant) -> Option<ColorValue> {
+        Self::resolve(self, semantic, variant)
+    }
+
+    fn radius(&self) -> Option<&str> {
+        Self::radius(self)
+    }
+}
+
+type Declarations<'a> = [(&'a str, &'a str)];
+type TokenValues = Vec<(SemanticColor, ColorValue)>;
+
+/// Resolve every semantic token from a scope, split into found values and missing tokens.
+fn resolve_tokens(
+    scope: &Declarations<'_>,
+) -> Result<(TokenValues, Vec<SemanticColor>), ThemeCssError> {
+    let mut values = Vec::new();
+    let mut missing = Vec::new();
+    for token in SemanticColor::ALL {
+        let Some(value) = lookup(scope, token.var_name(), 0) else {
+            missing.push(token);
+            continue;
+        };
+        let color = parse_color(value).ok_or_else(|| ThemeCssError::InvalidColor {
+            variable: token.var_name().to_owned(),
+            value: value.to_owned(),
+        })?;
+        values.push((token, color));
+    }
+    Ok((values, missing))
+}
+
+/// Find the last declaration of `--name`, following `var(--other)` references.
+fn lookup<'a>(scope: &Declarations<'a>, name: &str, depth: usize) -> Option<&'a str> {
+    let value = scope
+        .iter()
+        .rev()
+        .find(|(property, _)| property.strip_prefix("--") == Some(name))
+        .map(|(_, value)| *value)?;
+    match value
+        .strip_prefix("var(--")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        Some(reference) if depth < MAX_VAR_DEPTH => lookup(scope, reference.trim(), depth + 1),
+        Some(_) => None,
+        None => Some(value),
+    }
+}
+
+/// Parse a CSS color, also accepting shadcn v3 bare HSL channels like `0 0% 100%`.
+fn parse_color(value: &str) -> Option<ColorValue> {
+    ColorValue::from_css(value).or_else(|| {
+        let channels = value.split_whitespace().count();
+        (value.contains('%') && (channels == 3 || value.contains('/')))
+            .then(|| ColorValue::from_css(&format!("hsl({value})")))
+            .flatten()
+    })
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Walk style rules, descending into block at-rules like `@layer` and `@media`.
+fn collect_rules<'a>(css: &'a str, on_rule: &mut impl FnMut(&'a str, &'a str)) {
+    let mut rest = css;
+    while let Some(open) = rest.find(['{', ';']) {
+        if rest.as_bytes()[open] == b';' {
+            rest = &rest[open + 1..];
+            continue;
+        }
+
+        let prelude = rest[..open].trim();
+        let Some(close) = matching_brace(&rest[open..]) else {
+            return;
+        };
+        let body = &rest[open + 1..open + close];
+        if prelude.starts_with('@') {
+            collect_rules(body, on_rule);
+        } else {
+            on_rule(prelude, body);
+        }
+        rest = &rest[open + close + 1..];
+    }
+}
+
+/// Byte offset of the `}` matching the `{` at the start of `block`.
+fn matching_brace(block: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, byte) in block.bytes().enumerate() {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Custom-property declarations (`--name: value`) of a rule body.
+fn declarations(body: &str) -> impl Iterator<Item = (&str, &str)> {
+    body.split(';').filter_map(|declaration| {
+        let (property, value) = declaration.split_once(':')?;
+        let property = property.trim();
+        (property.starts_with("--") && !value.contains(['{', '}']))
+            .then(|| (property, value.trim()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::SemanticThemeVars;
+
+    const SHADCN_V4: &str = r#"
+        @import "tailwindcss";
+        @custom-variant dark (&:is(.dark *));
+
+        :root {
+          --radius: 0.625rem;
+          --background: oklch(1 0 0);
+          --foreground: oklch(0.145 0 0);
+          --primary: oklch(0.205 0 0);
+          --border: oklch(0.922 0 0);
+          --ring: var(--border);
+        }
+
+        .dark {
+          --background: oklch(0.145 0 0);
+          /* translucent borders in dark mode */
+          --border: oklch(1 0 0 / 10%);
+        }
+
+        @theme inline {
+          --color-background: var(--background);
+        }
+    "#;
+
+    #[test]
+    fn test_css_theme_reads_root_and_dark_blocks() {
+        let theme = CssSemanticTheme::from_css(SHADCN_V4).unwrap();
+
+        assert_eq!(theme.radius(), Some("0.625rem"));
+        assert_eq!(
+            theme.resolve_light(SemanticColor::Background),
+            Some(ColorValue::from_oklch(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            theme.resolve_dark(SemanticColor::Border),
+            Some(ColorValue::from_oklch(1.0, 0.0, 0.0).with_alpha(0.1))
+        );
+        assert_eq!(
+            theme.resolve_dark(SemanticColor::Primary),
+            theme.resolve_light(SemanticColor::Primary)
+        );
+        assert_eq!(
+            theme.resolve_light(SemanticColor::Ring),
+            theme.resolve_light(SemanticColor::Border)
+        );
+    }
+
+    #[test]
+    fn test_css_theme_reports_missing_tokens() {
+        let theme = CssSemanticTheme::from_css(SHADCN_V4).unwrap();
+        let missing = theme.missing_tokens(ThemeVariant::Light);
+
+        assert!(!theme.is_complete());
+        assert!(missing.contains(&SemanticColor::MutedForeground));
+        assert!(!missing.contains(&SemanticColor::Ring));
+        assert_eq!(theme.resolve_light(SemanticColor::MutedForeground), None);
+    }
+
+    #[test]
+    fn test_css_theme_accepts_shadcn_v3_layer_and_bare_hsl() {
+        let theme = CssSemanticTheme::from_css(
+            "@layer base { :root { --background: 0 0% 100%; --input: 214.3 31.8% 91.4%; } \
+             .dark { --background: 222.2 84% 4.9%; } }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            theme
+                .resolve_light(SemanticColor::Background)
+                .map(|c| c.to_rgb8()),
+            Some((255, 255, 255))
+        );
+        assert!(theme.resolve_dark(SemanticColor::Input).is_some());
+    }
+
+    #[test]
+    fn test_css_theme_errors() {
+        assert_eq!(
+            CssSemanticTheme::from_css(".dark { --background: #000; }"),
+            Err(ThemeCssError::MissingRoot)
+        );
+        assert_eq!(
+            CssSemanticTheme::from_css(":root { --primary: papayawhip; }"),
+            Err(ThemeCssError::InvalidColor {
+                variable: "primary".to_owned(),
+                value: "papayawhip".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_css_theme_matches_shadcn_neutral_values() {
+        let neutral = SemanticThemeVars::shadcn_neutral();
+        let mut css = String::from(":root {");
+        for (token, value) in neutral.light_values() {
+            css.push_str(&format!("--{token}: {value};"));
+        }
+        css.push_str("} .dark {");
+        for (token, value) in neutral.dark_values() {
+            css.push_str(&format!("--{token}: {value};"));
+        }
+        css.push('}');
+
+        let theme = CssSemanticTheme::from_css(&css).unwrap();
+        assert!(theme.is_complete());
+        assert_eq!(theme.light_values(), neutral.light_values());
+        assert_eq!(theme.dark_values(), neutral.dark_values());
+    }
+}
@@ -0,0 +1,459 @@
+//! Export semantic themes and core token scales for non-Rust consumers.
+//!
+//! [`TokenExport`] writes a CSS file with `:root`/`.dark` custom-property blocks and a
+//! [W3C Design Tokens Community Group](https://www.designtokens.org/) JSON file, so web
+//! projects can share the exact values a twill application renders with.
+
+use std::fmt::{self, Write as _};
+
+use crate::tokens::names::{self, name_of};
+use crate::tokens::{
+    BorderRadius, Color, ColorFamily, ColorValue, Scale, SemanticColor, SemanticThemeSource,
+    Shadow, Spacing, ThemeVariant,
+};
+use crate::traits::ComputeValue;
+
+/// Exporter for a semantic theme plus the palette, spacing, font-size, radius and shadow scales.
+///
+/// ```rust
+/// use twill_core::prelude::theme::*;
+///
+/// let theme = DynamicSemanticTheme::from_brand_oklch(0.628, 0.258, 29.234);
+/// let export = TokenExport::new(&theme).radius("0.5rem");
+///
+/// let css = export.to_css();
+/// assert!(css.starts_with(":root {\n  --radius: 0.5rem;\n  --background: oklch("));
+/// assert!(css.contains("--color-blue-500: oklch("));
+/// assert!(css.contains(".dark {\n  --background: oklch("));
+///
+/// let json = export.to_design_tokens_json();
+/// assert!(json.contains("\"$type\": \"color\""));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenExport<'a, S: SemanticThemeSource + ?Sized> {
+    theme: &'a S,
+    radius: Option<String>,
+    scales: bool,
+}
+
+impl<'a, S: SemanticThemeSource + ?Sized> TokenExport<'a, S> {
+    /// Export `theme`, taking `--radius` from [`SemanticThemeSource::radius`].
+    pub fn new(theme: &'a S) -> Self {
+        Self {
+            radius: theme.radius().map(str::to_owned),
+            theme,
+            scales: true,
+        }
+    }
+
+    /// Override the exported `--radius` value.
+    pub fn radius(mut self, radius: impl Into<String>) -> Self {
+        self.radius = Some(radius.into());
+        self
+    }
+
+    /// Include the palette, spacing, font-size, radius and shadow scales (default `true`).
+    ///
+    /// Turn this off to export only the semantic theme.
+    pub fn scales(mut self, scales: bool) -> Self {
+        self.scales = scales;
+        self
+    }
+
+    /// Render a stylesheet with a `:root` block (radius, light theme and scales) and a
+    /// `.dark` block (dark theme), using shadcn/ui and Tailwind v4 variable names.
+    pub fn to_css(&self) -> String {
+        let mut css = String::from(":root {\n");
+        if let Some(radius) = &self.radius {
+            declare(&mut css, "radius", radius);
+        }
+        self.declare_theme(&mut css, ThemeVariant::Light);
+        if self.scales {
+            declare_scales(&mut css);
+        }
+        css.push_str("}\n\n.dark {\n");
+        self.declare_theme(&mut css, ThemeVariant::Dark);
+        css.push_str("}\n");
+        css
+    }
+
+    /// Render a Design Tokens Community Group JSON document.
+    ///
+    /// Semantic colors live under `theme.light` and `theme.dark`; scales under `color`,
+    /// `spacing`, `font-size`, `line-height`, `radius` and `shadow`.
+    pub fn to_design_tokens_json(&self) -> String {
+        let mut theme = Vec::new();
+        if let Some(radius) = self.radius.as_deref().and_then(parse_dimension) {
+            theme.push(("radius".to_owned(), radius));
+        }
+        for (name, variant) in [("light", ThemeVariant::Light), ("dark", ThemeVariant::Dark)] {
+            let tokens = self
+                .theme_values(variant)
+                .map(|(token, value)| (token.var_name().to_owned(), color_token(value)))
+                .collect();
+            theme.push((name.to_owned(), Json::Object(tokens)));
+        }
+
+        let mut root = vec![("theme".to_owned(), Json::Object(theme))];
+        if self.scales {
+            root.extend(scale_groups());
+        }
+
+        let mut json = String::new();
+        Json::Object(root).write(&mut json, 0);
+        json.push('\n');
+        json
+    }
+
+    fn theme_values(
+        &self,
+        variant: ThemeVariant,
+    ) -> impl Iterator<Item = (SemanticColor, ColorValue)> + '_ {
+        SemanticColor::ALL.into_iter().filter_map(move |token| {
+            self.theme
+                .resolve_value(token, variant)
+                .map(|value| (token, value))
+        })
+    }
+
+    fn declare_theme(&self, css: &mut String, variant: ThemeVariant) {
+        for (token, value) in self.theme_values(variant) {
+            declare(css, token.var_name(), value);
+        }
+    }
+}
+
+fn declare(css: &mut String, name: &str, value: impl fmt::Display) {
+    let _ = writeln!(css, "  --{name}: {value};");
+}
+
+/// Palette colors in Tailwind order, keyed like `blue-500` (`black`/`white` unscaled).
+fn palette() -> impl Iterator<Item = (String, ColorValue)> {
+    names::COLOR_FAMILY
+        .iter()
+        .flat_map(|(family_name, family)| {
+            let scales: &[Scale] = match family {
+                ColorFamily::Black | ColorFamily::White => &[Scale::S500],
+                _ => &Scale::ALL,
+            };
+            scales.iter().map(move |scale| {
+                let name = match family {
+                    ColorFamily::Black | ColorFamily::White => (*family_name).to_owned(),
+                    _ => format!(
+                        "{family_name}-{}",
+                        name_of(names::SCALE, scale).unwrap_or("")
+                    ),
+                };
+                (name, Color::new(*family, *scale).compute())
+            })
+        })
+}
+
+/// Spacing tokens with a length, keyed like `0_5` (CSS-safe form of `0.5`).
+fn spacing() -> impl Iterator<Item = (String, Spacing)> {
+    names::SPACING
+        .iter()
+        .filter(|(_, spacing)| *spacing != Spacing::Auto)
+        .map(|(name, spacing)| (name.replace('.', "_"), *spacing))
+}
+
+fn radius_css(radius: BorderRadius) -> String {
+    match radius {
+        BorderRadius::None => "0".to_owned(),
+        BorderRadius::Full => "9999px".to_owned(),
+        radius => format!("{}rem", radius.px_value() / 16.0),
+    }
+}
+
+fn shadows() -> impl Iterator<Item = (&'static str, Shadow)> {
+    names::SHADOW
+        .iter()
+        .copied()
+        .filter(|(_, shadow)| *shadow != Shadow::None)
+}
+
+fn declare_scales(css: &mut String) {
+    for (name, value) in palette() {
+        declare(css, &format!("color-{name}"), value);
+    }
+    for (name, spacing) in spacing() {
+        declare(css, &format!("spacing-{name}"), spacing.compute());
+    }
+    for (name, size) in names::FONT_SIZE {
+        declare(css, &format!("text-{name}"), size.value());
+        declare(
+            css,
+            &format!("text-{name}--line-height"),
+            size.line_height(),
+        );
+    }
+    for (name, radius) in names::BORDER_RADIUS {
+        declare(css, &format!("radius-{name}"), radius_css(*radius));
+    }
+    for (name, shadow) in shadows() {
+        declare(css, &format!("shadow-{name}"), shadow.value());
+    }
+}
+
+fn scale_groups() -> Vec<(String, Json)> {
+    vec![
+        (
+            "color".to_owned(),
+            Json::Object(
+                palette()
+                    .map(|(name, value)| (name, color_token(value)))
+                    .collect(),
+            ),
+        ),
+        (
+            "spacing".to_owned(),
+            Json::Object(
+                spacing()
+                    .map(|(name, spacing)| {
+                        let value = match spacing.to_rem() {
+                            Some(rem) => dimension(rem, "rem"),
+                            None => dimension(spacing.to_px().unwrap_or(0) as f32, "px"),
+                        };
+                        (name, value)
+                    })
+                    .collect(),
+            ),
+        ),
+        (
+            "font-size".to_owned(),
+            Json::Object(
+                names::FONT_SIZE
+                    .iter()
+                    .map(|(name, size)| ((*name).to_owned(), dimension(size.size_rem(), "rem")))
+                    .collect(),
+            ),
+        ),
+        (
+            "line-height".to_owned(),
+            Json::Object(
+                names::FONT_SIZE
+                    .iter()
+                    .map(|(name, size)| {
+                        let value = typed("number", Json::Number(size.line_height()));
+                        ((*name).to_owned(), value)
+                    })
+                    .collect(),
+            ),
+        ),
+        (
+            "radius".to_owned(),
+            Json::Object(
+                names::BORDER_RADIUS
+                    .iter()
+                    .map(|(name, radius)| {
+                        let value = match radius {
+                            BorderRadius::Full => dimension(9999.0, "px"),
+                            radius => dimension(radius.px_value() / 16.0, "rem"),
+                        };
+                        ((*name).to_owned(), value)
+                    })
+                    .collect(),
+            ),
+        ),
+        (
+            "shadow".to_owned(),
+            Json::Object(
+                shadows()
+                    .map(|(name, shadow)| (name.to_owned(), shadow_token(shadow.value())))
+                    .collect(),
+            ),
+        ),
+    ]
+}
+
+fn typed(kind: &str, value: Json) -> Json {
+    Json::Object(vec![
+        ("$type".to_owned(), Json::String(kind.to_owned())),
+        ("$value".to_owned(), value),
+    ])
+}
+
+fn color_value(value: ColorValue) -> Json {
+    let (l, c, h) = value.to_oklch();
+    let (r, g, b) = value.to_rgb8();
+    Json::Object(vec![
+        ("colorSpace".to_owned(), Json::String("oklch".to_owned())),
+        (
+            "components".to_owned(),
+            Json::Array(vec![Json::Number(l), Json::Number(c), Json::Number(h)]),
+        ),
+        ("alpha".to_owned(), Json::Number(value.alpha())),
+        (
+            "hex".to_owned(),
+            Json::String(format!("#{r:02x}{g:02x}{b:02x}")),
+        ),
+    ])
+}
+
+fn color_token(value: ColorValue) -> Json {
+    typed("color", color_value(value))
+}
+
+fn dimension_value(value: f32, unit: &str) -> Json {
+    Json::Object(vec![
+        ("value".to_owned(), Json::Number(value)),
+        ("unit".to_owned(), Json::String(unit.to_owned())),
+    ])
+}
+
+fn dimension(value: f32, unit: &str) -> Json {
+    typed("dimension", dimension_value(value, unit))
+}
+
+/// Parse a `rem`/`px` length such as `0.625rem` into a dimension token.
+fn parse_dimension(raw: &str) -> Option<Json> {
+    let raw = raw.trim();
+    ["rem", "px"].into_iter().find_map(|unit| {
+        let value = raw.strip_suffix(unit)?.parse::<f32>().ok()?;
+        Some(dimension(value, unit))
+    })
+}
+
+/// Convert a CSS `box-shadow` list (`0 1px 2px 0 rgb(0 0 0 / 0.05), ...`) into a shadow token.
+fn shadow_token(css: &str) -> Json {
+    let layers = css
+        .split("), ")
+        .filter_map(|layer| {
+            let (lengths, color) = layer.split_once("rgb(")?;
+            let color = ColorValue::from_css(&format!("rgb({})", color.trim_end_matches(')')))?;
+            let mut lengths = lengths.split_whitespace().map(|length| {
+                let px = length.strip_suffix("px").unwrap_or(length);
+                dimension_value(px.parse().unwrap_or(0.0), "px")
+            });
+            let mut next = || lengths.next().unwrap_or_else(|| dimension_value(0.0, "px"));
+            Some(Json::Object(vec![
+                ("color".to_owned(), color_value(color)),
+                ("offsetX".to_owned(), next()),
+                ("offsetY".to_owned(), next()),
+                ("blur".to_owned(), next()),
+                ("spread".to_owned(), next()),
+            ]))
+        })
+        .collect();
+    typed("shadow", Json::Array(layers))
+}
+
+/// Minimal JSON tree, so exporting does not require the `serde` feature.
+enum Json {
+    Number(f32),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn write(&self, out: &mut String, indent: usize) {
+        let pad = |out: &mut String, depth: usize| out.push_str(&"  ".repeat(depth));
+        match self {
+            Json::Number(number) if number.is_finite() => {
+                let _ = write!(out, "{number}");
+            }
+            Json::Number(_) => out.push_str("null"),
+            Json::String(string) => write_string(out, string),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push_str("[\n");
+                for (index, item) in items.iter().enumerate() {
+                    pad(out, indent + 1);
+                    item.write(out, indent + 1);
+                    out.push_str(if index + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push(']');
+            }
+            Json::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Json::Object(entries) => {
+                out.push_str("{\n");
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    pad(out, indent + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                    out.push_str(if index + 1 < entries.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                pad(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            ch if ch.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(ch));
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::{CssSemanticTheme, SemanticThemeVars};
+
+    #[test]
+    fn test_css_export_round_trips_through_css_theme_loader() {
+        let neutral = SemanticThemeVars::shadcn_neutral();
+        let css = TokenExport::new(neutral).to_css();
+
+        assert!(css.contains("  --radius: 0.625rem;\n"));
+        assert!(css.contains("  --spacing-0_5: 0.125rem;\n"));
+        assert!(css.contains("  --text-sm: 0.875rem;\n"));
+        assert!(css.contains("  --radius-md: 0.375rem;\n"));
+        assert!(css.contains("  --shadow-sm: 0 1px 3px 0 rgb(0 0 0 / 0.1)"));
+        assert!(!css.contains("--shadow-none"));
+
+        let loaded = CssSemanticTheme::from_css(&css).unwrap();
+        assert!(loaded.is_complete());
+        assert_eq!(loaded.radius(), Some("0.625rem"));
+        assert_eq!(loaded.light_values(), neutral.light_values());
+        assert_eq!(loaded.dark_values(), neutral.dark_values());
+    }
+
+    #[test]
+    fn test_css_export_without_scales() {
+        let css = TokenExport::new(SemanticThemeVars::shadcn_neutral())
+            .scales(false)
+            .to_css();
+        assert!(!css.contains("--color-"));
+        assert!(css.contains("--muted-foreground: oklch("));
+    }
+
+    #[test]
+    fn test_design_tokens_json_structure() {
+        let json = TokenExport::new(SemanticThemeVars::shadcn_neutral()).to_design_tokens_json();
+
+        assert!(json.starts_with("{\n  \"theme\": {\n    \"radius\": {\n"));
+        assert!(json.contains("\"unit\": \"rem\""));
+        assert!(json.contains("\"colorSpace\": \"oklch\""));
+        assert!(json.contains("\"hex\": \"#ffffff\""));
+        assert!(json.contains("\"offsetY\": {"));
+        assert!(json.contains("\"blue-500\": {"));
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+
+    #[test]
+    fn test_shadow_token_parses_layers() {
+        let mut json = String::new();
+        shadow_token(Shadow::Md.value()).write(&mut json, 0);
+
+        assert_eq!(json.matches("\"offsetX\"").count(), 2);
+        assert!(json.contains("\"value\": -1,"));
+    }
+}
@@ -0,0 +1,282 @@
+//! Color filters: `brightness-*`, `contrast-*`, `grayscale`, `hue-rotate-*`, `invert`,
+//! `saturate-*` and `sepia`.
+
+use super::{ColorValue, OklchConverter};
+
+/// The color-matrix functions of a CSS `filter`.
+///
+/// Amounts are percentages (`brightness-125` is `125`) and the hue rotation is in
+/// degrees. Like Tailwind, each function is set by its own utility, so a state layer
+/// can change one of them and keep the rest. Elements fade with
+/// [`Style::opacity`](crate::Style::opacity) instead of a filter function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Filter {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) brightness: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) contrast: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) grayscale: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) hue_rotate: Option<i16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) invert: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) saturate: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) sepia: Option<u16>,
+}
+
+impl Filter {
+    /// `filter-none`: every function set to its identity value, so it also resets the
+    /// functions of the layers below.
+    pub const NONE: Self = Self {
+        brightness: Some(100),
+        contrast: Some(100),
+        grayscale: Some(0),
+        hue_rotate: Some(0),
+        invert: Some(0),
+        saturate: Some(100),
+        sepia: Some(0),
+    };
+
+    pub const fn new() -> Self {
+        Self {
+            brightness: None,
+            contrast: None,
+            grayscale: None,
+            hue_rotate: None,
+            invert: None,
+            saturate: None,
+            sepia: None,
+        }
+    }
+
+    pub const fn brightness(&self) -> Option<u16> {
+        self.brightness
+    }
+
+    pub const fn contrast(&self) -> Option<u16> {
+        self.contrast
+    }
+
+    pub const fn grayscale(&self) -> Option<u16> {
+        self.grayscale
+    }
+
+    pub const fn hue_rotate(&self) -> Option<i16> {
+        self.hue_rotate
+    }
+
+    pub const fn invert(&self) -> Option<u16> {
+        self.invert
+    }
+
+    pub const fn saturate(&self) -> Option<u16> {
+        self.saturate
+    }
+
+    pub const fn sepia(&self) -> Option<u16> {
+        self.sepia
+    }
+
+    /// Whether applying the filter leaves every color unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.brightness.is_none_or(|amount| amount == 100)
+            && self.contrast.is_none_or(|amount| amount == 100)
+            && self.grayscale.is_none_or(|amount| amount == 0)
+            && self.hue_rotate.is_none_or(|degrees| degrees % 360 == 0)
+            && self.invert.is_none_or(|amount| amount == 0)
+            && self.saturate.is_none_or(|amount| amount == 100)
+            && self.sepia.is_none_or(|amount| amount == 0)
+    }
+
+    /// Filter an sRGB color with straight (not premultiplied) alpha, every channel in
+    /// `0.0..=1.0`.
+    ///
+    /// The functions run in Tailwind's order (`brightness`, `contrast`, `grayscale`,
+    /// `hue-rotate`, `invert`, `saturate`, `sepia`) with the matrices of the
+    /// Filter Effects spec, applied to sRGB values like browsers do and clamped after
+    /// each step.
+    pub fn apply_rgba(&self, [r, g, b, a]: [f32; 4]) -> [f32; 4] {
+        let mut rgb = [r, g, b];
+        let percent = |amount: u16| f32::from(amount) / 100.0;
+
+        if let Some(amount) = self.brightness {
+            rgb = linear(rgb, percent(amount), 0.0);
+        }
+        if let Some(amount) = self.contrast {
+            let amount = percent(amount);
+            rgb = linear(rgb, amount, 0.5 - 0.5 * amount);
+        }
+        if let Some(amount) = self.grayscale {
+            let s = 1.0 - percent(amount).min(1.0);
+            rgb = matrix(
+                rgb,
+                [
+                    [
+                        0.2126 + 0.7874 * s,
+                        0.7152 - 0.7152 * s,
+                        0.0722 - 0.0722 * s,
+                    ],
+                    [
+                        0.2126 - 0.2126 * s,
+                        0.7152 + 0.2848 * s,
+                        0.0722 - 0.0722 * s,
+                    ],
+                    [
+                        0.2126 - 0.2126 * s,
+                        0.7152 - 0.7152 * s,
+                        0.0722 + 0.9278 * s,
+                    ],
+                ],
+            );
+        }
+        if let Some(degrees) = self.hue_rotate {
+            let (sin, cos) = f32::from(degrees).to_radians().sin_cos();
+            rgb = matrix(
+                rgb,
+                [
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                    ],
+                    [
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                    ],
+                    [
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                ],
+            );
+        }
+        if let Some(amount) = self.invert {
+            let amount = percent(amount).min(1.0);
+            rgb = linear(rgb, 1.0 - 2.0 * amount, amount);
+        }
+        if let Some(amount) = self.saturate {
+            let s = percent(amount);
+            rgb = matrix(
+                rgb,
+                [
+                    [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+                ],
+            );
+        }
+        if let Some(amount) = self.sepia {
+            let s = 1.0 - percent(amount).min(1.0);
+            rgb = matrix(
+                rgb,
+                [
+                    [0.393 + 0.607 * s, 0.769 - 0.769 * s, 0.189 - 0.189 * s],
+                    [0.349 - 0.349 * s, 0.686 + 0.314 * s, 0.168 - 0.168 * s],
+                    [0.272 - 0.272 * s, 0.534 - 0.534 * s, 0.131 + 0.869 * s],
+                ],
+            );
+        }
+
+        let [r, g, b] = rgb;
+        [r, g, b, a]
+    }
+
+    /// Filter a resolved color; see [`Filter::apply_rgba`].
+    pub fn apply(&self, color: ColorValue) -> ColorValue {
+        if self.is_identity() {
+            return color;
+        }
+        let (r, g, b) = color.to_rgb8();
+        let [r, g, b, a] = self.apply_rgba([
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0,
+            color.alpha(),
+        ]);
+        let (l, c, h) = OklchConverter::from_srgb(r, g, b);
+        ColorValue::new(l, c, h, a)
+    }
+}
+
+fn linear(rgb: [f32; 3], slope: f32, intercept: f32) -> [f32; 3] {
+    rgb.map(|channel| (channel * slope + intercept).clamp(0.0, 1.0))
+}
+
+fn matrix([r, g, b]: [f32; 3], rows: [[f32; 3]; 3]) -> [f32; 3] {
+    rows.map(|[x, y, z]| (r * x + g * y + b * z).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(actual: [f32; 4], expected: [f32; 4]) -> bool {
+        actual
+            .iter()
+            .zip(expected)
+            .all(|(actual, expected)| (actual - expected).abs() < 1e-3)
+    }
+
+    #[test]
+    fn test_filter_matrices_follow_the_spec() {
+        let orange = [1.0, 0.5, 0.0, 1.0];
+        let filter = |update: fn(&mut Filter)| {
+            let mut filter = Filter::new();
+            update(&mut filter);
+            filter
+        };
+
+        assert!(Filter::new().is_identity());
+        assert!(Filter::NONE.is_identity());
+        assert!(close(Filter::NONE.apply_rgba(orange), orange));
+
+        let gray = filter(|f| f.grayscale = Some(100)).apply_rgba(orange);
+        let luma = 0.2126 + 0.7152 * 0.5;
+        assert!(close(gray, [luma, luma, luma, 1.0]));
+
+        let half = filter(|f| f.brightness = Some(50)).apply_rgba(orange);
+        assert!(close(half, [0.5, 0.25, 0.0, 1.0]));
+
+        let flat = filter(|f| f.contrast = Some(0)).apply_rgba(orange);
+        assert!(close(flat, [0.5, 0.5, 0.5, 1.0]));
+
+        let inverted = filter(|f| f.invert = Some(100)).apply_rgba(orange);
+        assert!(close(inverted, [0.0, 0.5, 1.0, 1.0]));
+
+        // A full turn is the identity, and `saturate-0` matches `grayscale` up to the
+        // spec's rounded coefficients.
+        assert!(filter(|f| f.hue_rotate = Some(-360)).is_identity());
+        let desaturated = filter(|f| f.saturate = Some(0)).apply_rgba(orange);
+        assert!((desaturated[0] - desaturated[2]).abs() < 1e-3);
+
+        // Functions chain in Tailwind's order: `grayscale` then `invert`.
+        let chained = filter(|f| {
+            f.grayscale = Some(100);
+            f.invert = Some(100);
+        })
+        .apply_rgba(orange);
+        assert!(close(chained, [1.0 - luma, 1.0 - luma, 1.0 - luma, 1.0]));
+    }
+
+    #[test]
+    fn test_filter_applies_to_color_values() {
+        let blue = ColorValue::from_rgb(59, 130, 246).with_alpha(0.5);
+        let mut grayscale = Filter::new();
+        grayscale.grayscale = Some(100);
+
+        let gray = grayscale.apply(blue);
+        assert!(gray.chroma() < 1e-3);
+        assert!((gray.alpha() - 0.5).abs() < f32::EPSILON);
+        assert_eq!(Filter::new().apply(blue), blue);
+    }
+}
@@ -0,0 +1,363 @@
+//! Concrete fonts behind the `font-sans`, `font-serif` and `font-mono` families.
+//!
+//! [`FontFamily::stack`] is a CSS font stack that native toolkits cannot resolve, so apps
+//! register the font files they ship in a [`FontRegistry`] and backend adapters install
+//! them and pick the face nearest to a [`FontWeight`].
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+use super::typography::{FontFamily, FontWeight};
+
+/// Where the data of a [`FontFace`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontSource {
+    /// Font file contents, such as `include_bytes!("Inter-Regular.ttf")`.
+    Bytes(Cow<'static, [u8]>),
+    /// Path of a font file, read when the fonts are installed.
+    Path(PathBuf),
+}
+
+impl FontSource {
+    /// Font file contents embedded in the binary.
+    pub const fn bytes(bytes: &'static [u8]) -> Self {
+        Self::Bytes(Cow::Borrowed(bytes))
+    }
+
+    /// Path of a font file.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Self::Path(path.into())
+    }
+
+    /// The font file contents, reading them from disk for [`FontSource::Path`].
+    pub fn load(&self) -> Result<Cow<'static, [u8]>, FontLoadError> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes.clone()),
+            Self::Path(path) => {
+                std::fs::read(path)
+                    .map(Cow::Owned)
+                    .map_err(|error| FontLoadError {
+                        path: path.clone(),
+                        error,
+                    })
+            }
+        }
+    }
+}
+
+impl From<&'static [u8]> for FontSource {
+    fn from(bytes: &'static [u8]) -> Self {
+        Self::bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for FontSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(Cow::Owned(bytes))
+    }
+}
+
+impl From<PathBuf> for FontSource {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+/// A font file that could not be read.
+#[derive(Debug)]
+pub struct FontLoadError {
+    path: PathBuf,
+    error: std::io::Error,
+}
+
+impl FontLoadError {
+    /// The path of the font file.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl fmt::Display for FontLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to read font `{}`: {}",
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+impl Error for FontLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A family fonts are registered under: one of the [`FontFamily`] tokens or a custom
+/// family an app refers to by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegisteredFamily {
+    /// The fonts used for `font-sans`, `font-serif` or `font-mono`.
+    Token(FontFamily),
+    /// A family of its own, such as a display font for headings.
+    Custom(&'static str),
+}
+
+impl RegisteredFamily {
+    /// The name adapters install the family under: `twill-sans`, `twill-serif` and
+    /// `twill-mono` for the tokens, or the custom name.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Token(FontFamily::Sans) => "twill-sans",
+            Self::Token(FontFamily::Serif) => "twill-serif",
+            Self::Token(FontFamily::Mono) => "twill-mono",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl From<FontFamily> for RegisteredFamily {
+    fn from(family: FontFamily) -> Self {
+        Self::Token(family)
+    }
+}
+
+impl From<&'static str> for RegisteredFamily {
+    fn from(name: &'static str) -> Self {
+        Self::Custom(name)
+    }
+}
+
+/// One face of a registered family: a font file with its weight and slant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontFace {
+    name: &'static str,
+    source: FontSource,
+    weight: FontWeight,
+    italic: bool,
+}
+
+impl FontFace {
+    /// A regular face of the font named `name`, the family name stored in the font file
+    /// (`"Inter"`), which toolkits that look fonts up by name need.
+    pub fn new(name: &'static str, source: impl Into<FontSource>) -> Self {
+        Self {
+            name,
+            source: source.into(),
+            weight: FontWeight::Normal,
+            italic: false,
+        }
+    }
+
+    /// Set the weight of the face.
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Mark the face as italic.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// The family name stored in the font file.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Where the font data comes from.
+    pub const fn source(&self) -> &FontSource {
+        &self.source
+    }
+
+    /// The weight of the face.
+    pub const fn weight_value(&self) -> FontWeight {
+        self.weight
+    }
+
+    /// Whether the face is italic.
+    pub const fn is_italic(&self) -> bool {
+        self.italic
+    }
+}
+
+/// The fonts an app ships, by family, for backend adapters to install.
+///
+/// Faces are matched to a [`FontWeight`] like CSS font matching does: an exact weight
+/// wins, weights up to 500 look lighter first and heavier weights look heavier first,
+/// so `font-semibold` uses a bold face when there is no semibold one. Italic faces are
+/// only used for italic text while an upright face exists.
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// static INTER: &[u8] = &[]; // include_bytes!("Inter-Regular.ttf")
+/// static INTER_BOLD: &[u8] = &[]; // include_bytes!("Inter-Bold.ttf")
+///
+/// let fonts = FontRegistry::new()
+///     .register(FontFamily::Sans, FontFace::new("Inter", INTER))
+///     .register(
+///         FontFamily::Sans,
+///         FontFace::new("Inter", INTER_BOLD).weight(FontWeight::Bold),
+///     )
+///     .register("Display", FontFace::new("Fraunces", FontSource::path("fonts/Fraunces.ttf")));
+///
+/// let face = fonts.resolve(FontFamily::Sans, FontWeight::SemiBold, false);
+/// assert_eq!(face.map(FontFace::weight_value), Some(FontWeight::Bold));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FontRegistry {
+    families: Vec<(RegisteredFamily, Vec<FontFace>)>,
+}
+
+impl FontRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `face` to `family`.
+    pub fn register(mut self, family: impl Into<RegisteredFamily>, face: FontFace) -> Self {
+        let family = family.into();
+        match self.families.iter_mut().find(|(key, _)| *key == family) {
+            Some((_, faces)) => faces.push(face),
+            None => self.families.push((family, vec![face])),
+        }
+        self
+    }
+
+    /// Whether no faces are registered.
+    pub fn is_empty(&self) -> bool {
+        self.families.is_empty()
+    }
+
+    /// Registered families with their faces, in registration order.
+    pub fn families(&self) -> impl Iterator<Item = (RegisteredFamily, &[FontFace])> {
+        self.families
+            .iter()
+            .map(|(family, faces)| (*family, faces.as_slice()))
+    }
+
+    /// The faces registered for `family`.
+    pub fn faces(&self, family: impl Into<RegisteredFamily>) -> &[FontFace] {
+        let family = family.into();
+        self.families
+            .iter()
+            .find(|(key, _)| *key == family)
+            .map_or(&[], |(_, faces)| faces.as_slice())
+    }
+
+    /// The face of `family` nearest to `weight` and `italic`, if the family has any.
+    pub fn resolve(
+        &self,
+        family: impl Into<RegisteredFamily>,
+        weight: FontWeight,
+        italic: bool,
+    ) -> Option<&FontFace> {
+        let faces = self.faces(family);
+        let slant_matches = faces.iter().any(|face| face.italic == italic);
+        faces
+            .iter()
+            .filter(|face| !slant_matches || face.italic == italic)
+            .min_by_key(|face| weight_distance(weight.value(), face.weight.value()))
+    }
+}
+
+/// How far a face of weight `face` is from the `wanted` weight in CSS font-matching
+/// order; lower is better.
+fn weight_distance(wanted: u16, face: u16) -> (u8, u16) {
+    let lighter = (2, wanted.abs_diff(face));
+    let heavier = (1, wanted.abs_diff(face));
+    match (wanted, face) {
+        _ if face == wanted => (0, 0),
+        // 400 looks up to 500 first, then lighter, then heavier.
+        (400..=500, _) if face > wanted && face <= 500 => (1, face - wanted),
+        (400..=500, _) if face < wanted => lighter,
+        (400..=500, _) => (3, face - wanted),
+        (..400, _) if face < wanted => (1, wanted - face),
+        (..400, _) => (2, face - wanted),
+        (_, _) if face > wanted => heavier,
+        _ => lighter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face(weight: FontWeight) -> FontFace {
+        FontFace::new("Inter", FontSource::bytes(&[])).weight(weight)
+    }
+
+    #[test]
+    fn test_font_registry_resolves_nearest_weight() {
+        let fonts = [FontWeight::Light, FontWeight::Normal, FontWeight::Bold]
+            .into_iter()
+            .fold(FontRegistry::new(), |fonts, weight| {
+                fonts.register(FontFamily::Sans, face(weight))
+            })
+            .register(FontFamily::Sans, face(FontWeight::Normal).italic());
+        let resolved = |weight, italic| {
+            fonts
+                .resolve(FontFamily::Sans, weight, italic)
+                .map(|face| (face.weight_value(), face.is_italic()))
+        };
+
+        assert_eq!(
+            resolved(FontWeight::Normal, false),
+            Some((FontWeight::Normal, false))
+        );
+        // Heavier weights look heavier first, lighter ones lighter first.
+        assert_eq!(
+            resolved(FontWeight::SemiBold, false),
+            Some((FontWeight::Bold, false))
+        );
+        assert_eq!(
+            resolved(FontWeight::Black, false),
+            Some((FontWeight::Bold, false))
+        );
+        assert_eq!(
+            resolved(FontWeight::ExtraLight, false),
+            Some((FontWeight::Light, false))
+        );
+        // 500 looks lighter before heavier.
+        assert_eq!(
+            resolved(FontWeight::Medium, false),
+            Some((FontWeight::Normal, false))
+        );
+        assert_eq!(
+            resolved(FontWeight::Bold, true),
+            Some((FontWeight::Normal, true))
+        );
+        assert_eq!(
+            fonts.resolve(FontFamily::Mono, FontWeight::Normal, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_weight_distance_prefers_500_for_400() {
+        assert!(weight_distance(400, 500) < weight_distance(400, 300));
+        assert!(weight_distance(400, 300) < weight_distance(400, 600));
+        assert!(weight_distance(300, 200) < weight_distance(300, 400));
+    }
+
+    #[test]
+    fn test_font_source_reports_unreadable_paths() {
+        let error = FontSource::path("missing/Inter.ttf").load().unwrap_err();
+        assert_eq!(error.path(), std::path::Path::new("missing/Inter.ttf"));
+        assert!(
+            error
+                .to_string()
+                .starts_with("failed to read font `missing/Inter.ttf`")
+        );
+        assert_eq!(
+            FontSource::bytes(b"ttf").load().unwrap(),
+            Cow::Borrowed(b"ttf".as_slice())
+        );
+    }
+}
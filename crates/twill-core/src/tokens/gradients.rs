@@ -0,0 +1,484 @@
+//! Background gradients: `bg-linear-*`, `bg-radial` and `bg-conic-*` with
+//! `from-*`/`via-*`/`to-*` color stops.
+
+use super::{BackgroundColor, ColorValue, OklchConverter};
+
+/// Direction of a linear gradient (`bg-linear-to-r`, `bg-linear-45`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinearDirection {
+    ToT,
+    ToTr,
+    ToR,
+    ToBr,
+    ToB,
+    ToBl,
+    ToL,
+    ToTl,
+    /// Degrees clockwise from the top, in `0..360`.
+    Angle(u16),
+}
+
+impl LinearDirection {
+    /// An angle in degrees clockwise from the top, normalized so `-45` becomes `315`.
+    pub const fn angle(degrees: i32) -> Self {
+        Self::Angle(degrees.rem_euclid(360) as u16)
+    }
+
+    /// CSS angle of the gradient line in a `width` x `height` box, in degrees clockwise
+    /// from the top.
+    ///
+    /// Corner directions depend on the box shape: like CSS, the line is perpendicular to
+    /// the diagonal through the two other corners, so the named corner gets the last color.
+    pub fn degrees(self, width: f32, height: f32) -> f32 {
+        // `x`/`y` pick the corner: `1.0` right/bottom, `-1.0` left/top.
+        let corner = |x: f32, y: f32| {
+            (x * height)
+                .atan2(-y * width)
+                .to_degrees()
+                .rem_euclid(360.0)
+        };
+        match self {
+            Self::ToT => 0.0,
+            Self::ToTr => corner(1.0, -1.0),
+            Self::ToR => 90.0,
+            Self::ToBr => corner(1.0, 1.0),
+            Self::ToB => 180.0,
+            Self::ToBl => corner(-1.0, 1.0),
+            Self::ToL => 270.0,
+            Self::ToTl => corner(-1.0, -1.0),
+            Self::Angle(degrees) => f32::from(degrees),
+        }
+    }
+}
+
+/// Shape of a background gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GradientKind {
+    Linear(LinearDirection),
+    /// An ellipse centered in the box that reaches its corners (`bg-radial`).
+    Radial,
+    /// A sweep around the center starting at an angle in degrees clockwise from the
+    /// top (`bg-conic`, `bg-conic-180`).
+    Conic(u16),
+}
+
+impl GradientKind {
+    /// A conic gradient starting at `degrees`, normalized into `0..360`.
+    pub const fn conic(degrees: i32) -> Self {
+        Self::Conic(degrees.rem_euclid(360) as u16)
+    }
+}
+
+/// Color space that gradient stops are interpolated in (`bg-linear-to-r/srgb`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GradientInterpolation {
+    /// Perceptual interpolation along the shorter hue arc.
+    #[default]
+    Oklch,
+    Srgb,
+}
+
+impl GradientInterpolation {
+    /// Mix `from` and `to` at `t` (`0.0..=1.0`), with premultiplied alpha so fading to
+    /// `transparent` does not darken the color.
+    pub fn mix(self, from: ColorValue, to: ColorValue, t: f32) -> ColorValue {
+        let t = t.clamp(0.0, 1.0);
+        let alpha = lerp(from.a, to.a, t);
+        match self {
+            Self::Oklch => {
+                let channel = |a: f32, b: f32| premultiplied_lerp(a, from.a, b, to.a, t, alpha);
+                // A gray has no hue of its own, so it takes the hue of the other stop.
+                let (from_h, to_h) = match (from.c < HUE_EPSILON, to.c < HUE_EPSILON) {
+                    (true, false) => (to.h, to.h),
+                    (false, true) => (from.h, from.h),
+                    _ => (from.h, to.h),
+                };
+                let mut delta = (to_h - from_h).rem_euclid(360.0);
+                if delta > 180.0 {
+                    delta -= 360.0;
+                }
+                ColorValue::new(
+                    channel(from.l, to.l),
+                    channel(from.c, to.c),
+                    (from_h + delta * t).rem_euclid(360.0),
+                    alpha,
+                )
+            }
+            Self::Srgb => {
+                let srgb = |color: ColorValue| {
+                    let (r, g, b) = color.to_rgb8();
+                    [r, g, b].map(|channel| f32::from(channel) / 255.0)
+                };
+                let (from_rgb, to_rgb) = (srgb(from), srgb(to));
+                let [r, g, b] = [0, 1, 2].map(|index| {
+                    premultiplied_lerp(from_rgb[index], from.a, to_rgb[index], to.a, t, alpha)
+                });
+                let (l, c, h) = OklchConverter::from_srgb(r, g, b);
+                ColorValue::new(l, c, h, alpha)
+            }
+        }
+    }
+}
+
+/// Chroma below which a color counts as achromatic for hue interpolation.
+const HUE_EPSILON: f32 = 1e-4;
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+fn premultiplied_lerp(
+    from: f32,
+    from_alpha: f32,
+    to: f32,
+    to_alpha: f32,
+    t: f32,
+    alpha: f32,
+) -> f32 {
+    if alpha > f32::EPSILON {
+        lerp(from * from_alpha, to * to_alpha, t) / alpha
+    } else {
+        lerp(from, to, t)
+    }
+}
+
+/// One `from-*`, `via-*` or `to-*` stop: a color and a position in percent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct GradientStop {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) color: Option<BackgroundColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) position: Option<u8>,
+}
+
+impl GradientStop {
+    pub const fn new(color: BackgroundColor) -> Self {
+        Self {
+            color: Some(color),
+            position: None,
+        }
+    }
+
+    /// Place the stop at `percent` (`0..=100`) along the gradient.
+    pub const fn at(mut self, percent: u8) -> Self {
+        self.position = Some(if percent > 100 { 100 } else { percent });
+        self
+    }
+
+    pub const fn color(&self) -> Option<BackgroundColor> {
+        self.color
+    }
+
+    pub const fn position(&self) -> Option<u8> {
+        self.position
+    }
+}
+
+/// A background gradient drawn over the background color.
+///
+/// Like Tailwind, the kind and each stop are set by separate utilities, so a state
+/// layer can change one stop of the base gradient. Nothing is drawn without a kind;
+/// unset `from`/`to` colors are `transparent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Gradient {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) kind: Option<GradientKind>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) interpolation: Option<GradientInterpolation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) from: Option<GradientStop>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) via: Option<GradientStop>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) to: Option<GradientStop>,
+}
+
+impl Gradient {
+    pub fn new(kind: GradientKind) -> Self {
+        Self {
+            kind: Some(kind),
+            ..Self::default()
+        }
+    }
+
+    pub const fn kind(&self) -> Option<GradientKind> {
+        self.kind
+    }
+
+    pub const fn interpolation(&self) -> Option<GradientInterpolation> {
+        self.interpolation
+    }
+
+    pub const fn from_stop(&self) -> Option<GradientStop> {
+        self.from
+    }
+
+    pub const fn via_stop(&self) -> Option<GradientStop> {
+        self.via
+    }
+
+    pub const fn to_stop(&self) -> Option<GradientStop> {
+        self.to
+    }
+
+    /// Resolve the stop colors with `resolve`, or `None` without a kind.
+    ///
+    /// Stops sit at their positions or at `0%`, `50%` (`via`) and `100%`, never before
+    /// an earlier stop. Colors `resolve` cannot turn into a value are transparent.
+    pub fn resolve(
+        &self,
+        resolve: impl Fn(BackgroundColor) -> Option<ColorValue>,
+    ) -> Option<ResolvedGradient> {
+        let kind = self.kind?;
+        let color = |stop: Option<GradientStop>| {
+            stop.and_then(|stop| stop.color)
+                .and_then(&resolve)
+                .unwrap_or(ColorValue::TRANSPARENT)
+        };
+        let offset = |stop: Option<GradientStop>, default: f32| {
+            stop.and_then(|stop| stop.position)
+                .map_or(default, |percent| f32::from(percent) / 100.0)
+        };
+
+        let mut stops = vec![ColorStop::new(offset(self.from, 0.0), color(self.from))];
+        if self.via.and_then(|via| via.color).is_some() {
+            stops.push(ColorStop::new(offset(self.via, 0.5), color(self.via)));
+        }
+        stops.push(ColorStop::new(offset(self.to, 1.0), color(self.to)));
+        for index in 1..stops.len() {
+            stops[index].offset = stops[index].offset.max(stops[index - 1].offset);
+        }
+
+        Some(ResolvedGradient {
+            kind,
+            interpolation: self.interpolation.unwrap_or_default(),
+            stops,
+        })
+    }
+
+    /// The `from`, `via` and `to` stops that are set.
+    pub(crate) fn stops_mut(&mut self) -> impl Iterator<Item = &mut GradientStop> {
+        [&mut self.from, &mut self.via, &mut self.to]
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// A color at `offset` along a gradient, `0.0` at its start and `1.0` at its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    pub(crate) offset: f32,
+    pub(crate) color: ColorValue,
+}
+
+impl ColorStop {
+    pub const fn new(offset: f32, color: ColorValue) -> Self {
+        Self { offset, color }
+    }
+
+    pub const fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub const fn color(&self) -> ColorValue {
+        self.color
+    }
+}
+
+/// A [`Gradient`] with resolved colors, ready for a backend to paint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedGradient {
+    kind: GradientKind,
+    interpolation: GradientInterpolation,
+    stops: Vec<ColorStop>,
+}
+
+impl ResolvedGradient {
+    pub const fn kind(&self) -> GradientKind {
+        self.kind
+    }
+
+    pub const fn interpolation(&self) -> GradientInterpolation {
+        self.interpolation
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Apply `map` to every stop color, e.g. to fade the gradient with `opacity`.
+    pub fn map_colors(mut self, mut map: impl FnMut(ColorValue) -> ColorValue) -> Self {
+        for stop in &mut self.stops {
+            stop.color = map(stop.color);
+        }
+        self
+    }
+
+    /// The color at `offset`, interpolated between the surrounding stops.
+    pub fn color_at(&self, offset: f32) -> ColorValue {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return ColorValue::TRANSPARENT;
+        };
+        if offset <= first.offset {
+            return first.color;
+        }
+        self.stops
+            .windows(2)
+            .find(|pair| offset <= pair[1].offset)
+            .map_or(last.color, |pair| {
+                let span = pair[1].offset - pair[0].offset;
+                let t = if span > 0.0 {
+                    (offset - pair[0].offset) / span
+                } else {
+                    1.0
+                };
+                self.interpolation.mix(pair[0].color, pair[1].color, t)
+            })
+    }
+
+    /// `count` stops evenly spaced over `0.0..=1.0`, for backends that interpolate
+    /// in their own color space or support few stops.
+    pub fn sampled_stops(&self, count: usize) -> Vec<ColorStop> {
+        let last = count.max(2) - 1;
+        (0..=last)
+            .map(|index| {
+                let offset = index as f32 / last as f32;
+                ColorStop::new(offset, self.color_at(offset))
+            })
+            .collect()
+    }
+
+    /// Start and end of the gradient line of a linear gradient in a `width` x `height`
+    /// box, as `[x, y]` from its top-left corner.
+    ///
+    /// Like CSS, the line runs through the center and is long enough for the corners
+    /// to get the first and last colors.
+    pub fn linear_line(&self, width: f32, height: f32) -> Option<([f32; 2], [f32; 2])> {
+        let GradientKind::Linear(direction) = self.kind else {
+            return None;
+        };
+        let (sin, cos) = direction.degrees(width, height).to_radians().sin_cos();
+        let half = (width * sin.abs() + height * cos.abs()) / 2.0;
+        let (dx, dy) = (sin * half, -cos * half);
+        let (cx, cy) = (width / 2.0, height / 2.0);
+        Some(([cx - dx, cy - dy], [cx + dx, cy + dy]))
+    }
+
+    /// Offset of the point `[x, y]` (from the top-left corner of a `width` x `height`
+    /// box) along the gradient, unclamped for linear and radial gradients.
+    pub fn offset_at(&self, [x, y]: [f32; 2], width: f32, height: f32) -> f32 {
+        let (dx, dy) = (x - width / 2.0, y - height / 2.0);
+        match self.kind {
+            GradientKind::Linear(_) => {
+                let Some(([x0, y0], [x1, y1])) = self.linear_line(width, height) else {
+                    return 0.0;
+                };
+                let (lx, ly) = (x1 - x0, y1 - y0);
+                let length = lx * lx + ly * ly;
+                if length > 0.0 {
+                    ((x - x0) * lx + (y - y0) * ly) / length
+                } else {
+                    0.0
+                }
+            }
+            GradientKind::Radial => {
+                // `farthest-corner` ellipse: sqrt(2) times the half-size on each axis.
+                let (rx, ry) = (
+                    width * std::f32::consts::FRAC_1_SQRT_2,
+                    height * std::f32::consts::FRAC_1_SQRT_2,
+                );
+                if rx > 0.0 && ry > 0.0 {
+                    ((dx / rx).powi(2) + (dy / ry).powi(2)).sqrt()
+                } else {
+                    0.0
+                }
+            }
+            GradientKind::Conic(from) => {
+                let degrees = dx.atan2(-dy).to_degrees();
+                (degrees - f32::from(from)).rem_euclid(360.0) / 360.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::{Color, Scale};
+    use crate::traits::ComputeValue;
+
+    #[test]
+    fn test_gradient_stops_and_interpolation() {
+        let mut gradient = Gradient::new(GradientKind::Linear(LinearDirection::ToR));
+        gradient.from = Some(GradientStop::new(BackgroundColor::palette(Color::blue(
+            Scale::S500,
+        ))));
+        gradient.via = Some(GradientStop::new(BackgroundColor::palette(Color::white())).at(30));
+        gradient.to = Some(GradientStop::default().at(20));
+        let resolved = gradient
+            .resolve(|color| color.palette_value().map(|color| color.compute()))
+            .expect("the gradient has a kind");
+
+        let offsets: Vec<f32> = resolved.stops().iter().map(ColorStop::offset).collect();
+        assert_eq!(offsets, [0.0, 0.3, 0.3]);
+        assert_eq!(resolved.stops()[2].color(), ColorValue::TRANSPARENT);
+        assert_eq!(resolved.color_at(-1.0), Color::blue(Scale::S500).compute());
+        // Fading to transparent keeps the color and only lowers alpha.
+        let white = Color::white().compute();
+        let fading = GradientInterpolation::Oklch.mix(white, ColorValue::TRANSPARENT, 0.5);
+        assert!((fading.lightness() - white.lightness()).abs() < 1e-4);
+        assert!((fading.alpha() - 0.5).abs() < 1e-4);
+
+        let (red, blue) = (
+            Color::red(Scale::S500).compute(),
+            Color::blue(Scale::S500).compute(),
+        );
+        let oklch = GradientInterpolation::Oklch.mix(red, blue, 0.5);
+        let srgb = GradientInterpolation::Srgb.mix(red, blue, 0.5);
+        assert!(oklch.chroma() > srgb.chroma());
+        assert_eq!(Gradient::default().resolve(|_| None), None);
+    }
+
+    #[test]
+    fn test_gradient_geometry_follows_css() {
+        assert_eq!(LinearDirection::ToTr.degrees(10.0, 10.0), 45.0);
+        assert_eq!(LinearDirection::angle(-45), LinearDirection::Angle(315));
+        assert!((LinearDirection::ToBr.degrees(200.0, 100.0) - 153.435).abs() < 1e-3);
+
+        let stop = |offset| ColorStop::new(offset, ColorValue::TRANSPARENT);
+        let linear = ResolvedGradient {
+            kind: GradientKind::Linear(LinearDirection::ToBr),
+            interpolation: GradientInterpolation::Oklch,
+            stops: vec![stop(0.0), stop(1.0)],
+        };
+        // Corners get the first and last stops, the other corners the middle.
+        assert!(linear.offset_at([0.0, 0.0], 200.0, 100.0).abs() < 1e-4);
+        assert!((linear.offset_at([200.0, 100.0], 200.0, 100.0) - 1.0).abs() < 1e-4);
+        assert!((linear.offset_at([200.0, 0.0], 200.0, 100.0) - 0.5).abs() < 1e-4);
+
+        let radial = ResolvedGradient {
+            kind: GradientKind::Radial,
+            ..linear.clone()
+        };
+        assert_eq!(radial.offset_at([100.0, 50.0], 200.0, 100.0), 0.0);
+        assert!((radial.offset_at([0.0, 100.0], 200.0, 100.0) - 1.0).abs() < 1e-4);
+        assert_eq!(radial.linear_line(200.0, 100.0), None);
+
+        let conic = ResolvedGradient {
+            kind: GradientKind::conic(-90),
+            ..linear
+        };
+        assert!((conic.offset_at([0.0, 50.0], 200.0, 100.0)).abs() < 1e-4);
+        assert!((conic.offset_at([100.0, 0.0], 200.0, 100.0) - 0.25).abs() < 1e-4);
+    }
+}
@@ -7,14 +7,24 @@
 //! - Typography (font sizes, weights, line heights, letter spacing)
 //! - Borders (radius, width, style)
 //! - Shadows (box shadow, inset shadow, drop shadow, text shadow)
+//! - Background gradients (linear, radial and conic color stops)
+//! - Color filters (`brightness`, `grayscale`, `hue-rotate`, ...)
 //! - Perspective and motion primitives
+//! - Font registries backing the font family tokens
+//! - Semantic theme loading from and export to CSS / design-token files
 
 pub mod aspect_ratio;
 pub mod blur;
 pub mod borders;
 pub mod colors;
+pub mod css_theme;
 pub mod cursor;
+pub mod export;
+pub mod filters;
+pub mod fonts;
+pub mod gradients;
 pub mod motion;
+pub(crate) mod names;
 pub mod oklch;
 pub mod perspective;
 pub mod semantic;
@@ -31,7 +41,15 @@ pub use colors::{
     ColorValue, ColorValueToken, OutlineColor, OutlineColorVar, RingColor, RingColorVar, Scale,
     ShadowColorToken, ShadowColorVar, SpecialColor, TextColor, TextColorVar,
 };
+pub use css_theme::{CssSemanticTheme, ThemeCssError};
 pub use cursor::Cursor;
+pub use export::TokenExport;
+pub use filters::Filter;
+pub use fonts::{FontFace, FontLoadError, FontRegistry, FontSource, RegisteredFamily};
+pub use gradients::{
+    ColorStop, Gradient, GradientInterpolation, GradientKind, GradientStop, LinearDirection,
+    ResolvedGradient,
+};
 pub use motion::{AnimationToken, Easing, MotionDefaults, TransitionDuration, TransitionProperty};
 pub use oklch::OklchConverter;
 pub use perspective::Perspective;
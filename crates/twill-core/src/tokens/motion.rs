@@ -62,6 +62,59 @@ impl Easing {
             Self::InOut => "cubic-bezier(0.4, 0, 0.2, 1)",
         }
     }
+
+    /// Cubic-bezier control points `[x1, y1, x2, y2]`, or `None` for linear timing.
+    pub const fn control_points(&self) -> Option<[f32; 4]> {
+        match self {
+            Self::Linear => None,
+            Self::In => Some([0.4, 0.0, 1.0, 1.0]),
+            Self::Out => Some([0.0, 0.0, 0.2, 1.0]),
+            Self::InOut => Some([0.4, 0.0, 0.2, 1.0]),
+        }
+    }
+
+    /// Eased output for a linear `progress`, clamped to `0.0..=1.0`.
+    pub fn sample(&self, progress: f32) -> f32 {
+        match self.control_points() {
+            Some(points) => cubic_bezier(points, progress),
+            None => clamp_progress(progress),
+        }
+    }
+}
+
+fn clamp_progress(progress: f32) -> f32 {
+    if progress.is_nan() {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0)
+    }
+}
+
+/// Output of the CSS `cubic-bezier(x1, y1, x2, y2)` timing function for a linear
+/// `progress`, clamped to `0.0..=1.0`.
+pub(crate) fn cubic_bezier([x1, y1, x2, y2]: [f32; 4], progress: f32) -> f32 {
+    let progress = clamp_progress(progress);
+    let bezier = |a: f32, b: f32, t: f32| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+    };
+
+    // x(t) is monotonic for CSS easings, so bisection always converges.
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    let mut t = progress;
+    for _ in 0..24 {
+        let x = bezier(x1, x2, t);
+        if (x - progress).abs() < 1e-5 {
+            break;
+        }
+        if x < progress {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+    bezier(y1, y2, t)
 }
 
 /// Built-in animation tokens.
@@ -88,6 +141,11 @@ impl AnimationToken {
 
 /// Default transition tokens.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct MotionDefaults {
     pub(crate) duration: TransitionDuration,
     pub(crate) easing: Easing,
@@ -159,4 +217,18 @@ mod tests {
     fn test_easing_value() {
         assert_eq!(Easing::In.value(), "cubic-bezier(0.4, 0, 1, 1)");
     }
+
+    #[test]
+    fn test_easing_sample() {
+        for easing in [Easing::Linear, Easing::In, Easing::Out, Easing::InOut] {
+            assert_eq!(easing.sample(0.0), 0.0);
+            assert!((easing.sample(1.0) - 1.0).abs() < 1e-4);
+        }
+        assert_eq!(Easing::Linear.sample(0.25), 0.25);
+        assert!(Easing::In.sample(0.5) < 0.5);
+        assert!(Easing::Out.sample(0.5) > 0.5);
+        assert!(Easing::InOut.sample(0.1) < 0.1);
+        assert!(Easing::InOut.sample(0.5) > 0.5);
+        assert_eq!(Easing::Out.sample(2.0), Easing::Out.sample(1.0));
+    }
 }
@@ -0,0 +1,661 @@
+//! Tailwind utility-name tables shared by class parsing and serialization.
+//!
+//! Each table maps the class suffix used by Tailwind (`4` in `p-4`, `lg` in
+//! `rounded-lg`) to the typed token. Only keyword variants are listed; payload
+//! variants such as `FontSize::Px` are handled by the arbitrary-value syntax.
+
+use std::collections::BTreeSet;
+use std::sync::{LazyLock, Mutex};
+
+use super::semantic::DEFAULT_RADIUS;
+use super::{
+    AnimationToken, AspectRatio, Blur, BorderRadius, BorderStyle, BorderWidth, Breakpoint,
+    ColorFamily, Container, Cursor, DivideWidth, DropShadow, Easing, FontFamily, FontSize,
+    FontWeight, GradientInterpolation, InsetShadow, LetterSpacing, LineHeight, LinearDirection,
+    OutlineStyle, Percentage, Perspective, RingWidth, Scale, Shadow, Spacing, TextAlign,
+    TextDecoration, TextOverflow, TextShadow, TextTransform, TransitionDuration, WhiteSpace,
+    WordBreak,
+};
+use crate::utilities::{
+    AlignItems, Display, FlexDirection, FlexWrap, GridFlow, JustifyContent, JustifyItems,
+    JustifySelf, ObjectFit, Overflow, PlaceContent, PlaceItems, Position, TransformOrigin,
+    Visibility, ZIndex,
+};
+
+/// Look up a token by its Tailwind name.
+pub(crate) fn lookup<T: Copy>(table: &[(&'static str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find_map(|(candidate, value)| (*candidate == name).then_some(*value))
+}
+
+/// Find the Tailwind name registered for a token.
+pub(crate) fn name_of<T: PartialEq>(
+    table: &[(&'static str, T)],
+    value: &T,
+) -> Option<&'static str> {
+    table
+        .iter()
+        .find_map(|(name, candidate)| (candidate == value).then_some(*name))
+}
+
+/// Names interned so far, seeded with the `&'static str` names the crate already holds so
+/// interning those never allocates.
+static INTERNED: LazyLock<Mutex<BTreeSet<&'static str>>> =
+    LazyLock::new(|| Mutex::new(BTreeSet::from([DEFAULT_RADIUS])));
+
+/// Intern a runtime name so it can back the `&'static str` `*Var` tokens and theme radii.
+///
+/// Names already in the table are reused; every other distinct name is leaked once for
+/// the lifetime of the process, so callers validate names before interning them.
+pub(crate) fn intern(name: &str) -> &'static str {
+    let mut interned = INTERNED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(existing) = interned.get(name) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+pub(crate) const SCALE: &[(&str, Scale)] = &[
+    ("50", Scale::S50),
+    ("100", Scale::S100),
+    ("200", Scale::S200),
+    ("300", Scale::S300),
+    ("400", Scale::S400),
+    ("500", Scale::S500),
+    ("600", Scale::S600),
+    ("700", Scale::S700),
+    ("800", Scale::S800),
+    ("900", Scale::S900),
+    ("950", Scale::S950),
+];
+
+pub(crate) const COLOR_FAMILY: &[(&str, ColorFamily)] = &[
+    ("black", ColorFamily::Black),
+    ("white", ColorFamily::White),
+    ("slate", ColorFamily::Slate),
+    ("gray", ColorFamily::Gray),
+    ("zinc", ColorFamily::Zinc),
+    ("neutral", ColorFamily::Neutral),
+    ("stone", ColorFamily::Stone),
+    ("mauve", ColorFamily::Mauve),
+    ("olive", ColorFamily::Olive),
+    ("mist", ColorFamily::Mist),
+    ("taupe", ColorFamily::Taupe),
+    ("red", ColorFamily::Red),
+    ("orange", ColorFamily::Orange),
+    ("amber", ColorFamily::Amber),
+    ("yellow", ColorFamily::Yellow),
+    ("lime", ColorFamily::Lime),
+    ("green", ColorFamily::Green),
+    ("emerald", ColorFamily::Emerald),
+    ("teal", ColorFamily::Teal),
+    ("cyan", ColorFamily::Cyan),
+    ("sky", ColorFamily::Sky),
+    ("blue", ColorFamily::Blue),
+    ("indigo", ColorFamily::Indigo),
+    ("violet", ColorFamily::Violet),
+    ("purple", ColorFamily::Purple),
+    ("fuchsia", ColorFamily::Fuchsia),
+    ("pink", ColorFamily::Pink),
+    ("rose", ColorFamily::Rose),
+];
+
+pub(crate) const SPACING: &[(&str, Spacing)] = &[
+    ("0", Spacing::S0),
+    ("px", Spacing::Px),
+    ("0.5", Spacing::S0_5),
+    ("1", Spacing::S1),
+    ("1.5", Spacing::S1_5),
+    ("2", Spacing::S2),
+    ("2.5", Spacing::S2_5),
+    ("3", Spacing::S3),
+    ("3.5", Spacing::S3_5),
+    ("4", Spacing::S4),
+    ("5", Spacing::S5),
+    ("6", Spacing::S6),
+    ("7", Spacing::S7),
+    ("8", Spacing::S8),
+    ("9", Spacing::S9),
+    ("10", Spacing::S10),
+    ("11", Spacing::S11),
+    ("12", Spacing::S12),
+    ("14", Spacing::S14),
+    ("16", Spacing::S16),
+    ("20", Spacing::S20),
+    ("24", Spacing::S24),
+    ("28", Spacing::S28),
+    ("32", Spacing::S32),
+    ("36", Spacing::S36),
+    ("40", Spacing::S40),
+    ("44", Spacing::S44),
+    ("48", Spacing::S48),
+    ("52", Spacing::S52),
+    ("56", Spacing::S56),
+    ("60", Spacing::S60),
+    ("64", Spacing::S64),
+    ("72", Spacing::S72),
+    ("80", Spacing::S80),
+    ("96", Spacing::S96),
+    ("auto", Spacing::Auto),
+];
+
+pub(crate) const PERCENTAGE: &[(&str, Percentage)] = &[
+    ("1/2", Percentage::S1_2),
+    ("1/3", Percentage::S1_3),
+    ("2/3", Percentage::S2_3),
+    ("1/4", Percentage::S1_4),
+    ("2/4", Percentage::S2_4),
+    ("3/4", Percentage::S3_4),
+    ("1/5", Percentage::S1_5),
+    ("2/5", Percentage::S2_5),
+    ("3/5", Percentage::S3_5),
+    ("4/5", Percentage::S4_5),
+    ("1/6", Percentage::S1_6),
+    ("2/6", Percentage::S2_6),
+    ("3/6", Percentage::S3_6),
+    ("4/6", Percentage::S4_6),
+    ("5/6", Percentage::S5_6),
+    ("0", Percentage::S0),
+    ("full", Percentage::Full),
+    ("min", Percentage::Min),
+    ("max", Percentage::Max),
+    ("fit", Percentage::Fit),
+];
+
+pub(crate) const CONTAINER: &[(&str, Container)] = &[
+    ("3xs", Container::S3xs),
+    ("2xs", Container::S2xs),
+    ("xs", Container::Xs),
+    ("sm", Container::Sm),
+    ("md", Container::Md),
+    ("lg", Container::Lg),
+    ("xl", Container::Xl),
+    ("2xl", Container::S2xl),
+    ("3xl", Container::S3xl),
+    ("4xl", Container::S4xl),
+    ("5xl", Container::S5xl),
+    ("6xl", Container::S6xl),
+    ("7xl", Container::S7xl),
+];
+
+pub(crate) const BREAKPOINT: &[(&str, Breakpoint)] = &[
+    ("sm", Breakpoint::Sm),
+    ("md", Breakpoint::Md),
+    ("lg", Breakpoint::Lg),
+    ("xl", Breakpoint::Xl),
+    ("2xl", Breakpoint::S2xl),
+];
+
+pub(crate) const FONT_FAMILY: &[(&str, FontFamily)] = &[
+    ("sans", FontFamily::Sans),
+    ("serif", FontFamily::Serif),
+    ("mono", FontFamily::Mono),
+];
+
+pub(crate) const FONT_SIZE: &[(&str, FontSize)] = &[
+    ("xs", FontSize::Xs),
+    ("sm", FontSize::Sm),
+    ("base", FontSize::Base),
+    ("lg", FontSize::Lg),
+    ("xl", FontSize::Xl),
+    ("2xl", FontSize::S2xl),
+    ("3xl", FontSize::S3xl),
+    ("4xl", FontSize::S4xl),
+    ("5xl", FontSize::S5xl),
+    ("6xl", FontSize::S6xl),
+    ("7xl", FontSize::S7xl),
+    ("8xl", FontSize::S8xl),
+    ("9xl", FontSize::S9xl),
+];
+
+pub(crate) const FONT_WEIGHT: &[(&str, FontWeight)] = &[
+    ("thin", FontWeight::Thin),
+    ("extralight", FontWeight::ExtraLight),
+    ("light", FontWeight::Light),
+    ("normal", FontWeight::Normal),
+    ("medium", FontWeight::Medium),
+    ("semibold", FontWeight::SemiBold),
+    ("bold", FontWeight::Bold),
+    ("extrabold", FontWeight::ExtraBold),
+    ("black", FontWeight::Black),
+];
+
+pub(crate) const LETTER_SPACING: &[(&str, LetterSpacing)] = &[
+    ("tighter", LetterSpacing::Tighter),
+    ("tight", LetterSpacing::Tight),
+    ("normal", LetterSpacing::Normal),
+    ("wide", LetterSpacing::Wide),
+    ("wider", LetterSpacing::Wider),
+    ("widest", LetterSpacing::Widest),
+];
+
+pub(crate) const LINE_HEIGHT: &[(&str, LineHeight)] = &[
+    ("tight", LineHeight::Tight),
+    ("snug", LineHeight::Snug),
+    ("normal", LineHeight::Normal),
+    ("relaxed", LineHeight::Relaxed),
+    ("loose", LineHeight::Loose),
+    ("none", LineHeight::Number(1.0)),
+];
+
+pub(crate) const TEXT_ALIGN: &[(&str, TextAlign)] = &[
+    ("left", TextAlign::Left),
+    ("center", TextAlign::Center),
+    ("right", TextAlign::Right),
+    ("justify", TextAlign::Justify),
+    ("start", TextAlign::Start),
+    ("end", TextAlign::End),
+];
+
+/// Full class names (no shared prefix).
+pub(crate) const TEXT_DECORATION: &[(&str, TextDecoration)] = &[
+    ("no-underline", TextDecoration::None),
+    ("underline", TextDecoration::Underline),
+    ("overline", TextDecoration::Overline),
+    ("line-through", TextDecoration::LineThrough),
+];
+
+/// Full class names (no shared prefix).
+pub(crate) const TEXT_TRANSFORM: &[(&str, TextTransform)] = &[
+    ("normal-case", TextTransform::None),
+    ("uppercase", TextTransform::Uppercase),
+    ("lowercase", TextTransform::Lowercase),
+    ("capitalize", TextTransform::Capitalize),
+];
+
+pub(crate) const TEXT_OVERFLOW: &[(&str, TextOverflow)] = &[
+    ("clip", TextOverflow::Clip),
+    ("ellipsis", TextOverflow::Ellipsis),
+];
+
+pub(crate) const WHITE_SPACE: &[(&str, WhiteSpace)] = &[
+    ("normal", WhiteSpace::Normal),
+    ("nowrap", WhiteSpace::NoWrap),
+    ("pre", WhiteSpace::Pre),
+    ("pre-line", WhiteSpace::PreLine),
+    ("pre-wrap", WhiteSpace::PreWrap),
+];
+
+pub(crate) const WORD_BREAK: &[(&str, WordBreak)] = &[
+    ("normal", WordBreak::Normal),
+    ("all", WordBreak::BreakAll),
+    ("keep", WordBreak::KeepAll),
+    ("words", WordBreak::BreakWord),
+];
+
+pub(crate) const BORDER_RADIUS: &[(&str, BorderRadius)] = &[
+    ("none", BorderRadius::None),
+    ("xs", BorderRadius::Xs),
+    ("sm", BorderRadius::Sm),
+    ("md", BorderRadius::Md),
+    ("lg", BorderRadius::Lg),
+    ("xl", BorderRadius::Xl),
+    ("2xl", BorderRadius::S2xl),
+    ("3xl", BorderRadius::S3xl),
+    ("4xl", BorderRadius::S4xl),
+    ("full", BorderRadius::Full),
+];
+
+pub(crate) const BORDER_WIDTH: &[(&str, BorderWidth)] = &[
+    ("0", BorderWidth::S0),
+    ("1", BorderWidth::S1),
+    ("2", BorderWidth::S2),
+    ("4", BorderWidth::S4),
+    ("8", BorderWidth::S8),
+];
+
+pub(crate) const DIVIDE_WIDTH: &[(&str, DivideWidth)] = &[
+    ("0", DivideWidth::S0),
+    ("1", DivideWidth::S1),
+    ("2", DivideWidth::S2),
+    ("4", DivideWidth::S4),
+    ("8", DivideWidth::S8),
+];
+
+pub(crate) const LINEAR_DIRECTION: &[(&str, LinearDirection)] = &[
+    ("to-t", LinearDirection::ToT),
+    ("to-tr", LinearDirection::ToTr),
+    ("to-r", LinearDirection::ToR),
+    ("to-br", LinearDirection::ToBr),
+    ("to-b", LinearDirection::ToB),
+    ("to-bl", LinearDirection::ToBl),
+    ("to-l", LinearDirection::ToL),
+    ("to-tl", LinearDirection::ToTl),
+];
+
+pub(crate) const GRADIENT_INTERPOLATION: &[(&str, GradientInterpolation)] = &[
+    ("oklch", GradientInterpolation::Oklch),
+    ("srgb", GradientInterpolation::Srgb),
+];
+
+pub(crate) const BORDER_STYLE: &[(&str, BorderStyle)] = &[
+    ("solid", BorderStyle::Solid),
+    ("dashed", BorderStyle::Dashed),
+    ("dotted", BorderStyle::Dotted),
+    ("double", BorderStyle::Double),
+    ("hidden", BorderStyle::Hidden),
+    ("none", BorderStyle::None),
+];
+
+pub(crate) const OUTLINE_STYLE: &[(&str, OutlineStyle)] = &[
+    ("none", OutlineStyle::None),
+    ("solid", OutlineStyle::Solid),
+    ("dashed", OutlineStyle::Dashed),
+    ("dotted", OutlineStyle::Dotted),
+    ("double", OutlineStyle::Double),
+    ("hidden", OutlineStyle::Hidden),
+];
+
+pub(crate) const RING_WIDTH: &[(&str, RingWidth)] = &[
+    ("0", RingWidth::None),
+    ("1", RingWidth::S1),
+    ("2", RingWidth::S2),
+    ("4", RingWidth::S4),
+    ("8", RingWidth::S8),
+    ("inset", RingWidth::Inset),
+];
+
+pub(crate) const SHADOW: &[(&str, Shadow)] = &[
+    ("2xs", Shadow::Xs2),
+    ("xs", Shadow::Xs),
+    ("sm", Shadow::Sm),
+    ("md", Shadow::Md),
+    ("lg", Shadow::Lg),
+    ("xl", Shadow::Xl),
+    ("2xl", Shadow::S2xl),
+    ("none", Shadow::None),
+];
+
+pub(crate) const INSET_SHADOW: &[(&str, InsetShadow)] = &[
+    ("2xs", InsetShadow::Xs2),
+    ("xs", InsetShadow::Xs),
+    ("sm", InsetShadow::Sm),
+    ("none", InsetShadow::None),
+];
+
+/// `DropShadow::Base` is the bare `drop-shadow` class.
+pub(crate) const DROP_SHADOW: &[(&str, DropShadow)] = &[
+    ("xs", DropShadow::Xs),
+    ("sm", DropShadow::Sm),
+    ("md", DropShadow::Md),
+    ("lg", DropShadow::Lg),
+    ("xl", DropShadow::Xl),
+    ("2xl", DropShadow::S2xl),
+    ("none", DropShadow::None),
+];
+
+pub(crate) const TEXT_SHADOW: &[(&str, TextShadow)] = &[
+    ("2xs", TextShadow::Xs2),
+    ("xs", TextShadow::Xs),
+    ("sm", TextShadow::Sm),
+    ("md", TextShadow::Md),
+    ("lg", TextShadow::Lg),
+    ("none", TextShadow::None),
+];
+
+/// `Blur::Base` is the bare `blur` class.
+pub(crate) const BLUR: &[(&str, Blur)] = &[
+    ("none", Blur::None),
+    ("xs", Blur::Xs),
+    ("sm", Blur::Sm),
+    ("md", Blur::Md),
+    ("lg", Blur::Lg),
+    ("xl", Blur::Xl),
+    ("2xl", Blur::S2xl),
+    ("3xl", Blur::S3xl),
+];
+
+pub(crate) const PERSPECTIVE: &[(&str, Perspective)] = &[
+    ("dramatic", Perspective::Dramatic),
+    ("near", Perspective::Near),
+    ("normal", Perspective::Normal),
+    ("midrange", Perspective::Midrange),
+    ("distant", Perspective::Distant),
+];
+
+pub(crate) const ASPECT_RATIO: &[(&str, AspectRatio)] = &[
+    ("auto", AspectRatio::Auto),
+    ("square", AspectRatio::Square),
+    ("video", AspectRatio::Video),
+];
+
+pub(crate) const TRANSITION_DURATION: &[(&str, TransitionDuration)] = &[
+    ("0", TransitionDuration::Ms0),
+    ("75", TransitionDuration::Ms75),
+    ("100", TransitionDuration::Ms100),
+    ("150", TransitionDuration::Ms150),
+    ("200", TransitionDuration::Ms200),
+    ("300", TransitionDuration::Ms300),
+    ("500", TransitionDuration::Ms500),
+    ("700", TransitionDuration::Ms700),
+    ("1000", TransitionDuration::Ms1000),
+];
+
+pub(crate) const EASING: &[(&str, Easing)] = &[
+    ("linear", Easing::Linear),
+    ("in", Easing::In),
+    ("out", Easing::Out),
+    ("in-out", Easing::InOut),
+];
+
+pub(crate) const ANIMATION: &[(&str, AnimationToken)] = &[
+    ("none", AnimationToken::None),
+    ("spin", AnimationToken::Spin),
+    ("ping", AnimationToken::Ping),
+    ("pulse", AnimationToken::Pulse),
+    ("bounce", AnimationToken::Bounce),
+];
+
+pub(crate) const CURSOR: &[(&str, Cursor)] = &[
+    ("auto", Cursor::Auto),
+    ("default", Cursor::Default),
+    ("pointer", Cursor::Pointer),
+    ("wait", Cursor::Wait),
+    ("text", Cursor::Text),
+    ("move", Cursor::Move),
+    ("help", Cursor::Help),
+    ("not-allowed", Cursor::NotAllowed),
+    ("none", Cursor::None),
+    ("context-menu", Cursor::ContextMenu),
+    ("progress", Cursor::Progress),
+    ("cell", Cursor::Cell),
+    ("crosshair", Cursor::Crosshair),
+    ("vertical-text", Cursor::VerticalText),
+    ("alias", Cursor::Alias),
+    ("copy", Cursor::Copy),
+    ("no-drop", Cursor::NoDrop),
+    ("grab", Cursor::Grab),
+    ("grabbing", Cursor::Grabbing),
+    ("all-scroll", Cursor::AllScroll),
+    ("col-resize", Cursor::ColResize),
+    ("row-resize", Cursor::RowResize),
+    ("n-resize", Cursor::NResize),
+    ("e-resize", Cursor::EResize),
+    ("s-resize", Cursor::SResize),
+    ("w-resize", Cursor::WResize),
+    ("ne-resize", Cursor::NeResize),
+    ("nw-resize", Cursor::NwResize),
+    ("se-resize", Cursor::SeResize),
+    ("sw-resize", Cursor::SwResize),
+    ("ew-resize", Cursor::EwResize),
+    ("ns-resize", Cursor::NsResize),
+    ("nesw-resize", Cursor::NeswResize),
+    ("nwse-resize", Cursor::NwseResize),
+    ("zoom-in", Cursor::ZoomIn),
+    ("zoom-out", Cursor::ZoomOut),
+];
+
+/// Full class names (no shared prefix).
+pub(crate) const DISPLAY: &[(&str, Display)] = &[
+    ("block", Display::Block),
+    ("inline-block", Display::InlineBlock),
+    ("inline", Display::Inline),
+    ("flex", Display::Flex),
+    ("inline-flex", Display::InlineFlex),
+    ("grid", Display::Grid),
+    ("inline-grid", Display::InlineGrid),
+    ("hidden", Display::Hidden),
+    ("contents", Display::Contents),
+    ("flow-root", Display::FlowRoot),
+];
+
+/// Full class names (no shared prefix).
+pub(crate) const POSITION: &[(&str, Position)] = &[
+    ("static", Position::Static),
+    ("relative", Position::Relative),
+    ("absolute", Position::Absolute),
+    ("fixed", Position::Fixed),
+    ("sticky", Position::Sticky),
+];
+
+/// Full class names (no shared prefix).
+pub(crate) const VISIBILITY: &[(&str, Visibility)] = &[
+    ("visible", Visibility::Visible),
+    ("invisible", Visibility::Hidden),
+    ("collapse", Visibility::Collapse),
+];
+
+pub(crate) const Z_INDEX: &[(&str, ZIndex)] = &[
+    ("auto", ZIndex::Auto),
+    ("0", ZIndex::S0),
+    ("10", ZIndex::S10),
+    ("20", ZIndex::S20),
+    ("30", ZIndex::S30),
+    ("40", ZIndex::S40),
+    ("50", ZIndex::S50),
+];
+
+pub(crate) const OVERFLOW: &[(&str, Overflow)] = &[
+    ("auto", Overflow::Auto),
+    ("hidden", Overflow::Hidden),
+    ("clip", Overflow::Clip),
+    ("visible", Overflow::Visible),
+    ("scroll", Overflow::Scroll),
+];
+
+pub(crate) const OBJECT_FIT: &[(&str, ObjectFit)] = &[
+    ("contain", ObjectFit::Contain),
+    ("cover", ObjectFit::Cover),
+    ("fill", ObjectFit::Fill),
+    ("none", ObjectFit::None),
+    ("scale-down", ObjectFit::ScaleDown),
+];
+
+pub(crate) const TRANSFORM_ORIGIN: &[(&str, TransformOrigin)] = &[
+    ("center", TransformOrigin::Center),
+    ("top", TransformOrigin::Top),
+    ("top-right", TransformOrigin::TopRight),
+    ("right", TransformOrigin::Right),
+    ("bottom-right", TransformOrigin::BottomRight),
+    ("bottom", TransformOrigin::Bottom),
+    ("bottom-left", TransformOrigin::BottomLeft),
+    ("left", TransformOrigin::Left),
+    ("top-left", TransformOrigin::TopLeft),
+];
+
+pub(crate) const FLEX_DIRECTION: &[(&str, FlexDirection)] = &[
+    ("row", FlexDirection::Row),
+    ("row-reverse", FlexDirection::RowReverse),
+    ("col", FlexDirection::Col),
+    ("col-reverse", FlexDirection::ColReverse),
+];
+
+pub(crate) const FLEX_WRAP: &[(&str, FlexWrap)] = &[
+    ("wrap", FlexWrap::Wrap),
+    ("wrap-reverse", FlexWrap::WrapReverse),
+    ("nowrap", FlexWrap::NoWrap),
+];
+
+pub(crate) const JUSTIFY_CONTENT: &[(&str, JustifyContent)] = &[
+    ("start", JustifyContent::Start),
+    ("end", JustifyContent::End),
+    ("end-safe", JustifyContent::EndSafe),
+    ("center", JustifyContent::Center),
+    ("center-safe", JustifyContent::CenterSafe),
+    ("between", JustifyContent::Between),
+    ("around", JustifyContent::Around),
+    ("evenly", JustifyContent::Evenly),
+    ("stretch", JustifyContent::Stretch),
+    ("baseline", JustifyContent::Baseline),
+    ("normal", JustifyContent::Normal),
+];
+
+pub(crate) const ALIGN_ITEMS: &[(&str, AlignItems)] = &[
+    ("start", AlignItems::Start),
+    ("end", AlignItems::End),
+    ("end-safe", AlignItems::EndSafe),
+    ("center", AlignItems::Center),
+    ("center-safe", AlignItems::CenterSafe),
+    ("baseline", AlignItems::Baseline),
+    ("baseline-last", AlignItems::BaselineLast),
+    ("stretch", AlignItems::Stretch),
+];
+
+pub(crate) const JUSTIFY_ITEMS: &[(&str, JustifyItems)] = &[
+    ("normal", JustifyItems::Normal),
+    ("center", JustifyItems::Center),
+    ("start", JustifyItems::Start),
+    ("end", JustifyItems::End),
+    ("stretch", JustifyItems::Stretch),
+];
+
+pub(crate) const JUSTIFY_SELF: &[(&str, JustifySelf)] = &[
+    ("auto", JustifySelf::Auto),
+    ("start", JustifySelf::Start),
+    ("end", JustifySelf::End),
+    ("center", JustifySelf::Center),
+    ("stretch", JustifySelf::Stretch),
+];
+
+pub(crate) const GRID_FLOW: &[(&str, GridFlow)] = &[
+    ("row", GridFlow::Row),
+    ("col", GridFlow::Col),
+    ("dense", GridFlow::Dense),
+    ("row-dense", GridFlow::RowDense),
+    ("col-dense", GridFlow::ColDense),
+];
+
+pub(crate) const PLACE_CONTENT: &[(&str, PlaceContent)] = &[
+    ("center", PlaceContent::Center),
+    ("start", PlaceContent::Start),
+    ("end", PlaceContent::End),
+    ("between", PlaceContent::Between),
+    ("around", PlaceContent::Around),
+    ("evenly", PlaceContent::Evenly),
+    ("baseline", PlaceContent::Baseline),
+    ("stretch", PlaceContent::Stretch),
+];
+
+pub(crate) const PLACE_ITEMS: &[(&str, PlaceItems)] = &[
+    ("center", PlaceItems::Center),
+    ("start", PlaceItems::Start),
+    ("end", PlaceItems::End),
+    ("baseline", PlaceItems::Baseline),
+    ("stretch", PlaceItems::Stretch),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_round_trips_names() {
+        assert_eq!(lookup(SPACING, "2.5"), Some(Spacing::S2_5));
+        assert_eq!(name_of(SPACING, &Spacing::S2_5), Some("2.5"));
+        assert_eq!(lookup(SHADOW, "2xs"), Some(Shadow::Xs2));
+        assert_eq!(lookup(SPACING, "13"), None);
+    }
+
+    #[test]
+    fn test_intern_reuses_allocations() {
+        let first = intern("--twill-test-intern");
+        let second = intern(&String::from("--twill-test-intern"));
+        assert!(std::ptr::eq(first, second));
+        assert!(std::ptr::eq(intern("0.625rem"), DEFAULT_RADIUS));
+    }
+}
@@ -1,4 +1,4 @@
-use palette::{FromColor, IntoColor, Oklch, Srgb};
+use palette::{FromColor, Hsl, IntoColor, Oklch, Srgb};
 
 /// A utility struct to convert between OKLCH and RGB colors.
 pub struct OklchConverter;
@@ -21,7 +21,21 @@ impl OklchConverter {
     /// Converts RGB bytes into OKLCH `(l, c, h)`.
     pub fn from_rgb(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
         let srgb = Srgb::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
-        let oklch: Oklch = Oklch::from_color(srgb);
+        Self::oklch_components(Oklch::from_color(srgb))
+    }
+
+    /// Converts floating-point sRGB channels (0.0 to 1.0) into OKLCH `(l, c, h)`.
+    pub fn from_srgb(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+        Self::oklch_components(Oklch::from_color(Srgb::new(r, g, b)))
+    }
+
+    /// Converts HSL (hue in degrees, saturation and lightness 0.0 to 1.0) into OKLCH `(l, c, h)`.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+        let srgb: Srgb = Srgb::from_color(Hsl::new(h, s, l));
+        Self::oklch_components(Oklch::from_color(srgb))
+    }
+
+    fn oklch_components(oklch: Oklch) -> (f32, f32, f32) {
         let hue = oklch.hue.into_inner();
         let safe_hue = if hue.is_finite() { hue } else { 0.0 };
         (oklch.l, oklch.chroma, safe_hue)
@@ -45,6 +45,41 @@ pub enum SemanticColor {
 }
 
 impl SemanticColor {
+    /// Every semantic token, in declaration order.
+    pub const ALL: [SemanticColor; 31] = [
+        Self::Background,
+        Self::Foreground,
+        Self::Card,
+        Self::CardForeground,
+        Self::Popover,
+        Self::PopoverForeground,
+        Self::Primary,
+        Self::PrimaryForeground,
+        Self::Secondary,
+        Self::SecondaryForeground,
+        Self::Muted,
+        Self::MutedForeground,
+        Self::Accent,
+        Self::AccentForeground,
+        Self::Destructive,
+        Self::Border,
+        Self::Input,
+        Self::Ring,
+        Self::Chart1,
+        Self::Chart2,
+        Self::Chart3,
+        Self::Chart4,
+        Self::Chart5,
+        Self::Sidebar,
+        Self::SidebarForeground,
+        Self::SidebarPrimary,
+        Self::SidebarPrimaryForeground,
+        Self::SidebarAccent,
+        Self::SidebarAccentForeground,
+        Self::SidebarBorder,
+        Self::SidebarRing,
+    ];
+
     pub const fn var_name(&self) -> &'static str {
         match self {
             Self::Background => "background",
@@ -106,6 +141,11 @@ impl ThemeVariant {
 /// This is the common theme contract used by Twill's style layer and backend adapters.
 pub trait SemanticThemeSource {
     fn resolve_value(&self, semantic: SemanticColor, variant: ThemeVariant) -> Option<ColorValue>;
+
+    /// Base corner radius (`--radius`), when the theme defines one.
+    fn radius(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Semantic token set with light and dark variable definitions.
@@ -130,6 +170,9 @@ pub struct SemanticThemeVars {
     pub(crate) dark_values: Vec<(SemanticColor, ColorValue)>,
 }
 
+/// `--radius` of the built-in shadcn themes.
+pub(crate) const DEFAULT_RADIUS: &str = "0.625rem";
+
 const SEMANTIC_COLOR_COUNT: usize = 31;
 
 const fn semantic_color_index(token: SemanticColor) -> usize {
@@ -319,7 +362,7 @@ impl SemanticThemeVars {
         }
 
         Self {
-            radius: "0.625rem",
+            radius: DEFAULT_RADIUS,
             light,
             dark,
             light_values,
@@ -372,6 +415,10 @@ impl SemanticThemeSource for SemanticThemeVars {
     fn resolve_value(&self, semantic: SemanticColor, variant: ThemeVariant) -> Option<ColorValue> {
         Self::resolve_value(self, semantic, variant)
     }
+
+    fn radius(&self) -> Option<&str> {
+        Some(self.radius)
+    }
 }
 
 /// Dynamic semantic theme generated from an arbitrary brand color using OKLCH.
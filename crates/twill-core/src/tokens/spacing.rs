@@ -279,6 +279,26 @@ impl Percentage {
             Percentage::Fit => "fit-content",
         }
     }
+
+    /// Fraction of the containing size, or `None` for intrinsic sizes (`min`/`max`/`fit`).
+    pub fn to_ratio(&self) -> Option<f32> {
+        match self {
+            Percentage::S0 => Some(0.0),
+            Percentage::S1_2 | Percentage::S2_4 | Percentage::S3_6 => Some(0.5),
+            Percentage::S1_3 | Percentage::S2_6 => Some(1.0 / 3.0),
+            Percentage::S2_3 | Percentage::S4_6 => Some(2.0 / 3.0),
+            Percentage::S1_4 => Some(0.25),
+            Percentage::S3_4 => Some(0.75),
+            Percentage::S1_5 => Some(0.2),
+            Percentage::S2_5 => Some(0.4),
+            Percentage::S3_5 => Some(0.6),
+            Percentage::S4_5 => Some(0.8),
+            Percentage::S1_6 => Some(1.0 / 6.0),
+            Percentage::S5_6 => Some(5.0 / 6.0),
+            Percentage::Full => Some(1.0),
+            Percentage::Min | Percentage::Max | Percentage::Fit => None,
+        }
+    }
 }
 
 /// Container max-width values.
@@ -343,6 +363,15 @@ pub enum Breakpoint {
 }
 
 impl Breakpoint {
+    /// Every breakpoint, smallest first.
+    pub const ALL: [Breakpoint; 5] = [
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::S2xl,
+    ];
+
     /// Value in rem.
     pub fn to_rem(&self) -> f32 {
         match self {
@@ -2,6 +2,9 @@
 
 use std::fmt;
 
+use super::spacing::Spacing;
+use crate::traits::ComputeValue;
+
 /// Named font-size variable for custom-property style mapping.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FontSizeVar(&'static str);
@@ -367,6 +370,8 @@ pub enum LineHeight {
     Loose,
     /// Numeric value (e.g. `1.75`).
     Number(f32),
+    /// Fixed height on the spacing scale (`leading-6` is `1.5rem`).
+    Spacing(Spacing),
     /// Custom property (e.g. `var(--leading)`).
     Var(LineHeightVar),
 }
@@ -388,6 +393,7 @@ impl LineHeight {
             LineHeight::Relaxed => "1.625".to_string(),
             LineHeight::Loose => "2".to_string(),
             LineHeight::Number(n) => n.to_string(),
+            LineHeight::Spacing(spacing) => spacing.compute(),
             LineHeight::Var(var) => format!("var({})", var.as_str()),
         }
     }
@@ -402,6 +408,7 @@ impl fmt::Display for LineHeight {
             LineHeight::Relaxed => f.write_str("1.625"),
             LineHeight::Loose => f.write_str("2"),
             LineHeight::Number(n) => write!(f, "{n}"),
+            LineHeight::Spacing(spacing) => f.write_str(&spacing.compute()),
             LineHeight::Var(var) => write!(f, "var({var})"),
         }
     }
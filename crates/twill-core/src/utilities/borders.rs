@@ -0,0 +1,168 @@
+//! Per-side border width and per-corner border radius utilities.
+
+use crate::tokens::{BorderRadius, BorderWidth};
+
+/// Per-side border widths (`border-t-*`, `border-x-*`, ...).
+///
+/// Unset sides fall back to the uniform `border-*` width of the style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct BorderWidths {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) top: Option<BorderWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) right: Option<BorderWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bottom: Option<BorderWidth>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) left: Option<BorderWidth>,
+}
+
+impl BorderWidths {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The same width on every side.
+    pub const fn all(width: BorderWidth) -> Self {
+        Self {
+            top: Some(width),
+            right: Some(width),
+            bottom: Some(width),
+            left: Some(width),
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.top.is_none() && self.right.is_none() && self.bottom.is_none() && self.left.is_none()
+    }
+
+    pub const fn top_side(&self) -> Option<BorderWidth> {
+        self.top
+    }
+
+    pub const fn right_side(&self) -> Option<BorderWidth> {
+        self.right
+    }
+
+    pub const fn bottom_side(&self) -> Option<BorderWidth> {
+        self.bottom
+    }
+
+    pub const fn left_side(&self) -> Option<BorderWidth> {
+        self.left
+    }
+
+    /// Fill unset sides with `width`.
+    pub fn or(self, width: Option<BorderWidth>) -> Self {
+        Self {
+            top: self.top.or(width),
+            right: self.right.or(width),
+            bottom: self.bottom.or(width),
+            left: self.left.or(width),
+        }
+    }
+
+    /// Whether every side resolves to the same width.
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right && self.right == self.bottom && self.bottom == self.left
+    }
+
+    /// Side widths in pixels as `[top, right, bottom, left]`; unset sides are `0`.
+    pub fn to_px(&self) -> [f32; 4] {
+        [self.top, self.right, self.bottom, self.left]
+            .map(|width| width.map_or(0, |width| width.px_value()) as f32)
+    }
+}
+
+/// Per-corner border radii (`rounded-t-*`, `rounded-tl-*`, ...).
+///
+/// Unset corners fall back to the uniform `rounded-*` radius of the style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct BorderRadii {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) top_left: Option<BorderRadius>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) top_right: Option<BorderRadius>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bottom_right: Option<BorderRadius>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bottom_left: Option<BorderRadius>,
+}
+
+impl BorderRadii {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The same radius on every corner.
+    pub const fn all(radius: BorderRadius) -> Self {
+        Self {
+            top_left: Some(radius),
+            top_right: Some(radius),
+            bottom_right: Some(radius),
+            bottom_left: Some(radius),
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.top_left.is_none()
+            && self.top_right.is_none()
+            && self.bottom_right.is_none()
+            && self.bottom_left.is_none()
+    }
+
+    pub const fn top_left_corner(&self) -> Option<BorderRadius> {
+        self.top_left
+    }
+
+    pub const fn top_right_corner(&self) -> Option<BorderRadius> {
+        self.top_right
+    }
+
+    pub const fn bottom_right_corner(&self) -> Option<BorderRadius> {
+        self.bottom_right
+    }
+
+    pub const fn bottom_left_corner(&self) -> Option<BorderRadius> {
+        self.bottom_left
+    }
+
+    /// Fill unset corners with `radius`.
+    pub fn or(self, radius: Option<BorderRadius>) -> Self {
+        Self {
+            top_left: self.top_left.or(radius),
+            top_right: self.top_right.or(radius),
+            bottom_right: self.bottom_right.or(radius),
+            bottom_left: self.bottom_left.or(radius),
+        }
+    }
+
+    /// Whether every corner resolves to the same radius.
+    pub fn is_uniform(&self) -> bool {
+        self.top_left == self.top_right
+            && self.top_right == self.bottom_right
+            && self.bottom_right == self.bottom_left
+    }
+
+    /// Corner radii in pixels as `[top_left, top_right, bottom_right, bottom_left]`;
+    /// unset corners are `0`.
+    pub fn to_px(&self) -> [f32; 4] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .map(|radius| radius.map_or(0.0, |radius| radius.px_value()))
+    }
+}
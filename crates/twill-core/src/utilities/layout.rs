@@ -3,8 +3,9 @@
 use std::fmt;
 use std::num::{NonZeroU8, NonZeroU16};
 
+use super::{GridFlow, TrackList};
 use crate::tokens::Container;
-use crate::tokens::Spacing;
+use crate::tokens::{Percentage, Spacing};
 
 /// Display type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -72,6 +73,167 @@ impl fmt::Display for Position {
     }
 }
 
+/// Inset value used by the `top`/`right`/`bottom`/`left`/`inset-*` utilities, and by
+/// `translate-*`, where fractions are of the element's own size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InsetValue {
+    /// Theme spacing token (`top-<number>`, `inset-x-px`).
+    Scale(Spacing),
+    /// Negative theme spacing token (`-top-<number>`).
+    NegativeScale(Spacing),
+    /// Fraction of the containing block (`top-1/2`, `inset-full`).
+    Fraction(Percentage),
+    /// Negative fraction of the containing block (`-top-1/2`).
+    NegativeFraction(Percentage),
+    /// Arbitrary pixel value (`top-[<value>]`); may be negative.
+    Px(f32),
+    /// Arbitrary percentage of the containing block (`top-[<value>%]`); may be negative.
+    Percent(f32),
+    /// `top-auto`: the side is left to the content size.
+    Auto,
+}
+
+impl InsetValue {
+    pub const fn scale(spacing: Spacing) -> Self {
+        if matches!(spacing, Spacing::Auto) {
+            Self::Auto
+        } else {
+            Self::Scale(spacing)
+        }
+    }
+
+    pub const fn neg_scale(spacing: Spacing) -> Self {
+        if matches!(spacing, Spacing::Auto) {
+            Self::Auto
+        } else {
+            Self::NegativeScale(spacing)
+        }
+    }
+
+    pub const fn fraction(fraction: Percentage) -> Self {
+        Self::Fraction(fraction)
+    }
+
+    pub const fn neg_fraction(fraction: Percentage) -> Self {
+        Self::NegativeFraction(fraction)
+    }
+
+    pub const fn px(value: f32) -> Self {
+        Self::Px(value)
+    }
+
+    pub const fn auto() -> Self {
+        Self::Auto
+    }
+
+    /// Offset in px against a containing block `containing_px` long on this axis.
+    ///
+    /// Returns `None` for `auto` and intrinsic fractions (`min`/`max`/`fit`).
+    pub fn to_px(self, containing_px: f32) -> Option<f32> {
+        let spacing_px = |spacing: Spacing| spacing.to_px().map(|px| px as f32);
+        match self {
+            Self::Scale(spacing) => spacing_px(spacing),
+            Self::NegativeScale(spacing) => spacing_px(spacing).map(|px| -px),
+            Self::Fraction(fraction) => fraction.to_ratio().map(|ratio| ratio * containing_px),
+            Self::NegativeFraction(fraction) => {
+                fraction.to_ratio().map(|ratio| -ratio * containing_px)
+            }
+            Self::Px(px) => px.is_finite().then_some(px),
+            Self::Percent(percent) => percent
+                .is_finite()
+                .then_some(percent / 100.0 * containing_px),
+            Self::Auto => None,
+        }
+    }
+}
+
+impl From<Spacing> for InsetValue {
+    fn from(value: Spacing) -> Self {
+        Self::scale(value)
+    }
+}
+
+impl From<Percentage> for InsetValue {
+    fn from(value: Percentage) -> Self {
+        Self::fraction(value)
+    }
+}
+
+/// Offsets of a positioned element from its containing block.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Inset {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) top: Option<InsetValue>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) right: Option<InsetValue>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bottom: Option<InsetValue>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) left: Option<InsetValue>,
+}
+
+impl Inset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.top.is_none() && self.right.is_none() && self.bottom.is_none() && self.left.is_none()
+    }
+
+    pub const fn top_side(&self) -> Option<InsetValue> {
+        self.top
+    }
+
+    pub const fn right_side(&self) -> Option<InsetValue> {
+        self.right
+    }
+
+    pub const fn bottom_side(&self) -> Option<InsetValue> {
+        self.bottom
+    }
+
+    pub const fn left_side(&self) -> Option<InsetValue> {
+        self.left
+    }
+
+    /// `inset-*` family: set all sides.
+    pub fn all(value: impl Into<InsetValue>) -> Self {
+        let value = value.into();
+        Self {
+            top: Some(value),
+            right: Some(value),
+            bottom: Some(value),
+            left: Some(value),
+        }
+    }
+
+    /// `inset-x-*` family: set left and right.
+    pub fn x(value: impl Into<InsetValue>) -> Self {
+        let value = value.into();
+        Self {
+            right: Some(value),
+            left: Some(value),
+            ..Self::default()
+        }
+    }
+
+    /// `inset-y-*` family: set top and bottom.
+    pub fn y(value: impl Into<InsetValue>) -> Self {
+        let value = value.into();
+        Self {
+            top: Some(value),
+            bottom: Some(value),
+            ..Self::default()
+        }
+    }
+}
+
 /// Flex direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FlexDirection {
@@ -317,13 +479,25 @@ impl fmt::Display for Flex {
 
 /// Flex container utility.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct FlexContainer {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) direction: Option<FlexDirection>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) wrap: Option<FlexWrap>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) justify: Option<JustifyContent>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) align: Option<AlignItems>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) row_gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) col_gap: Option<Spacing>,
 }
 
@@ -555,14 +729,31 @@ impl GridTemplate {
     pub fn track_count_hint(&self) -> Option<u16> {
         match self {
             GridTemplate::Count(count) => Some(count.get()),
-            GridTemplate::None
-            | GridTemplate::Subgrid
-            | GridTemplate::CustomProperty(_)
-            | GridTemplate::Arbitrary(_) => None,
+            GridTemplate::Arbitrary(_) => self
+                .track_list()?
+                .fixed_track_count()
+                .and_then(|count| u16::try_from(count).ok()),
+            GridTemplate::None | GridTemplate::Subgrid | GridTemplate::CustomProperty(_) => None,
+        }
+    }
+
+    /// The typed tracks of a `Count` template, or of an `Arbitrary` one that parses as a
+    /// track list.
+    pub fn track_list(&self) -> Option<TrackList> {
+        match self {
+            GridTemplate::Count(count) => Some(TrackList::equal(count.get())),
+            GridTemplate::Arbitrary(value) => TrackList::parse(value).ok(),
+            GridTemplate::None | GridTemplate::Subgrid | GridTemplate::CustomProperty(_) => None,
         }
     }
 }
 
+impl From<TrackList> for GridTemplate {
+    fn from(tracks: TrackList) -> Self {
+        Self::Arbitrary(tracks.to_string())
+    }
+}
+
 /// Multi-column layout utility (`columns-*`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Columns {
@@ -603,14 +794,28 @@ impl Columns {
 
 /// Grid container utility.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct GridContainer {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) columns: Option<GridTemplate>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) rows: Option<GridTemplate>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) row_gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) col_gap: Option<Spacing>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) justify: Option<JustifyContent>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub(crate) align: Option<AlignItems>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) flow: Option<GridFlow>,
 }
 
 impl GridContainer {
@@ -646,8 +851,12 @@ impl GridContainer {
         self.align
     }
 
-    pub fn columns(mut self, cols: GridTemplate) -> Self {
-        self.columns = Some(cols);
+    pub const fn flow_value(&self) -> Option<GridFlow> {
+        self.flow
+    }
+
+    pub fn columns(mut self, cols: impl Into<GridTemplate>) -> Self {
+        self.columns = Some(cols.into());
         self
     }
 
@@ -676,8 +885,19 @@ impl GridContainer {
         self.columns(GridTemplate::arbitrary(value))
     }
 
-    pub fn rows(mut self, rows: GridTemplate) -> Self {
-        self.rows = Some(rows);
+    pub fn rows(mut self, rows: impl Into<GridTemplate>) -> Self {
+        self.rows = Some(rows.into());
+        self
+    }
+
+    /// Set repeated equal grid rows.
+    pub fn rows_count(self, count: u16) -> Self {
+        self.rows(GridTemplate::count(count))
+    }
+
+    /// Set the auto-placement flow (`grid-flow-*`).
+    pub fn flow(mut self, flow: GridFlow) -> Self {
+        self.flow = Some(flow);
         self
     }
 
@@ -1034,6 +1254,22 @@ mod tests {
             GridTemplate::arbitrary("200px_minmax(0,_1fr)_100px"),
             GridTemplate::Arbitrary("200px minmax(0, 1fr) 100px".to_string())
         );
+        assert_eq!(
+            GridTemplate::arbitrary("200px_minmax(0,_1fr)_100px").track_count_hint(),
+            Some(3)
+        );
+        assert_eq!(
+            GridTemplate::arbitrary("repeat(auto-fill,_minmax(200px,_1fr))").track_count_hint(),
+            None
+        );
+        assert_eq!(
+            GridTemplate::from(TrackList::new().px(240.0).fr(1.0).auto()),
+            GridTemplate::arbitrary("240px_1fr_auto")
+        );
+        assert_eq!(
+            GridTemplate::count(2).track_list(),
+            Some(TrackList::equal(2))
+        );
     }
 
     #[test]
@@ -3,8 +3,16 @@
 //! This module contains utility structs that combine design tokens
 //! into usable style properties.
 
+pub mod borders;
 pub mod layout;
+pub mod placement;
 pub mod spacing;
+pub mod tracks;
+pub mod transforms;
 
+pub use borders::*;
 pub use layout::*;
+pub use placement::*;
 pub use spacing::*;
+pub use tracks::*;
+pub use transforms::*;
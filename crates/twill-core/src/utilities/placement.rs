@@ -0,0 +1,442 @@
+//! Grid item placement: `col-*`/`row-*` lines and spans, `grid-flow-*` and auto-placement.
+
+/// Most tracks an item spans, and most implicit tracks placement creates.
+const MAX_TRACKS: usize = 1000;
+
+/// One edge of a grid item's placement along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridLine {
+    /// Left to auto-placement (`col-start-auto`).
+    Auto,
+    /// A grid line numbered from 1 (`col-start-2`). Negative lines count back from the
+    /// end of the explicit grid, so `-1` is its last line; `0` is read as `Auto`.
+    Line(i16),
+    /// Span this many tracks (`col-span-2`).
+    Span(u16),
+}
+
+impl From<i16> for GridLine {
+    fn from(line: i16) -> Self {
+        Self::Line(line)
+    }
+}
+
+/// Where a grid item sits along one axis (`grid-column` or `grid-row`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct GridPlacement {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) start: Option<GridLine>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) end: Option<GridLine>,
+}
+
+impl GridPlacement {
+    pub const fn new() -> Self {
+        Self {
+            start: None,
+            end: None,
+        }
+    }
+
+    /// `col-span-<count>`: span `count` tracks wherever the item is placed.
+    pub const fn span(count: u16) -> Self {
+        let span = GridLine::Span(if count == 0 { 1 } else { count });
+        Self {
+            start: Some(span),
+            end: Some(span),
+        }
+    }
+
+    /// `col-span-full`: from the first to the last line of the explicit grid.
+    pub const fn full() -> Self {
+        Self {
+            start: Some(GridLine::Line(1)),
+            end: Some(GridLine::Line(-1)),
+        }
+    }
+
+    /// `col-auto`: placed by auto-placement, one track wide.
+    pub const fn auto() -> Self {
+        Self {
+            start: Some(GridLine::Auto),
+            end: Some(GridLine::Auto),
+        }
+    }
+
+    pub const fn start_value(&self) -> Option<GridLine> {
+        self.start
+    }
+
+    pub const fn end_value(&self) -> Option<GridLine> {
+        self.end
+    }
+
+    /// Set the start line (`col-start-*`).
+    pub const fn start(mut self, line: GridLine) -> Self {
+        self.start = Some(line);
+        self
+    }
+
+    /// Set the end line (`col-end-*`).
+    pub const fn end(mut self, line: GridLine) -> Self {
+        self.end = Some(line);
+        self
+    }
+
+    /// Resolve against an explicit grid of `tracks` tracks: the first track (from 0)
+    /// when the placement fixes one, and the number of tracks spanned.
+    pub fn resolve(&self, tracks: usize) -> (Option<usize>, usize) {
+        let line = |line: Option<GridLine>| match line {
+            Some(GridLine::Line(line)) if line > 0 => {
+                Some((usize::from(line.unsigned_abs()) - 1).min(MAX_TRACKS))
+            }
+            Some(GridLine::Line(line)) if line < 0 => {
+                Some((tracks + 1).saturating_sub(usize::from(line.unsigned_abs())))
+            }
+            _ => None,
+        };
+        let span = |line: Option<GridLine>| match line {
+            Some(GridLine::Span(span)) => usize::from(span).clamp(1, MAX_TRACKS),
+            _ => 1,
+        };
+
+        match (line(self.start), line(self.end)) {
+            (Some(start), Some(end)) if start == end => (Some(start), 1),
+            (Some(start), Some(end)) => (Some(start.min(end)), start.abs_diff(end)),
+            (Some(start), None) => (Some(start), span(self.end)),
+            (None, Some(end)) => {
+                let span = span(self.start);
+                (Some(end.saturating_sub(span)), span)
+            }
+            (None, None) => (None, span(self.start)),
+        }
+    }
+}
+
+/// Auto-placement direction and packing (`grid-flow-*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GridFlow {
+    /// Fill each row before starting the next (`grid-flow-row`).
+    #[default]
+    Row,
+    /// Fill each column before starting the next (`grid-flow-col`).
+    Col,
+    /// Row flow that backfills holes left by earlier items (`grid-flow-dense`).
+    Dense,
+    /// `grid-flow-row-dense`, the same as [`GridFlow::Dense`].
+    RowDense,
+    /// Column flow that backfills holes (`grid-flow-col-dense`).
+    ColDense,
+}
+
+impl GridFlow {
+    /// Whether items fill columns rather than rows.
+    pub const fn is_column(self) -> bool {
+        matches!(self, GridFlow::Col | GridFlow::ColDense)
+    }
+
+    /// Whether later items may fill holes before earlier ones.
+    pub const fn is_dense(self) -> bool {
+        matches!(
+            self,
+            GridFlow::Dense | GridFlow::RowDense | GridFlow::ColDense
+        )
+    }
+}
+
+/// The cells a grid item occupies, from [`place_grid_items`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridArea {
+    pub(crate) column: usize,
+    pub(crate) row: usize,
+    pub(crate) column_span: usize,
+    pub(crate) row_span: usize,
+}
+
+impl GridArea {
+    /// First column, from 0.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// First row, from 0.
+    pub const fn row(&self) -> usize {
+        self.row
+    }
+
+    pub const fn column_span(&self) -> usize {
+        self.column_span
+    }
+
+    pub const fn row_span(&self) -> usize {
+        self.row_span
+    }
+}
+
+/// Place grid items the way CSS grid auto-placement does.
+///
+/// `items` holds the column and row placement of each item in document order, and
+/// `columns` and `rows` the number of explicit tracks. Items with both positions set
+/// are placed first, then items locked to a row (a column in column flow), then the
+/// rest in order behind a cursor, or from the start of the grid for dense flows. The
+/// grid grows implicit rows (columns in column flow), and implicit columns for items
+/// placed or spanning past the explicit ones.
+///
+/// ```rust
+/// use twill_core::utilities::{GridFlow, GridPlacement, place_grid_items};
+///
+/// let auto = (GridPlacement::new(), GridPlacement::new());
+/// let wide = (GridPlacement::span(2), GridPlacement::new());
+///
+/// let sparse = place_grid_items(&[auto, wide, auto], 2, 0, GridFlow::Row);
+/// assert_eq!((sparse[2].column(), sparse[2].row()), (0, 2));
+///
+/// // Dense packing backfills the cell the wide item skipped.
+/// let dense = place_grid_items(&[auto, wide, auto], 2, 0, GridFlow::Dense);
+/// assert_eq!((dense[2].column(), dense[2].row()), (1, 0));
+/// ```
+pub fn place_grid_items(
+    items: &[(GridPlacement, GridPlacement)],
+    columns: usize,
+    rows: usize,
+    flow: GridFlow,
+) -> Vec<GridArea> {
+    // Work along the axis items fill (`minor`) and the axis the grid grows in (`major`).
+    let column_flow = flow.is_column();
+    let (minor_tracks, major_tracks) = if column_flow {
+        (rows, columns)
+    } else {
+        (columns, rows)
+    };
+    let resolved: Vec<_> = items
+        .iter()
+        .map(|(column, row)| {
+            let (minor, major) = if column_flow {
+                (row, column)
+            } else {
+                (column, row)
+            };
+            (minor.resolve(minor_tracks), major.resolve(major_tracks))
+        })
+        .collect();
+
+    let mut grid = Occupancy::default();
+    let mut placed: Vec<Option<(usize, usize)>> = vec![None; items.len()];
+
+    // Items with both positions set.
+    for (index, &((minor, minor_span), (major, major_span))) in resolved.iter().enumerate() {
+        if let (Some(minor), Some(major)) = (minor, major) {
+            grid.occupy(minor, minor_span, major, major_span);
+            placed[index] = Some((minor, major));
+        }
+    }
+
+    // Items locked to a major track.
+    let mut lane_cursors: Vec<(usize, usize)> = Vec::new();
+    for (index, &((minor, minor_span), (major, major_span))) in resolved.iter().enumerate() {
+        let (None, Some(major)) = (minor, major) else {
+            continue;
+        };
+        let mut start = if flow.is_dense() {
+            0
+        } else {
+            lane_cursors
+                .iter()
+                .find(|(lane, _)| *lane == major)
+                .map_or(0, |(_, cursor)| *cursor)
+        };
+        while !grid.is_free(start, minor_span, major, major_span) {
+            start += 1;
+        }
+        grid.occupy(start, minor_span, major, major_span);
+        placed[index] = Some((start, major));
+        match lane_cursors.iter_mut().find(|(lane, _)| *lane == major) {
+            Some((_, cursor)) => *cursor = start + minor_span,
+            None => lane_cursors.push((major, start + minor_span)),
+        }
+    }
+
+    // The minor axis holds the explicit tracks, every placed item and the widest span.
+    let minor_count = resolved
+        .iter()
+        .zip(&placed)
+        .map(|(&((minor, minor_span), _), placed)| {
+            minor.or(placed.map(|(minor, _)| minor)).unwrap_or(0) + minor_span
+        })
+        .fold(minor_tracks.max(1), usize::max)
+        .min(MAX_TRACKS);
+
+    // Everything else, behind the cursor.
+    let (mut cursor_minor, mut cursor_major) = (0, 0);
+    for (index, &((minor, minor_span), (_, major_span))) in resolved.iter().enumerate() {
+        if placed[index].is_some() {
+            continue;
+        }
+        if flow.is_dense() {
+            (cursor_minor, cursor_major) = (0, 0);
+        }
+        match minor {
+            Some(minor) => {
+                if minor < cursor_minor {
+                    cursor_major += 1;
+                }
+                cursor_minor = minor;
+                while !grid.is_free(minor, minor_span, cursor_major, major_span) {
+                    cursor_major += 1;
+                }
+            }
+            None => loop {
+                if cursor_minor + minor_span > minor_count {
+                    (cursor_minor, cursor_major) = (0, cursor_major + 1);
+                } else if grid.is_free(cursor_minor, minor_span, cursor_major, major_span) {
+                    break;
+                } else {
+                    cursor_minor += 1;
+                }
+            },
+        }
+        grid.occupy(cursor_minor, minor_span, cursor_major, major_span);
+        placed[index] = Some((cursor_minor, cursor_major));
+        if minor.is_none() {
+            cursor_minor += minor_span;
+        }
+    }
+
+    resolved
+        .iter()
+        .zip(placed)
+        .map(|(&((_, minor_span), (_, major_span)), placed)| {
+            let (minor, major) = placed.unwrap_or_default();
+            if column_flow {
+                GridArea {
+                    column: major,
+                    row: minor,
+                    column_span: major_span,
+                    row_span: minor_span,
+                }
+            } else {
+                GridArea {
+                    column: minor,
+                    row: major,
+                    column_span: minor_span,
+                    row_span: major_span,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Occupied cells, one lane of minor-axis cells per major track.
+#[derive(Default)]
+struct Occupancy {
+    lanes: Vec<Vec<bool>>,
+}
+
+impl Occupancy {
+    fn is_free(&self, minor: usize, minor_span: usize, major: usize, major_span: usize) -> bool {
+        self.lanes.iter().skip(major).take(major_span).all(|lane| {
+            lane.iter()
+                .skip(minor)
+                .take(minor_span)
+                .all(|occupied| !occupied)
+        })
+    }
+
+    fn occupy(&mut self, minor: usize, minor_span: usize, major: usize, major_span: usize) {
+        if self.lanes.len() < major + major_span {
+            self.lanes.resize_with(major + major_span, Vec::new);
+        }
+        for lane in &mut self.lanes[major..major + major_span] {
+            if lane.len() < minor + minor_span {
+                lane.resize(minor + minor_span, false);
+            }
+            lane[minor..minor + minor_span].fill(true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(areas: &[GridArea]) -> Vec<(usize, usize, usize, usize)> {
+        areas
+            .iter()
+            .map(|area| (area.column, area.row, area.column_span, area.row_span))
+            .collect()
+    }
+
+    #[test]
+    fn test_placements_resolve_lines_and_spans() {
+        assert_eq!(GridPlacement::new().resolve(3), (None, 1));
+        assert_eq!(GridPlacement::span(2).resolve(3), (None, 2));
+        assert_eq!(GridPlacement::full().resolve(3), (Some(0), 3));
+        assert_eq!(
+            GridPlacement::new()
+                .start(GridLine::Line(2))
+                .end(GridLine::Line(4))
+                .resolve(3),
+            (Some(1), 2)
+        );
+        assert_eq!(
+            GridPlacement::new().end(GridLine::Line(-1)).resolve(3),
+            (Some(2), 1)
+        );
+        assert_eq!(
+            GridPlacement::span(2).end(GridLine::Line(4)).resolve(3),
+            (Some(1), 2)
+        );
+        assert_eq!(GridPlacement::span(0), GridPlacement::span(1));
+    }
+
+    #[test]
+    fn test_auto_placement_flows_around_placed_items() {
+        let auto = (GridPlacement::new(), GridPlacement::new());
+        let hero = (GridPlacement::span(2), GridPlacement::span(2));
+        let sidebar = (
+            GridPlacement::new().start(GridLine::Line(3)),
+            GridPlacement::full(),
+        );
+
+        // The sidebar is placed first, in the last column of both explicit rows.
+        let bento = place_grid_items(&[hero, auto, sidebar, auto, auto], 3, 2, GridFlow::Row);
+        assert_eq!(
+            cells(&bento),
+            vec![
+                (0, 0, 2, 2),
+                (0, 2, 1, 1),
+                (2, 0, 1, 2),
+                (1, 2, 1, 1),
+                (2, 2, 1, 1),
+            ]
+        );
+
+        let wide = (GridPlacement::span(2), GridPlacement::new());
+        let form = place_grid_items(&[auto, wide, auto, auto], 3, 0, GridFlow::Row);
+        assert_eq!(
+            cells(&form),
+            vec![(0, 0, 1, 1), (1, 0, 2, 1), (0, 1, 1, 1), (1, 1, 1, 1)]
+        );
+
+        // Column flow fills rows first and grows columns.
+        let columns = place_grid_items(&[auto, auto, auto], 0, 2, GridFlow::Col);
+        assert_eq!(
+            cells(&columns),
+            vec![(0, 0, 1, 1), (0, 1, 1, 1), (1, 0, 1, 1)]
+        );
+
+        // Spans wider than the grid add implicit columns.
+        let overflow = place_grid_items(
+            &[(GridPlacement::span(3), GridPlacement::new())],
+            2,
+            0,
+            GridFlow::Row,
+        );
+        assert_eq!(cells(&overflow), vec![(0, 0, 3, 1)]);
+    }
+}
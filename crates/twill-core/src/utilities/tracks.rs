@@ -0,0 +1,918 @@
+//! Grid track lists (`grid-cols-[240px_1fr_auto]`) and the track sizing algorithm.
+
+use std::{error::Error, fmt, str::FromStr};
+
+/// Most tracks a list expands to, however small its auto-repeated tracks are.
+const MAX_TRACKS: usize = 1000;
+
+/// Pixels per `rem` when parsing track lengths.
+const REM_PX: f32 = 16.0;
+
+/// The size of a grid track, or one bound of a [`TrackSize::MinMax`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackBreadth {
+    /// Fixed length in logical pixels (`240px`; `rem` lengths are converted at 16px).
+    Px(f32),
+    /// Percentage of the grid's width (`25%`); `auto` when the grid has no definite width.
+    Percent(f32),
+    /// Share of the space left by the other tracks (`1fr`).
+    Fr(f32),
+    /// Sized to the content, stretched into space nothing else takes (`auto`).
+    Auto,
+    /// Narrowest the content can wrap to (`min-content`).
+    MinContent,
+    /// Width of the content laid out without wrapping (`max-content`).
+    MaxContent,
+}
+
+impl TrackBreadth {
+    /// Length in pixels when the breadth does not depend on content or free space.
+    fn fixed(self, available: f32) -> Option<f32> {
+        match self {
+            TrackBreadth::Px(px) => Some(px),
+            TrackBreadth::Percent(percent) if available.is_finite() => {
+                Some(available * percent / 100.0)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TrackBreadth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackBreadth::Px(px) if *px == 0.0 => f.write_str("0"),
+            TrackBreadth::Px(px) => write!(f, "{px}px"),
+            TrackBreadth::Percent(percent) => write!(f, "{percent}%"),
+            TrackBreadth::Fr(fr) => write!(f, "{fr}fr"),
+            TrackBreadth::Auto => f.write_str("auto"),
+            TrackBreadth::MinContent => f.write_str("min-content"),
+            TrackBreadth::MaxContent => f.write_str("max-content"),
+        }
+    }
+}
+
+/// The sizing function of one grid track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackSize {
+    /// A single breadth. A bare `1fr` is `minmax(auto, 1fr)`, so it does not shrink
+    /// below its content.
+    Breadth(TrackBreadth),
+    /// `minmax(min, max)`. An `fr` minimum is read as `auto`.
+    MinMax(TrackBreadth, TrackBreadth),
+}
+
+impl TrackSize {
+    /// `minmax(min, max)`.
+    pub const fn minmax(min: TrackBreadth, max: TrackBreadth) -> Self {
+        Self::MinMax(min, max)
+    }
+
+    /// Minimum and maximum sizing functions.
+    fn bounds(self) -> (TrackBreadth, TrackBreadth) {
+        let (min, max) = match self {
+            TrackSize::Breadth(breadth) => (breadth, breadth),
+            TrackSize::MinMax(min, max) => (min, max),
+        };
+        match min {
+            TrackBreadth::Fr(_) => (TrackBreadth::Auto, max),
+            min => (min, max),
+        }
+    }
+
+    /// Whether sizing the track needs the size of its content.
+    fn is_intrinsic(self, available: f32) -> bool {
+        let (min, max) = self.bounds();
+        min.fixed(available).is_none()
+            || !matches!(max, TrackBreadth::Fr(_)) && max.fixed(available).is_none()
+    }
+
+    /// Size used to count auto repetitions: the maximum when it is fixed, otherwise the
+    /// minimum when that is.
+    fn definite(self, available: f32) -> Option<f32> {
+        let (min, max) = self.bounds();
+        max.fixed(available).or_else(|| min.fixed(available))
+    }
+}
+
+impl From<TrackBreadth> for TrackSize {
+    fn from(breadth: TrackBreadth) -> Self {
+        Self::Breadth(breadth)
+    }
+}
+
+impl fmt::Display for TrackSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackSize::Breadth(breadth) => breadth.fmt(f),
+            TrackSize::MinMax(min, max) => write!(f, "minmax({min}, {max})"),
+        }
+    }
+}
+
+/// How many times the tracks of a `repeat()` are repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrackRepeat {
+    /// `repeat(<n>, ...)`.
+    Count(u16),
+    /// `repeat(auto-fill, ...)`: as many times as fit, keeping empty tracks.
+    AutoFill,
+    /// `repeat(auto-fit, ...)`: as many times as fit, collapsing tracks left empty.
+    AutoFit,
+}
+
+impl fmt::Display for TrackRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackRepeat::Count(count) => write!(f, "{count}"),
+            TrackRepeat::AutoFill => f.write_str("auto-fill"),
+            TrackRepeat::AutoFit => f.write_str("auto-fit"),
+        }
+    }
+}
+
+/// An entry of a [`TrackList`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Track {
+    Size(TrackSize),
+    Repeat(TrackRepeat, Vec<TrackSize>),
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Track::Size(size) => size.fmt(f),
+            Track::Repeat(repeat, sizes) => {
+                write!(f, "repeat({repeat},")?;
+                for size in sizes {
+                    write!(f, " {size}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// A `grid-template-columns` track list such as `240px 1fr auto` or
+/// `repeat(auto-fill, minmax(200px, 1fr))`.
+///
+/// Lists parse from the CSS syntax of arbitrary grid templates and build from Rust;
+/// [`TrackList::resolve`] sizes the columns of a grid the way browsers do, so backends
+/// lay out `fr`, `minmax()` and auto-repeated tracks alike.
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let sidebar: TrackList = "240px 1fr auto".parse().unwrap();
+/// assert_eq!(sidebar, TrackList::new().px(240.0).fr(1.0).auto());
+///
+/// let cards = TrackList::new().auto_fill([TrackSize::minmax(
+///     TrackBreadth::Px(200.0),
+///     TrackBreadth::Fr(1.0),
+/// )]);
+/// let style = Style::new().display(Display::Grid).grid_cols(cards);
+/// assert_eq!(
+///     style.to_class_string(),
+///     "grid grid-cols-[repeat(auto-fill,_minmax(200px,_1fr))]"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackList {
+    pub(crate) tracks: Vec<Track>,
+}
+
+impl TrackList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `repeat(count, minmax(0, 1fr))`: `count` equal columns, like `grid-cols-<count>`.
+    pub fn equal(count: u16) -> Self {
+        Self::new().repeat(
+            count.max(1),
+            [TrackSize::minmax(
+                TrackBreadth::Px(0.0),
+                TrackBreadth::Fr(1.0),
+            )],
+        )
+    }
+
+    /// Parse the CSS syntax of a track list. `_` separates tracks like a space, as in
+    /// arbitrary class values.
+    pub fn parse(value: &str) -> Result<Self, ParseTrackListError> {
+        let value = value.replace('_', " ");
+        let mut list = Self::new();
+        for token in split_top_level(&value, char::is_whitespace) {
+            let track = match function_args(token, "repeat") {
+                Some(args) => repeat(token, args)?,
+                None => Track::Size(track_size(token)?),
+            };
+            if matches!(
+                track,
+                Track::Repeat(TrackRepeat::AutoFill | TrackRepeat::AutoFit, _)
+            ) && list.auto_repeat().is_some()
+            {
+                return Err(ParseTrackListError::MultipleAutoRepeats);
+            }
+            list.tracks.push(track);
+        }
+        if list.tracks.is_empty() {
+            return Err(ParseTrackListError::Empty);
+        }
+        Ok(list)
+    }
+
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Add a track.
+    pub fn track(mut self, size: impl Into<TrackSize>) -> Self {
+        self.tracks.push(Track::Size(size.into()));
+        self
+    }
+
+    /// Add a fixed track in logical pixels.
+    pub fn px(self, px: f32) -> Self {
+        self.track(TrackBreadth::Px(px))
+    }
+
+    /// Add a track sized as a percentage of the grid's width.
+    pub fn percent(self, percent: f32) -> Self {
+        self.track(TrackBreadth::Percent(percent))
+    }
+
+    /// Add a flexible `fr` track.
+    pub fn fr(self, fr: f32) -> Self {
+        self.track(TrackBreadth::Fr(fr))
+    }
+
+    /// Add an `auto` track.
+    pub fn auto(self) -> Self {
+        self.track(TrackBreadth::Auto)
+    }
+
+    /// Add a `min-content` track.
+    pub fn min_content(self) -> Self {
+        self.track(TrackBreadth::MinContent)
+    }
+
+    /// Add a `max-content` track.
+    pub fn max_content(self) -> Self {
+        self.track(TrackBreadth::MaxContent)
+    }
+
+    /// Add a `minmax(min, max)` track.
+    pub fn minmax(self, min: TrackBreadth, max: TrackBreadth) -> Self {
+        self.track(TrackSize::minmax(min, max))
+    }
+
+    /// Add `repeat(count, sizes)`.
+    pub fn repeat(mut self, count: u16, sizes: impl IntoIterator<Item = TrackSize>) -> Self {
+        self.tracks.push(Track::Repeat(
+            TrackRepeat::Count(count),
+            sizes.into_iter().collect(),
+        ));
+        self
+    }
+
+    /// Add `repeat(auto-fill, sizes)`. Only the first auto repeat of a list is repeated
+    /// to fill the grid; `sizes` need a fixed minimum or maximum to count how many fit.
+    pub fn auto_fill(mut self, sizes: impl IntoIterator<Item = TrackSize>) -> Self {
+        self.tracks.push(Track::Repeat(
+            TrackRepeat::AutoFill,
+            sizes.into_iter().collect(),
+        ));
+        self
+    }
+
+    /// Add `repeat(auto-fit, sizes)`, which collapses the repeated tracks no item lands
+    /// in so the filled ones share their space.
+    pub fn auto_fit(mut self, sizes: impl IntoIterator<Item = TrackSize>) -> Self {
+        self.tracks.push(Track::Repeat(
+            TrackRepeat::AutoFit,
+            sizes.into_iter().collect(),
+        ));
+        self
+    }
+
+    /// Number of tracks when the list has no auto repeat.
+    pub fn fixed_track_count(&self) -> Option<usize> {
+        if self.auto_repeat().is_some() {
+            return None;
+        }
+        Some(self.expand(f32::INFINITY, 0.0).0.len())
+    }
+
+    /// Number of tracks the list expands to in a grid `available` pixels wide
+    /// (`f32::INFINITY` when the width is not definite), with `gap` between tracks.
+    pub fn track_count(&self, available: f32, gap: f32) -> usize {
+        let (available, gap) = sanitize(available, gap);
+        self.expand(available, gap).0.len()
+    }
+
+    /// Size the columns of a grid `available` pixels wide (`f32::INFINITY` when the
+    /// width is not definite) holding `items` items, with `gap` between columns.
+    ///
+    /// Items are placed row by row, one cell each. `content` is called with the index of
+    /// an item in a content-sized column and returns its min-content and max-content
+    /// widths. Returns the offset and width of every column; `auto-fit` columns left
+    /// empty have no width and no gap.
+    pub fn resolve(
+        &self,
+        available: f32,
+        gap: f32,
+        items: usize,
+        content: impl FnMut(usize) -> (f32, f32),
+    ) -> Vec<(f32, f32)> {
+        let count = self.track_count(available, gap);
+        let spans: Vec<(usize, usize)> = (0..items).map(|item| (item % count, 1)).collect();
+        self.resolve_spans(available, gap, &spans, content)
+    }
+
+    /// Size the tracks of a grid whose items occupy `spans`: the first track (from 0)
+    /// and the number of tracks of each item, as placed by
+    /// [`place_grid_items`](super::place_grid_items).
+    ///
+    /// Works like [`TrackList::resolve`] along either axis. Tracks past the end of the
+    /// list are `auto`, like the implicit tracks of a CSS grid, and the min-content size
+    /// of an item spanning several tracks is shared between the content-sized ones.
+    pub fn resolve_spans(
+        &self,
+        available: f32,
+        gap: f32,
+        spans: &[(usize, usize)],
+        mut content: impl FnMut(usize) -> (f32, f32),
+    ) -> Vec<(f32, f32)> {
+        let (available, gap) = sanitize(available, gap);
+        let definite = available.is_finite();
+        let (mut tracks, mut auto_fit) = self.expand(available, gap);
+        let end = spans
+            .iter()
+            .map(|&(start, span)| start + span.max(1))
+            .max()
+            .unwrap_or(0);
+        if end > tracks.len() {
+            tracks.resize(end, TrackSize::Breadth(TrackBreadth::Auto));
+            auto_fit.resize(end, false);
+        }
+        let count = tracks.len();
+
+        let mut contents = vec![(0.0_f32, 0.0_f32); count];
+        let mut filled = vec![false; count];
+        let mut spanning = Vec::new();
+        for (item, &(start, span)) in spans.iter().enumerate() {
+            let covered = start.min(count)..(start + span.max(1)).min(count);
+            filled[covered.clone()].fill(true);
+            if covered.len() > 1 {
+                if covered
+                    .clone()
+                    .any(|track| tracks[track].is_intrinsic(available))
+                {
+                    spanning.push((covered, content(item).0));
+                }
+            } else if let Some(track) = covered.clone().next()
+                && tracks[track].is_intrinsic(available)
+            {
+                let (min, max) = content(item);
+                let (track_min, track_max) = &mut contents[track];
+                *track_min = track_min.max(min);
+                *track_max = track_max.max(min).max(max);
+            }
+        }
+        let sized: Vec<bool> = auto_fit
+            .iter()
+            .zip(&filled)
+            .map(|(&auto_fit, &filled)| !auto_fit || filled)
+            .collect();
+
+        // Initialize each track from its content and sizing functions.
+        let mut base = vec![0.0_f32; count];
+        let mut limit = vec![0.0_f32; count];
+        let mut flex = vec![0.0_f32; count];
+        for track in (0..count).filter(|&track| sized[track]) {
+            let (min, max) = tracks[track].bounds();
+            let (content_min, content_max) = contents[track];
+            base[track] = match min {
+                TrackBreadth::MinContent => content_min,
+                TrackBreadth::MaxContent => content_max,
+                min => min.fixed(available).unwrap_or(content_min),
+            };
+            limit[track] = match max {
+                TrackBreadth::Fr(fr) => {
+                    flex[track] = fr;
+                    base[track]
+                }
+                TrackBreadth::MinContent => content_min,
+                TrackBreadth::MaxContent => content_max,
+                max => max.fixed(available).unwrap_or(content_max),
+            }
+            .max(base[track]);
+        }
+        // Share what spanning items need beyond their tracks between the content-sized
+        // ones.
+        for (covered, min) in spanning {
+            let intrinsic: Vec<usize> = covered
+                .clone()
+                .filter(|&track| sized[track] && tracks[track].is_intrinsic(available))
+                .collect();
+            let spanned_gaps = gap * (covered.len() - 1) as f32;
+            let missing = min - spanned_gaps - covered.map(|track| base[track]).sum::<f32>();
+            if missing > 0.0 && !intrinsic.is_empty() {
+                let share = missing / intrinsic.len() as f32;
+                for track in intrinsic {
+                    base[track] += share;
+                    limit[track] = limit[track].max(base[track]);
+                }
+            }
+        }
+        let gaps = gap
+            * sized
+                .iter()
+                .filter(|&&sized| sized)
+                .count()
+                .saturating_sub(1) as f32;
+        let free = |base: &[f32]| available - gaps - base.iter().sum::<f32>();
+
+        // Grow the tracks up to their limits, then give what is left to the `fr` tracks.
+        if definite {
+            let free = free(&base);
+            grow(&mut base, &limit, free, |_| true);
+        } else {
+            base.copy_from_slice(&limit);
+        }
+        if flex.iter().any(|&fr| fr > 0.0) {
+            let fr = if definite {
+                fr_size(&base, &flex, available - gaps)
+            } else {
+                (0..count)
+                    .filter(|&track| flex[track] > 0.0)
+                    .map(|track| base[track].max(contents[track].1) / flex[track].max(1.0))
+                    .fold(0.0, f32::max)
+            };
+            for (base, &flex) in base.iter_mut().zip(&flex) {
+                if flex > 0.0 {
+                    *base = base.max(fr * flex);
+                }
+            }
+        }
+
+        // Stretch `auto` tracks into space still free.
+        if definite {
+            let stretch = |track: usize| {
+                sized[track] && matches!(tracks[track].bounds().1, TrackBreadth::Auto)
+            };
+            let unlimited = vec![f32::INFINITY; count];
+            let free = free(&base);
+            grow(&mut base, &unlimited, free, stretch);
+        }
+
+        let mut offset = 0.0;
+        let mut first = true;
+        let mut sizes = Vec::with_capacity(count);
+        for track in 0..count {
+            if !sized[track] {
+                sizes.push((offset, 0.0));
+                continue;
+            }
+            if !first {
+                offset += gap;
+            }
+            first = false;
+            sizes.push((offset, base[track]));
+            offset += base[track];
+        }
+        sizes
+    }
+
+    /// The first `auto-fill`/`auto-fit` repeat.
+    fn auto_repeat(&self) -> Option<&Track> {
+        self.tracks.iter().find(|track| {
+            matches!(
+                track,
+                Track::Repeat(TrackRepeat::AutoFill | TrackRepeat::AutoFit, _)
+            )
+        })
+    }
+
+    /// Every track with repeats expanded, and whether each comes from `auto-fit`. An
+    /// empty list is a single `auto` track, like the implicit column of a grid without
+    /// a template.
+    fn expand(&self, available: f32, gap: f32) -> (Vec<TrackSize>, Vec<bool>) {
+        let repetitions = self.auto_repetitions(available, gap);
+        let mut auto_repeated = false;
+        let mut tracks = Vec::new();
+        let mut auto_fit = Vec::new();
+
+        for track in &self.tracks {
+            let (times, sizes, fit) = match track {
+                Track::Size(size) => (1, std::slice::from_ref(size), false),
+                Track::Repeat(TrackRepeat::Count(count), sizes) => {
+                    (usize::from(*count), sizes.as_slice(), false)
+                }
+                Track::Repeat(repeat, sizes) => {
+                    let times = if auto_repeated { 1 } else { repetitions };
+                    auto_repeated = true;
+                    (times, sizes.as_slice(), *repeat == TrackRepeat::AutoFit)
+                }
+            };
+            for _ in 0..times {
+                for size in sizes {
+                    if tracks.len() == MAX_TRACKS {
+                        break;
+                    }
+                    tracks.push(*size);
+                    auto_fit.push(fit);
+                }
+            }
+        }
+
+        if tracks.is_empty() {
+            tracks.push(TrackSize::Breadth(TrackBreadth::Auto));
+            auto_fit.push(false);
+        }
+        (tracks, auto_fit)
+    }
+
+    /// How many times the auto repeat fits next to the other tracks: at least once, and
+    /// once when the grid has no definite width.
+    fn auto_repetitions(&self, available: f32, gap: f32) -> usize {
+        let Some(Track::Repeat(_, repeated)) = self.auto_repeat() else {
+            return 1;
+        };
+        if !available.is_finite() || repeated.is_empty() {
+            return 1;
+        }
+
+        let definite = |size: &TrackSize| size.definite(available).unwrap_or(0.0);
+        let mut others = 0.0;
+        let mut other_count = 0_usize;
+        for track in &self.tracks {
+            match track {
+                Track::Size(size) => {
+                    others += definite(size);
+                    other_count += 1;
+                }
+                Track::Repeat(TrackRepeat::Count(count), sizes) => {
+                    others += f32::from(*count) * sizes.iter().map(definite).sum::<f32>();
+                    other_count += usize::from(*count) * sizes.len();
+                }
+                Track::Repeat(..) => {}
+            }
+        }
+
+        let repetition = repeated.iter().map(definite).sum::<f32>() + gap * repeated.len() as f32;
+        if repetition <= 0.0 {
+            return 1;
+        }
+        let space = available - others - gap * (other_count as f32 - 1.0);
+        ((space / repetition).floor().max(1.0) as usize).min(MAX_TRACKS)
+    }
+}
+
+/// Treat a NaN width as not definite and an invalid gap as none.
+fn sanitize(available: f32, gap: f32) -> (f32, f32) {
+    let available = if available.is_nan() {
+        f32::INFINITY
+    } else {
+        available.max(0.0)
+    };
+    (available, if gap.is_finite() { gap.max(0.0) } else { 0.0 })
+}
+
+/// Grow the `eligible` tracks equally by `free` pixels, each up to its `limit`.
+fn grow(base: &mut [f32], limit: &[f32], mut free: f32, eligible: impl Fn(usize) -> bool) {
+    loop {
+        let growable: Vec<usize> = (0..base.len())
+            .filter(|&column| eligible(column) && limit[column] - base[column] > f32::EPSILON)
+            .collect();
+        if growable.is_empty() || free <= f32::EPSILON {
+            return;
+        }
+        let share = free / growable.len() as f32;
+        for column in growable {
+            let grown = share.min(limit[column] - base[column]);
+            base[column] += grown;
+            free -= grown;
+        }
+    }
+}
+
+/// Size of `1fr` when `space` is shared: tracks whose base already exceeds their share
+/// keep it and leave the rest to the others.
+fn fr_size(base: &[f32], flex: &[f32], space: f32) -> f32 {
+    let mut inflexible = vec![false; base.len()];
+    loop {
+        let mut leftover = space;
+        let mut factors = 0.0;
+        for column in 0..base.len() {
+            if flex[column] > 0.0 && !inflexible[column] {
+                factors += flex[column];
+            } else {
+                leftover -= base[column];
+            }
+        }
+        let fr = leftover.max(0.0) / factors.max(1.0);
+
+        let mut changed = false;
+        for column in 0..base.len() {
+            if flex[column] > 0.0 && !inflexible[column] && fr * flex[column] < base[column] {
+                inflexible[column] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            return fr;
+        }
+    }
+}
+
+impl fmt::Display for TrackList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, track) in self.tracks.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            track.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TrackList {
+    type Err = ParseTrackListError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+/// Error returned by [`TrackList::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseTrackListError {
+    /// The value has no tracks.
+    Empty,
+    /// A track is not a supported track size or `repeat()`.
+    InvalidTrack(String),
+    /// The list has more than one `repeat(auto-fill, ...)` or `repeat(auto-fit, ...)`.
+    MultipleAutoRepeats,
+}
+
+impl fmt::Display for ParseTrackListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("track list is empty"),
+            Self::InvalidTrack(track) => write!(f, "invalid grid track `{track}`"),
+            Self::MultipleAutoRepeats => {
+                f.write_str("track list repeats `auto-fill`/`auto-fit` more than once")
+            }
+        }
+    }
+}
+
+impl Error for ParseTrackListError {}
+
+fn invalid(token: &str) -> ParseTrackListError {
+    ParseTrackListError::InvalidTrack(token.to_owned())
+}
+
+/// Split `value` at the characters matching `separator` outside parentheses, dropping
+/// empty parts.
+fn split_top_level(value: &str, separator: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ch if depth == 0 && separator(ch) => {
+                parts.push(value[start..index].trim());
+                start = index + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(value[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// The arguments of `name(...)` when `token` is a call to it.
+fn function_args<'a>(token: &'a str, name: &str) -> Option<&'a str> {
+    token
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+fn repeat(token: &str, args: &str) -> Result<Track, ParseTrackListError> {
+    let (count, sizes) = args.split_once(',').ok_or_else(|| invalid(token))?;
+    let repeat = match count.trim() {
+        "auto-fill" => TrackRepeat::AutoFill,
+        "auto-fit" => TrackRepeat::AutoFit,
+        count => match count.parse::<u16>() {
+            Ok(count) if count > 0 => TrackRepeat::Count(count),
+            _ => return Err(invalid(token)),
+        },
+    };
+    let sizes = split_top_level(sizes, char::is_whitespace)
+        .into_iter()
+        .map(track_size)
+        .collect::<Result<Vec<_>, _>>()?;
+    let auto = !matches!(repeat, TrackRepeat::Count(_));
+    // Auto repeats need a fixed size to count how many fit.
+    if sizes.is_empty() || auto && sizes.iter().any(|size| size.definite(0.0).is_none()) {
+        return Err(invalid(token));
+    }
+    Ok(Track::Repeat(repeat, sizes))
+}
+
+fn track_size(token: &str) -> Result<TrackSize, ParseTrackListError> {
+    let Some(args) = function_args(token, "minmax") else {
+        return breadth(token).map(TrackSize::Breadth);
+    };
+    match split_top_level(args, |ch| ch == ',').as_slice() {
+        [min, max] => match (breadth(min)?, breadth(max)?) {
+            (TrackBreadth::Fr(_), _) => Err(invalid(token)),
+            (min, max) => Ok(TrackSize::MinMax(min, max)),
+        },
+        _ => Err(invalid(token)),
+    }
+}
+
+fn breadth(token: &str) -> Result<TrackBreadth, ParseTrackListError> {
+    let number = |raw: &str| {
+        raw.parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite() && *value >= 0.0)
+            .ok_or_else(|| invalid(token))
+    };
+    match token {
+        "auto" => Ok(TrackBreadth::Auto),
+        "min-content" => Ok(TrackBreadth::MinContent),
+        "max-content" => Ok(TrackBreadth::MaxContent),
+        "0" => Ok(TrackBreadth::Px(0.0)),
+        _ => {
+            if let Some(px) = token.strip_suffix("px") {
+                number(px).map(TrackBreadth::Px)
+            } else if let Some(rem) = token.strip_suffix("rem") {
+                number(rem).map(|rem| TrackBreadth::Px(rem * REM_PX))
+            } else if let Some(percent) = token.strip_suffix('%') {
+                number(percent).map(TrackBreadth::Percent)
+            } else if let Some(fr) = token.strip_suffix("fr") {
+                number(fr).map(TrackBreadth::Fr)
+            } else {
+                Err(invalid(token))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths(columns: &[(f32, f32)]) -> Vec<f32> {
+        columns.iter().map(|&(_, width)| width).collect()
+    }
+
+    #[test]
+    fn test_track_lists_parse_and_print_css() {
+        let list = TrackList::parse("240px_minmax(0,_1fr) auto 1.5rem").unwrap();
+        assert_eq!(
+            list,
+            TrackList::new()
+                .px(240.0)
+                .minmax(TrackBreadth::Px(0.0), TrackBreadth::Fr(1.0))
+                .auto()
+                .px(24.0)
+        );
+        assert_eq!(list.to_string(), "240px minmax(0, 1fr) auto 24px");
+        assert_eq!(list.fixed_track_count(), Some(4));
+
+        let cards: TrackList = "repeat(auto-fit, minmax(12rem, 1fr)) max-content"
+            .parse()
+            .unwrap();
+        assert_eq!(cards.fixed_track_count(), None);
+        assert_eq!(
+            cards.to_string(),
+            "repeat(auto-fit, minmax(192px, 1fr)) max-content"
+        );
+        assert_eq!(TrackList::equal(3).fixed_track_count(), Some(3));
+
+        assert_eq!(TrackList::parse("  "), Err(ParseTrackListError::Empty));
+        assert_eq!(
+            TrackList::parse("1fr 2em"),
+            Err(ParseTrackListError::InvalidTrack("2em".to_owned()))
+        );
+        assert_eq!(
+            TrackList::parse("minmax(1fr, 200px)"),
+            Err(ParseTrackListError::InvalidTrack(
+                "minmax(1fr, 200px)".to_owned()
+            ))
+        );
+        // Auto repeats need a fixed size to count how many fit.
+        assert!(TrackList::parse("repeat(auto-fill, 1fr)").is_err());
+        assert_eq!(
+            TrackList::parse("repeat(auto-fill, 100px) repeat(auto-fit, 50px)"),
+            Err(ParseTrackListError::MultipleAutoRepeats)
+        );
+    }
+
+    #[test]
+    fn test_resolve_shares_free_space_between_fr_tracks() {
+        let content = |_| (50.0, 80.0);
+
+        let sidebar = TrackList::parse("240px 1fr auto").unwrap();
+        assert_eq!(
+            sidebar.resolve(600.0, 10.0, 3, content),
+            vec![(0.0, 240.0), (250.0, 260.0), (520.0, 80.0)]
+        );
+
+        // A bare `fr` track keeps its min-content width, `minmax(0, 1fr)` does not.
+        let wide = |item| {
+            if item == 0 {
+                (300.0, 300.0)
+            } else {
+                (0.0, 0.0)
+            }
+        };
+        assert_eq!(
+            widths(
+                &TrackList::parse("1fr 1fr")
+                    .unwrap()
+                    .resolve(400.0, 0.0, 2, wide)
+            ),
+            vec![300.0, 100.0]
+        );
+        assert_eq!(
+            widths(&TrackList::equal(2).resolve(400.0, 0.0, 2, wide)),
+            vec![200.0, 200.0]
+        );
+
+        // `auto` tracks stretch when no `fr` track takes the space.
+        let stretched = TrackList::parse("100px auto 25%").unwrap();
+        assert_eq!(
+            widths(&stretched.resolve(400.0, 0.0, 3, content)),
+            vec![100.0, 200.0, 100.0]
+        );
+
+        // Without a definite width, `fr` tracks are as wide as their widest content.
+        assert_eq!(
+            widths(
+                &TrackList::parse("1fr 2fr")
+                    .unwrap()
+                    .resolve(f32::INFINITY, 0.0, 2, content)
+            ),
+            vec![80.0, 160.0]
+        );
+    }
+
+    #[test]
+    fn test_resolve_repeats_auto_fill_and_collapses_auto_fit() {
+        let fill = TrackList::parse("repeat(auto-fill, minmax(100px, 1fr))").unwrap();
+        assert_eq!(
+            widths(&fill.resolve(350.0, 10.0, 2, |_| (0.0, 0.0))),
+            vec![110.0; 3]
+        );
+
+        let fit = TrackList::parse("repeat(auto-fit, minmax(100px, 1fr))").unwrap();
+        assert_eq!(
+            fit.resolve(350.0, 10.0, 2, |_| (0.0, 0.0)),
+            vec![(0.0, 170.0), (180.0, 170.0), (350.0, 0.0)]
+        );
+
+        // Never fewer than one repetition, even when a track is wider than the grid.
+        assert_eq!(
+            widths(&fill.resolve(50.0, 10.0, 1, |_| (0.0, 0.0))),
+            vec![100.0]
+        );
+        assert_eq!(
+            widths(&TrackList::new().resolve(50.0, 10.0, 1, |_| (20.0, 30.0))),
+            vec![50.0]
+        );
+    }
+
+    #[test]
+    fn test_resolve_spans_adds_implicit_tracks_and_shares_spanning_content() {
+        let list = TrackList::parse("100px auto").unwrap();
+
+        // The spanning item needs 300px: the fixed track keeps 100, `auto` takes the rest.
+        let spans = [(0, 2), (2, 1)];
+        let content = |item| {
+            if item == 0 {
+                (300.0, 300.0)
+            } else {
+                (40.0, 60.0)
+            }
+        };
+        assert_eq!(
+            list.resolve_spans(f32::INFINITY, 0.0, &spans, content),
+            vec![(0.0, 100.0), (100.0, 200.0), (300.0, 60.0)]
+        );
+        assert_eq!(list.track_count(f32::INFINITY, 0.0), 2);
+    }
+}
@@ -0,0 +1,257 @@
+//! 2D transform utilities: `translate-*`, `rotate-*`, `scale-*`, `skew-*` and `origin-*`.
+
+use super::InsetValue;
+
+/// The 2D transform functions of an element.
+///
+/// Translations take spacing tokens, fractions of the element's own size or pixels
+/// (the same values as [`InsetValue`]); `scale` is in percent (`scale-105` is `105`)
+/// and `rotate`/`skew` are in degrees. Like Tailwind, each function is set by its own
+/// utility, so a state layer can change one of them and keep the rest. Transforms move
+/// the painted element and its content without changing the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Transform {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) translate_x: Option<InsetValue>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) translate_y: Option<InsetValue>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) rotate: Option<i16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) scale_x: Option<i16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) scale_y: Option<i16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) skew_x: Option<i16>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) skew_y: Option<i16>,
+}
+
+impl Transform {
+    /// `transform-none`: every function set to its identity value, so it also resets the
+    /// functions of the layers below.
+    pub const NONE: Self = Self {
+        translate_x: Some(InsetValue::Px(0.0)),
+        translate_y: Some(InsetValue::Px(0.0)),
+        rotate: Some(0),
+        scale_x: Some(100),
+        scale_y: Some(100),
+        skew_x: Some(0),
+        skew_y: Some(0),
+    };
+
+    pub const fn new() -> Self {
+        Self {
+            translate_x: None,
+            translate_y: None,
+            rotate: None,
+            scale_x: None,
+            scale_y: None,
+            skew_x: None,
+            skew_y: None,
+        }
+    }
+
+    pub const fn translate_x(&self) -> Option<InsetValue> {
+        self.translate_x
+    }
+
+    pub const fn translate_y(&self) -> Option<InsetValue> {
+        self.translate_y
+    }
+
+    pub const fn rotate(&self) -> Option<i16> {
+        self.rotate
+    }
+
+    pub const fn scale_x(&self) -> Option<i16> {
+        self.scale_x
+    }
+
+    pub const fn scale_y(&self) -> Option<i16> {
+        self.scale_y
+    }
+
+    pub const fn skew_x(&self) -> Option<i16> {
+        self.skew_x
+    }
+
+    pub const fn skew_y(&self) -> Option<i16> {
+        self.skew_y
+    }
+
+    /// Translation in px of an element `width` by `height` large; fractions are of the
+    /// element's own size.
+    pub fn translation(&self, width: f32, height: f32) -> (f32, f32) {
+        let offset = |value: Option<InsetValue>, size: f32| {
+            value.and_then(|value| value.to_px(size)).unwrap_or(0.0)
+        };
+        (
+            offset(self.translate_x, width),
+            offset(self.translate_y, height),
+        )
+    }
+
+    /// Scale factors on each axis, `1.0` when unset.
+    pub fn scale(&self) -> (f32, f32) {
+        let factor =
+            |percent: Option<i16>| percent.map_or(1.0, |percent| f32::from(percent) / 100.0);
+        (factor(self.scale_x), factor(self.scale_y))
+    }
+
+    /// Whether only translations and one scale factor shared by both axes are set, so the
+    /// transform keeps right angles and aspect ratios.
+    pub fn is_translate_and_scale(&self) -> bool {
+        let (scale_x, scale_y) = self.scale();
+        self.rotate.is_none_or(|degrees| degrees % 360 == 0)
+            && self.skew_x.is_none_or(|degrees| degrees % 180 == 0)
+            && self.skew_y.is_none_or(|degrees| degrees % 180 == 0)
+            && scale_x == scale_y
+    }
+
+    /// The affine matrix of the transform for an element at `x`, `y` and `width` by
+    /// `height` large, in CSS `matrix(a, b, c, d, e, f)` order: a point maps to
+    /// `(a * x + c * y + e, b * x + d * y + f)`.
+    ///
+    /// Functions apply around `origin` in the order of Tailwind's properties:
+    /// `translate`, `rotate`, `scale`, then `skew-x` and `skew-y`.
+    pub fn to_matrix(
+        &self,
+        origin: TransformOrigin,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) -> [f32; 6] {
+        let (origin_x, origin_y) = origin.point(width, height);
+        let (origin_x, origin_y) = (x + origin_x, y + origin_y);
+        let (translate_x, translate_y) = self.translation(width, height);
+        let (sin, cos) = f32::from(self.rotate.unwrap_or(0)).to_radians().sin_cos();
+        let (scale_x, scale_y) = self.scale();
+        let skew = |degrees: Option<i16>| f32::from(degrees.unwrap_or(0)).to_radians().tan();
+
+        [
+            [
+                1.0,
+                0.0,
+                0.0,
+                1.0,
+                origin_x + translate_x,
+                origin_y + translate_y,
+            ],
+            [cos, sin, -sin, cos, 0.0, 0.0],
+            [scale_x, 0.0, 0.0, scale_y, 0.0, 0.0],
+            [1.0, 0.0, skew(self.skew_x), 1.0, 0.0, 0.0],
+            [1.0, skew(self.skew_y), 0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 1.0, -origin_x, -origin_y],
+        ]
+        .into_iter()
+        .reduce(multiply)
+        .expect("the list is not empty")
+    }
+}
+
+/// `m * n` for matrices in CSS `matrix(a, b, c, d, e, f)` order.
+fn multiply(m: [f32; 6], n: [f32; 6]) -> [f32; 6] {
+    [
+        m[0] * n[0] + m[2] * n[1],
+        m[1] * n[0] + m[3] * n[1],
+        m[0] * n[2] + m[2] * n[3],
+        m[1] * n[2] + m[3] * n[3],
+        m[0] * n[4] + m[2] * n[5] + m[4],
+        m[1] * n[4] + m[3] * n[5] + m[5],
+    ]
+}
+
+/// The point transforms apply around (`origin-*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransformOrigin {
+    #[default]
+    Center,
+    Top,
+    TopRight,
+    Right,
+    BottomRight,
+    Bottom,
+    BottomLeft,
+    Left,
+    TopLeft,
+    /// `origin-[<x>%_<y>%]`: percentages of the element's width and height.
+    Percent(i16, i16),
+}
+
+impl TransformOrigin {
+    /// The origin in px from the top-left corner of an element `width` by `height` large.
+    pub fn point(&self, width: f32, height: f32) -> (f32, f32) {
+        let (x, y) = match self {
+            Self::Center => (50, 50),
+            Self::Top => (50, 0),
+            Self::TopRight => (100, 0),
+            Self::Right => (100, 50),
+            Self::BottomRight => (100, 100),
+            Self::Bottom => (50, 100),
+            Self::BottomLeft => (0, 100),
+            Self::Left => (0, 50),
+            Self::TopLeft => (0, 0),
+            Self::Percent(x, y) => (*x, *y),
+        };
+        (f32::from(x) / 100.0 * width, f32::from(y) / 100.0 * height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::{Percentage, Spacing};
+
+    fn apply([a, b, c, d, e, f]: [f32; 6], (x, y): (f32, f32)) -> (f32, f32) {
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+
+    fn close((x, y): (f32, f32), (expected_x, expected_y): (f32, f32)) -> bool {
+        (x - expected_x).abs() < 1e-3 && (y - expected_y).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_transform_matrix_applies_around_the_origin() {
+        assert!(Transform::new().is_translate_and_scale());
+        assert!(close(
+            apply(
+                Transform::NONE.to_matrix(TransformOrigin::Center, 10.0, 10.0, 100.0, 40.0),
+                (10.0, 10.0)
+            ),
+            (10.0, 10.0)
+        ));
+
+        let mut pressed = Transform::new();
+        pressed.scale_x = Some(50);
+        pressed.scale_y = Some(50);
+        let matrix = pressed.to_matrix(TransformOrigin::Center, 0.0, 0.0, 100.0, 40.0);
+        assert!(close(apply(matrix, (0.0, 0.0)), (25.0, 10.0)));
+        assert!(close(apply(matrix, (50.0, 20.0)), (50.0, 20.0)));
+        let matrix = pressed.to_matrix(TransformOrigin::TopLeft, 0.0, 0.0, 100.0, 40.0);
+        assert!(close(apply(matrix, (100.0, 40.0)), (50.0, 20.0)));
+
+        let mut centered = Transform::new();
+        centered.translate_x = Some(InsetValue::NegativeFraction(Percentage::S1_2));
+        centered.translate_y = Some(InsetValue::Scale(Spacing::S2));
+        assert_eq!(centered.translation(100.0, 40.0), (-50.0, 8.0));
+
+        let mut turned = Transform::new();
+        turned.rotate = Some(90);
+        assert!(!turned.is_translate_and_scale());
+        let matrix = turned.to_matrix(TransformOrigin::Center, 0.0, 0.0, 40.0, 40.0);
+        // Clockwise on screen, where y points down.
+        assert!(close(apply(matrix, (0.0, 0.0)), (40.0, 0.0)));
+
+        let mut slanted = Transform::new();
+        slanted.skew_x = Some(45);
+        let matrix = slanted.to_matrix(TransformOrigin::TopLeft, 0.0, 0.0, 40.0, 40.0);
+        assert!(close(apply(matrix, (0.0, 40.0)), (40.0, 40.0)));
+    }
+}
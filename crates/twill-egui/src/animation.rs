@@ -0,0 +1,110 @@
+//! Keyframe animations for styles painted every egui pass.
+
+use std::time::Duration;
+
+use twill_core::style::{Keyframes, Style};
+
+/// Animation started under an id, stored in egui's temporary memory.
+#[derive(Clone)]
+struct Running {
+    keyframes: Keyframes,
+    start: f64,
+}
+
+/// `style` with the animation of its `animate-*` token merged on top.
+///
+/// The animation starts the first pass `style` is shown under `id` with that token and
+/// restarts when the token changes. [`styled_frame`](crate::styled_frame) calls this
+/// itself, so spinners and skeleton pulses only need the token:
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let skeleton = Style::new()
+///     .h(Spacing::S4)
+///     .bg(Color::slate(Scale::S200))
+///     .animate(AnimationToken::Pulse);
+///
+/// fn show_skeleton(ui: &mut egui::Ui, style: &Style) {
+///     let style = twill_egui::animated_style(ui.ctx(), ui.id().with("skeleton"), style);
+///     twill_egui::show_frame(ui, &style, |ui| ui.set_min_width(120.0));
+/// }
+/// # let _ = (skeleton, show_skeleton);
+/// ```
+pub fn animated_style(ctx: &egui::Context, id: egui::Id, style: &Style) -> Style {
+    match style
+        .animation_value()
+        .and_then(|animation| animation.keyframes())
+    {
+        Some(keyframes) => animate_keyframes(ctx, id, &keyframes, style),
+        None => {
+            ctx.data_mut(|data| data.remove::<Running>(id));
+            style.clone()
+        }
+    }
+}
+
+/// `style` with `keyframes` merged on top, sampled at the time of the current pass.
+///
+/// The animation starts the first pass it is shown under `id` and restarts when
+/// `keyframes` change. A repaint is requested while it runs.
+pub fn animate_keyframes(
+    ctx: &egui::Context,
+    id: egui::Id,
+    keyframes: &Keyframes,
+    style: &Style,
+) -> Style {
+    let now = ctx.input(|input| input.time);
+    let start = ctx.data_mut(|data| {
+        let running = data.get_temp_mut_or_insert_with(id, || Running {
+            keyframes: keyframes.clone(),
+            start: now,
+        });
+        if running.keyframes != *keyframes {
+            *running = Running {
+                keyframes: keyframes.clone(),
+                start: now,
+            };
+        }
+        running.start
+    });
+
+    let elapsed = Duration::from_secs_f64((now - start).max(0.0));
+    if keyframes.is_running(elapsed) {
+        ctx.request_repaint();
+    }
+    style.clone().merged(keyframes.sample(elapsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::AnimationToken;
+
+    fn style_at(ctx: &egui::Context, time: f64, style: &Style) -> Style {
+        let mut animated = Style::new();
+        let _ = ctx.run(
+            egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            },
+            |ctx| animated = animated_style(ctx, egui::Id::new("spinner"), style),
+        );
+        animated
+    }
+
+    #[test]
+    fn test_animated_style_starts_when_first_shown() {
+        let ctx = egui::Context::default();
+        let spinner = Style::new().animate(AnimationToken::Spin);
+        let rotation = |style: Style| style.transform_value().and_then(|t| t.rotate());
+
+        assert_eq!(rotation(style_at(&ctx, 10.0, &spinner)), Some(0));
+        assert_eq!(rotation(style_at(&ctx, 10.25, &spinner)), Some(90));
+        assert!(ctx.has_requested_repaint());
+
+        // Dropping the token forgets the start, so the animation plays from the top again.
+        assert_eq!(style_at(&ctx, 10.5, &Style::new()), Style::new());
+        assert_eq!(rotation(style_at(&ctx, 11.0, &spinner)), Some(0));
+    }
+}
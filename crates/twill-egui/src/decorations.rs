@@ -0,0 +1,700 @@
+//! Blurred background, background gradient, ring, outline, inset shadow and per-side
+//! border painting for frames built from a [`Style`].
+
+use egui::epaint::tessellator::path;
+use egui::epaint::{CornerRadiusF32, Mesh, RectShape};
+use egui::{Color32, CornerRadius, Pos2, Rect, Shape, Stroke, StrokeKind};
+use twill_core::style::Style;
+use twill_core::tokens::{ColorValue, InsetShadow, OutlineStyle, ResolvedGradient, RingWidth};
+
+use crate::transform::{next_shape_idx, transform_shapes};
+use crate::{
+    apply_filter_to_color32, apply_opacity_to_color_value, apply_opacity_to_color32,
+    background_blur_width, resolve_background_color_token, resolve_border_color_token,
+    resolve_outline_color_token, resolve_ring_color_token, resolve_text_color_token,
+    resolved_background, resolved_filter, resolved_opacity, to_color32_value, to_frame,
+};
+
+/// Blurred background, background gradient, ring, outline, inset shadow and unequal
+/// border sides of a style, with colors resolved.
+///
+/// None of them take layout space: the ring and outline are painted outside the frame,
+/// and the gradient and inset shadow inside its border. Unset colors fall back to the
+/// text color, like `currentColor` in CSS, and every color goes through the color
+/// filters of the style.
+#[derive(Clone, PartialEq)]
+struct Decorations {
+    blurred_background: Option<BlurredBackground>,
+    gradient: Option<ResolvedGradient>,
+    ring: Option<Ring>,
+    outline: Option<Outline>,
+    inset_shadow: Option<InsetShadowLayer>,
+    border_sides: Option<BorderSides>,
+}
+
+/// A background color under `blur-*`, with the width of its blurred edge.
+#[derive(Clone, Copy, PartialEq)]
+struct BlurredBackground {
+    color: Color32,
+    blur_width: f32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Ring {
+    width: f32,
+    inset: bool,
+    color: Color32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Outline {
+    width: f32,
+    style: OutlineStyle,
+    color: Color32,
+}
+
+/// Border whose sides differ in width, which `egui::Frame` cannot stroke itself.
+#[derive(Clone, Copy, PartialEq)]
+struct BorderSides {
+    /// `[top, right, bottom, left]` in points.
+    widths: [f32; 4],
+    color: Color32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct InsetShadowLayer {
+    offset_y: f32,
+    blur: f32,
+    color: Color32,
+}
+
+impl Decorations {
+    fn new(style: &Style, current_color: Color32) -> Self {
+        let opacity = resolved_opacity(style);
+        let filter = resolved_filter(style);
+        let current_color = style
+            .text_color_token_value()
+            .and_then(resolve_text_color_token)
+            .map_or(current_color, to_color32_value);
+        let [r, g, b, a] = current_color.to_srgba_unmultiplied();
+        let current_value = ColorValue::from_rgb(r, g, b).with_alpha(f32::from(a) / 255.0);
+        let gradient = style.background_gradient_value().and_then(|gradient| {
+            gradient
+                .resolve(|color| resolve_background_color_token(color, Some(current_value)))
+                .map(|gradient| {
+                    gradient.map_colors(|color| {
+                        apply_opacity_to_color_value(filter.apply(color), opacity)
+                    })
+                })
+        });
+        let color = |resolved: Option<Color32>| {
+            apply_opacity_to_color32(
+                apply_filter_to_color32(resolved.unwrap_or(current_color), filter),
+                opacity,
+            )
+        };
+        let blurred_background = background_blur_width(style).and_then(|blur_width| {
+            resolved_background(style).map(|background| BlurredBackground {
+                color: to_color32_value(apply_opacity_to_color_value(background, opacity)),
+                blur_width,
+            })
+        });
+
+        let ring = style.ring_width_value().and_then(|width| {
+            let (width, inset) = match width {
+                RingWidth::Inset => (1.0, true),
+                width => (f32::from(width.px_value().unwrap_or(0)), false),
+            };
+            (width > 0.0).then(|| Ring {
+                width,
+                inset,
+                color: color(
+                    style
+                        .ring_color_token_value()
+                        .and_then(resolve_ring_color_token)
+                        .map(to_color32_value),
+                ),
+            })
+        });
+
+        // `outline-<style>` alone keeps Tailwind's 1px default width, and `outline-<n>`
+        // alone draws solid.
+        let outline = match (style.outline_width_value(), style.outline_style_value()) {
+            (None, None) => None,
+            (width, outline_style) => {
+                let width = f32::from(width.map_or(1, |width| width.px_value()));
+                let outline_style = outline_style.unwrap_or(OutlineStyle::Solid);
+                let visible = !matches!(outline_style, OutlineStyle::None | OutlineStyle::Hidden);
+                (width > 0.0 && visible).then(|| Outline {
+                    width,
+                    style: outline_style,
+                    color: color(
+                        style
+                            .outline_color_token_value()
+                            .and_then(resolve_outline_color_token)
+                            .map(to_color32_value),
+                    ),
+                })
+            }
+        };
+
+        let inset_shadow = style.inset_shadow_value().and_then(|shadow| {
+            let (offset_y, blur, alpha) = match shadow {
+                InsetShadow::None => return None,
+                InsetShadow::Xs2 => (1.0, 0.0, 0.05),
+                InsetShadow::Xs => (1.0, 1.0, 0.05),
+                InsetShadow::Sm => (2.0, 4.0, 0.05),
+            };
+            Some(InsetShadowLayer {
+                offset_y,
+                blur,
+                color: color(Some(Color32::BLACK.gamma_multiply(alpha))),
+            })
+        });
+
+        let widths = style.resolved_border_widths();
+        let border_sides = style
+            .border_color_token_value()
+            .and_then(resolve_border_color_token)
+            .filter(|_| !widths.is_uniform())
+            .map(|color| BorderSides {
+                widths: widths.to_px(),
+                color: apply_opacity_to_color32(
+                    apply_filter_to_color32(to_color32_value(color), filter),
+                    opacity,
+                ),
+            });
+
+        Self {
+            blurred_background,
+            gradient,
+            ring,
+            outline,
+            inset_shadow,
+            border_sides,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.blurred_background.is_none()
+            && self.gradient.is_none()
+            && self.ring.is_none()
+            && self.outline.is_none()
+            && self.inset_shadow.is_none()
+            && self.border_sides.is_none()
+    }
+
+    /// Shapes painted between the frame fill and its content: the blurred background,
+    /// the gradient, unequal border sides, the ring, the inset shadow and an inset ring.
+    fn under(&self, frame: &egui::Frame, rect: Rect) -> Vec<Shape> {
+        let radius = frame.corner_radius;
+        let [top, right, bottom, left] = self
+            .border_sides
+            .map_or([frame.stroke.width; 4], |sides| sides.widths);
+        let padding_box = Rect::from_min_max(
+            rect.min + egui::vec2(left, top),
+            rect.max - egui::vec2(right, bottom),
+        );
+        let padding_radius = shrink_radius(radius, top.max(right).max(bottom).max(left));
+        let mut shapes = Vec::new();
+
+        if let Some(background) = self.blurred_background {
+            shapes.push(Shape::Rect(
+                RectShape::filled(rect, radius, background.color)
+                    .with_blur_width(background.blur_width),
+            ));
+        }
+        if let Some(gradient) = &self.gradient {
+            shapes.push(Shape::mesh(gradient_mesh(
+                padding_box,
+                padding_radius,
+                gradient,
+            )));
+        }
+        if let Some(sides) = self.border_sides {
+            // Straight strokes along the middle of each side's band.
+            let lines = [
+                (
+                    top,
+                    [rect.left_top(), rect.right_top()],
+                    egui::vec2(0.0, 1.0),
+                ),
+                (
+                    right,
+                    [rect.right_top(), rect.right_bottom()],
+                    egui::vec2(-1.0, 0.0),
+                ),
+                (
+                    bottom,
+                    [rect.left_bottom(), rect.right_bottom()],
+                    egui::vec2(0.0, -1.0),
+                ),
+                (
+                    left,
+                    [rect.left_top(), rect.left_bottom()],
+                    egui::vec2(1.0, 0.0),
+                ),
+            ];
+            for (width, [from, to], inward) in lines {
+                if width > 0.0 {
+                    let offset = inward * (width / 2.0);
+                    shapes.push(Shape::line_segment(
+                        [from + offset, to + offset],
+                        Stroke::new(width, sides.color),
+                    ));
+                }
+            }
+        }
+
+        if let Some(ring) = self.ring.filter(|ring| !ring.inset) {
+            shapes.push(stroke_rect(
+                rect.expand(ring.width),
+                grow_radius(radius, ring.width),
+                Stroke::new(ring.width, ring.color),
+            ));
+        }
+        if let Some(shadow) = self.inset_shadow {
+            shapes.push(Shape::mesh(inset_shadow_mesh(
+                padding_box,
+                padding_radius,
+                shadow,
+            )));
+        }
+        if let Some(ring) = self.ring.filter(|ring| ring.inset) {
+            shapes.push(stroke_rect(
+                padding_box,
+                padding_radius,
+                Stroke::new(ring.width, ring.color),
+            ));
+        }
+
+        shapes
+    }
+
+    /// Shapes painted above the frame content: the outline.
+    fn over(&self, frame: &egui::Frame, rect: Rect) -> Vec<Shape> {
+        let Some(outline) = self.outline else {
+            return Vec::new();
+        };
+        let radius = frame.corner_radius;
+        let width = outline.width;
+
+        match outline.style {
+            OutlineStyle::Double if width >= 3.0 => {
+                let line = width / 3.0;
+                let stroke = Stroke::new(line, outline.color);
+                vec![
+                    stroke_rect(rect.expand(width), grow_radius(radius, width), stroke),
+                    stroke_rect(rect.expand(line), grow_radius(radius, line), stroke),
+                ]
+            }
+            OutlineStyle::Dashed | OutlineStyle::Dotted => {
+                // Trace the middle of the outline band and dash it like a dashed border.
+                let mut points = Vec::new();
+                path::rounded_rectangle(
+                    &mut points,
+                    rect.expand(width / 2.0),
+                    CornerRadiusF32::from(grow_radius(radius, width / 2.0)),
+                );
+                if let Some(first) = points.first().copied() {
+                    points.push(first);
+                }
+                if outline.style == OutlineStyle::Dashed {
+                    Shape::dashed_line(&points, Stroke::new(width, outline.color), 8.0, 5.0)
+                } else {
+                    Shape::dotted_line(&points, outline.color, width + 5.0, width / 2.0)
+                }
+            }
+            _ => vec![stroke_rect(
+                rect.expand(width),
+                grow_radius(radius, width),
+                Stroke::new(width, outline.color),
+            )],
+        }
+    }
+}
+
+fn stroke_rect(rect: Rect, radius: CornerRadius, stroke: Stroke) -> Shape {
+    Shape::Rect(RectShape::stroke(rect, radius, stroke, StrokeKind::Inside))
+}
+
+/// Corners of an edge drawn `spread` outside `radius`; square corners stay square.
+fn grow_radius(radius: CornerRadius, spread: f32) -> CornerRadius {
+    let grow = |corner: u8| {
+        if corner > 0 {
+            (f32::from(corner) + spread).round().min(f32::from(u8::MAX)) as u8
+        } else {
+            0
+        }
+    };
+    CornerRadius {
+        nw: grow(radius.nw),
+        ne: grow(radius.ne),
+        sw: grow(radius.sw),
+        se: grow(radius.se),
+    }
+}
+
+fn shrink_radius(radius: CornerRadius, inset: f32) -> CornerRadius {
+    let shrink = |corner: u8| (f32::from(corner) - inset).max(0.0).round() as u8;
+    CornerRadius {
+        nw: shrink(radius.nw),
+        ne: shrink(radius.ne),
+        sw: shrink(radius.sw),
+        se: shrink(radius.se),
+    }
+}
+
+/// Inward offset of a corner of `radius` at `y` from the edge it rounds.
+fn corner_inset(radius: u8, y: f32) -> f32 {
+    let radius = f32::from(radius);
+    if y < radius {
+        radius - (radius * radius - (radius - y) * (radius - y)).sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// `gradient` over `rect` as a grid of vertex colors, clipped to its rounded corners.
+///
+/// Rows are a point apart where corners curve and about 8 points apart elsewhere, and
+/// columns about 8 points apart; egui blends between the vertices.
+fn gradient_mesh(rect: Rect, radius: CornerRadius, gradient: &ResolvedGradient) -> Mesh {
+    let mut mesh = Mesh::default();
+    let (width, height) = (rect.width(), rect.height());
+    if width <= 0.0 || height <= 0.0 {
+        return mesh;
+    }
+
+    let top_corners = f32::from(radius.nw.max(radius.ne)).min(height / 2.0);
+    let bottom_corners = f32::from(radius.sw.max(radius.se)).min(height / 2.0);
+    let mut rows = vec![0.0];
+    let mut y = 0.0;
+    while y < height {
+        let step = if y < top_corners || y >= height - bottom_corners {
+            1.0
+        } else {
+            8.0_f32.min(height - bottom_corners - y)
+        };
+        y = (y + step.max(1.0)).min(height);
+        rows.push(y);
+    }
+    let columns = ((width / 8.0).ceil() as u32).clamp(1, 96);
+
+    for (row, y) in rows.iter().copied().enumerate() {
+        let (left, right) = if y < height / 2.0 {
+            (corner_inset(radius.nw, y), corner_inset(radius.ne, y))
+        } else {
+            (
+                corner_inset(radius.sw, height - y),
+                corner_inset(radius.se, height - y),
+            )
+        };
+        for column in 0..=columns {
+            let x = left + (width - left - right) * column as f32 / columns as f32;
+            let offset = gradient.offset_at([x, y], width, height);
+            mesh.colored_vertex(
+                rect.min + egui::vec2(x, y),
+                to_color32_value(gradient.color_at(offset)),
+            );
+        }
+        if row > 0 {
+            let stride = columns + 1;
+            let (above, below) = ((row as u32 - 1) * stride, row as u32 * stride);
+            for column in 0..columns {
+                mesh.add_triangle(above + column, above + column + 1, below + column);
+                mesh.add_triangle(above + column + 1, below + column + 1, below + column);
+            }
+        }
+    }
+
+    mesh
+}
+
+/// A vertical gradient along the top edge of `rect`, clipped to its rounded corners.
+///
+/// This is the shadow of a box shifted down by `offset_y` and blurred over `blur`:
+/// fully shaded down to `offset_y - blur / 2`, fading out by `offset_y + blur / 2`.
+fn inset_shadow_mesh(rect: Rect, radius: CornerRadius, shadow: InsetShadowLayer) -> Mesh {
+    let mut mesh = Mesh::default();
+    let depth = (shadow.offset_y + shadow.blur / 2.0).min(rect.height());
+    if depth <= 0.0 {
+        return mesh;
+    }
+    let solid = (shadow.offset_y - shadow.blur / 2.0).max(0.0);
+
+    let rows = (depth * 2.0).ceil() as u32;
+    for row in 0..=rows {
+        let y = depth * row as f32 / rows as f32;
+        let strength = if y <= solid {
+            1.0
+        } else {
+            1.0 - (y - solid) / (depth - solid)
+        };
+        let color = shadow.color.gamma_multiply(strength);
+        let top = rect.top() + y;
+        mesh.colored_vertex(
+            Pos2::new(rect.left() + corner_inset(radius.nw, y), top),
+            color,
+        );
+        mesh.colored_vertex(
+            Pos2::new(rect.right() - corner_inset(radius.ne, y), top),
+            color,
+        );
+        if row > 0 {
+            let index = 2 * row;
+            mesh.add_triangle(index - 2, index - 1, index);
+            mesh.add_triangle(index - 1, index + 1, index);
+        }
+    }
+
+    mesh
+}
+
+/// Show `add_contents` in [`to_frame`] of `style`, painting the style's background
+/// gradient, ring, outline, inset shadow and per-side borders around the frame.
+///
+/// `egui::Frame` only paints a fill, one stroke width and one drop shadow, so
+/// `bg_linear`/`bg_radial`/`bg_conic` gradients, `ring_*`, `outline_*`, `inset_shadow`
+/// and borders whose sides differ (`border_b`, `border_x`, ...) need this helper (or
+/// [`styled_frame`](crate::styled_frame), which uses it). Gradients are painted as a
+/// mesh inside the border and per-side borders as straight strokes along each side.
+/// None of them change the frame's size, and neither do `translate-*`, `rotate-*`,
+/// `scale-*` and `skew-*`, which move everything the frame paints around its
+/// `origin-*` while egui keeps laying out and hit-testing the untransformed frame:
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let field = Style::new()
+///     .p(Spacing::S2)
+///     .rounded(BorderRadius::Md)
+///     .inset_shadow(InsetShadow::Xs)
+///     .ring(RingWidth::S2, Color::blue(Scale::S500));
+///
+/// fn show_field(ui: &mut egui::Ui, style: &Style) {
+///     twill_egui::show_frame(ui, style, |ui| ui.label("Search"));
+/// }
+/// # let _ = (field, show_field);
+/// ```
+pub fn show_frame<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    show_decorated(ui, to_frame(style), style, add_contents)
+}
+
+/// Show `add_contents` in `frame`, painting the decorations of `style` around it.
+pub(crate) fn show_decorated<R>(
+    ui: &mut egui::Ui,
+    frame: egui::Frame,
+    style: &Style,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let decorations = Decorations::new(style, ui.visuals().text_color());
+    let transform = style.transform_value();
+    if decorations.is_empty() && transform.is_none() {
+        return frame.show(ui, add_contents);
+    }
+
+    let start = next_shape_idx(ui.ctx(), ui.layer_id());
+    let mut prepared = frame.begin(ui);
+    // Reserved after the frame background, so it lands between the fill and the content.
+    let under = prepared.content_ui.painter().add(Shape::Noop);
+    let inner = add_contents(&mut prepared.content_ui);
+    let rect = frame.widget_rect(prepared.content_ui.min_rect());
+    let response = prepared.end(ui);
+
+    ui.painter().set(under, decorations.under(&frame, rect));
+    ui.painter().extend(decorations.over(&frame, rect));
+    if let Some(transform) = transform {
+        let origin = style.transform_origin_value().unwrap_or_default();
+        transform_shapes(ui.ctx(), ui.layer_id(), start, transform, origin, rect);
+    }
+
+    egui::InnerResponse::new(inner, response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{Blur, BorderRadius, BorderWidth, Color, LinearDirection, Scale};
+
+    #[test]
+    fn test_decorations_paint_ring_inset_shadow_and_outline() {
+        let style = Style::new()
+            .ring(RingWidth::S2, Color::blue(Scale::S500))
+            .inset_shadow(InsetShadow::Sm)
+            .outline(
+                BorderWidth::S2,
+                OutlineStyle::Dashed,
+                Color::red(Scale::S500),
+            );
+        let frame = to_frame(&style).corner_radius(6.0);
+        let rect = Rect::from_min_size(Pos2::new(10.0, 10.0), egui::vec2(100.0, 40.0));
+        let decorations = Decorations::new(&style, Color32::WHITE);
+
+        let under = decorations.under(&frame, rect);
+        let [Shape::Rect(ring), Shape::Mesh(shadow)] = under.as_slice() else {
+            panic!("expected a ring and an inset shadow");
+        };
+        assert_eq!(ring.rect, rect.expand(2.0));
+        assert_eq!(ring.corner_radius, CornerRadius::same(8));
+        assert_eq!(
+            ring.stroke.color,
+            crate::to_color32(Color::blue(Scale::S500))
+        );
+        assert!(
+            shadow
+                .vertices
+                .iter()
+                .all(|vertex| rect.contains(vertex.pos))
+        );
+        assert!(shadow.vertices[0].color.a() > shadow.vertices.last().unwrap().color.a());
+
+        let over = decorations.over(&frame, rect);
+        assert!(over.len() > 4, "dashed outlines are split into dashes");
+    }
+
+    #[test]
+    fn test_unequal_border_sides_are_painted_as_strokes() {
+        let tab = Style::new()
+            .rounded_t(BorderRadius::Md)
+            .border_b(BorderWidth::S2)
+            .border_color(Color::blue(Scale::S500));
+        let frame = to_frame(&tab);
+        assert_eq!(
+            frame.corner_radius,
+            CornerRadius {
+                nw: 6,
+                ne: 6,
+                sw: 0,
+                se: 0,
+            }
+        );
+        assert_eq!(frame.stroke, Stroke::NONE);
+
+        let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(80.0, 32.0));
+        let under = Decorations::new(&tab, Color32::WHITE).under(&frame, rect);
+        let [Shape::LineSegment { points, stroke }] = under.as_slice() else {
+            panic!("expected one bottom stroke");
+        };
+        assert_eq!(*points, [Pos2::new(0.0, 31.0), Pos2::new(80.0, 31.0)]);
+        assert_eq!(stroke.width, 2.0);
+        assert_eq!(stroke.color, crate::to_color32(Color::blue(Scale::S500)));
+
+        let boxed = Style::new()
+            .border_width(BorderWidth::S1)
+            .border_color(Color::blue(Scale::S500));
+        assert_eq!(to_frame(&boxed).stroke.width, 1.0);
+        assert!(Decorations::new(&boxed, Color32::WHITE).is_empty());
+    }
+
+    #[test]
+    fn test_gradient_mesh_follows_the_stops_inside_the_border() {
+        let banner = Style::new()
+            .bg_linear(LinearDirection::ToR)
+            .gradient_from(Color::sky(Scale::S500))
+            .gradient_to(Color::indigo(Scale::S500))
+            .border_width(BorderWidth::S2)
+            .border_color(Color::slate(Scale::S200));
+        let frame = to_frame(&banner).corner_radius(8.0);
+        let rect = Rect::from_min_size(Pos2::new(10.0, 10.0), egui::vec2(120.0, 40.0));
+        let under = Decorations::new(&banner, Color32::WHITE).under(&frame, rect);
+        let [Shape::Mesh(mesh)] = under.as_slice() else {
+            panic!("expected a gradient mesh");
+        };
+
+        let padding_box = rect.shrink(2.0);
+        assert!(
+            mesh.vertices
+                .iter()
+                .all(|vertex| padding_box.expand(1e-3).contains(vertex.pos))
+        );
+        // The top-left vertex is pulled in by the rounded corner.
+        assert!(mesh.vertices[0].pos.x > padding_box.left());
+        let leftmost = mesh
+            .vertices
+            .iter()
+            .min_by(|a, b| a.pos.x.total_cmp(&b.pos.x))
+            .unwrap();
+        let rightmost = mesh
+            .vertices
+            .iter()
+            .max_by(|a, b| a.pos.x.total_cmp(&b.pos.x))
+            .unwrap();
+        assert_eq!(leftmost.color, crate::to_color32(Color::sky(Scale::S500)));
+        assert_eq!(
+            rightmost.color,
+            crate::to_color32(Color::indigo(Scale::S500))
+        );
+
+        let faded = Decorations::new(
+            &Style::new()
+                .bg_radial()
+                .gradient_from(Color::white())
+                .opacity(0.5),
+            Color32::WHITE,
+        );
+        let under = faded.under(&egui::Frame::NONE, rect);
+        let [Shape::Mesh(mesh)] = under.as_slice() else {
+            panic!("expected a gradient mesh");
+        };
+        assert!(mesh.vertices.iter().all(|vertex| vertex.color.a() <= 128));
+    }
+
+    #[test]
+    fn test_blurred_background_replaces_the_frame_fill() {
+        let glow = Style::new()
+            .bg(Color::blue(Scale::S500))
+            .blur(Blur::Xl)
+            .grayscale(100);
+        let frame = to_frame(&glow);
+        assert_eq!(frame.fill, Color32::TRANSPARENT);
+
+        let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(60.0, 60.0));
+        let under = Decorations::new(&glow, Color32::WHITE).under(&frame, rect);
+        let [Shape::Rect(fill)] = under.as_slice() else {
+            panic!("expected a blurred fill");
+        };
+        assert_eq!(fill.rect, rect);
+        assert_eq!(fill.blur_width, 48.0);
+        let [r, g, b, _] = fill.fill.to_array();
+        assert!(r.abs_diff(g) <= 1 && g.abs_diff(b) <= 1, "grayscale fill");
+
+        let frosted = to_frame(
+            &Style::new()
+                .bg_arbitrary(ColorValue::from_rgb(255, 255, 255).with_alpha(0.2).into())
+                .backdrop_blur(Blur::Xl),
+        );
+        assert!(frosted.fill.a() > 51);
+        assert!(Decorations::new(&Style::new().backdrop_blur(Blur::Xl), Color32::WHITE).is_empty());
+    }
+
+    #[test]
+    fn test_decorations_fall_back_to_current_color() {
+        let decorations = Decorations::new(
+            &Style::new()
+                .ring_width(RingWidth::S1)
+                .outline_width(BorderWidth::S1),
+            Color32::WHITE,
+        );
+        let frame = egui::Frame::NONE;
+        let rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(20.0, 20.0));
+
+        let (under, over) = (
+            decorations.under(&frame, rect),
+            decorations.over(&frame, rect),
+        );
+        let [Shape::Rect(ring)] = under.as_slice() else {
+            panic!("expected a ring");
+        };
+        let [Shape::Rect(outline)] = over.as_slice() else {
+            panic!("expected a solid outline");
+        };
+        assert_eq!(ring.stroke.color, Color32::WHITE);
+        assert_eq!(outline.stroke.color, Color32::WHITE);
+        assert_eq!(outline.rect, rect.expand(1.0));
+    }
+}
@@ -0,0 +1,162 @@
+//! Installing the fonts of a [`FontRegistry`] into egui font definitions.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use egui::{FontData, FontDefinitions};
+use twill_core::tokens::{FontFamily, FontLoadError, FontRegistry, FontWeight, RegisteredFamily};
+
+const WEIGHTS: [FontWeight; 9] = [
+    FontWeight::Thin,
+    FontWeight::ExtraLight,
+    FontWeight::Light,
+    FontWeight::Normal,
+    FontWeight::Medium,
+    FontWeight::SemiBold,
+    FontWeight::Bold,
+    FontWeight::ExtraBold,
+    FontWeight::Black,
+];
+
+/// Add the fonts of `fonts` to `definitions`.
+///
+/// Every registered family gets an egui font family per [`FontWeight`], named by
+/// [`font_family`], holding the face nearest to that weight and falling back to the
+/// proportional fonts. The regular `font-sans` and `font-mono` faces also become the
+/// first [`egui::FontFamily::Proportional`] and [`egui::FontFamily::Monospace`] fonts, so
+/// they are used for all text. egui text has no italic style, so italic faces are only
+/// used by families without upright faces.
+///
+/// [`styled_label`](crate::styled_label) picks up the installed families for
+/// `font-<family>` and `font-<weight>`:
+///
+/// ```rust,no_run
+/// use twill_core::prelude::*;
+///
+/// let fonts = FontRegistry::new()
+///     .register(FontFamily::Sans, FontFace::new("Inter", FontSource::path("fonts/Inter-Regular.ttf")))
+///     .register(
+///         FontFamily::Sans,
+///         FontFace::new("Inter", FontSource::path("fonts/Inter-Bold.ttf")).weight(FontWeight::Bold),
+///     );
+///
+/// let ctx = egui::Context::default();
+/// let mut definitions = egui::FontDefinitions::default();
+/// twill_egui::install_fonts(&fonts, &mut definitions)?;
+/// ctx.set_fonts(definitions);
+/// # Ok::<(), twill_core::tokens::FontLoadError>(())
+/// ```
+pub fn install_fonts(
+    fonts: &FontRegistry,
+    definitions: &mut FontDefinitions,
+) -> Result<(), FontLoadError> {
+    let fallback = definitions
+        .families
+        .get(&egui::FontFamily::Proportional)
+        .cloned()
+        .unwrap_or_default();
+
+    for (family, faces) in fonts.families() {
+        for face in faces {
+            let data = match face.source().load()? {
+                Cow::Borrowed(bytes) => FontData::from_static(bytes),
+                Cow::Owned(bytes) => FontData::from_owned(bytes),
+            };
+            definitions
+                .font_data
+                .insert(face_key(family, face), Arc::new(data));
+        }
+
+        for weight in WEIGHTS {
+            let Some(face) = fonts.resolve(family, weight, false) else {
+                continue;
+            };
+            let mut keys = vec![face_key(family, face)];
+            keys.extend(fallback.iter().cloned());
+            definitions
+                .families
+                .insert(font_family(family, weight), keys);
+        }
+
+        let default_family = match family {
+            RegisteredFamily::Token(FontFamily::Sans) => egui::FontFamily::Proportional,
+            RegisteredFamily::Token(FontFamily::Mono) => egui::FontFamily::Monospace,
+            _ => continue,
+        };
+        if let Some(face) = fonts.resolve(family, FontWeight::Normal, false) {
+            definitions
+                .families
+                .entry(default_family)
+                .or_default()
+                .insert(0, face_key(family, face));
+        }
+    }
+    Ok(())
+}
+
+/// The egui font family [`install_fonts`] adds for `family` at `weight`, such as
+/// `twill-sans-700` for bold `font-sans` text.
+pub fn font_family(family: impl Into<RegisteredFamily>, weight: FontWeight) -> egui::FontFamily {
+    egui::FontFamily::Name(format!("{}-{}", family.into().name(), weight.value()).into())
+}
+
+/// Key of the font data of `face` in the font definitions.
+fn face_key(family: RegisteredFamily, face: &twill_core::tokens::FontFace) -> String {
+    let italic = if face.is_italic() { "-italic" } else { "" };
+    format!(
+        "{}-{}{italic}-{}",
+        family.name(),
+        face.weight_value().value(),
+        face.name()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{FontFace, FontSource};
+
+    #[test]
+    fn test_install_fonts_adds_weighted_families() {
+        let fonts = FontRegistry::new()
+            .register(
+                FontFamily::Sans,
+                FontFace::new("Inter", FontSource::bytes(b"regular")),
+            )
+            .register(
+                FontFamily::Sans,
+                FontFace::new("Inter", FontSource::bytes(b"bold")).weight(FontWeight::Bold),
+            )
+            .register(
+                FontFamily::Serif,
+                FontFace::new("Lora", FontSource::bytes(b"serif")),
+            );
+        let mut definitions = FontDefinitions::empty();
+        install_fonts(&fonts, &mut definitions).unwrap();
+
+        assert_eq!(
+            definitions.families[&font_family(FontFamily::Sans, FontWeight::SemiBold)],
+            ["twill-sans-700-Inter"]
+        );
+        assert_eq!(
+            definitions.families[&font_family(FontFamily::Sans, FontWeight::Light)],
+            ["twill-sans-400-Inter"]
+        );
+        assert_eq!(
+            definitions.families[&egui::FontFamily::Proportional][0],
+            "twill-sans-400-Inter"
+        );
+        assert!(
+            definitions
+                .families
+                .contains_key(&font_family(FontFamily::Serif, FontWeight::Black))
+        );
+        assert!(
+            definitions
+                .families
+                .get(&egui::FontFamily::Monospace)
+                .is_none_or(Vec::is_empty)
+        );
+        assert_eq!(definitions.font_data.len(), 3);
+    }
+}
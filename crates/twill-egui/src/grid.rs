@@ -0,0 +1,218 @@
+//! CSS grids: items placed by `col-*`/`row-*` spans on `grid-cols-*`/`grid-rows-*` tracks.
+
+use egui::{Rect, Sense, UiBuilder, Vec2};
+use twill_core::style::Style;
+use twill_core::utilities::{AlignItems, GridPlacement, GridTemplate, TrackList, place_grid_items};
+
+use crate::spacing_to_px;
+
+/// Items of a [`grid`], added one at a time with [`Grid::add`].
+pub struct Grid<'a> {
+    items: Vec<GridItem<'a>>,
+}
+
+struct GridItem<'a> {
+    placement: (GridPlacement, GridPlacement),
+    add_contents: Box<dyn FnOnce(&mut egui::Ui) + 'a>,
+}
+
+impl<'a> Grid<'a> {
+    /// Add an item placed by the `col-*` and `row-*` placement of `style`.
+    ///
+    /// `add_contents` runs once the grid is sized, in a `Ui` covering the item's area.
+    pub fn add(&mut self, style: &Style, add_contents: impl FnOnce(&mut egui::Ui) + 'a) {
+        self.items.push(GridItem {
+            placement: (
+                style.grid_column_value().unwrap_or_default(),
+                style.grid_row_value().unwrap_or_default(),
+            ),
+            add_contents: Box::new(add_contents),
+        });
+    }
+}
+
+/// Item sizes measured in the previous pass, stored in egui's temporary memory.
+#[derive(Clone, Default, PartialEq)]
+struct Measured(Vec<Vec2>);
+
+/// Lay out items on the tracks of the `grid-cols-*` and `grid-rows-*` templates of
+/// `style`, placed by their `col-*` and `row-*` placement and auto-placement.
+///
+/// Tracks are sized like CSS grid tracks from the sizes items had in the previous
+/// pass; when those change, the pass is discarded so the grid never shows a stale
+/// layout. Rows past the `grid-rows-*` template are `auto`, `grid-flow-*` picks the
+/// fill direction and dense packing, `gap-*` spaces the tracks and `items-center` or
+/// `items-end` aligns items in their area:
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let form = Style::new().grid_cols_count(2).gap(Spacing::S2);
+///
+/// fn show_form(ui: &mut egui::Ui, style: &Style) {
+///     twill_egui::grid(ui, style, |grid| {
+///         grid.add(&Style::new(), |ui| {
+///             ui.label("Name");
+///         });
+///         grid.add(&Style::new(), |ui| {
+///             ui.label("Email");
+///         });
+///         grid.add(&Style::new().col_span_full(), |ui| {
+///             ui.label("Notes span both columns");
+///         });
+///     });
+/// }
+/// # let _ = (form, show_form);
+/// ```
+pub fn grid<'a, R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    add_items: impl FnOnce(&mut Grid<'a>) -> R,
+) -> egui::InnerResponse<R> {
+    let mut items = Grid { items: Vec::new() };
+    let inner = add_items(&mut items);
+    let items = items.items;
+
+    let grid = style.grid_container().cloned().unwrap_or_default();
+    let tracks = |template: Option<&GridTemplate>| {
+        template
+            .and_then(GridTemplate::track_list)
+            .unwrap_or_default()
+    };
+    let (columns, rows) = (tracks(grid.columns_value()), tracks(grid.rows_value()));
+    let explicit = |tracks: &TrackList, available: f32, gap: f32| {
+        if tracks.tracks().is_empty() {
+            0
+        } else {
+            tracks.track_count(available, gap)
+        }
+    };
+    let gap = grid.gap_value().map_or(0.0, spacing_to_px);
+    let column_gap = grid.col_gap_value().map_or(gap, spacing_to_px);
+    let row_gap = grid.row_gap_value().map_or(gap, spacing_to_px);
+    let available = ui.available_width();
+
+    let placements: Vec<_> = items.iter().map(|item| item.placement).collect();
+    let areas = place_grid_items(
+        &placements,
+        explicit(&columns, available, column_gap),
+        explicit(&rows, f32::INFINITY, row_gap),
+        grid.flow_value().unwrap_or_default(),
+    );
+
+    let id = ui.next_auto_id().with("twill_grid");
+    let measured = ui
+        .data(|data| data.get_temp::<Measured>(id))
+        .filter(|measured| measured.0.len() == items.len())
+        .unwrap_or_else(|| Measured(vec![Vec2::ZERO; items.len()]));
+
+    let column_spans: Vec<_> = areas
+        .iter()
+        .map(|area| (area.column(), area.column_span()))
+        .collect();
+    let column_tracks = columns.resolve_spans(available, column_gap, &column_spans, |item| {
+        (measured.0[item].x, measured.0[item].x)
+    });
+    let row_spans: Vec<_> = areas
+        .iter()
+        .map(|area| (area.row(), area.row_span()))
+        .collect();
+    let row_tracks = rows.resolve_spans(f32::INFINITY, row_gap, &row_spans, |item| {
+        (measured.0[item].y, measured.0[item].y)
+    });
+
+    let origin = ui.available_rect_before_wrap().min;
+    let mut sizes = Vec::with_capacity(items.len());
+    for ((item, area), measured) in items.into_iter().zip(&areas).zip(&measured.0) {
+        let (x, width) = span_bounds(&column_tracks, area.column(), area.column_span());
+        let (y, height) = span_bounds(&row_tracks, area.row(), area.row_span());
+        let free = (height - measured.y).max(0.0);
+        let offset = match grid.align_value() {
+            Some(AlignItems::Center) => free / 2.0,
+            Some(AlignItems::End) => free,
+            _ => 0.0,
+        };
+        let rect = Rect::from_min_size(
+            origin + Vec2::new(x, y + offset),
+            Vec2::new(width, height - offset),
+        );
+        let mut child = ui.new_child(UiBuilder::new().max_rect(rect));
+        (item.add_contents)(&mut child);
+        sizes.push(child.min_rect().size());
+    }
+
+    let sizes = Measured(sizes);
+    if sizes != measured {
+        ui.data_mut(|data| data.insert_temp(id, sizes));
+        ui.ctx().request_discard("twill grid item sizes changed");
+    }
+
+    let extent = |tracks: &[(f32, f32)]| tracks.last().map_or(0.0, |&(at, size)| at + size);
+    let rect = Rect::from_min_size(
+        origin,
+        Vec2::new(extent(&column_tracks), extent(&row_tracks)),
+    );
+    egui::InnerResponse::new(inner, ui.allocate_rect(rect, Sense::hover()))
+}
+
+/// Offset and size of the `span` tracks from `start` in `tracks`.
+fn span_bounds(tracks: &[(f32, f32)], start: usize, span: usize) -> (f32, f32) {
+    let first = tracks.get(start).copied().unwrap_or_default();
+    let last = tracks
+        .get(start + span.max(1) - 1)
+        .copied()
+        .unwrap_or(first);
+    (first.0, (last.0 + last.1 - first.0).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+    use std::cell::RefCell;
+    use twill_core::tokens::Spacing;
+
+    #[test]
+    fn test_grid_places_spanning_items_from_measured_sizes() {
+        let ctx = egui::Context::default();
+        let mut placed = Vec::new();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::ZERO;
+                let rects = RefCell::new(Vec::new());
+                let form = Style::new().grid_cols_count(2).gap(Spacing::S2_5);
+                ui.allocate_ui(Vec2::new(210.0, 400.0), |ui| {
+                    grid(ui, &form, |grid| {
+                        for (style, height) in [
+                            (Style::new(), 20.0),
+                            (Style::new(), 30.0),
+                            (Style::new().col_span_full(), 15.0),
+                        ] {
+                            let rects = &rects;
+                            grid.add(&style, move |ui| {
+                                let (rect, _) = ui.allocate_exact_size(
+                                    Vec2::new(ui.available_width(), height),
+                                    Sense::hover(),
+                                );
+                                rects.borrow_mut().push(rect);
+                            });
+                        }
+                    });
+                });
+                placed = rects.into_inner();
+            });
+        });
+
+        let origin = placed[0].min.to_vec2();
+        let placed: Vec<Rect> = placed.iter().map(|rect| rect.translate(-origin)).collect();
+        assert_eq!(
+            placed,
+            vec![
+                Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 20.0)),
+                Rect::from_min_size(Pos2::new(110.0, 0.0), Vec2::new(100.0, 30.0)),
+                // The full-width item starts below the taller first-row item.
+                Rect::from_min_size(Pos2::new(0.0, 40.0), Vec2::new(210.0, 15.0)),
+            ]
+        );
+    }
+}
@@ -2,15 +2,33 @@
 
 #![forbid(unsafe_code)]
 
-use twill_backend_common::ShadowColor;
-use twill_core::style::Style;
+use twill_backend_common::{ShadowColor, filter_blur_shadow_radius, frosted_alpha};
+use twill_core::style::{InteractionState, ResolveContext, Style};
 use twill_core::tokens::{
     AspectRatio, BackgroundColor, Blur, BorderColor, BorderRadius, BorderWidth, Color, ColorValue,
-    Cursor, FontSize, FontWeight, SemanticColor, SemanticThemeVars, Shadow, ShadowColorToken,
-    Spacing, TextColor, ThemeVariant, TransitionDuration,
+    Cursor, Filter, FontSize, FontWeight, OutlineColor, RingColor, SemanticColor,
+    SemanticThemeSource, SemanticThemeVars, Shadow, ShadowColorToken, Spacing, TextColor,
+    ThemeVariant, TransitionDuration,
 };
 use twill_core::traits::ComputeValue;
-use twill_core::utilities::PaddingValue;
+use twill_core::utilities::{BorderRadii, PaddingValue};
+
+mod animation;
+mod decorations;
+mod fonts;
+mod grid;
+mod stack;
+mod text;
+mod transform;
+mod transition;
+
+pub use animation::{animate_keyframes, animated_style};
+pub use decorations::show_frame;
+pub use fonts::{font_family, install_fonts};
+pub use grid::{Grid, grid};
+pub use stack::{Stack, stack, stack_with_semantic_theme};
+pub use text::{StyledLabel, styled_label};
+pub use transition::transition_frame;
 
 mod private {
     pub trait Sealed {}
@@ -73,6 +91,15 @@ impl ToEgui for BorderRadius {
     }
 }
 
+impl private::Sealed for BorderRadii {}
+impl ToEgui for BorderRadii {
+    type Output = egui::CornerRadius;
+
+    fn to_egui(self) -> Self::Output {
+        to_corner_radii(self)
+    }
+}
+
 impl private::Sealed for Blur {}
 impl ToEgui for Blur {
     type Output = f32;
@@ -165,6 +192,52 @@ fn resolved_opacity(style: &Style) -> f32 {
     style.opacity_value().unwrap_or(1.0).clamp(0.0, 1.0)
 }
 
+/// Color filters of `style`, or the identity when it has none.
+fn resolved_filter(style: &Style) -> Filter {
+    style.filter_value().unwrap_or_default()
+}
+
+fn apply_filter_to_color32(color: egui::Color32, filter: Filter) -> egui::Color32 {
+    if filter.is_identity() {
+        return color;
+    }
+    let [r, g, b, a] = color
+        .to_srgba_unmultiplied()
+        .map(|channel| f32::from(channel) / 255.0);
+    let [r, g, b, a] = filter
+        .apply_rgba([r, g, b, a])
+        .map(|channel| (channel * 255.0).round() as u8);
+    egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Background color of `style` with its color filters, before opacity.
+///
+/// egui cannot read what is painted behind a frame, so `backdrop-blur` makes the
+/// background more opaque instead (see [`frosted_alpha`]).
+fn resolved_background(style: &Style) -> Option<ColorValue> {
+    let background = style.background_color_value().and_then(|bg| {
+        resolve_background_color_token(
+            bg,
+            style
+                .text_color_token_value()
+                .and_then(resolve_text_color_token),
+        )
+    })?;
+    let background = resolved_filter(style).apply(background);
+    Some(match style.backdrop_blur_value() {
+        Some(blur) => background.with_alpha(frosted_alpha(background.alpha(), blur)),
+        None => background,
+    })
+}
+
+/// Blur width of the background of `style` under `blur-*`, or `None` when it is sharp.
+fn background_blur_width(style: &Style) -> Option<f32> {
+    style
+        .blur_value()
+        .map(filter_blur_shadow_radius)
+        .filter(|width| *width > 0.0)
+}
+
 fn spacing_to_px(spacing: Spacing) -> f32 {
     match spacing.to_px() {
         Some(px) => px as f32,
@@ -225,19 +298,57 @@ fn resolve_text_color_token(token: TextColor) -> Option<ColorValue> {
 }
 
 fn resolve_border_color_token(token: BorderColor) -> Option<ColorValue> {
+    resolve_border_color_token_with_semantic_theme(
+        token,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    )
+}
+
+fn resolve_border_color_token_with_semantic_theme<S: SemanticThemeSource + ?Sized>(
+    token: BorderColor,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<ColorValue> {
     match token {
         BorderColor::Inherit => None,
         BorderColor::Current => None,
         BorderColor::Transparent => Some(ColorValue::TRANSPARENT),
         BorderColor::Palette(color) => Some(color.compute()),
-        BorderColor::Semantic(color) => {
-            SemanticThemeVars::shadcn_neutral().resolve_value(color, ThemeVariant::Light)
-        }
+        BorderColor::Semantic(color) => semantic_theme.resolve_value(color, variant),
         BorderColor::CustomProperty(_) => None,
         BorderColor::Arbitrary(value) => Some(value.into()),
     }
 }
 
+fn resolve_outline_color_token(token: OutlineColor) -> Option<ColorValue> {
+    match token {
+        OutlineColor::Inherit => None,
+        OutlineColor::Current => None,
+        OutlineColor::Transparent => Some(ColorValue::TRANSPARENT),
+        OutlineColor::Palette(color) => Some(color.compute()),
+        OutlineColor::Semantic(color) => {
+            SemanticThemeVars::shadcn_neutral().resolve_value(color, ThemeVariant::Light)
+        }
+        OutlineColor::CustomProperty(_) => None,
+        OutlineColor::Arbitrary(value) => Some(value.into()),
+    }
+}
+
+fn resolve_ring_color_token(token: RingColor) -> Option<ColorValue> {
+    match token {
+        RingColor::Inherit => None,
+        RingColor::Current => None,
+        RingColor::Transparent => Some(ColorValue::TRANSPARENT),
+        RingColor::Palette(color) => Some(color.compute()),
+        RingColor::Semantic(color) => {
+            SemanticThemeVars::shadcn_neutral().resolve_value(color, ThemeVariant::Light)
+        }
+        RingColor::CustomProperty(_) => None,
+        RingColor::Arbitrary(value) => Some(value.into()),
+    }
+}
+
 fn resolve_shadow_color_token(token: ShadowColorToken) -> Option<ColorValue> {
     match token {
         ShadowColorToken::Inherit => None,
@@ -290,6 +401,13 @@ pub fn to_corner_radius(radius: BorderRadius) -> f32 {
     }
 }
 
+/// Convert twill per-corner radii to an egui corner radius; unset corners are square.
+pub fn to_corner_radii(radii: BorderRadii) -> egui::CornerRadius {
+    // `as` saturates, so `rounded-full` becomes the largest radius egui can store.
+    let [nw, ne, se, sw] = radii.to_px().map(|px| px.round() as u8);
+    egui::CornerRadius { nw, ne, sw, se }
+}
+
 /// Convert twill Blur to egui blur radius (f32).
 pub fn to_blur_radius(blur: Blur) -> f32 {
     blur.radius_px() as f32
@@ -409,9 +527,17 @@ pub fn to_cursor_icon(cursor: Cursor) -> egui::CursorIcon {
 ///
 /// Note: `Style::margin` is intentionally not mapped here because `egui::Frame`
 /// only owns inner spacing; outer spacing is controlled by parent layout code.
+/// Rings, outlines and inset shadows are painted outside the frame shape, and
+/// `egui::Frame` has a single stroke width and a sharp fill, so borders whose sides
+/// differ (`border_b`, `border_x`, ...) and blurred backgrounds (`blur-*`) are left
+/// out; show the frame with [`show_frame`] to draw them. Color filters (`grayscale`,
+/// `brightness-*`, ...) apply to every color.
 pub fn to_frame(style: &Style) -> egui::Frame {
+    frame_with_opacity(style, resolved_opacity(style))
+}
+
+fn frame_with_opacity(style: &Style, opacity: f32) -> egui::Frame {
     let mut frame = egui::Frame::default();
-    let opacity = resolved_opacity(style);
 
     // Padding
     if let Some(p) = style.padding_value() {
@@ -428,15 +554,11 @@ pub fn to_frame(style: &Style) -> egui::Frame {
         });
     }
 
-    // Background
-    if let Some(bg) = style.background_color_value().and_then(|bg| {
-        resolve_background_color_token(
-            bg,
-            style
-                .text_color_token_value()
-                .and_then(resolve_text_color_token),
-        )
-    }) {
+    let filter = resolved_filter(style);
+
+    // Background; a blurred one is painted by `show_frame`.
+    if let Some(bg) = resolved_background(style).filter(|_| background_blur_width(style).is_none())
+    {
         frame = frame.fill(to_color32_value(apply_opacity_to_color_value(bg, opacity)));
     }
 
@@ -444,11 +566,17 @@ pub fn to_frame(style: &Style) -> egui::Frame {
     if let Some(r) = style.border_radius_value() {
         frame = frame.corner_radius(to_corner_radius(r));
     }
+    if style.border_radii_value().is_some() {
+        frame = frame.corner_radius(to_corner_radii(style.resolved_border_radii()));
+    }
 
-    // Border
-    if let (Some(width), Some(color)) =
-        (style.border_width_value(), style.border_color_token_value())
-    {
+    // Border; sides of different widths are painted by `show_frame`.
+    let widths = style.resolved_border_widths();
+    if let (Some(width), Some(color), true) = (
+        widths.top_side(),
+        style.border_color_token_value(),
+        widths.is_uniform(),
+    ) {
         let w: f32 = match width {
             BorderWidth::S0 => 0.0,
             BorderWidth::S1 => 1.0,
@@ -459,7 +587,10 @@ pub fn to_frame(style: &Style) -> egui::Frame {
         if let Some(color) = resolve_border_color_token(color) {
             frame = frame.stroke(egui::Stroke::new(
                 w,
-                apply_opacity_to_color32(to_color32_value(color), opacity),
+                apply_opacity_to_color32(
+                    apply_filter_to_color32(to_color32_value(color), filter),
+                    opacity,
+                ),
             ));
         }
     }
@@ -472,7 +603,8 @@ pub fn to_frame(style: &Style) -> egui::Frame {
             .map(to_color32_value)
             .unwrap_or_else(|| to_color32(Color::black()));
         if let Some(mut egui_shadow) = to_shadow(s) {
-            egui_shadow.color = apply_opacity_to_color32(shadow_color, opacity);
+            egui_shadow.color =
+                apply_opacity_to_color32(apply_filter_to_color32(shadow_color, filter), opacity);
             frame = frame.shadow(egui_shadow);
         }
     }
@@ -480,10 +612,136 @@ pub fn to_frame(style: &Style) -> egui::Frame {
     frame
 }
 
+/// Snapshot the interaction state of an egui widget from its [`egui::Response`].
+///
+/// Hover, press (`active`) and focus come from the response; `disabled` comes from
+/// [`egui::Ui::is_enabled`]. egui does not track whether focus came from the keyboard,
+/// so `focus_visible` mirrors `focus`.
+pub fn interaction_state(ui: &egui::Ui, response: &egui::Response) -> InteractionState {
+    with_response_state(InteractionState::new(), ui, response)
+}
+
+fn with_response_state(
+    state: InteractionState,
+    ui: &egui::Ui,
+    response: &egui::Response,
+) -> InteractionState {
+    let focused = response.has_focus();
+    state
+        .hovered(response.hovered())
+        .pressed(response.is_pointer_button_down_on())
+        .focused(focused)
+        .focus_visible(focused)
+        .disabled(!ui.is_enabled())
+}
+
+/// Show `add_contents` inside a frame that follows the style's state layers.
+///
+/// The frame senses clicks, so hover, press, focus and the enclosing `Ui`'s enabled
+/// flag select the matching `hover`, `active`, `focus` and `disabled` layers through
+/// [`Style::resolve_for`] before the frame is painted, together with its ring, outline
+/// and inset shadow (see [`show_frame`]). Declared `transition_*` tokens animate the
+/// frame through [`transition_frame`], and `animate-*` tokens play through
+/// [`animated_style`]. The returned response is the frame's own, so it can be used like
+/// a button:
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let style = Style::new()
+///     .p(Spacing::S2)
+///     .bg(Color::slate(Scale::S100))
+///     .hover(|s| s.bg(Color::slate(Scale::S200)))
+///     .active(|s| s.bg(Color::slate(Scale::S300)));
+///
+/// fn save_button(ui: &mut egui::Ui, style: &Style) -> bool {
+///     twill_egui::styled_frame(ui, style, |ui| ui.label("Save"))
+///         .response
+///         .clicked()
+/// }
+/// # let _ = (style, save_button);
+/// ```
+pub fn styled_frame<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    styled_frame_with_state(ui, style, InteractionState::new(), add_contents)
+}
+
+/// Like [`styled_frame`], starting from caller-owned state such as `selected`,
+/// `checked`, `open` or `data-*`/`aria-*` attributes.
+///
+/// Hover, press, focus and disabled flags detected from egui override the same
+/// flags in `state`.
+pub fn styled_frame_with_state<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    state: InteractionState,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    ui.scope_builder(egui::UiBuilder::new().sense(egui::Sense::click()), |ui| {
+        // The scope's background response is registered before its content, so nested
+        // widgets still receive their own clicks.
+        let response = ui.response();
+        let state = with_response_state(state, ui, &response);
+        let resolved = animated_style(ui.ctx(), response.id, &style.resolve_for(&state));
+        if response.hovered()
+            && let Some(cursor) = resolved.cursor_value()
+        {
+            ui.ctx().set_cursor_icon(to_cursor_icon(cursor));
+        }
+        let frame = transition_frame(ui.ctx(), response.id, &resolved);
+        decorations::show_decorated(ui, frame, &resolved, add_contents).inner
+    })
+}
+
+/// Run `add_contents` with `style` resolved for the width available in `ui`.
+///
+/// The available width is the viewport of [`Style::resolve_breakpoints`], so the
+/// `sm`/`md`/`lg` layers follow the window or panel size while state layers are kept
+/// for [`styled_frame_with_state`]:
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let sidebar = Style::new().p(Spacing::S2).lg(|s| s.p(Spacing::S6));
+///
+/// fn show_sidebar(ui: &mut egui::Ui, style: &Style) {
+///     twill_egui::responsive(ui, style, |ui, style| {
+///         twill_egui::to_frame(&style).show(ui, |ui| ui.label("Navigation"));
+///     });
+/// }
+/// # let _ = (sidebar, show_sidebar);
+/// ```
+pub fn responsive<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    add_contents: impl FnOnce(&mut egui::Ui, Style) -> R,
+) -> R {
+    responsive_with_context(ui, style, &ResolveContext::new(), add_contents)
+}
+
+/// Run `add_contents` like [`responsive`], matching breakpoints against the available
+/// width with the root font size of `context`.
+pub fn responsive_with_context<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    context: &ResolveContext<'_>,
+    add_contents: impl FnOnce(&mut egui::Ui, Style) -> R,
+) -> R {
+    let resolved = style.resolve_breakpoints(&ResolveContext {
+        viewport_width_px: ui.available_width(),
+        ..context.clone()
+    });
+    add_contents(ui, resolved)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use twill_core::tokens::Scale;
+    use twill_core::utilities::Padding;
 
     #[test]
     fn test_color_conversion() {
@@ -547,4 +805,100 @@ mod tests {
             Color::blue(Scale::S500).compute().to_rgb8().2
         );
     }
+
+    fn run_pass(
+        ctx: &egui::Context,
+        pointer: Option<egui::Pos2>,
+        show: impl FnMut(&mut egui::Ui),
+    ) -> egui::FullOutput {
+        let mut input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(400.0, 300.0),
+            )),
+            ..Default::default()
+        };
+        if let Some(pos) = pointer {
+            input.events.push(egui::Event::PointerMoved(pos));
+        }
+        let mut show = show;
+        ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| show(ui));
+        })
+    }
+
+    #[test]
+    fn test_responsive_uses_available_width() {
+        let style = Style::new()
+            .p(Spacing::S1)
+            .md(|style| style.p(Spacing::S4))
+            .xl(|style| style.p(Spacing::S8));
+        let padding_at = |width: f32| {
+            let mut padding = None;
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(width, 300.0),
+                )),
+                ..Default::default()
+            };
+            let _ = egui::Context::default().run(input, |ctx| {
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::NONE)
+                    .show(ctx, |ui| {
+                        padding = responsive(ui, &style, |_, style| style.padding_value().copied());
+                    });
+            });
+            padding
+        };
+
+        assert_eq!(padding_at(400.0), Some(Padding::all(Spacing::S1)));
+        assert_eq!(padding_at(900.0), Some(Padding::all(Spacing::S4)));
+        assert_eq!(padding_at(1400.0), Some(Padding::all(Spacing::S8)));
+    }
+
+    #[test]
+    fn test_styled_frame_detects_hover() {
+        let ctx = egui::Context::default();
+        let style = Style::new()
+            .p(Spacing::S4)
+            .hover(|s| s.bg(Color::blue(Scale::S500)));
+        let hover_fill = to_color32(Color::blue(Scale::S500));
+        let mut rect = egui::Rect::NOTHING;
+        let mut hovered = false;
+        let mut filled = Vec::new();
+
+        for pointer in [None, None, Some(egui::pos2(20.0, 20.0))] {
+            let output = run_pass(&ctx, pointer, |ui| {
+                let response = styled_frame(ui, &style, |ui| ui.label("Hover me")).response;
+                rect = response.rect;
+                hovered = interaction_state(ui, &response).is_hovered();
+            });
+            // The frame paints its fill as a rect shape over the whole frame.
+            filled.push(output.shapes.iter().any(|clipped| {
+                matches!(&clipped.shape, egui::Shape::Rect(shape)
+                    if shape.fill == hover_fill && shape.rect == rect)
+            }));
+        }
+
+        assert!(rect.contains(egui::pos2(20.0, 20.0)));
+        assert!(hovered);
+        assert_eq!(filled, [false, false, true]);
+    }
+
+    #[test]
+    fn test_interaction_state_reads_disabled_ui() {
+        let ctx = egui::Context::default();
+        let mut state = InteractionState::new();
+
+        run_pass(&ctx, None, |ui| {
+            ui.add_enabled_ui(false, |ui| {
+                let response = ui.label("Disabled");
+                state = interaction_state(ui, &response);
+            });
+        });
+
+        assert!(state.is_disabled());
+        assert!(!state.is_hovered());
+    }
 }
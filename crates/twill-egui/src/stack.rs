@@ -0,0 +1,295 @@
+//! Stacks spaced by `space-*`/`gap-*` utilities with `divide-*` rules between children.
+
+use egui::layers::ShapeIdx;
+use egui::{Align, Color32, Direction, Layout, Pos2, Rect, Shape, Vec2};
+use twill_core::style::Style;
+use twill_core::tokens::{SemanticThemeSource, SemanticThemeVars, ThemeVariant};
+use twill_core::utilities::{AlignItems, Display, FlexDirection};
+
+use crate::{resolve_border_color_token_with_semantic_theme, spacing_to_px, to_color32_value};
+
+/// Children of a [`stack`], added one at a time with [`Stack::add`].
+pub struct Stack<'a> {
+    ui: &'a mut egui::Ui,
+    gap: f32,
+    divider: Option<(f32, Color32)>,
+    item_spacing: Vec2,
+    children: usize,
+    /// Placeholder shape and cursor before each divider.
+    dividers: Vec<(ShapeIdx, Rect)>,
+}
+
+impl Stack<'_> {
+    /// Add a child, preceded by the gap and the divider unless it is the first one.
+    ///
+    /// `add_contents` runs with the item spacing of the parent `Ui`.
+    pub fn add<R>(&mut self, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R {
+        if self.children > 0 {
+            self.ui.add_space(self.gap);
+            if let Some((width, _)) = self.divider {
+                // The rule is painted once the cross size of the stack is known.
+                let shape = self.ui.painter().add(Shape::Noop);
+                self.dividers.push((shape, self.ui.cursor()));
+                self.ui.add_space(width);
+            }
+        }
+        self.children += 1;
+
+        let item_spacing = self.item_spacing;
+        self.ui
+            .scope(|ui| {
+                ui.spacing_mut().item_spacing = item_spacing;
+                add_contents(ui)
+            })
+            .inner
+    }
+
+    fn paint_dividers(&self) {
+        let Some((width, color)) = self.divider else {
+            return;
+        };
+        let content = self.ui.min_rect();
+        let bounds = self.ui.max_rect();
+
+        for (shape, cursor) in &self.dividers {
+            let rect = match self.ui.layout().main_dir() {
+                Direction::LeftToRight => Rect::from_min_size(
+                    Pos2::new(cursor.min.x, content.min.y),
+                    Vec2::new(width, content.height()),
+                ),
+                Direction::RightToLeft => Rect::from_min_size(
+                    Pos2::new(cursor.max.x - width, content.min.y),
+                    Vec2::new(width, content.height()),
+                ),
+                Direction::TopDown => Rect::from_min_size(
+                    Pos2::new(bounds.min.x, cursor.min.y),
+                    Vec2::new(bounds.width(), width),
+                ),
+                Direction::BottomUp => Rect::from_min_size(
+                    Pos2::new(bounds.min.x, cursor.max.y - width),
+                    Vec2::new(bounds.width(), width),
+                ),
+            };
+            self.ui
+                .painter()
+                .set(*shape, Shape::rect_filled(rect, 0.0, color));
+        }
+    }
+}
+
+/// Lay out children along the flex direction of `style`, spaced by `space-x-*`
+/// (rows) or `space-y-*` (columns) and separated by `divide-x-*`/`divide-y-*` rules.
+///
+/// Styles without a flex direction stack children vertically, like block flow, unless
+/// `display` is `flex`. The spacing falls back to the flex `gap-*` values and `items-*`
+/// sets the cross-axis alignment. Each rule sits between the gap and the child after
+/// it, in `divide-<color>` or the egui separator color, and spans the stack:
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let menu = Style::new()
+///     .space_y(Spacing::S1)
+///     .divide_y(DivideWidth::S1)
+///     .divide_color(Color::slate(Scale::S200));
+///
+/// fn show_menu(ui: &mut egui::Ui, style: &Style) {
+///     twill_egui::stack(ui, style, |stack| {
+///         stack.add(|ui| ui.label("Edit"));
+///         stack.add(|ui| ui.label("Delete"));
+///     });
+/// }
+/// # let _ = (menu, show_menu);
+/// ```
+pub fn stack<R>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    add_children: impl FnOnce(&mut Stack<'_>) -> R,
+) -> egui::InnerResponse<R> {
+    stack_with_semantic_theme(
+        ui,
+        style,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+        add_children,
+    )
+}
+
+/// Lay out children like [`stack`], resolving a semantic `divide-<color>` against
+/// `semantic_theme` in `variant`.
+pub fn stack_with_semantic_theme<R, S: SemanticThemeSource + ?Sized>(
+    ui: &mut egui::Ui,
+    style: &Style,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+    add_children: impl FnOnce(&mut Stack<'_>) -> R,
+) -> egui::InnerResponse<R> {
+    let flex = style.flex_container();
+    let direction =
+        flex.and_then(|flex| flex.direction_value())
+            .unwrap_or(match style.display_mode() {
+                Some(Display::Flex | Display::InlineFlex) => FlexDirection::Row,
+                _ => FlexDirection::Col,
+            });
+    let align = match flex.and_then(|flex| flex.align_value()) {
+        Some(AlignItems::Center | AlignItems::CenterSafe) => Align::Center,
+        Some(AlignItems::End | AlignItems::EndSafe) => Align::Max,
+        _ => Align::Min,
+    };
+    let (layout, space, divide, flex_gap) = match direction {
+        FlexDirection::Row => (
+            Layout::left_to_right(align),
+            style.space_x_value(),
+            style.divide_x_value(),
+            flex.and_then(|flex| flex.col_gap_value()),
+        ),
+        FlexDirection::RowReverse => (
+            Layout::right_to_left(align),
+            style.space_x_value(),
+            style.divide_x_value(),
+            flex.and_then(|flex| flex.col_gap_value()),
+        ),
+        FlexDirection::Col => (
+            Layout::top_down(align),
+            style.space_y_value(),
+            style.divide_y_value(),
+            flex.and_then(|flex| flex.row_gap_value()),
+        ),
+        FlexDirection::ColReverse => (
+            Layout::bottom_up(align),
+            style.space_y_value(),
+            style.divide_y_value(),
+            flex.and_then(|flex| flex.row_gap_value()),
+        ),
+    };
+    let gap = space
+        .or(flex_gap)
+        .or_else(|| flex.and_then(|flex| flex.gap_value()))
+        .map_or(0.0, spacing_to_px);
+    let divider = divide
+        .map(|width| f32::from(width.px_value()))
+        .filter(|width| *width > 0.0)
+        .map(|width| {
+            let color = style
+                .divide_color_token_value()
+                .and_then(|color| {
+                    resolve_border_color_token_with_semantic_theme(color, semantic_theme, variant)
+                })
+                .map_or(
+                    ui.visuals().widgets.noninteractive.bg_stroke.color,
+                    to_color32_value,
+                );
+            (width, color)
+        });
+
+    ui.with_layout(layout, |ui| {
+        let item_spacing = ui.spacing().item_spacing;
+        ui.spacing_mut().item_spacing = Vec2::ZERO;
+        let mut stack = Stack {
+            ui,
+            gap,
+            divider,
+            item_spacing,
+            children: 0,
+            dividers: Vec::new(),
+        };
+        let inner = add_children(&mut stack);
+        stack.paint_dividers();
+        inner
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{BorderColor, Color, DivideWidth, Scale, SemanticColor, Spacing};
+
+    fn painted_rects(style: &Style) -> Vec<Rect> {
+        let ctx = egui::Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                stack(ui, style, |stack| {
+                    for _ in 0..3 {
+                        stack.add(|ui| {
+                            ui.allocate_exact_size(Vec2::new(40.0, 10.0), egui::Sense::hover())
+                        });
+                    }
+                });
+            });
+        });
+
+        output
+            .shapes
+            .into_iter()
+            .filter_map(|clipped| match clipped.shape {
+                Shape::Rect(rect) if rect.fill == crate::to_color32(Color::slate(Scale::S200)) => {
+                    Some(rect.rect)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stack_draws_dividers_between_spaced_children() {
+        let list = Style::new()
+            .space_y(Spacing::S2)
+            .divide_y(DivideWidth::S1)
+            .divide_color(Color::slate(Scale::S200));
+        let [first, second] = painted_rects(&list)[..] else {
+            panic!("expected a divider between each pair of children");
+        };
+
+        assert_eq!(first.height(), 1.0);
+        assert!(first.width() > 40.0);
+        // Gap, rule, then the next 10px child.
+        assert_eq!(second.min.y - first.min.y, 8.0 + 1.0 + 10.0);
+
+        let toolbar = Style::new()
+            .flex(twill_core::utilities::FlexContainer::row())
+            .space_x(Spacing::S1)
+            .divide_x(DivideWidth::S2)
+            .divide_color(Color::slate(Scale::S200));
+        let [first, second] = painted_rects(&toolbar)[..] else {
+            panic!("expected a divider between each pair of children");
+        };
+
+        assert_eq!(first.size(), Vec2::new(2.0, 10.0));
+        assert_eq!(second.min.x - first.min.x, 4.0 + 2.0 + 40.0);
+        assert!(painted_rects(&Style::new().space_y(Spacing::S2)).is_empty());
+    }
+
+    #[test]
+    fn test_stack_resolves_divide_color_in_semantic_theme() {
+        let list = Style::new()
+            .divide_y(DivideWidth::S1)
+            .divide_color_token(BorderColor::semantic(SemanticColor::Border));
+        let theme = SemanticThemeVars::shadcn_neutral();
+        let dark = crate::to_color32_value(
+            theme
+                .resolve_value(SemanticColor::Border, ThemeVariant::Dark)
+                .unwrap(),
+        );
+
+        let ctx = egui::Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                stack_with_semantic_theme(ui, &list, theme, ThemeVariant::Dark, |stack| {
+                    for _ in 0..2 {
+                        stack.add(|ui| ui.label("Row"));
+                    }
+                });
+            });
+        });
+
+        let fills: Vec<_> = output
+            .shapes
+            .into_iter()
+            .filter_map(|clipped| match clipped.shape {
+                Shape::Rect(rect) => Some(rect.fill),
+                _ => None,
+            })
+            .collect();
+        assert!(fills.contains(&dark));
+    }
+}
@@ -0,0 +1,290 @@
+//! Labels styled by typography utilities and laid out by `whitespace-*`, `break-*`,
+//! `line-clamp-*` and `truncate` utilities.
+
+use std::sync::Arc;
+
+use egui::{Align, Color32, FontId, FontSelection, RichText, TextWrapMode, Vec2, WidgetText};
+use twill_backend_common::{
+    display_text, letter_spacing_em, line_height_multiplier, text_layout, text_shadow_layers,
+};
+use twill_core::style::Style;
+use twill_core::tokens::{
+    FontFamily, FontWeight, TextAlign, TextDecoration, TextOverflow, WordBreak,
+};
+
+use crate::fonts::font_family;
+use crate::{resolve_text_color_token, to_color32_value};
+
+/// A label showing text with the typography and text layout of a [`Style`]; see
+/// [`styled_label`].
+pub struct StyledLabel {
+    text: String,
+    style: Style,
+}
+
+/// Create a [`StyledLabel`] showing `text` with the typography and text layout of
+/// `style`.
+///
+/// The text is built as a [`RichText`] from `font-sans`/`font-mono`, `text-<size>`,
+/// `leading-*` (or the line height of the font size), `tracking-*`, `text-<color>`,
+/// `underline`/`line-through`, `text-<align>` and `uppercase`/`lowercase`/`capitalize`.
+/// Fonts added with [`install_fonts`](crate::install_fonts) are used for `font-serif`
+/// and, with the face nearest to the weight, for `font-<weight>`. Without them
+/// `font-serif` uses the proportional font and `font-<weight>` is not applied, since
+/// egui fonts have no weights. `text-shadow-*` layers are painted unblurred under the
+/// text.
+///
+/// White space collapses like in CSS. `whitespace-nowrap` keeps the text on one line,
+/// cut at the edge of the `Ui` with an ellipsis under `truncate`/`text-ellipsis` or
+/// without one under `text-clip`. Wrapped text stops after `line-clamp-*` rows, ending
+/// in an ellipsis, and `break-all` breaks rows anywhere instead of between words:
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let eyebrow = Style::new()
+///     .text_size(FontSize::Xs)
+///     .tracking(LetterSpacing::Wider)
+///     .uppercase()
+///     .text_color(Color::slate(Scale::S500));
+///
+/// fn file_row(ui: &mut egui::Ui, eyebrow: &Style, name: &str, description: &str) {
+///     ui.add(twill_egui::styled_label(eyebrow, "Shared with you"));
+///     ui.add(twill_egui::styled_label(&Style::new().truncate(), name));
+///     ui.add(twill_egui::styled_label(
+///         &Style::new().line_clamp(2).break_words(),
+///         description,
+///     ));
+/// }
+/// # let _ = (eyebrow, file_row);
+/// ```
+pub fn styled_label(style: &Style, text: impl Into<String>) -> StyledLabel {
+    let text = text.into();
+    let text = match display_text(&text, style) {
+        std::borrow::Cow::Borrowed(_) => text,
+        std::borrow::Cow::Owned(displayed) => displayed,
+    };
+    StyledLabel {
+        text,
+        style: style.clone(),
+    }
+}
+
+impl StyledLabel {
+    /// The text as a [`RichText`] in the typography of the style.
+    fn rich_text(&self, ui: &egui::Ui) -> RichText {
+        let style = &self.style;
+        let body = FontSelection::Default.resolve(ui.style());
+        let size = style
+            .font_size_value()
+            .and_then(|size| size.resolve_px(&[]))
+            .unwrap_or(body.size);
+        let registered = |family: egui::FontFamily| {
+            ui.fonts(|fonts| fonts.definitions().families.contains_key(&family))
+                .then_some(family)
+        };
+        let weighted = style.font_weight_value().and_then(|weight| {
+            let family = style.font_family_value().unwrap_or(FontFamily::Sans);
+            registered(font_family(family, weight))
+        });
+        let family = match (weighted, style.font_family_value()) {
+            (Some(family), _) => family,
+            (None, None) => body.family,
+            (None, Some(FontFamily::Sans)) => egui::FontFamily::Proportional,
+            (None, Some(FontFamily::Mono)) => egui::FontFamily::Monospace,
+            (None, Some(FontFamily::Serif)) => {
+                registered(font_family(FontFamily::Serif, FontWeight::Normal))
+                    .unwrap_or(egui::FontFamily::Proportional)
+            }
+        };
+
+        let mut text = RichText::new(self.text.as_str()).font(FontId::new(size, family));
+        if let Some(multiplier) = line_height_multiplier(style) {
+            text = text.line_height(Some(size * multiplier));
+        }
+        if let Some(em) = style.letter_spacing_value().and_then(letter_spacing_em) {
+            text = text.extra_letter_spacing(em * size);
+        }
+        if let Some(color) = style
+            .text_color_token_value()
+            .and_then(resolve_text_color_token)
+        {
+            text = text.color(to_color32_value(color));
+        }
+        match style.text_decoration_value() {
+            Some(TextDecoration::Underline) => text.underline(),
+            Some(TextDecoration::LineThrough) => text.strikethrough(),
+            _ => text,
+        }
+    }
+}
+
+impl egui::Widget for StyledLabel {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let layout = text_layout(&self.style);
+        let mut job = Arc::unwrap_or_clone(WidgetText::from(self.rich_text(ui)).into_layout_job(
+            ui.style(),
+            FontSelection::Default,
+            ui.text_valign(),
+        ));
+        job.wrap.overflow_character = match layout.overflow {
+            Some(TextOverflow::Clip) => None,
+            _ => Some('…'),
+        };
+
+        let wrap_mode = if layout.wrap {
+            job.wrap.max_rows = layout.max_lines.unwrap_or(usize::MAX);
+            job.wrap.break_anywhere = layout.word_break == WordBreak::BreakAll;
+            TextWrapMode::Wrap
+        } else if layout.overflow.is_some() {
+            TextWrapMode::Truncate
+        } else {
+            TextWrapMode::Extend
+        };
+        let mut label = egui::Label::new(job).wrap_mode(wrap_mode);
+        if let Some(align) = self.style.text_align_value() {
+            label = label.halign(match align {
+                TextAlign::Center => Align::Center,
+                TextAlign::Right | TextAlign::End => Align::RIGHT,
+                TextAlign::Left | TextAlign::Start | TextAlign::Justify => Align::LEFT,
+            });
+        }
+
+        let shadow = self
+            .style
+            .text_shadow_value()
+            .map_or(&[][..], text_shadow_layers);
+        if shadow.is_empty() {
+            return label.ui(ui);
+        }
+
+        // egui labels paint their own text, so a shadowed label is painted here with its
+        // shadow layers under it, the first layer top-most like in CSS.
+        let (position, galley, response) = label.layout_in_ui(ui);
+        if ui.is_rect_visible(response.rect) {
+            let color = ui.visuals().text_color();
+            for &(offset, _, alpha) in shadow.iter().rev() {
+                ui.painter().galley_with_override_text_color(
+                    position + Vec2::new(0.0, offset),
+                    galley.clone(),
+                    Color32::from_black_alpha((alpha * 255.0).round() as u8),
+                );
+            }
+            ui.painter().galley(position, galley, color);
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{FontSize, LetterSpacing};
+
+    #[test]
+    fn test_styled_label_truncates_and_clamps_rows() {
+        let text = "quarterly report final version two with reviewer notes.xlsx";
+        let ctx = egui::Context::default();
+        let mut heights = Vec::new();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.allocate_ui(Vec2::new(80.0, 400.0), |ui| {
+                    for style in [
+                        Style::new().truncate(),
+                        Style::new().line_clamp(2),
+                        Style::new(),
+                    ] {
+                        let response = ui.add(styled_label(&style, text));
+                        assert!(response.rect.width() <= 80.0);
+                        heights.push(response.rect.height());
+                    }
+                });
+            });
+        });
+
+        let [one_row, two_rows, all_rows] = heights[..] else {
+            panic!("expected three labels");
+        };
+        assert!((two_rows - 2.0 * one_row).abs() < 1.0);
+        assert!(all_rows > two_rows);
+    }
+
+    #[test]
+    fn test_styled_label_builds_rich_text_from_typography() {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let heading = Style::new()
+                    .font(FontFamily::Mono)
+                    .text_size(FontSize::Xl)
+                    .tracking(LetterSpacing::Widest)
+                    .capitalize()
+                    .line_through();
+                let label = styled_label(&heading, "monthly  revenue");
+                let job = WidgetText::from(label.rich_text(ui)).into_layout_job(
+                    ui.style(),
+                    FontSelection::Default,
+                    Align::Min,
+                );
+
+                assert_eq!(job.text, "Monthly  Revenue");
+                let format = &job.sections[0].format;
+                assert_eq!(format.font_id, FontId::monospace(20.0));
+                assert_eq!(format.extra_letter_spacing, 2.0);
+                // `text-xl` brings its 28px line height.
+                assert_eq!(format.line_height, Some(28.0));
+                assert_ne!(format.strikethrough, egui::Stroke::NONE);
+
+                // `font-serif` falls back to the proportional font until one is installed.
+                let serif = styled_label(&Style::new().font(FontFamily::Serif), "Serif");
+                let job = WidgetText::from(serif.rich_text(ui)).into_layout_job(
+                    ui.style(),
+                    FontSelection::Default,
+                    Align::Min,
+                );
+                assert_eq!(
+                    job.sections[0].format.font_id.family,
+                    egui::FontFamily::Proportional
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_styled_label_uses_installed_font_weights() {
+        let mut definitions = egui::FontDefinitions::default();
+        let ubuntu = definitions.font_data["Ubuntu-Light"].font.to_vec();
+        let fonts = twill_core::tokens::FontRegistry::new().register(
+            FontFamily::Sans,
+            twill_core::tokens::FontFace::new("Ubuntu", ubuntu).weight(FontWeight::Bold),
+        );
+        crate::install_fonts(&fonts, &mut definitions).unwrap();
+
+        let ctx = egui::Context::default();
+        ctx.set_fonts(definitions);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let family = |style: &Style| {
+                    let label = styled_label(style, "Total");
+                    WidgetText::from(label.rich_text(ui))
+                        .into_layout_job(ui.style(), FontSelection::Default, Align::Min)
+                        .sections[0]
+                        .format
+                        .font_id
+                        .family
+                        .clone()
+                };
+
+                assert_eq!(
+                    family(&Style::new().font_semibold()),
+                    font_family(FontFamily::Sans, FontWeight::SemiBold)
+                );
+                // No mono faces are installed, so the weight is dropped.
+                assert_eq!(
+                    family(&Style::new().font(FontFamily::Mono).font_bold()),
+                    egui::FontFamily::Monospace
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,148 @@
+//! Painting `translate-*`, `rotate-*`, `scale-*` and `skew-*` transforms.
+
+use egui::emath::TSTransform;
+use egui::epaint::{Mesh, Tessellator};
+use egui::layers::{PaintList, ShapeIdx};
+use egui::{Context, LayerId, Pos2, Rect, Shape};
+use twill_core::utilities::{Transform, TransformOrigin};
+
+/// The index the next shape painted on `layer` gets.
+pub(crate) fn next_shape_idx(ctx: &Context, layer: LayerId) -> ShapeIdx {
+    ctx.graphics(|graphics| graphics.get(layer).map_or(ShapeIdx(0), PaintList::next_idx))
+}
+
+/// Move the shapes painted on `layer` since `start` by `transform` of an element at
+/// `rect`.
+///
+/// Translations and positive uniform scales move the shapes themselves, which keeps
+/// text sharp. Other transforms tessellate each shape into a mesh and map its vertices,
+/// so callbacks are left in place. Only what is painted moves: egui still lays out and
+/// hit-tests the element at `rect`.
+pub(crate) fn transform_shapes(
+    ctx: &Context,
+    layer: LayerId,
+    start: ShapeIdx,
+    transform: Transform,
+    origin: TransformOrigin,
+    rect: Rect,
+) {
+    let [a, b, c, d, e, f] =
+        transform.to_matrix(origin, rect.min.x, rect.min.y, rect.width(), rect.height());
+
+    if transform.is_translate_and_scale() && a > 0.0 {
+        let moved = TSTransform::new(egui::vec2(e, f), a);
+        if moved != TSTransform::IDENTITY {
+            ctx.graphics_mut(|graphics| {
+                let list = graphics.entry(layer);
+                let end = list.next_idx();
+                list.transform_range(start, end, moved);
+            });
+        }
+        return;
+    }
+
+    let map = |pos: Pos2| Pos2::new(a * pos.x + c * pos.y + e, b * pos.x + d * pos.y + f);
+    // Read before `graphics_mut`, which holds the context lock.
+    let mut tessellator = Tessellator::new(
+        ctx.pixels_per_point(),
+        ctx.tessellation_options(|options| *options),
+        ctx.fonts(|fonts| fonts.font_image_size()),
+        Vec::new(),
+    );
+    ctx.graphics_mut(|graphics| {
+        let list = graphics.entry(layer);
+        for idx in start.0..list.next_idx().0 {
+            list.mutate_shape(ShapeIdx(idx), |clipped| {
+                let shape = std::mem::replace(&mut clipped.shape, Shape::Noop);
+                clipped.shape = transformed(&mut tessellator, shape, &map);
+                let clip = clipped.clip_rect;
+                if clip.is_finite() {
+                    clipped.clip_rect = Rect::from_points(
+                        &[
+                            clip.left_top(),
+                            clip.right_top(),
+                            clip.left_bottom(),
+                            clip.right_bottom(),
+                        ]
+                        .map(map),
+                    );
+                }
+            });
+        }
+    });
+}
+
+fn transformed(tessellator: &mut Tessellator, shape: Shape, map: &impl Fn(Pos2) -> Pos2) -> Shape {
+    match shape {
+        Shape::Vec(shapes) => Shape::Vec(
+            shapes
+                .into_iter()
+                .map(|shape| transformed(tessellator, shape, map))
+                .collect(),
+        ),
+        Shape::Noop | Shape::Callback(_) => shape,
+        // One mesh per shape: the shapes of a list can use different textures.
+        shape => {
+            let mut mesh = Mesh::default();
+            tessellator.tessellate_shape(shape, &mut mesh);
+            for vertex in &mut mesh.vertices {
+                vertex.pos = map(vertex.pos);
+            }
+            Shape::mesh(mesh)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::prelude::core::*;
+
+    fn painted(style: &Style) -> Vec<Shape> {
+        let ctx = Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                crate::show_frame(ui, style, |ui| {
+                    ui.allocate_exact_size(egui::vec2(100.0, 40.0), egui::Sense::hover())
+                });
+            });
+        });
+        output
+            .shapes
+            .into_iter()
+            .map(|clipped| clipped.shape)
+            .filter(|shape| match shape {
+                Shape::Rect(rect) => rect.fill == crate::to_color32(Color::blue(Scale::S500)),
+                Shape::Mesh(_) => true,
+                _ => false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_transforms_move_the_painted_frame() {
+        let card = Style::new().bg(Color::blue(Scale::S500));
+        let plain = painted(&card);
+        let [Shape::Rect(plain)] = plain.as_slice() else {
+            panic!("expected the frame fill");
+        };
+        let plain = plain.rect;
+
+        let pressed = painted(&card.clone().scale(50));
+        let [Shape::Rect(pressed)] = pressed.as_slice() else {
+            panic!("uniform scales keep the shapes");
+        };
+        assert_eq!(pressed.rect.center(), plain.center());
+        assert!((pressed.rect.width() - plain.width() / 2.0).abs() < 1e-3);
+
+        let turned = painted(&card.rotate(90));
+        let [Shape::Mesh(mesh)] = turned.as_slice() else {
+            panic!("rotations are painted as meshes");
+        };
+        // Feathering adds half a pixel around the tessellated rectangle.
+        let bounds = mesh.calc_bounds().shrink(0.5);
+        assert!((bounds.width() - plain.height()).abs() < 1e-3);
+        assert!((bounds.height() - plain.width()).abs() < 1e-3);
+        assert!((bounds.center() - plain.center()).length() < 1e-3);
+    }
+}
@@ -0,0 +1,260 @@
+//! CSS-like transitions for frames painted from a resolved [`Style`].
+
+use twill_core::style::Style;
+use twill_core::tokens::{Easing, MotionDefaults};
+
+use crate::{
+    apply_opacity_to_color32, frame_with_opacity, resolved_opacity, to_duration, to_frame,
+};
+
+/// Frame painted at full opacity plus the opacity applied on top of it.
+#[derive(Clone, Copy, PartialEq)]
+struct FrameValues {
+    frame: egui::Frame,
+    opacity: f32,
+}
+
+impl FrameValues {
+    fn from_style(style: &Style) -> Self {
+        Self {
+            frame: frame_with_opacity(style, 1.0),
+            opacity: resolved_opacity(style),
+        }
+    }
+
+    fn to_frame(self) -> egui::Frame {
+        let mut frame = self.frame;
+        frame.fill = frame.fill.gamma_multiply(self.opacity);
+        frame.stroke.color = apply_opacity_to_color32(frame.stroke.color, self.opacity);
+        frame.shadow.color = apply_opacity_to_color32(frame.shadow.color, self.opacity);
+        frame
+    }
+}
+
+/// Which frame channels follow `transition-property`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Channels {
+    fill: bool,
+    border_color: bool,
+    border_width: bool,
+    radius: bool,
+    shadow: bool,
+    opacity: bool,
+}
+
+impl Channels {
+    fn from_style(style: &Style) -> Self {
+        Self {
+            fill: style.transitions_property("background-color"),
+            border_color: style.transitions_property("border-color"),
+            border_width: style.transitions_property("border-width"),
+            radius: style.transitions_property("border-radius"),
+            shadow: style.transitions_property("box-shadow"),
+            opacity: style.transitions_property("opacity"),
+        }
+    }
+
+    const fn any(self) -> bool {
+        self.fill
+            || self.border_color
+            || self.border_width
+            || self.radius
+            || self.shadow
+            || self.opacity
+    }
+}
+
+/// In-flight transition stored per widget in egui's temporary memory.
+#[derive(Clone, Copy)]
+struct FrameTransition {
+    from: FrameValues,
+    to: FrameValues,
+    channels: Channels,
+    easing: Easing,
+    start: f64,
+    duration: f64,
+}
+
+impl FrameTransition {
+    fn settled(values: FrameValues, now: f64) -> Self {
+        Self {
+            from: values,
+            to: values,
+            channels: Channels {
+                fill: false,
+                border_color: false,
+                border_width: false,
+                radius: false,
+                shadow: false,
+                opacity: false,
+            },
+            easing: Easing::Linear,
+            start: now,
+            duration: 0.0,
+        }
+    }
+
+    fn progress(&self, now: f64) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        ((now - self.start) / self.duration).clamp(0.0, 1.0) as f32
+    }
+
+    fn sample(&self, now: f64) -> FrameValues {
+        let t = self.easing.sample(self.progress(now));
+        if t >= 1.0 {
+            return self.to;
+        }
+
+        let (from, to, channels) = (self.from.frame, self.to.frame, self.channels);
+        let mut frame = to;
+        if channels.fill {
+            frame.fill = from.fill.lerp_to_gamma(to.fill, t);
+        }
+        if channels.border_color {
+            frame.stroke.color = from.stroke.color.lerp_to_gamma(to.stroke.color, t);
+        }
+        if channels.border_width {
+            frame.stroke.width = lerp(from.stroke.width, to.stroke.width, t);
+        }
+        if channels.radius {
+            frame.corner_radius = egui::CornerRadius {
+                nw: lerp_u8(from.corner_radius.nw, to.corner_radius.nw, t),
+                ne: lerp_u8(from.corner_radius.ne, to.corner_radius.ne, t),
+                sw: lerp_u8(from.corner_radius.sw, to.corner_radius.sw, t),
+                se: lerp_u8(from.corner_radius.se, to.corner_radius.se, t),
+            };
+        }
+        if channels.shadow {
+            frame.shadow = egui::epaint::Shadow {
+                offset: [
+                    lerp_i8(from.shadow.offset[0], to.shadow.offset[0], t),
+                    lerp_i8(from.shadow.offset[1], to.shadow.offset[1], t),
+                ],
+                blur: lerp_u8(from.shadow.blur, to.shadow.blur, t),
+                spread: lerp_u8(from.shadow.spread, to.shadow.spread, t),
+                color: from.shadow.color.lerp_to_gamma(to.shadow.color, t),
+            };
+        }
+        let opacity = if channels.opacity {
+            lerp(self.from.opacity, self.to.opacity, t)
+        } else {
+            self.to.opacity
+        };
+
+        FrameValues { frame, opacity }
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+fn lerp_u8(from: u8, to: u8, t: f32) -> u8 {
+    lerp(f32::from(from), f32::from(to), t).round() as u8
+}
+
+fn lerp_i8(from: i8, to: i8, t: f32) -> i8 {
+    lerp(f32::from(from), f32::from(to), t).round() as i8
+}
+
+/// Build the frame for `style`, animating from the frame last shown under `id`.
+///
+/// `style` should already be resolved for the current state (see
+/// [`Style::resolve_for`]). When its frame differs from the previous pass, the fill,
+/// border, corner radius, shadow and opacity channels named by `transition_property`
+/// are interpolated over `transition_duration` after `transition_delay`, eased with
+/// `transition_timing_function`. As with Tailwind's `transition` utilities, a
+/// property list without a duration uses [`MotionDefaults`]. Channels outside the list
+/// snap immediately, and a frame at rest is identical to [`to_frame`].
+///
+/// A repaint is requested while a transition is running.
+pub fn transition_frame(ctx: &egui::Context, id: egui::Id, style: &Style) -> egui::Frame {
+    let target = FrameValues::from_style(style);
+    let now = ctx.input(|input| input.time);
+    let previous = ctx.data(|data| data.get_temp::<FrameTransition>(id));
+
+    let channels = Channels::from_style(style);
+    let defaults = MotionDefaults::default();
+    let duration = style
+        .transition_duration_value()
+        .unwrap_or(defaults.duration());
+
+    let transition = match previous {
+        Some(previous) if previous.to == target => previous,
+        Some(previous) if channels.any() && duration.as_millis() > 0 => {
+            let delay = style
+                .transition_delay_value()
+                .map_or(0.0, |delay| to_duration(delay).as_secs_f64());
+            FrameTransition {
+                from: previous.sample(now),
+                to: target,
+                channels,
+                easing: style
+                    .transition_timing_function_value()
+                    .unwrap_or(defaults.easing()),
+                start: now + delay,
+                duration: to_duration(duration).as_secs_f64(),
+            }
+        }
+        _ => FrameTransition::settled(target, now),
+    };
+    ctx.data_mut(|data| data.insert_temp(id, transition));
+
+    if transition.progress(now) >= 1.0 {
+        return to_frame(style);
+    }
+    ctx.request_repaint();
+    transition.sample(now).to_frame()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{Color, Scale, TransitionDuration, TransitionProperty};
+
+    fn frame_at(ctx: &egui::Context, time: f64, style: &Style) -> egui::Frame {
+        let mut frame = egui::Frame::NONE;
+        let _ = ctx.run(
+            egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            },
+            |ctx| frame = transition_frame(ctx, egui::Id::new("card"), style),
+        );
+        frame
+    }
+
+    #[test]
+    fn test_transition_frame_interpolates_declared_properties() {
+        let ctx = egui::Context::default();
+        let base = Style::new()
+            .bg(Color::slate(Scale::S100))
+            .opacity(1.0)
+            .transition(TransitionProperty::Colors)
+            .transition_duration(TransitionDuration::Ms200)
+            .transition_ease(Easing::Linear);
+        let hovered = base.clone().bg(Color::slate(Scale::S900)).opacity(0.5);
+
+        assert_eq!(frame_at(&ctx, 0.0, &base), to_frame(&base));
+        let start = frame_at(&ctx, 1.0, &hovered);
+        let middle = frame_at(&ctx, 1.1, &hovered);
+        let end = frame_at(&ctx, 1.2, &hovered);
+
+        assert_eq!(start.fill, to_frame(&base).fill.gamma_multiply(0.5));
+        assert!(middle.fill.r() < start.fill.r());
+        assert!(middle.fill.r() > end.fill.r());
+        assert_eq!(end, to_frame(&hovered));
+    }
+
+    #[test]
+    fn test_transition_frame_snaps_without_transition() {
+        let ctx = egui::Context::default();
+        let base = Style::new().bg(Color::slate(Scale::S100));
+        let hovered = Style::new().bg(Color::slate(Scale::S900));
+
+        let _ = frame_at(&ctx, 0.0, &base);
+        assert_eq!(frame_at(&ctx, 0.01, &hovered), to_frame(&hovered));
+    }
+}
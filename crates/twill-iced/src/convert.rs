@@ -1,9 +1,11 @@
 use twill_backend_common::ShadowColor;
+use twill_core::style::Style;
 use twill_core::tokens::{
-    AspectRatio, Blur, BorderRadius, Color, ColorValue, Cursor, Easing, FontSize, FontWeight,
-    SemanticColor, Shadow, Spacing, TextAlign, ThemeVariant, TransitionDuration,
+    AspectRatio, Blur, BorderRadius, Color, ColorValue, Cursor, Easing, FontFamily, FontSize,
+    FontWeight, ResolvedGradient, SemanticColor, Shadow, Spacing, TextAlign, ThemeVariant,
+    TransitionDuration,
 };
-use twill_core::utilities::ObjectFit;
+use twill_core::utilities::{BorderRadii, ObjectFit};
 
 use super::widgets::SemanticThemeSource;
 
@@ -29,6 +31,10 @@ pub fn to_border_radius(radius: BorderRadius) -> f32 {
     super::widgets::to_border_radius(radius)
 }
 
+pub fn to_border_radii(radii: BorderRadii) -> iced_core::border::Radius {
+    super::widgets::to_border_radii(radii)
+}
+
 pub fn to_blur_radius(blur: Blur) -> f32 {
     super::widgets::to_blur_radius(blur)
 }
@@ -41,6 +47,17 @@ pub fn to_content_fit(fit: ObjectFit) -> iced_core::ContentFit {
     super::widgets::to_content_fit(fit)
 }
 
+pub fn to_gradient(
+    gradient: &ResolvedGradient,
+    size: iced_core::Size,
+) -> Option<iced_core::Gradient> {
+    super::widgets::to_gradient(gradient, size)
+}
+
+pub fn to_rotation(style: &Style) -> Option<iced_core::Rotation> {
+    super::widgets::to_rotation(style)
+}
+
 pub fn to_shadow_with_color(shadow: Shadow, color: ShadowColor) -> iced_core::Shadow {
     super::widgets::to_shadow_with_color(shadow, color)
 }
@@ -61,6 +78,10 @@ pub fn resolve_font_size(size: FontSize, custom_properties: &[(&str, f32)]) -> O
     super::widgets::resolve_font_size(size, custom_properties)
 }
 
+pub fn to_font_family(family: FontFamily) -> iced_core::font::Family {
+    super::widgets::to_font_family(family)
+}
+
 pub fn to_font_weight(weight: FontWeight) -> iced_core::font::Weight {
     super::widgets::to_font_weight(weight)
 }
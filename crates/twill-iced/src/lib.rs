@@ -5,25 +5,31 @@ mod widgets;
 
 use iced_core::ContentFit;
 use twill_core::tokens::{
-    AspectRatio, Blur, BorderRadius, Color, ColorValue, Cursor, Easing, FontSize, FontWeight,
-    Shadow, Spacing, TextAlign, TransitionDuration,
+    AspectRatio, Blur, BorderRadius, Color, ColorValue, Cursor, Easing, FontFamily, FontSize,
+    FontWeight, Shadow, Spacing, TextAlign, TransitionDuration,
 };
-use twill_core::utilities::ObjectFit;
+use twill_core::utilities::{BorderRadii, ObjectFit};
 
 pub use convert::{
-    TextDirection, resolve_font_size, to_aspect_ratio, to_blur_radius, to_border_radius, to_color,
-    to_color_value, to_content_fit, to_duration, to_easing, to_font_size, to_font_weight,
-    to_interaction, to_padding, to_semantic_color, to_semantic_color_with_theme, to_shadow,
+    TextDirection, resolve_font_size, to_aspect_ratio, to_blur_radius, to_border_radii,
+    to_border_radius, to_color, to_color_value, to_content_fit, to_duration, to_easing,
+    to_font_family, to_font_size, to_font_weight, to_gradient, to_interaction, to_padding,
+    to_rotation, to_semantic_color, to_semantic_color_with_theme, to_shadow,
     to_shadow_layers_with_color, to_shadow_with_color, to_text_alignment,
     to_text_alignment_with_direction,
 };
 pub use widgets::SemanticThemeSource;
 pub use widgets::{
+    Animation, BlurRenderer, PositionedStack, StyledGrid, StyledText, Transformed, TwillWidget,
     align_items_layout, apply_flex_item, apply_flex_item_with_custom_properties, apply_layout,
     apply_layout_with_custom_properties, apply_layout_with_semantic_theme, columns_layout,
-    flex_direction_layout, gap_layout, gap_x_layout, gap_y_layout, grid_template_columns_layout,
-    grid_template_columns_layout_with_context, justify_content_layout, styled_container,
-    styled_container_with_custom_properties, styled_container_with_semantic_theme,
+    flex_direction_layout, font_bytes, gap_layout, gap_x_layout, gap_y_layout,
+    grid_template_columns_layout, grid_template_columns_layout_with_context,
+    justify_content_layout, positioned_stack, responsive, responsive_with_context,
+    styled_container, styled_container_with_custom_properties,
+    styled_container_with_semantic_theme, styled_flex_layout,
+    styled_flex_layout_with_semantic_theme, styled_grid, styled_text, to_font, transformed,
+    twill_widget,
 };
 
 mod private {
@@ -84,6 +90,15 @@ impl ToIced for BorderRadius {
     }
 }
 
+impl private::Sealed for BorderRadii {}
+impl ToIced for BorderRadii {
+    type Output = iced_core::border::Radius;
+
+    fn to_iced(self) -> Self::Output {
+        to_border_radii(self)
+    }
+}
+
 impl private::Sealed for Blur {}
 impl ToIced for Blur {
     type Output = f32;
@@ -129,6 +144,15 @@ impl ToIced for FontSize {
     }
 }
 
+impl private::Sealed for FontFamily {}
+impl ToIced for FontFamily {
+    type Output = iced_core::font::Family;
+
+    fn to_iced(self) -> Self::Output {
+        to_font_family(self)
+    }
+}
+
 impl private::Sealed for FontWeight {}
 impl ToIced for FontWeight {
     type Output = iced_core::font::Weight;
@@ -1,31 +1,53 @@
+mod animation;
+mod blur;
 mod common;
 mod container;
 mod conversions;
+mod decorations;
+mod filters;
 mod flex;
+mod fonts;
+mod gradients;
 mod grid;
+mod interactive;
 mod layout;
+mod positioned;
 mod ratio_boxes;
+mod responsive;
+mod text;
+mod transform;
 
+pub use animation::Animation;
+pub use blur::BlurRenderer;
 pub use container::{
     styled_container, styled_container_with_custom_properties, styled_container_with_semantic_theme,
 };
 pub use conversions::{
-    SemanticThemeSource, resolve_font_size, to_aspect_ratio, to_blur_radius, to_border_radius,
-    to_color, to_color_value, to_content_fit, to_duration, to_easing, to_font_size, to_font_weight,
-    to_interaction, to_padding, to_semantic_color, to_semantic_color_with_theme, to_shadow,
-    to_shadow_layers_with_color, to_shadow_with_color, to_text_alignment,
-    to_text_alignment_with_direction,
+    SemanticThemeSource, resolve_font_size, to_aspect_ratio, to_blur_radius, to_border_radii,
+    to_border_radius, to_color, to_color_value, to_content_fit, to_duration, to_easing,
+    to_font_family, to_font_size, to_font_weight, to_interaction, to_padding, to_semantic_color,
+    to_semantic_color_with_theme, to_shadow, to_shadow_layers_with_color, to_shadow_with_color,
+    to_text_alignment, to_text_alignment_with_direction,
 };
 pub use flex::{
     align_items_layout, apply_flex_item, apply_flex_item_with_custom_properties,
     flex_direction_layout, gap_layout, gap_x_layout, gap_y_layout, justify_content_layout,
+    styled_flex_layout, styled_flex_layout_with_semantic_theme,
 };
+pub use fonts::{font_bytes, to_font};
+pub use gradients::to_gradient;
 pub use grid::{
-    columns_layout, grid_template_columns_layout, grid_template_columns_layout_with_context,
+    StyledGrid, columns_layout, grid_template_columns_layout,
+    grid_template_columns_layout_with_context, styled_grid,
 };
+pub use interactive::{TwillWidget, twill_widget};
 pub use layout::{
     apply_layout, apply_layout_with_custom_properties, apply_layout_with_semantic_theme,
 };
+pub use positioned::{PositionedStack, positioned_stack};
+pub use responsive::{responsive, responsive_with_context};
+pub use text::{StyledText, styled_text};
+pub use transform::{Transformed, to_rotation, transformed};
 
 #[cfg(test)]
 pub(crate) use common::{
@@ -40,11 +62,16 @@ pub(crate) use conversions::{
 };
 #[cfg(test)]
 pub(crate) use flex::{
-    column_alignment_for_items, gap_on_main_axis, is_reverse_direction, normalize_justify_content,
-    row_alignment_for_items,
+    Divider, column_alignment_for_items, divider_for_direction, gap_on_main_axis,
+    is_reverse_direction, normalize_justify_content, row_alignment_for_items, styled_main_axis_gap,
 };
 #[cfg(test)]
 pub(crate) use grid::{resolve_grid_template_track_count, track_count_from_template_value};
 
+#[cfg(test)]
+pub(crate) use text::{ellipsize, to_wrapping};
+#[cfg(test)]
+pub(crate) use transform::to_transformation;
+
 #[cfg(test)]
 mod tests;
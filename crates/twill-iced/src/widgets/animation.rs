@@ -0,0 +1,76 @@
+use iced_core::time::Instant;
+use twill_core::style::{Keyframes, Style};
+
+/// A running keyframe animation, kept in application state.
+///
+/// iced only redraws after messages, so an application subscribes to
+/// `iced::window::frames()` while [`Animation::is_running`], stores the instant of each
+/// frame and builds its view from [`Animation::apply`]. Spinners and skeleton pulses
+/// usually come from the `animate-*` token of their style:
+///
+/// ```rust
+/// use iced_core::time::{Duration, Instant};
+/// use twill_core::prelude::*;
+///
+/// let skeleton = Style::new()
+///     .h(Spacing::S4)
+///     .bg(Color::slate(Scale::S200))
+///     .animate(AnimationToken::Pulse);
+/// let start = Instant::now();
+/// let pulse = twill_iced::Animation::from_style(&skeleton, start).expect("pulse animates");
+///
+/// let now = start + Duration::from_secs(1);
+/// assert!(pulse.is_running(now));
+/// let frame: iced_widget::Container<'_, ()> =
+///     twill_iced::styled_container(iced_widget::space().into(), &pulse.apply(&skeleton, now));
+/// # let _ = frame;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    keyframes: Keyframes,
+    start: Instant,
+}
+
+impl Animation {
+    /// Start `keyframes` at `start`.
+    pub fn new(keyframes: Keyframes, start: Instant) -> Self {
+        Self { keyframes, start }
+    }
+
+    /// Start the animation of the `animate-*` token of `style` at `start`, or `None`
+    /// when the style has none.
+    pub fn from_style(style: &Style, start: Instant) -> Option<Self> {
+        style
+            .animation_value()
+            .and_then(|animation| animation.keyframes())
+            .map(|keyframes| Self::new(keyframes, start))
+    }
+
+    /// Play the animation again from `start`.
+    pub fn restart(&mut self, start: Instant) {
+        self.start = start;
+    }
+
+    pub fn keyframes(&self) -> &Keyframes {
+        &self.keyframes
+    }
+
+    /// Whether the animation still changes at `now`, so frames should keep coming.
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.keyframes.is_running(self.elapsed(now))
+    }
+
+    /// The animated values at `now`; see [`Keyframes::sample`].
+    pub fn sample(&self, now: Instant) -> Style {
+        self.keyframes.sample(self.elapsed(now))
+    }
+
+    /// `style` with the animated values at `now` merged on top.
+    pub fn apply(&self, style: &Style, now: Instant) -> Style {
+        style.clone().merged(self.sample(now))
+    }
+
+    fn elapsed(&self, now: Instant) -> std::time::Duration {
+        now.saturating_duration_since(self.start)
+    }
+}
@@ -0,0 +1,438 @@
+use iced_core::border::Radius;
+use iced_core::layout::{Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::widget::Tree;
+use iced_core::{Border, Color, Length, Rectangle, Size, Theme, Widget as AdvancedWidget, mouse};
+use iced_widget::Renderer;
+use iced_widget::renderer::fallback;
+
+use super::decorations::map_corners;
+
+/// Quads a blurred background is painted with when the renderer has no blur shader.
+const BLUR_LAYERS: usize = 16;
+/// Standard deviations a blurred edge fades over on each side.
+const BLUR_EXTENT: f32 = 2.5;
+
+/// A renderer that can fill a rounded box blurred like `filter: blur(<radius>)`.
+///
+/// iced's wgpu renderer computes the Gaussian edge in a shader. Every other renderer,
+/// tiny-skia included, uses the provided method, which stacks nested rounded quads
+/// whose alphas follow the same curve; a custom renderer gets that fallback from an
+/// empty `impl`.
+pub trait BlurRenderer: renderer::Renderer {
+    /// Fill `bounds`, rounded by `radius`, with `color` blurred by `sigma` pixels.
+    ///
+    /// The blurred edge spreads outside `bounds`. A shader blur is drawn above the quads
+    /// of the current layer, so draw what goes over it in a new layer.
+    fn fill_blurred(&mut self, bounds: Rectangle, radius: Radius, color: Color, sigma: f32) {
+        fill_blurred_quads(self, bounds, radius, color, sigma);
+    }
+}
+
+impl BlurRenderer for () {}
+
+impl<A: BlurRenderer, B: BlurRenderer> BlurRenderer for fallback::Renderer<A, B> {
+    fn fill_blurred(&mut self, bounds: Rectangle, radius: Radius, color: Color, sigma: f32) {
+        match self {
+            Self::Primary(renderer) => renderer.fill_blurred(bounds, radius, color, sigma),
+            Self::Secondary(renderer) => renderer.fill_blurred(bounds, radius, color, sigma),
+        }
+    }
+}
+
+#[cfg(feature = "tiny-skia")]
+impl BlurRenderer for iced_tiny_skia::Renderer {}
+
+#[cfg(feature = "wgpu")]
+impl BlurRenderer for iced_widget::renderer::wgpu::Renderer {
+    fn fill_blurred(&mut self, bounds: Rectangle, radius: Radius, color: Color, sigma: f32) {
+        use iced_widget::renderer::wgpu::primitive::Renderer as _;
+
+        // The shader samples three standard deviations, where the edge has faded out.
+        let spread = 3.0 * sigma;
+        let extent = bounds.expand(spread);
+        self.draw_primitive(
+            extent,
+            shader::BlurPrimitive::new(
+                extent.size(),
+                Rectangle::new(iced_core::Point::new(spread, spread), bounds.size()),
+                radius,
+                color,
+                sigma,
+            ),
+        );
+    }
+}
+
+/// Fill `bounds` with `color` blurred by `sigma` pixels from nested rounded quads, each
+/// growing the alpha accumulated inside it to the coverage at its edge.
+fn fill_blurred_quads<R: renderer::Renderer + ?Sized>(
+    renderer: &mut R,
+    bounds: Rectangle,
+    radius: Radius,
+    color: Color,
+    sigma: f32,
+) {
+    let extent = BLUR_EXTENT * sigma;
+    let step = 2.0 * extent / BLUR_LAYERS as f32;
+    let mut covered = 0.0;
+
+    for layer in 0..=BLUR_LAYERS {
+        // Signed distance of this quad's edge from the unblurred edge, outside first.
+        let distance = extent - step * layer as f32;
+        let target = if layer == BLUR_LAYERS {
+            color.a
+        } else {
+            color.a * normal_cdf(-(distance - step / 2.0) / sigma)
+        };
+        let layer_bounds = bounds.expand(distance);
+        if layer_bounds.width <= 0.0 || layer_bounds.height <= 0.0 || covered >= 1.0 {
+            break;
+        }
+        let alpha = 1.0 - (1.0 - target) / (1.0 - covered);
+        covered = target;
+        if alpha <= 0.0 {
+            continue;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layer_bounds,
+                border: Border {
+                    radius: map_corners(radius, |corner| (corner + distance).max(0.0)),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            Color { a: alpha, ..color },
+        );
+    }
+}
+
+/// Logistic approximation of the standard normal CDF, within 0.01 of it.
+fn normal_cdf(x: f32) -> f32 {
+    1.0 / (1.0 + (-1.702 * x).exp())
+}
+
+/// A background color under `blur-*`, blurred by `sigma` pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct BlurredBackground {
+    pub(super) color: Color,
+    pub(super) sigma: f32,
+}
+
+impl BlurredBackground {
+    pub(super) fn draw<R: BlurRenderer>(
+        &self,
+        renderer: &mut R,
+        bounds: Rectangle,
+        radius: Radius,
+    ) {
+        renderer.fill_blurred(bounds, radius, self.color, self.sigma);
+    }
+}
+
+/// The blurred background of a styled container, stacked under it so that the
+/// container, drawn in the next layer, stays above a shader blur.
+pub(super) struct BlurLayer {
+    background: BlurredBackground,
+    radius: Radius,
+}
+
+impl BlurLayer {
+    pub(super) fn new(background: BlurredBackground, radius: Radius) -> Self {
+        Self { background, radius }
+    }
+}
+
+impl<Message> AdvancedWidget<Message, Theme, Renderer> for BlurLayer {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.max())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.background.draw(renderer, layout.bounds(), self.radius);
+    }
+}
+
+impl<'a, Message: 'a> From<BlurLayer> for super::common::Element<'a, Message> {
+    fn from(layer: BlurLayer) -> Self {
+        Self::new(layer)
+    }
+}
+
+#[cfg(feature = "wgpu")]
+mod shader {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use iced_core::border::Radius;
+    use iced_core::{Color, Rectangle, Size};
+    use iced_widget::graphics::color;
+    use iced_widget::renderer::wgpu::wgpu;
+    use iced_widget::shader::{Pipeline, Primitive, Viewport};
+
+    /// Floats in the `Blur` uniform of `blur.wgsl`.
+    const UNIFORM_FLOATS: usize = 16;
+    const UNIFORM_SIZE: u64 = (UNIFORM_FLOATS * size_of::<f32>()) as u64;
+    /// Uniform slots the buffer starts with.
+    const INITIAL_SLOTS: u64 = 16;
+
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    /// A rounded box blurred by `blur.wgsl`, drawn over the whole primitive bounds.
+    #[derive(Debug)]
+    pub(crate) struct BlurPrimitive {
+        /// Tells the uniforms of primitives prepared in the same frame apart.
+        id: u64,
+        /// Size of the primitive bounds before transformations.
+        size: Size,
+        /// The unblurred box, relative to the primitive bounds.
+        rect: Rectangle,
+        radius: Radius,
+        color: Color,
+        sigma: f32,
+    }
+
+    impl BlurPrimitive {
+        pub(crate) fn new(
+            size: Size,
+            rect: Rectangle,
+            radius: Radius,
+            color: Color,
+            sigma: f32,
+        ) -> Self {
+            Self {
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                size,
+                rect,
+                radius,
+                color,
+                sigma,
+            }
+        }
+
+        /// The shader uniforms, in physical pixels `scale` times the logical ones.
+        pub(crate) fn uniforms(&self, scale: f32) -> [f32; UNIFORM_FLOATS] {
+            let [r, g, b, a] = color::pack(self.color).components();
+            let Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } = self.rect * scale;
+            let Radius {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } = self.radius;
+
+            [
+                r,
+                g,
+                b,
+                a,
+                x,
+                y,
+                x + width,
+                y + height,
+                top_left * scale,
+                top_right * scale,
+                bottom_right * scale,
+                bottom_left * scale,
+                self.size.width * scale,
+                self.size.height * scale,
+                self.sigma * scale,
+                0.0,
+            ]
+        }
+    }
+
+    impl Primitive for BlurPrimitive {
+        type Pipeline = BlurPipeline;
+
+        fn prepare(
+            &self,
+            pipeline: &mut Self::Pipeline,
+            device: &wgpu::Device,
+            queue: &wgpu::Queue,
+            bounds: &Rectangle,
+            viewport: &Viewport,
+        ) {
+            // `bounds` went through the transformations of the layer; `size` did not.
+            let scale = bounds.width / self.size.width * viewport.scale_factor();
+            pipeline.push(device, queue, self.id, &self.uniforms(scale));
+        }
+
+        fn draw(&self, pipeline: &Self::Pipeline, render_pass: &mut wgpu::RenderPass<'_>) -> bool {
+            if let Some(offset) = pipeline.offsets.get(&self.id) {
+                render_pass.set_pipeline(&pipeline.pipeline);
+                render_pass.set_bind_group(0, &pipeline.bind_group, &[*offset]);
+                render_pass.draw(0..3, 0..1);
+            }
+            true
+        }
+    }
+
+    /// The render pipeline of [`BlurPrimitive`], with one uniform slot per primitive
+    /// prepared in the current frame.
+    pub(crate) struct BlurPipeline {
+        pipeline: wgpu::RenderPipeline,
+        layout: wgpu::BindGroupLayout,
+        buffer: wgpu::Buffer,
+        bind_group: wgpu::BindGroup,
+        /// Bytes between slots, a multiple of the uniform offset alignment.
+        stride: u64,
+        /// Every slot written this frame, to refill the buffer when it grows.
+        uniforms: Vec<u8>,
+        offsets: HashMap<u64, u32>,
+    }
+
+    impl BlurPipeline {
+        fn push(
+            &mut self,
+            device: &wgpu::Device,
+            queue: &wgpu::Queue,
+            id: u64,
+            uniforms: &[f32; UNIFORM_FLOATS],
+        ) {
+            let offset = self.uniforms.len();
+            self.uniforms
+                .extend(uniforms.iter().flat_map(|value| value.to_le_bytes()));
+            self.uniforms.resize(offset + self.stride as usize, 0);
+
+            let end = self.uniforms.len() as u64;
+            if end > self.buffer.size() {
+                self.buffer = create_buffer(device, end.max(2 * self.buffer.size()));
+                self.bind_group = create_bind_group(device, &self.layout, &self.buffer);
+                queue.write_buffer(&self.buffer, 0, &self.uniforms);
+            } else {
+                queue.write_buffer(&self.buffer, offset as u64, &self.uniforms[offset..]);
+            }
+            let _ = self.offsets.insert(id, offset as u32);
+        }
+    }
+
+    impl Pipeline for BlurPipeline {
+        fn new(device: &wgpu::Device, _queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Self {
+            let stride = u64::from(device.limits().min_uniform_buffer_offset_alignment)
+                .max(UNIFORM_SIZE)
+                .next_multiple_of(UNIFORM_SIZE);
+
+            let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("twill_iced.blur.shader"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("blur.wgsl"))),
+            });
+
+            let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("twill_iced.blur.bind_group_layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(UNIFORM_SIZE),
+                    },
+                    count: None,
+                }],
+            });
+
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("twill_iced.blur.pipeline_layout"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+            let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("twill_iced.blur.pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            });
+
+            let buffer = create_buffer(device, INITIAL_SLOTS * stride);
+            let bind_group = create_bind_group(device, &layout, &buffer);
+
+            Self {
+                pipeline,
+                layout,
+                buffer,
+                bind_group,
+                stride,
+                uniforms: Vec::new(),
+                offsets: HashMap::new(),
+            }
+        }
+
+        fn trim(&mut self) {
+            self.uniforms.clear();
+            self.offsets.clear();
+        }
+    }
+
+    fn create_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("twill_iced.blur.uniforms"),
+            size,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("twill_iced.blur.bind_group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(UNIFORM_SIZE),
+                }),
+            }],
+        })
+    }
+}
+
+#[cfg(all(test, feature = "wgpu"))]
+pub(crate) use shader::BlurPrimitive;
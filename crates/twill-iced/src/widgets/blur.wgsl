@@ -0,0 +1,87 @@
+// A rounded box blurred by a Gaussian, after Evan Wallace's "Fast Rounded Rectangle
+// Shadows": the blur is exact across each row and sampled at four points down the
+// column.
+
+struct Blur {
+    // Straight alpha color, linear when iced corrects gamma.
+    color: vec4<f32>,
+    // Unblurred box as `min.xy, max.xy` inside the primitive bounds.
+    rect: vec4<f32>,
+    // Corner radii: top left, top right, bottom right, bottom left.
+    radii: vec4<f32>,
+    // Size of the primitive bounds.
+    size: vec2<f32>,
+    sigma: f32,
+}
+
+@group(0) @binding(0) var<uniform> blur: Blur;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) point: vec2<f32>,
+}
+
+// One triangle covering the viewport, which iced sets to the primitive bounds.
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.point = uv * blur.size;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let coverage = blurred_box(in.point);
+    return vec4<f32>(blur.color.rgb * blur.color.a, blur.color.a) * coverage;
+}
+
+fn blurred_box(point: vec2<f32>) -> f32 {
+    let center = (blur.rect.xy + blur.rect.zw) * 0.5;
+    let half_size = (blur.rect.zw - blur.rect.xy) * 0.5;
+    let p = point - center;
+
+    var corner: f32;
+    if p.y < 0.0 {
+        corner = select(blur.radii.y, blur.radii.x, p.x < 0.0);
+    } else {
+        corner = select(blur.radii.z, blur.radii.w, p.x < 0.0);
+    }
+    corner = min(corner, min(half_size.x, half_size.y));
+
+    let sigma = blur.sigma;
+    let start = clamp(-3.0 * sigma, p.y - half_size.y, p.y + half_size.y);
+    let end = clamp(3.0 * sigma, p.y - half_size.y, p.y + half_size.y);
+    let step = (end - start) / 4.0;
+
+    var y = start + step * 0.5;
+    var value = 0.0;
+    for (var i = 0; i < 4; i++) {
+        value += blurred_row(p.x, p.y - y, corner, half_size) * gaussian(y, sigma) * step;
+        y += step;
+    }
+    return value;
+}
+
+// Coverage of the row `y` of the box, blurred horizontally.
+fn blurred_row(x: f32, y: f32, corner: f32, half_size: vec2<f32>) -> f32 {
+    let delta = min(half_size.y - corner - abs(y), 0.0);
+    let curved = half_size.x - corner + sqrt(max(0.0, corner * corner - delta * delta));
+    let integral = 0.5 + 0.5 * erf((x + vec2<f32>(-curved, curved)) * (sqrt(0.5) / blur.sigma));
+    return integral.y - integral.x;
+}
+
+fn gaussian(x: f32, sigma: f32) -> f32 {
+    return exp(-(x * x) / (2.0 * sigma * sigma)) / (sqrt(2.0 * 3.14159265) * sigma);
+}
+
+// Abramowitz and Stegun's approximation, within 5e-4 of `erf`.
+fn erf(x: vec2<f32>) -> vec2<f32> {
+    let s = sign(x);
+    let a = abs(x);
+    var r = 1.0 + (0.278393 + (0.230389 + 0.078108 * (a * a)) * a) * a;
+    r = r * r;
+    return s - s / (r * r);
+}
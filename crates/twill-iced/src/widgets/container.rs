@@ -1,16 +1,22 @@
-use iced_core::{Point, Rectangle, Size, Theme, border, mouse};
-use iced_widget::{Renderer, canvas, stack};
+use iced_core::{Padding, Point, Rectangle, Size, Theme, border, mouse};
+use iced_widget::graphics::geometry;
+use iced_widget::{Renderer, Stack, canvas, stack};
 use twill_core::style::Style;
 use twill_core::tokens::BorderStyle;
 
-use super::common::{apply_opacity_to_color, apply_opacity_to_color_value, resolved_opacity};
+use super::blur::{BlurLayer, BlurredBackground};
+use super::common::{apply_opacity_to_color, resolved_opacity};
 use super::conversions::{
-    SemanticThemeSource, resolve_background_color_token_with_semantic_theme,
-    resolve_border_color_token_with_semantic_theme, resolve_shadow_color_token_with_semantic_theme,
-    resolve_text_color_token_with_semantic_theme, shadow_layers_with_color_value_and_opacity,
-    shadow_layers_with_opacity, to_border_radius, to_color_value, to_style_padding,
-    wrap_with_shadow_layers,
+    SemanticThemeSource, resolve_border_color_token_with_semantic_theme,
+    resolve_shadow_color_token_with_semantic_theme, resolve_text_color_token_with_semantic_theme,
+    resolved_border_radius, resolved_border_widths, shadow_layers_with_color_value_and_opacity,
+    shadow_layers_with_opacity, to_color_value, to_style_padding, wrap_with_shadow_layers,
 };
+use super::decorations::{Decorated, Decorations, inner_radius, map_corners};
+use super::filters::{
+    apply_filter_to_color, filter_shadow_layers, resolved_background, resolved_filter,
+};
+use super::transform::transformed;
 use twill_backend_common::ShadowColor;
 use twill_core::tokens::{SemanticThemeVars, ThemeVariant};
 
@@ -72,101 +78,200 @@ pub(super) fn styled_container_with_custom_properties_and_semantic_theme<
     semantic_theme: &S,
     variant: ThemeVariant,
 ) -> iced_widget::Container<'a, Message> {
-    let opacity = resolved_opacity(style);
     let padding = style
         .padding_value()
         .copied()
         .map(|padding| to_style_padding(padding, custom_properties));
 
-    let bg_color = style
-        .background_color_value()
-        .and_then(|bg| {
-            resolve_background_color_token_with_semantic_theme(
-                bg,
-                style.text_color_token_value().and_then(|text| {
-                    resolve_text_color_token_with_semantic_theme(text, semantic_theme, variant)
-                }),
-                semantic_theme,
-                variant,
+    let visuals = BoxVisuals::new(style, semantic_theme, variant);
+    let quad_border = visuals.has_quad_border();
+    let BoxVisuals {
+        background: bg_color,
+        border_style,
+        border_widths,
+        border_radius,
+        border_color,
+        shadows: shadow_layers,
+        decorations,
+        text_color,
+    } = visuals;
+    let blurred_background = decorations.blurred_background();
+
+    // iced quads draw solid borders of one width, so other borders are painted on a canvas.
+    let container = if quad_border {
+        let mut container = iced_widget::container(content);
+        if let Some(p) = padding {
+            container = container.padding(p);
+        }
+        if !decorations.is_empty() {
+            container = iced_widget::container(Decorated::new(
+                container,
+                decorations,
+                border_radius,
+                border_widths,
+            ));
+        }
+        let base = container.style(move |_| iced_widget::container::Style {
+            background: bg_color.map(iced_core::Background::Color),
+            border: iced_core::Border {
+                radius: border_radius,
+                width: border_widths.top,
+                color: border_color,
+            },
+            text_color,
+            ..Default::default()
+        });
+
+        let base = over_blurred_background(base.into(), blurred_background, border_radius);
+        wrap_with_shadow_layers(base, &shadow_layers, border_radius)
+    } else {
+        let mut content_layer = iced_widget::container(content);
+        if let Some(p) = padding {
+            content_layer = content_layer.padding(p);
+        }
+        let content_layer: super::common::Element<'a, Message> = if decorations.is_empty() {
+            content_layer.into()
+        } else {
+            Decorated::new(
+                content_layer
+                    .width(iced_core::Length::Fill)
+                    .height(iced_core::Length::Fill),
+                decorations,
+                border_radius,
+                border_widths,
             )
+            .into()
+        };
+
+        let border_layer = canvas(BorderCanvas {
+            border_style,
+            border_widths,
+            border_radius,
+            border_color,
+            background: bg_color,
         })
-        .map(|bg| apply_opacity_to_color_value(bg, opacity))
-        .map(to_color_value);
-    let base_border_width: f32 = style.border_width_value().map_or(0.0, |w| match w {
-        twill_core::tokens::BorderWidth::S0 => 0.0,
-        twill_core::tokens::BorderWidth::S1 => 1.0,
-        twill_core::tokens::BorderWidth::S2 => 2.0,
-        twill_core::tokens::BorderWidth::S4 => 4.0,
-        twill_core::tokens::BorderWidth::S8 => 8.0,
-    });
-    let border_radius = style.border_radius_value().map_or(0.0, to_border_radius);
-    let border_color = style
-        .border_color_token_value()
-        .and_then(|color| {
-            resolve_border_color_token_with_semantic_theme(color, semantic_theme, variant)
-        })
-        .map(to_color_value)
-        .map(|color| apply_opacity_to_color(color, opacity))
-        .unwrap_or(iced_core::Color::TRANSPARENT);
-    let border_style = style.border_style_value().unwrap_or(BorderStyle::Solid);
-    let border_width = base_border_width;
-    let shadow_layers = style
-        .box_shadow_value()
-        .map(|s| {
-            if let Some(shadow_color) = style.shadow_color_token_value().and_then(|color| {
-                resolve_shadow_color_token_with_semantic_theme(color, semantic_theme, variant)
-            }) {
-                shadow_layers_with_color_value_and_opacity(s, shadow_color, opacity)
-            } else {
-                shadow_layers_with_opacity(s, ShadowColor::Default, opacity)
-            }
-        })
-        .unwrap_or_default();
+        .width(iced_core::Length::Fill)
+        .height(iced_core::Length::Fill);
 
-    match border_style {
-        BorderStyle::Solid => {
-            let mut container = iced_widget::container(content);
-            if let Some(p) = padding {
-                container = container.padding(p);
-            }
-            let base = container.style(move |_| iced_widget::container::Style {
-                background: bg_color.map(iced_core::Background::Color),
-                border: iced_core::Border {
-                    radius: border_radius.into(),
-                    width: border_width,
-                    color: border_color,
-                },
+        let base = iced_widget::container(stack![border_layer, content_layer]).style(move |_| {
+            iced_widget::container::Style {
+                text_color,
                 ..Default::default()
-            });
-
-            wrap_with_shadow_layers(base.into(), &shadow_layers, border_radius)
-        }
-        _ => {
-            let mut content_layer = iced_widget::container(content);
-            if let Some(p) = padding {
-                content_layer = content_layer.padding(p);
             }
+        });
+        let base = over_blurred_background(base.into(), blurred_background, border_radius);
+        wrap_with_shadow_layers(base, &shadow_layers, border_radius)
+    };
 
-            let border_layer = canvas(BorderCanvas {
-                border_style,
-                border_width,
-                border_radius,
-                border_color,
-                background: bg_color,
-            })
-            .width(iced_core::Length::Fill)
-            .height(iced_core::Length::Fill);
+    // Transforms move what is drawn, shadows included, and keep the layout.
+    if style.transform_value().is_some() {
+        iced_widget::container(transformed(container, style))
+    } else {
+        container
+    }
+}
+
+/// Paint values of a resolved [`Style`], shared by [`styled_container`] and
+/// [`TwillWidget`](super::TwillWidget).
+///
+/// Colors go through the style's color filters and opacity. A blurred background is
+/// drawn by the decorations, so `background` is `None` under a `backdrop-blur-*`.
+pub(super) struct BoxVisuals {
+    pub(super) background: Option<iced_core::Color>,
+    pub(super) border_style: BorderStyle,
+    pub(super) border_widths: Padding,
+    pub(super) border_radius: border::Radius,
+    pub(super) border_color: iced_core::Color,
+    pub(super) shadows: Vec<iced_core::Shadow>,
+    pub(super) decorations: Decorations,
+    pub(super) text_color: Option<iced_core::Color>,
+}
+
+impl BoxVisuals {
+    pub(super) fn new<S: SemanticThemeSource + ?Sized>(
+        style: &Style,
+        semantic_theme: &S,
+        variant: ThemeVariant,
+    ) -> Self {
+        let opacity = resolved_opacity(style);
+        let filter = resolved_filter(style);
+        let paint = |color| apply_opacity_to_color(apply_filter_to_color(color, filter), opacity);
+        let decorations = Decorations::new(style, semantic_theme, variant);
 
-            let base = iced_widget::container(stack![border_layer, content_layer]);
-            wrap_with_shadow_layers(base.into(), &shadow_layers, border_radius)
+        Self {
+            background: resolved_background(style, semantic_theme, variant)
+                .filter(|_| !decorations.blurs_background()),
+            border_style: style.border_style_value().unwrap_or(BorderStyle::Solid),
+            border_widths: resolved_border_widths(style),
+            border_radius: resolved_border_radius(style),
+            border_color: style
+                .border_color_token_value()
+                .and_then(|color| {
+                    resolve_border_color_token_with_semantic_theme(color, semantic_theme, variant)
+                })
+                .map(to_color_value)
+                .map(paint)
+                .unwrap_or(iced_core::Color::TRANSPARENT),
+            shadows: style
+                .box_shadow_value()
+                .map(|shadow| {
+                    match style.shadow_color_token_value().and_then(|color| {
+                        resolve_shadow_color_token_with_semantic_theme(
+                            color,
+                            semantic_theme,
+                            variant,
+                        )
+                    }) {
+                        Some(color) => {
+                            shadow_layers_with_color_value_and_opacity(shadow, color, opacity)
+                        }
+                        None => shadow_layers_with_opacity(shadow, ShadowColor::Default, opacity),
+                    }
+                })
+                .map(|layers| filter_shadow_layers(layers, filter))
+                .unwrap_or_default(),
+            decorations,
+            text_color: style
+                .text_color_token_value()
+                .and_then(|text| {
+                    resolve_text_color_token_with_semantic_theme(text, semantic_theme, variant)
+                })
+                .map(to_color_value)
+                .map(paint),
         }
     }
+
+    /// Whether an iced quad can draw the border: iced quads have one width and no
+    /// dashes, so other borders are painted as geometry with [`paint_border`].
+    pub(super) fn has_quad_border(&self) -> bool {
+        self.border_style == BorderStyle::Solid && is_uniform(self.border_widths)
+    }
+}
+
+fn is_uniform(widths: Padding) -> bool {
+    widths.top == widths.right && widths.top == widths.bottom && widths.top == widths.left
+}
+
+/// Stack `base` over its blurred background, if any. The stack draws `base` in a layer
+/// of its own, so the border and content stay above a shader blur.
+fn over_blurred_background<'a, Message: 'a>(
+    base: super::common::Element<'a, Message>,
+    background: Option<BlurredBackground>,
+    radius: border::Radius,
+) -> super::common::Element<'a, Message> {
+    match background {
+        Some(background) => Stack::new()
+            .push(base)
+            .push_under(BlurLayer::new(background, radius))
+            .into(),
+        None => base,
+    }
 }
 
 struct BorderCanvas {
     border_style: BorderStyle,
-    border_width: f32,
-    border_radius: f32,
+    border_widths: Padding,
+    border_radius: border::Radius,
     border_color: iced_core::Color,
     background: Option<iced_core::Color>,
 }
@@ -187,86 +292,219 @@ impl<Message> canvas::Program<Message> for BorderCanvas {
         let fill_path = canvas::Path::rounded_rectangle(
             Point::ORIGIN,
             bounds.size(),
-            border::Radius::from(self.border_radius.max(0.0)),
+            map_corners(self.border_radius, |corner| corner.max(0.0)),
         );
         if let Some(bg) = self.background {
             frame.fill(&fill_path, bg);
         }
 
-        match self.border_style {
-            BorderStyle::None | BorderStyle::Hidden => {}
-            BorderStyle::Double => {
-                let outer = border_path(bounds.size(), self.border_width * 0.5, self.border_radius);
-                let inner = border_path(
-                    bounds.size(),
-                    self.border_width * 2.0,
-                    (self.border_radius - self.border_width).max(0.0),
-                );
-                frame.stroke(
-                    &outer,
-                    canvas::Stroke::default()
-                        .with_width(self.border_width.max(1.0))
-                        .with_color(self.border_color),
-                );
-                frame.stroke(
-                    &inner,
-                    canvas::Stroke::default()
-                        .with_width(self.border_width.max(1.0))
-                        .with_color(self.border_color),
-                );
-            }
-            BorderStyle::Solid => {
-                let path = border_path(bounds.size(), self.border_width * 0.5, self.border_radius);
-                frame.stroke(
-                    &path,
-                    canvas::Stroke::default()
-                        .with_width(self.border_width.max(1.0))
-                        .with_color(self.border_color),
-                );
-            }
-            BorderStyle::Dashed => {
-                let path = border_path(bounds.size(), self.border_width * 0.5, self.border_radius);
-                frame.stroke(
-                    &path,
-                    canvas::Stroke {
-                        line_dash: canvas::LineDash {
-                            segments: &[8.0, 5.0],
-                            offset: 0,
-                        },
-                        ..canvas::Stroke::default()
-                    }
-                    .with_width(self.border_width.max(1.0))
-                    .with_color(self.border_color),
-                );
-            }
-            BorderStyle::Dotted => {
-                let path = border_path(bounds.size(), self.border_width * 0.5, self.border_radius);
-                frame.stroke(
-                    &path,
-                    canvas::Stroke {
-                        line_cap: canvas::LineCap::Round,
-                        line_dash: canvas::LineDash {
-                            segments: &[1.0, 5.0],
-                            offset: 0,
-                        },
-                        ..canvas::Stroke::default()
-                    }
-                    .with_width(self.border_width.max(1.0))
-                    .with_color(self.border_color),
-                );
-            }
-        }
+        paint_border(
+            &mut frame,
+            bounds.size(),
+            self.border_style,
+            self.border_widths,
+            self.border_radius,
+            self.border_color,
+        );
 
         vec![frame.into_geometry()]
     }
 }
 
-fn border_path(size: Size, inset: f32, radius: f32) -> canvas::Path {
+/// Paint a border of `style` along the inside edge of a box `size` large: stroked when
+/// its sides are equal, side by side when they differ.
+pub(super) fn paint_border<R: geometry::Renderer>(
+    frame: &mut geometry::Frame<R>,
+    size: Size,
+    style: BorderStyle,
+    widths: Padding,
+    radius: border::Radius,
+    color: iced_core::Color,
+) {
+    if is_uniform(widths) {
+        stroke_border(frame, size, style, widths.top, radius, color);
+    } else {
+        paint_border_sides(frame, size, style, widths, radius, color);
+    }
+}
+
+/// Stroke a `width` wide border of `style` along the inside edge of a box `size` large.
+pub(super) fn stroke_border<R: geometry::Renderer>(
+    frame: &mut geometry::Frame<R>,
+    size: Size,
+    style: BorderStyle,
+    width: f32,
+    radius: border::Radius,
+    color: iced_core::Color,
+) {
+    match style {
+        BorderStyle::None | BorderStyle::Hidden => {}
+        BorderStyle::Double => {
+            let outer = border_path(size, width * 0.5, radius);
+            let inner = border_path(
+                size,
+                width * 2.0,
+                map_corners(radius, |corner| corner - width),
+            );
+            frame.stroke(
+                &outer,
+                canvas::Stroke::default()
+                    .with_width(width.max(1.0))
+                    .with_color(color),
+            );
+            frame.stroke(
+                &inner,
+                canvas::Stroke::default()
+                    .with_width(width.max(1.0))
+                    .with_color(color),
+            );
+        }
+        BorderStyle::Solid => {
+            let path = border_path(size, width * 0.5, radius);
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_width(width.max(1.0))
+                    .with_color(color),
+            );
+        }
+        BorderStyle::Dashed => {
+            let path = border_path(size, width * 0.5, radius);
+            frame.stroke(
+                &path,
+                canvas::Stroke {
+                    line_dash: canvas::LineDash {
+                        segments: &[8.0, 5.0],
+                        offset: 0,
+                    },
+                    ..canvas::Stroke::default()
+                }
+                .with_width(width.max(1.0))
+                .with_color(color),
+            );
+        }
+        BorderStyle::Dotted => {
+            let path = border_path(size, width * 0.5, radius);
+            frame.stroke(
+                &path,
+                canvas::Stroke {
+                    line_cap: canvas::LineCap::Round,
+                    line_dash: canvas::LineDash {
+                        segments: &[1.0, 5.0],
+                        offset: 0,
+                    },
+                    ..canvas::Stroke::default()
+                }
+                .with_width(width.max(1.0))
+                .with_color(color),
+            );
+        }
+    }
+}
+
+/// Paint a border whose sides have different `widths` along the inside edge of a box
+/// `size` large.
+///
+/// Solid and double borders fill the band between the outer and inner rounded edges;
+/// dashed and dotted sides are stroked as straight lines.
+fn paint_border_sides<R: geometry::Renderer>(
+    frame: &mut geometry::Frame<R>,
+    size: Size,
+    style: BorderStyle,
+    widths: Padding,
+    radius: border::Radius,
+    color: iced_core::Color,
+) {
+    let fill = canvas::Fill {
+        style: canvas::Style::Solid(color),
+        rule: canvas::fill::Rule::EvenOdd,
+    };
+    match style {
+        BorderStyle::None | BorderStyle::Hidden => {}
+        BorderStyle::Solid => frame.fill(&band_path(size, Padding::ZERO, widths, radius), fill),
+        BorderStyle::Double => {
+            let scaled = |factor: f32| Padding {
+                top: widths.top * factor,
+                right: widths.right * factor,
+                bottom: widths.bottom * factor,
+                left: widths.left * factor,
+            };
+            frame.fill(
+                &band_path(size, Padding::ZERO, scaled(1.0 / 3.0), radius),
+                fill,
+            );
+            frame.fill(&band_path(size, scaled(2.0 / 3.0), widths, radius), fill);
+        }
+        BorderStyle::Dashed | BorderStyle::Dotted => {
+            let (line_cap, segments): (_, &'static [f32]) = if style == BorderStyle::Dotted {
+                (canvas::LineCap::Round, &[1.0, 5.0])
+            } else {
+                (canvas::LineCap::default(), &[8.0, 5.0])
+            };
+            let sides = [
+                (
+                    widths.top,
+                    Point::new(0.0, widths.top * 0.5),
+                    Point::new(size.width, widths.top * 0.5),
+                ),
+                (
+                    widths.right,
+                    Point::new(size.width - widths.right * 0.5, 0.0),
+                    Point::new(size.width - widths.right * 0.5, size.height),
+                ),
+                (
+                    widths.bottom,
+                    Point::new(0.0, size.height - widths.bottom * 0.5),
+                    Point::new(size.width, size.height - widths.bottom * 0.5),
+                ),
+                (
+                    widths.left,
+                    Point::new(widths.left * 0.5, 0.0),
+                    Point::new(widths.left * 0.5, size.height),
+                ),
+            ];
+            for (width, from, to) in sides {
+                if width > 0.0 {
+                    frame.stroke(
+                        &canvas::Path::line(from, to),
+                        canvas::Stroke {
+                            line_cap,
+                            line_dash: canvas::LineDash {
+                                segments,
+                                offset: 0,
+                            },
+                            ..canvas::Stroke::default()
+                        }
+                        .with_width(width)
+                        .with_color(color),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The band between the box `size` large shrunk by `outer` and by `inner`, with
+/// corners following `radius`; fill it with the even-odd rule.
+fn band_path(size: Size, outer: Padding, inner: Padding, radius: border::Radius) -> canvas::Path {
+    canvas::Path::new(|builder| {
+        for sides in [outer, inner] {
+            let edge = Rectangle::with_size(size).shrink(sides);
+            builder.rounded_rectangle(
+                edge.position(),
+                Size::new(edge.width.max(0.0), edge.height.max(0.0)),
+                inner_radius(radius, sides),
+            );
+        }
+    })
+}
+
+fn border_path(size: Size, inset: f32, radius: border::Radius) -> canvas::Path {
     let width = (size.width - inset * 2.0).max(1.0);
     let height = (size.height - inset * 2.0).max(1.0);
     canvas::Path::rounded_rectangle(
         Point::new(inset, inset),
         Size::new(width, height),
-        border::Radius::from(radius.max(0.0)),
+        map_corners(radius, |corner| corner.max(0.0)),
     )
 }
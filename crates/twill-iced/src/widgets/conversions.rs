@@ -1,12 +1,14 @@
 use iced_core::{ContentFit, Length};
 use twill_backend_common::ShadowColor;
+use twill_core::style::Style;
 use twill_core::tokens::{
     AspectRatio, BackgroundColor, Blur, BorderColor, BorderRadius, Color, ColorValue, Cursor,
-    Easing, FontSize, FontWeight, Percentage, SemanticColor, SemanticThemeVars, Shadow,
-    ShadowColorToken, Spacing, TextAlign, TextColor, ThemeVariant, TransitionDuration,
+    Easing, FontFamily, FontSize, FontWeight, OutlineColor, Percentage, RingColor, SemanticColor,
+    SemanticThemeVars, Shadow, ShadowColorToken, Spacing, TextAlign, TextColor, ThemeVariant,
+    TransitionDuration,
 };
 use twill_core::traits::ComputeValue;
-use twill_core::utilities::{MarginValue, ObjectFit, PaddingValue};
+use twill_core::utilities::{BorderRadii, MarginValue, ObjectFit, PaddingValue};
 
 use super::common::{apply_opacity_to_color, container_to_px, spacing_to_px};
 pub use crate::convert::TextDirection;
@@ -59,6 +61,38 @@ pub(crate) fn resolve_border_color_token_with_semantic_theme<S: SemanticThemeSou
     }
 }
 
+pub(crate) fn resolve_outline_color_token_with_semantic_theme<S: SemanticThemeSource + ?Sized>(
+    token: OutlineColor,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<ColorValue> {
+    match token {
+        OutlineColor::Inherit => None,
+        OutlineColor::Current => None,
+        OutlineColor::Transparent => Some(ColorValue::TRANSPARENT),
+        OutlineColor::Palette(color) => Some(color.compute()),
+        OutlineColor::Semantic(color) => semantic_theme.resolve_value(color, variant),
+        OutlineColor::CustomProperty(_) => None,
+        OutlineColor::Arbitrary(value) => Some(value.into()),
+    }
+}
+
+pub(crate) fn resolve_ring_color_token_with_semantic_theme<S: SemanticThemeSource + ?Sized>(
+    token: RingColor,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<ColorValue> {
+    match token {
+        RingColor::Inherit => None,
+        RingColor::Current => None,
+        RingColor::Transparent => Some(ColorValue::TRANSPARENT),
+        RingColor::Palette(color) => Some(color.compute()),
+        RingColor::Semantic(color) => semantic_theme.resolve_value(color, variant),
+        RingColor::CustomProperty(_) => None,
+        RingColor::Arbitrary(value) => Some(value.into()),
+    }
+}
+
 pub(crate) fn resolve_shadow_color_token_with_semantic_theme<S: SemanticThemeSource + ?Sized>(
     token: ShadowColorToken,
     semantic_theme: &S,
@@ -253,25 +287,6 @@ pub(crate) enum ResolvedHeight {
     Ratio(f32),
 }
 
-fn percentage_to_ratio(value: Percentage) -> Option<f32> {
-    match value {
-        Percentage::S0 => Some(0.0),
-        Percentage::S1_2 | Percentage::S2_4 | Percentage::S3_6 => Some(0.5),
-        Percentage::S1_3 | Percentage::S2_6 => Some(1.0 / 3.0),
-        Percentage::S2_3 | Percentage::S4_6 => Some(2.0 / 3.0),
-        Percentage::S1_4 => Some(0.25),
-        Percentage::S3_4 => Some(0.75),
-        Percentage::S1_5 => Some(0.2),
-        Percentage::S2_5 => Some(0.4),
-        Percentage::S3_5 => Some(0.6),
-        Percentage::S4_5 => Some(0.8),
-        Percentage::S1_6 => Some(1.0 / 6.0),
-        Percentage::S5_6 => Some(5.0 / 6.0),
-        Percentage::Full => Some(1.0),
-        Percentage::Min | Percentage::Max | Percentage::Fit => None,
-    }
-}
-
 pub(crate) fn resolve_width(
     width: twill_core::utilities::Width,
     custom_properties: &[(&str, f32)],
@@ -289,7 +304,8 @@ pub(crate) fn resolve_width(
             ) {
                 Some(ResolvedWidth::Length(Length::Shrink))
             } else {
-                percentage_to_ratio(fraction)
+                fraction
+                    .to_ratio()
                     .map(|ratio| ResolvedWidth::Ratio(ratio.clamp(0.0, 1.0)))
             }
         }
@@ -339,7 +355,8 @@ pub(crate) fn resolve_height(
             ) {
                 Some(ResolvedHeight::Length(Length::Shrink))
             } else {
-                percentage_to_ratio(fraction)
+                fraction
+                    .to_ratio()
                     .map(|ratio| ResolvedHeight::Ratio(ratio.clamp(0.0, 1.0)))
             }
         }
@@ -391,6 +408,33 @@ pub fn to_border_radius(radius: BorderRadius) -> f32 {
     }
 }
 
+/// Convert twill per-corner radii to an iced border radius; unset corners are square.
+pub fn to_border_radii(radii: BorderRadii) -> iced_core::border::Radius {
+    let [top_left, top_right, bottom_right, bottom_left] = radii.to_px();
+    iced_core::border::Radius {
+        top_left,
+        top_right,
+        bottom_right,
+        bottom_left,
+    }
+}
+
+/// Corner radii of `style`, with `rounded-t-*`/`rounded-tl-*`... over `rounded-*`.
+pub(super) fn resolved_border_radius(style: &Style) -> iced_core::border::Radius {
+    to_border_radii(style.resolved_border_radii())
+}
+
+/// Side widths of `style`, with `border-t-*`/`border-x-*`... over `border-*`.
+pub(super) fn resolved_border_widths(style: &Style) -> iced_core::Padding {
+    let [top, right, bottom, left] = style.resolved_border_widths().to_px();
+    iced_core::Padding {
+        top,
+        right,
+        bottom,
+        left,
+    }
+}
+
 /// Convert twill Blur to iced blur radius (f32).
 pub fn to_blur_radius(blur: Blur) -> f32 {
     match blur {
@@ -588,7 +632,7 @@ pub(super) fn shadow_layers_with_color_value_and_opacity(
 pub(super) fn wrap_with_shadow_layers<'a, Message: 'a>(
     content: super::common::Element<'a, Message>,
     layers: &[iced_core::Shadow],
-    border_radius: f32,
+    border_radius: iced_core::border::Radius,
 ) -> iced_widget::Container<'a, Message> {
     let mut current = content;
 
@@ -596,7 +640,7 @@ pub(super) fn wrap_with_shadow_layers<'a, Message: 'a>(
         current = iced_widget::container(current)
             .style(move |_| iced_widget::container::Style {
                 border: iced_core::Border {
-                    radius: border_radius.into(),
+                    radius: border_radius,
                     width: 0.0,
                     color: iced_core::Color::TRANSPARENT,
                 },
@@ -624,6 +668,15 @@ pub fn resolve_font_size(size: FontSize, custom_properties: &[(&str, f32)]) -> O
     size.resolve_px(custom_properties)
 }
 
+/// Convert twill FontFamily to the matching generic iced font family.
+pub fn to_font_family(family: FontFamily) -> iced_core::font::Family {
+    match family {
+        FontFamily::Sans => iced_core::font::Family::SansSerif,
+        FontFamily::Serif => iced_core::font::Family::Serif,
+        FontFamily::Mono => iced_core::font::Family::Monospace,
+    }
+}
+
 /// Convert twill FontWeight to iced font Weight
 pub fn to_font_weight(weight: FontWeight) -> iced_core::font::Weight {
     match weight {
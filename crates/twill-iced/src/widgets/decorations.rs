@@ -0,0 +1,622 @@
+use std::f32::consts::PI;
+
+use iced_core::border::Radius;
+use iced_core::gradient::{Gradient, Linear};
+use iced_core::layout::{Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    Background, Border, Clipboard, Color, Event, Length, Padding, Rectangle, Shell, Size, Theme,
+    Vector, Widget as AdvancedWidget, mouse, overlay,
+};
+use iced_widget::Renderer;
+use iced_widget::graphics::geometry;
+use twill_core::style::Style;
+use twill_core::tokens::{
+    BorderStyle, Filter, InsetShadow, OutlineStyle, ResolvedGradient, RingWidth, ThemeVariant,
+};
+
+use super::blur::BlurredBackground;
+use super::common::apply_opacity_to_color;
+use super::container::stroke_border;
+use super::conversions::{
+    SemanticThemeSource, resolve_outline_color_token_with_semantic_theme,
+    resolve_ring_color_token_with_semantic_theme, resolve_text_color_token_with_semantic_theme,
+    to_color_value,
+};
+use super::filters::{apply_filter_to_color, resolved_background, resolved_filter};
+use super::gradients::{fill_gradient, resolved_gradient, to_gradient};
+
+/// Blurred background, background gradient, ring, outline and inset shadow of a
+/// [`Style`].
+///
+/// None of them take layout space: the ring and outline are drawn outside the border
+/// box, and the gradient and inset shadow inside the border. Colors left unresolved fall
+/// back to the current text color, like `currentColor` in CSS, and every color goes
+/// through the color filters of the style.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Decorations {
+    blurred_background: Option<BlurredBackground>,
+    gradient: Option<ResolvedGradient>,
+    ring: Option<Ring>,
+    outline: Option<Outline>,
+    inset_shadow: Option<InsetShadowLayer>,
+    text_color: Option<Color>,
+    filter: Filter,
+    opacity: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Ring {
+    width: f32,
+    inset: bool,
+    color: Option<Color>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Outline {
+    width: f32,
+    style: BorderStyle,
+    color: Option<Color>,
+}
+
+/// `(offset_y, blur, alpha)` of an inset shadow token.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct InsetShadowLayer {
+    offset_y: f32,
+    blur: f32,
+    alpha: f32,
+}
+
+impl Decorations {
+    pub(super) fn new<S: SemanticThemeSource + ?Sized>(
+        style: &Style,
+        semantic_theme: &S,
+        variant: ThemeVariant,
+    ) -> Self {
+        let ring = style.ring_width_value().and_then(|width| {
+            let (width, inset) = match width {
+                RingWidth::Inset => (1.0, true),
+                width => (f32::from(width.px_value().unwrap_or(0)), false),
+            };
+            (width > 0.0).then(|| Ring {
+                width,
+                inset,
+                color: style
+                    .ring_color_token_value()
+                    .and_then(|color| {
+                        resolve_ring_color_token_with_semantic_theme(color, semantic_theme, variant)
+                    })
+                    .map(to_color_value),
+            })
+        });
+
+        // `outline-<style>` alone keeps Tailwind's 1px default width, and `outline-<n>`
+        // alone draws solid.
+        let outline = match (style.outline_width_value(), style.outline_style_value()) {
+            (None, None) => None,
+            (width, outline_style) => {
+                let width = f32::from(width.map_or(1, |width| width.px_value()));
+                let outline_style = to_border_style(outline_style.unwrap_or(OutlineStyle::Solid));
+                let visible = !matches!(outline_style, BorderStyle::None | BorderStyle::Hidden);
+                (width > 0.0 && visible).then(|| Outline {
+                    width,
+                    style: outline_style,
+                    color: style
+                        .outline_color_token_value()
+                        .and_then(|color| {
+                            resolve_outline_color_token_with_semantic_theme(
+                                color,
+                                semantic_theme,
+                                variant,
+                            )
+                        })
+                        .map(to_color_value),
+                })
+            }
+        };
+
+        let blurred_background = style
+            .blur_value()
+            .map(|blur| f32::from(blur.radius_px()))
+            .filter(|sigma| *sigma > 0.0)
+            .and_then(|sigma| {
+                resolved_background(style, semantic_theme, variant)
+                    .map(|color| BlurredBackground { color, sigma })
+            });
+
+        Self {
+            blurred_background,
+            gradient: resolved_gradient(style, semantic_theme, variant),
+            ring,
+            outline,
+            inset_shadow: style.inset_shadow_value().and_then(inset_shadow_layer),
+            text_color: style
+                .text_color_token_value()
+                .and_then(|text| {
+                    resolve_text_color_token_with_semantic_theme(text, semantic_theme, variant)
+                })
+                .map(to_color_value),
+            filter: resolved_filter(style),
+            opacity: style.opacity_value().unwrap_or(1.0).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Whether nothing but the blurred background, which is drawn in a layer of its
+    /// own, is left to draw.
+    pub(super) fn is_empty(&self) -> bool {
+        self.gradient.is_none()
+            && self.ring.is_none()
+            && self.outline.is_none()
+            && self.inset_shadow.is_none()
+    }
+
+    /// Whether the background color is drawn blurred instead of by the container.
+    pub(super) fn blurs_background(&self) -> bool {
+        self.blurred_background.is_some()
+    }
+
+    /// The background color under `blur-*`, drawn below the border box and everything
+    /// in it.
+    pub(super) fn blurred_background(&self) -> Option<BlurredBackground> {
+        self.blurred_background
+    }
+
+    fn color(&self, color: Option<Color>, current_color: Color) -> Color {
+        self.paint(color.or(self.text_color).unwrap_or(current_color))
+    }
+
+    /// Apply the color filters and opacity to `color`.
+    fn paint(&self, color: Color) -> Color {
+        apply_opacity_to_color(apply_filter_to_color(color, self.filter), self.opacity)
+    }
+
+    /// Draw the layers below the content of a border box: the gradient, the ring, the
+    /// inset shadow and an inset ring.
+    ///
+    /// Linear gradients are filled as quads; radial and conic ones, which quads cannot
+    /// draw, are painted as geometry in thin bands and wedges.
+    pub(super) fn draw_under<R: geometry::Renderer>(
+        &self,
+        renderer: &mut R,
+        bounds: Rectangle,
+        radius: Radius,
+        border_widths: Padding,
+        current_color: Color,
+    ) {
+        if let Some(gradient) = &self.gradient {
+            let padding_box = bounds.shrink(border_widths);
+            let padding_radius = inner_radius(radius, border_widths);
+            match to_gradient(gradient, padding_box.size()) {
+                Some(linear) => renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: padding_box,
+                        border: Border {
+                            radius: padding_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Background::Gradient(linear),
+                ),
+                None => {
+                    let mut frame = geometry::Frame::new(renderer, padding_box.size());
+                    fill_gradient(&mut frame, padding_box.size(), padding_radius, gradient);
+                    renderer.with_translation(
+                        Vector::new(padding_box.x, padding_box.y),
+                        |renderer| {
+                            renderer.draw_geometry(frame.into_geometry());
+                        },
+                    );
+                }
+            }
+        }
+        self.draw_shadows_and_rings(renderer, bounds, radius, border_widths, current_color);
+    }
+
+    /// Draw the ring, the inset shadow and an inset ring.
+    fn draw_shadows_and_rings<R: renderer::Renderer>(
+        &self,
+        renderer: &mut R,
+        bounds: Rectangle,
+        radius: Radius,
+        border_widths: Padding,
+        current_color: Color,
+    ) {
+        let padding_box = bounds.shrink(border_widths);
+        let padding_radius = inner_radius(radius, border_widths);
+
+        if let Some(ring) = self.ring.filter(|ring| !ring.inset) {
+            stroke_quad(
+                renderer,
+                bounds.expand(ring.width),
+                grow_radius(radius, ring.width),
+                ring.width,
+                self.color(ring.color, current_color),
+            );
+        }
+
+        if let Some(shadow) = self.inset_shadow
+            && padding_box.height > 0.0
+        {
+            // The shadow of a box shifted down by `offset_y`, blurred over `blur`: fully
+            // shaded down to `offset_y - blur / 2`, fading out by `offset_y + blur / 2`.
+            let color = apply_opacity_to_color(
+                apply_filter_to_color(Color::BLACK, self.filter),
+                shadow.alpha * self.opacity,
+            );
+            let solid =
+                ((shadow.offset_y - shadow.blur / 2.0) / padding_box.height).clamp(0.0, 1.0);
+            let fade = ((shadow.offset_y + shadow.blur / 2.0) / padding_box.height).clamp(0.0, 1.0);
+            let gradient = Linear::new(PI)
+                .add_stop(0.0, color)
+                .add_stop(solid, color)
+                .add_stop(fade, Color::TRANSPARENT);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: padding_box,
+                    border: Border {
+                        radius: padding_radius,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Background::Gradient(Gradient::Linear(gradient)),
+            );
+        }
+
+        if let Some(ring) = self.ring.filter(|ring| ring.inset) {
+            stroke_quad(
+                renderer,
+                padding_box,
+                padding_radius,
+                ring.width,
+                self.color(ring.color, current_color),
+            );
+        }
+    }
+
+    /// Draw the outline above the content of a border box, stroking dashed and dotted
+    /// outlines as geometry like dashed borders.
+    pub(super) fn draw_outline<R: geometry::Renderer>(
+        &self,
+        renderer: &mut R,
+        bounds: Rectangle,
+        radius: Radius,
+        current_color: Color,
+    ) {
+        let Some(outline) = self
+            .outline
+            .filter(|outline| matches!(outline.style, BorderStyle::Dashed | BorderStyle::Dotted))
+        else {
+            self.draw_over(renderer, bounds, radius, current_color);
+            return;
+        };
+        let outer = bounds.expand(outline.width);
+        let mut frame = geometry::Frame::new(renderer, outer.size());
+        stroke_border(
+            &mut frame,
+            outer.size(),
+            outline.style,
+            outline.width,
+            grow_radius(radius, outline.width),
+            self.color(outline.color, current_color),
+        );
+
+        renderer.with_translation(Vector::new(outer.x, outer.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+
+    /// Draw the outline above the content of a border box with quads.
+    ///
+    /// Dashed and dotted outlines need geometry, so they are drawn solid here; see
+    /// [`Decorations::draw_outline`], which strokes them.
+    pub(super) fn draw_over<R: renderer::Renderer>(
+        &self,
+        renderer: &mut R,
+        bounds: Rectangle,
+        radius: Radius,
+        current_color: Color,
+    ) {
+        let Some(outline) = self.outline else {
+            return;
+        };
+        let color = self.color(outline.color, current_color);
+
+        if outline.style == BorderStyle::Double && outline.width >= 3.0 {
+            let line = outline.width / 3.0;
+            stroke_quad(
+                renderer,
+                bounds.expand(outline.width),
+                grow_radius(radius, outline.width),
+                line,
+                color,
+            );
+            stroke_quad(
+                renderer,
+                bounds.expand(line),
+                grow_radius(radius, line),
+                line,
+                color,
+            );
+        } else {
+            stroke_quad(
+                renderer,
+                bounds.expand(outline.width),
+                grow_radius(radius, outline.width),
+                outline.width,
+                color,
+            );
+        }
+    }
+}
+
+/// Draw a `width` wide border along the inside edge of `bounds`.
+fn stroke_quad<R: renderer::Renderer>(
+    renderer: &mut R,
+    bounds: Rectangle,
+    radius: Radius,
+    width: f32,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius,
+                width,
+                color,
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Apply `corner` to every corner of `radius`.
+pub(super) fn map_corners(radius: Radius, corner: impl Fn(f32) -> f32) -> Radius {
+    Radius {
+        top_left: corner(radius.top_left),
+        top_right: corner(radius.top_right),
+        bottom_right: corner(radius.bottom_right),
+        bottom_left: corner(radius.bottom_left),
+    }
+}
+
+/// Radius of an edge drawn `spread` outside `radius`; square corners stay square.
+fn grow_radius(radius: Radius, spread: f32) -> Radius {
+    map_corners(
+        radius,
+        |corner| {
+            if corner > 0.0 { corner + spread } else { 0.0 }
+        },
+    )
+}
+
+/// Radius of the edge `sides` inside `radius`, each corner shrinking by the wider of
+/// its two sides.
+pub(super) fn inner_radius(radius: Radius, sides: Padding) -> Radius {
+    Radius {
+        top_left: (radius.top_left - sides.top.max(sides.left)).max(0.0),
+        top_right: (radius.top_right - sides.top.max(sides.right)).max(0.0),
+        bottom_right: (radius.bottom_right - sides.bottom.max(sides.right)).max(0.0),
+        bottom_left: (radius.bottom_left - sides.bottom.max(sides.left)).max(0.0),
+    }
+}
+
+fn to_border_style(style: OutlineStyle) -> BorderStyle {
+    match style {
+        OutlineStyle::None => BorderStyle::None,
+        OutlineStyle::Solid => BorderStyle::Solid,
+        OutlineStyle::Dashed => BorderStyle::Dashed,
+        OutlineStyle::Dotted => BorderStyle::Dotted,
+        OutlineStyle::Double => BorderStyle::Double,
+        OutlineStyle::Hidden => BorderStyle::Hidden,
+    }
+}
+
+fn inset_shadow_layer(shadow: InsetShadow) -> Option<InsetShadowLayer> {
+    let (offset_y, blur, alpha) = match shadow {
+        InsetShadow::None => return None,
+        InsetShadow::Xs2 => (1.0, 0.0, 0.05),
+        InsetShadow::Xs => (1.0, 1.0, 0.05),
+        InsetShadow::Sm => (2.0, 4.0, 0.05),
+    };
+
+    Some(InsetShadowLayer {
+        offset_y,
+        blur,
+        alpha,
+    })
+}
+
+/// Content drawn inside a styled container, with the container's [`Decorations`]
+/// around it.
+///
+/// Its bounds are the container's border box, so the ring and outline are drawn
+/// outside them, and the gradient and inset shadow inside the border, between the
+/// container background and the content. Radial and conic gradients are painted as
+/// geometry and dashed and dotted outlines are stroked like dashed borders.
+pub(super) struct Decorated<'a, Message> {
+    content: super::common::Element<'a, Message>,
+    decorations: Decorations,
+    radius: Radius,
+    border_widths: Padding,
+}
+
+impl<'a, Message> Decorated<'a, Message> {
+    pub(super) fn new(
+        content: impl Into<super::common::Element<'a, Message>>,
+        decorations: Decorations,
+        radius: Radius,
+        border_widths: Padding,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            decorations,
+            radius,
+            border_widths,
+        }
+    }
+}
+
+impl<Message> AdvancedWidget<Message, Theme, Renderer> for Decorated<'_, Message> {
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let Some(child_state) = tree.children.first_mut() else {
+            return Node::new(Size::ZERO);
+        };
+        let node = self
+            .content
+            .as_widget_mut()
+            .layout(child_state, renderer, limits);
+
+        Node::with_children(node.size(), vec![node])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        self.decorations.draw_under(
+            renderer,
+            bounds,
+            self.radius,
+            self.border_widths,
+            style.text_color,
+        );
+
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first(), layout.children().next())
+        {
+            self.content.as_widget().draw(
+                child_state,
+                renderer,
+                theme,
+                style,
+                child_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        self.decorations
+            .draw_outline(renderer, bounds, self.radius, style.text_color);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            self.content.as_widget_mut().update(
+                child_state,
+                event,
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            self.content
+                .as_widget_mut()
+                .operate(child_state, child_layout, renderer, operation);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (tree.children.first(), layout.children().next()) {
+            (Some(child_state), Some(child_layout)) => self.content.as_widget().mouse_interaction(
+                child_state,
+                child_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: AdvancedLayout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        else {
+            return None;
+        };
+
+        self.content.as_widget_mut().overlay(
+            child_state,
+            child_layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message: 'a> From<Decorated<'a, Message>> for super::common::Element<'a, Message> {
+    fn from(decorated: Decorated<'a, Message>) -> Self {
+        Self::new(decorated)
+    }
+}
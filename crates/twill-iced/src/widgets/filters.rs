@@ -0,0 +1,61 @@
+use iced_core::{Color, Shadow};
+use twill_backend_common::frosted_alpha;
+use twill_core::style::Style;
+use twill_core::tokens::{Filter, ThemeVariant};
+
+use super::common::{apply_opacity_to_color_value, resolved_opacity};
+use super::conversions::{
+    SemanticThemeSource, resolve_background_color_token_with_semantic_theme,
+    resolve_text_color_token_with_semantic_theme, to_color_value,
+};
+
+/// Color filters of `style`, or the identity when it has none.
+pub(super) fn resolved_filter(style: &Style) -> Filter {
+    style.filter_value().unwrap_or_default()
+}
+
+pub(super) fn apply_filter_to_color(color: Color, filter: Filter) -> Color {
+    if filter.is_identity() {
+        return color;
+    }
+    let [r, g, b, a] = filter.apply_rgba([color.r, color.g, color.b, color.a]);
+    Color { r, g, b, a }
+}
+
+pub(super) fn filter_shadow_layers(mut layers: Vec<Shadow>, filter: Filter) -> Vec<Shadow> {
+    for layer in &mut layers {
+        layer.color = apply_filter_to_color(layer.color, filter);
+    }
+    layers
+}
+
+/// Resolve the background color of `style` with its color filters and opacity.
+///
+/// Neither renderer can read what is drawn behind a widget, so `backdrop-blur` makes
+/// the background more opaque instead (see [`frosted_alpha`]).
+pub(super) fn resolved_background<S: SemanticThemeSource + ?Sized>(
+    style: &Style,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<Color> {
+    let text = style.text_color_token_value().and_then(|text| {
+        resolve_text_color_token_with_semantic_theme(text, semantic_theme, variant)
+    });
+    let background = style.background_color_value().and_then(|background| {
+        resolve_background_color_token_with_semantic_theme(
+            background,
+            text,
+            semantic_theme,
+            variant,
+        )
+    })?;
+    let background = resolved_filter(style).apply(background);
+    let background = match style.backdrop_blur_value() {
+        Some(blur) => background.with_alpha(frosted_alpha(background.alpha(), blur)),
+        None => background,
+    };
+    Some(to_color_value(apply_opacity_to_color_value(
+        background,
+        resolved_opacity(style),
+    )))
+}
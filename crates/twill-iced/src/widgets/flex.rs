@@ -1,11 +1,12 @@
 use iced_core::Length;
 use twill_core::style::Style;
-use twill_core::tokens::Spacing;
+use twill_core::tokens::{SemanticThemeSource, SemanticThemeVars, Spacing, ThemeVariant};
 use twill_core::utilities::{
     AlignItems, Flex, FlexDirection, JustifyContent, MarginValue, PaddingValue,
 };
 
 use super::common::spacing_to_px;
+use super::conversions::{resolve_border_color_token_with_semantic_theme, to_color_value};
 use super::layout::apply_layout_with_custom_properties;
 
 pub(crate) fn is_reverse_direction(direction: FlexDirection) -> bool {
@@ -161,12 +162,111 @@ fn distribute_items_for_justify<'a, Message: Clone + 'a>(
     (distributed, needs_main_axis_fill)
 }
 
+/// A rule drawn between adjacent flex items (`divide-x-*` / `divide-y-*`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Divider {
+    pub(crate) width: f32,
+    /// `None` keeps the rule color of the iced theme.
+    pub(crate) color: Option<iced_core::Color>,
+}
+
+impl Divider {
+    /// Prefix `item` with the divider so it sits between the gap and the item, like the
+    /// leading border CSS puts on every child after the first.
+    fn before<'a, Message: 'a>(
+        self,
+        item: super::common::Element<'a, Message>,
+        direction: FlexDirection,
+        stretch: bool,
+    ) -> super::common::Element<'a, Message> {
+        // A shrinking cross axis makes the rule span the item instead of the parent.
+        let cross = if stretch {
+            Length::Fill
+        } else {
+            Length::Shrink
+        };
+
+        match direction {
+            FlexDirection::Row | FlexDirection::RowReverse => iced_widget::Row::new()
+                .push(self.rule(iced_widget::rule::vertical(self.width)))
+                .push(item)
+                .height(cross)
+                .into(),
+            FlexDirection::Col | FlexDirection::ColReverse => iced_widget::Column::new()
+                .push(self.rule(iced_widget::rule::horizontal(self.width)))
+                .push(item)
+                .width(cross)
+                .into(),
+        }
+    }
+
+    fn rule<'a>(self, rule: iced_widget::Rule<'a>) -> iced_widget::Rule<'a> {
+        match self.color {
+            Some(color) => rule.style(move |theme| iced_widget::rule::Style {
+                color,
+                ..iced_widget::rule::default(theme)
+            }),
+            None => rule,
+        }
+    }
+}
+
+/// The divider `style` draws between items laid out in `direction`, if any.
+///
+/// Rows read `divide-x-*` and columns `divide-y-*`; semantic colors resolve against
+/// `semantic_theme` in `variant`.
+pub(crate) fn divider_for_direction<S: SemanticThemeSource + ?Sized>(
+    style: &Style,
+    direction: FlexDirection,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<Divider> {
+    let width = match direction {
+        FlexDirection::Row | FlexDirection::RowReverse => style.divide_x_value(),
+        FlexDirection::Col | FlexDirection::ColReverse => style.divide_y_value(),
+    }?
+    .px_value();
+    if width == 0 {
+        return None;
+    }
+
+    let color = style.divide_color_token_value().and_then(|color| {
+        resolve_border_color_token_with_semantic_theme(color, semantic_theme, variant)
+            .map(to_color_value)
+    });
+
+    Some(Divider {
+        width: f32::from(width),
+        color,
+    })
+}
+
+/// Main-axis spacing of a styled flex layout: `space-x-*` for rows and `space-y-*` for
+/// columns, falling back to the flex container gaps.
+pub(crate) fn styled_main_axis_gap(style: &Style, direction: FlexDirection) -> Spacing {
+    let space = match direction {
+        FlexDirection::Row | FlexDirection::RowReverse => style.space_x_value(),
+        FlexDirection::Col | FlexDirection::ColReverse => style.space_y_value(),
+    };
+    let flex = style.flex_container();
+
+    space.unwrap_or_else(|| {
+        gap_on_main_axis(
+            direction,
+            flex.and_then(|flex| flex.gap_value()),
+            flex.and_then(|flex| flex.row_gap_value()),
+            flex.and_then(|flex| flex.col_gap_value()),
+        )
+    })
+}
+
 fn flex_layout<'a, Message: Clone + 'a>(
     mut items: Vec<super::common::Element<'a, Message>>,
     direction: FlexDirection,
     gap: Spacing,
     align_items: Option<AlignItems>,
     justify_content: Option<JustifyContent>,
+    divider: Option<Divider>,
 ) -> super::common::Element<'a, Message> {
     if is_reverse_direction(direction) {
         items.reverse();
@@ -201,6 +301,29 @@ fn flex_layout<'a, Message: Clone + 'a>(
             .collect();
     }
 
+    if let Some(divider) = divider {
+        let stretch = match direction {
+            FlexDirection::Row | FlexDirection::RowReverse => {
+                matches!(align_items, Some(AlignItems::Stretch))
+            }
+            // Columns already span the full width, so dividers do too unless items align.
+            FlexDirection::Col | FlexDirection::ColReverse => {
+                matches!(align_items, None | Some(AlignItems::Stretch))
+            }
+        };
+        items = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                if index == 0 {
+                    item
+                } else {
+                    divider.before(item, direction, stretch)
+                }
+            })
+            .collect();
+    }
+
     let (items, needs_main_axis_fill) =
         distribute_items_for_justify(items, direction, justify_content);
 
@@ -250,21 +373,25 @@ pub(crate) fn gap_on_main_axis(
 }
 
 /// Create an iced flex layout for a given direction.
+///
+/// Use [`styled_flex_layout`] to take the gap, alignment and dividers from a [`Style`].
 pub fn flex_direction_layout<'a, Message: Clone + 'a>(
     items: Vec<super::common::Element<'a, Message>>,
     direction: FlexDirection,
     gap: Spacing,
 ) -> super::common::Element<'a, Message> {
-    flex_layout(items, direction, gap, None, None)
+    flex_layout(items, direction, gap, None, None, None)
 }
 
 /// Create an iced layout for `gap-*` utilities.
+///
+/// See [`styled_flex_layout`] for `space-x-*`/`space-y-*` and `divide-*` utilities.
 pub fn gap_layout<'a, Message: Clone + 'a>(
     items: Vec<super::common::Element<'a, Message>>,
     direction: FlexDirection,
     gap: Spacing,
 ) -> super::common::Element<'a, Message> {
-    flex_layout(items, direction, gap, None, None)
+    flex_layout(items, direction, gap, None, None, None)
 }
 
 /// Create an iced layout for `gap-x-*` utilities.
@@ -276,7 +403,7 @@ pub fn gap_x_layout<'a, Message: Clone + 'a>(
     gap_x: Spacing,
 ) -> super::common::Element<'a, Message> {
     let gap = gap_on_main_axis(direction, None, None, Some(gap_x));
-    flex_layout(items, direction, gap, None, None)
+    flex_layout(items, direction, gap, None, None, None)
 }
 
 /// Create an iced layout for `gap-y-*` utilities.
@@ -288,7 +415,7 @@ pub fn gap_y_layout<'a, Message: Clone + 'a>(
     gap_y: Spacing,
 ) -> super::common::Element<'a, Message> {
     let gap = gap_on_main_axis(direction, None, Some(gap_y), None);
-    flex_layout(items, direction, gap, None, None)
+    flex_layout(items, direction, gap, None, None, None)
 }
 
 /// Create an iced flex layout for a typed align-items value.
@@ -298,7 +425,7 @@ pub fn align_items_layout<'a, Message: Clone + 'a>(
     gap: Spacing,
     align_items: AlignItems,
 ) -> super::common::Element<'a, Message> {
-    flex_layout(items, direction, gap, Some(align_items), None)
+    flex_layout(items, direction, gap, Some(align_items), None, None)
 }
 
 /// Create an iced flex layout for a typed justify-content value.
@@ -308,7 +435,70 @@ pub fn justify_content_layout<'a, Message: Clone + 'a>(
     gap: Spacing,
     justify_content: JustifyContent,
 ) -> super::common::Element<'a, Message> {
-    flex_layout(items, direction, gap, None, Some(justify_content))
+    flex_layout(items, direction, gap, None, Some(justify_content), None)
+}
+
+/// Create an iced flex layout from the container utilities of `style`.
+///
+/// The main-axis spacing comes from `space-x-*` (rows) or `space-y-*` (columns), falling
+/// back to the flex `gap-*` values, and `items-*`/`justify-*` come from the flex
+/// container. `divide-x-*` (rows) or `divide-y-*` (columns) draws a rule in
+/// `divide-<color>` before every item but the first, between the gap and the item. In
+/// rows the rule spans the height of the item it precedes unless items stretch.
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let menu = Style::new()
+///     .space_y(Spacing::S1)
+///     .divide_y(DivideWidth::S1)
+///     .divide_color(Color::slate(Scale::S200));
+///
+/// let list: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::styled_flex_layout(
+///         vec![iced_widget::text("Edit").into(), iced_widget::text("Delete").into()],
+///         FlexDirection::Col,
+///         &menu,
+///     );
+/// # let _ = list;
+/// ```
+pub fn styled_flex_layout<'a, Message: Clone + 'a>(
+    items: Vec<super::common::Element<'a, Message>>,
+    direction: FlexDirection,
+    style: &Style,
+) -> super::common::Element<'a, Message> {
+    styled_flex_layout_with_semantic_theme(
+        items,
+        direction,
+        style,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    )
+}
+
+/// Create an iced flex layout like [`styled_flex_layout`], resolving a semantic
+/// `divide-<color>` against `semantic_theme` in `variant`.
+pub fn styled_flex_layout_with_semantic_theme<
+    'a,
+    Message: Clone + 'a,
+    S: SemanticThemeSource + ?Sized,
+>(
+    items: Vec<super::common::Element<'a, Message>>,
+    direction: FlexDirection,
+    style: &Style,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> super::common::Element<'a, Message> {
+    let flex = style.flex_container();
+
+    flex_layout(
+        items,
+        direction,
+        styled_main_axis_gap(style, direction),
+        flex.and_then(|flex| flex.align_value()),
+        flex.and_then(|flex| flex.justify_value()),
+        divider_for_direction(style, direction, semantic_theme, variant),
+    )
 }
 
 fn fraction_to_portion(numerator: u16, denominator: u16) -> u16 {
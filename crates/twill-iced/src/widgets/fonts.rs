@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use iced_core::Font;
+use iced_core::font::{Family, Style};
+use twill_core::tokens::{FontLoadError, FontRegistry, FontWeight, RegisteredFamily};
+
+use super::conversions::to_font_weight;
+
+/// The data of every face in `fonts`, for `iced::Settings::fonts` or `iced::font::load`.
+///
+/// ```rust,no_run
+/// use twill_core::prelude::*;
+///
+/// let fonts = FontRegistry::new()
+///     .register(FontFamily::Sans, FontFace::new("Inter", FontSource::path("fonts/Inter-Regular.ttf")))
+///     .register(
+///         FontFamily::Sans,
+///         FontFace::new("Inter", FontSource::path("fonts/Inter-Bold.ttf")).weight(FontWeight::Bold),
+///     );
+///
+/// // iced::application(...).settings(iced::Settings { fonts: ..., ..Default::default() })
+/// let data = twill_iced::font_bytes(&fonts)?;
+/// # let _ = data;
+/// # Ok::<(), twill_core::tokens::FontLoadError>(())
+/// ```
+pub fn font_bytes(fonts: &FontRegistry) -> Result<Vec<Cow<'static, [u8]>>, FontLoadError> {
+    fonts
+        .families()
+        .flat_map(|(_, faces)| faces)
+        .map(|face| face.source().load())
+        .collect()
+}
+
+/// The iced [`Font`] of the face of `family` nearest to `weight` and `italic`, once the
+/// data from [`font_bytes`] is loaded.
+///
+/// The font is looked up by the name of the face, with the weight of the face rather
+/// than the requested one, so iced does not synthesize a different weight.
+pub fn to_font(
+    fonts: &FontRegistry,
+    family: impl Into<RegisteredFamily>,
+    weight: FontWeight,
+    italic: bool,
+) -> Option<Font> {
+    let face = fonts.resolve(family, weight, italic)?;
+    Some(Font {
+        family: Family::Name(face.name()),
+        weight: to_font_weight(face.weight_value()),
+        style: if face.is_italic() {
+            Style::Italic
+        } else {
+            Style::Normal
+        },
+        ..Font::DEFAULT
+    })
+}
@@ -0,0 +1,194 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use iced_core::border::Radius;
+use iced_core::gradient::Linear;
+use iced_core::{Point, Radians, Size, Vector};
+use iced_widget::canvas;
+use iced_widget::graphics::geometry;
+use twill_core::style::Style;
+use twill_core::tokens::{GradientKind, ResolvedGradient, ThemeVariant};
+
+use super::common::{apply_opacity_to_color_value, resolved_opacity};
+use super::conversions::{
+    SemanticThemeSource, resolve_background_color_token_with_semantic_theme,
+    resolve_text_color_token_with_semantic_theme, to_color_value,
+};
+use super::filters::resolved_filter;
+
+/// Color stops an iced gradient holds.
+const MAX_STOPS: usize = 8;
+/// Segments per straight edge and per rounded corner of the outline that radial and
+/// conic gradients are painted in.
+const EDGE_SEGMENTS: usize = 24;
+const CORNER_SEGMENTS: usize = 8;
+/// Bands a radial gradient is painted in.
+const RADIAL_BANDS: usize = 48;
+
+/// Convert a linear gradient into an iced gradient for a box `size` large, or `None`
+/// for radial and conic gradients, which iced cannot express.
+///
+/// iced ends its gradient line at the box edges rather than past the corners like CSS,
+/// so the stops are resampled along the iced line.
+pub fn to_gradient(gradient: &ResolvedGradient, size: Size) -> Option<iced_core::Gradient> {
+    let GradientKind::Linear(direction) = gradient.kind() else {
+        return None;
+    };
+    let angle = direction.degrees(size.width, size.height).to_radians();
+    let (sin, cos) = angle.sin_cos();
+    let css_length = size.width * sin.abs() + size.height * cos.abs();
+    let iced_length = (size.width * sin.abs()).max(size.height * cos.abs());
+
+    let linear = (0..MAX_STOPS).fold(Linear::new(Radians(angle)), |linear, index| {
+        let offset = index as f32 / (MAX_STOPS - 1) as f32;
+        let css_offset = if css_length > 0.0 {
+            (offset - 0.5) * iced_length / css_length + 0.5
+        } else {
+            offset
+        };
+        linear.add_stop(offset, to_color_value(gradient.color_at(css_offset)))
+    });
+    Some(iced_core::Gradient::Linear(linear))
+}
+
+/// Resolve the background gradient of `style` with its color filters and opacity.
+pub(super) fn resolved_gradient<S: SemanticThemeSource + ?Sized>(
+    style: &Style,
+    semantic_theme: &S,
+    variant: ThemeVariant,
+) -> Option<ResolvedGradient> {
+    let opacity = resolved_opacity(style);
+    let filter = resolved_filter(style);
+    let text = style.text_color_token_value().and_then(|text| {
+        resolve_text_color_token_with_semantic_theme(text, semantic_theme, variant)
+    });
+    style
+        .background_gradient_value()?
+        .resolve(|color| {
+            resolve_background_color_token_with_semantic_theme(color, text, semantic_theme, variant)
+        })
+        .map(|gradient| {
+            gradient.map_colors(|color| apply_opacity_to_color_value(filter.apply(color), opacity))
+        })
+}
+
+/// Paint a radial or conic gradient, which quads cannot draw, into a box `size` large with `radius` corners.
+///
+/// The box is split into thin bands (radial) or wedges (conic) along its rounded
+/// outline, each filled with the color at its middle.
+pub(super) fn fill_gradient<R: geometry::Renderer>(
+    frame: &mut geometry::Frame<R>,
+    size: Size,
+    radius: Radius,
+    gradient: &ResolvedGradient,
+) {
+    let center = Point::new(size.width / 2.0, size.height / 2.0);
+    let outline = outline(size, radius);
+
+    match gradient.kind() {
+        // Quads draw linear gradients, see `to_gradient`.
+        GradientKind::Linear(_) => {}
+        GradientKind::Radial => {
+            // `farthest-corner` ellipse, whose radius along each outline direction
+            // is where the gradient reaches its last stop.
+            let (rx, ry) = (
+                size.width * std::f32::consts::FRAC_1_SQRT_2,
+                size.height * std::f32::consts::FRAC_1_SQRT_2,
+            );
+            if rx <= 0.0 || ry <= 0.0 {
+                return;
+            }
+            let ring = |offset: f32| -> Vec<Vector> {
+                outline
+                    .iter()
+                    .map(|edge| {
+                        let length = edge.x.hypot(edge.y);
+                        if length <= 0.0 {
+                            return *edge;
+                        }
+                        let ellipse = 1.0 / ((edge.x / rx).powi(2) + (edge.y / ry).powi(2)).sqrt();
+                        *edge * ((ellipse * offset).min(length) / length)
+                    })
+                    .collect()
+            };
+            let mut inner = ring(0.0);
+            for band in 0..RADIAL_BANDS {
+                let start = band as f32 / RADIAL_BANDS as f32;
+                let end = (band + 1) as f32 / RADIAL_BANDS as f32;
+                let outer = ring(end);
+                let path = canvas::Path::new(|builder| {
+                    polygon(builder, center, &outer);
+                    polygon(builder, center, &inner);
+                });
+                frame.fill(
+                    &path,
+                    canvas::Fill {
+                        style: canvas::Style::Solid(to_color_value(
+                            gradient.color_at((start + end) / 2.0),
+                        )),
+                        rule: canvas::fill::Rule::EvenOdd,
+                    },
+                );
+                inner = outer;
+            }
+        }
+        GradientKind::Conic(_) => {
+            for (index, from) in outline.iter().enumerate() {
+                let to = outline[(index + 1) % outline.len()];
+                let middle = center + (*from + to) * 0.5;
+                let offset = gradient.offset_at([middle.x, middle.y], size.width, size.height);
+                let path = canvas::Path::new(|builder| {
+                    builder.move_to(center);
+                    builder.line_to(center + *from);
+                    builder.line_to(center + to);
+                    builder.close();
+                });
+                frame.fill(&path, to_color_value(gradient.color_at(offset)));
+            }
+        }
+    }
+}
+
+fn polygon(builder: &mut canvas::path::Builder, center: Point, points: &[Vector]) {
+    for (index, point) in points.iter().enumerate() {
+        if index == 0 {
+            builder.move_to(center + *point);
+        } else {
+            builder.line_to(center + *point);
+        }
+    }
+    builder.close();
+}
+
+/// Points along the edge of a box `size` large with `radius` corners, clockwise from
+/// the top-left corner, relative to its center.
+fn outline(size: Size, radius: Radius) -> Vec<Vector> {
+    let (half_width, half_height) = (size.width / 2.0, size.height / 2.0);
+    let limit = |corner: f32| corner.clamp(0.0, half_width.min(half_height));
+    // Each corner as its arc center, radius and the angle its arc starts at, measured
+    // like screen coordinates (`0` pointing right, `PI / 2` down).
+    let corners = [
+        (-1.0, -1.0, limit(radius.top_left), PI),
+        (1.0, -1.0, limit(radius.top_right), -FRAC_PI_2),
+        (1.0, 1.0, limit(radius.bottom_right), 0.0),
+        (-1.0, 1.0, limit(radius.bottom_left), FRAC_PI_2),
+    ];
+
+    let mut points = Vec::with_capacity(4 * (EDGE_SEGMENTS + CORNER_SEGMENTS));
+    for (index, (sx, sy, corner, start)) in corners.into_iter().enumerate() {
+        let arc_center = Vector::new(sx * (half_width - corner), sy * (half_height - corner));
+        for step in 0..CORNER_SEGMENTS {
+            let angle = start + FRAC_PI_2 * step as f32 / CORNER_SEGMENTS as f32;
+            points.push(arc_center + Vector::new(angle.cos(), angle.sin()) * corner);
+        }
+        // The straight edge from the end of this arc to the start of the next one.
+        let from =
+            arc_center + Vector::new((start + FRAC_PI_2).cos(), (start + FRAC_PI_2).sin()) * corner;
+        let (nx, ny, next, next_start) = corners[(index + 1) % corners.len()];
+        let next_center = Vector::new(nx * (half_width - next), ny * (half_height - next));
+        let to = next_center + Vector::new(next_start.cos(), next_start.sin()) * next;
+        for step in 0..EDGE_SEGMENTS {
+            points.push(from + (to - from) * (step as f32 / EDGE_SEGMENTS as f32));
+        }
+    }
+    points
+}
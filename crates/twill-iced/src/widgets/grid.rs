@@ -6,7 +6,9 @@ use iced_core::{Clipboard, Shell, Widget as AdvancedWidget};
 use iced_core::{Length, Point, Rectangle, Size, Vector, mouse};
 use twill_core::style::Style;
 use twill_core::tokens::Spacing;
-use twill_core::utilities::{Columns, GridTemplate};
+use twill_core::utilities::{
+    AlignItems, Columns, GridFlow, GridPlacement, GridTemplate, TrackList, place_grid_items,
+};
 
 use super::common::{
     ABSOLUTE_MAX_COLUMNS, DEFAULT_MAX_COLUMNS, normalize_max_columns, resolve_column_width,
@@ -140,56 +142,32 @@ pub(crate) fn resolve_grid_template_track_count(
     clamp_track_count(resolved.unwrap_or(1))
 }
 
-fn build_grid_template_columns_layout<'a, Message: Clone + 'a>(
-    items: Vec<super::common::Element<'a, Message>>,
-    track_count: usize,
-    gap: Spacing,
-) -> super::common::Element<'a, Message> {
-    let track_count = clamp_track_count(track_count);
-    let gap_px = spacing_to_px(gap);
-
-    if track_count <= 1 {
-        let mut col = iced_widget::Column::new()
-            .spacing(gap_px)
-            .width(Length::Fill);
-        for item in items {
-            col = col.push(item);
-        }
-        return col.into();
-    }
-
-    let mut grid_rows = iced_widget::Column::new()
-        .spacing(gap_px)
-        .width(Length::Fill);
-    let mut current_row = iced_widget::Row::new().spacing(gap_px).width(Length::Fill);
-    let mut items_in_row = 0_usize;
-
-    for item in items {
-        current_row = current_row.push(iced_widget::container(item).width(Length::FillPortion(1)));
-        items_in_row += 1;
-
-        if items_in_row == track_count {
-            grid_rows = grid_rows.push(current_row);
-            current_row = iced_widget::Row::new().spacing(gap_px).width(Length::Fill);
-            items_in_row = 0;
-        }
-    }
-
-    if items_in_row > 0 {
-        for _ in items_in_row..track_count {
-            current_row = current_row.push(
-                iced_widget::Space::new()
-                    .width(Length::FillPortion(1))
-                    .height(Length::Shrink),
-            );
-        }
-        grid_rows = grid_rows.push(current_row);
-    }
-
-    grid_rows.into()
+/// The tracks `template` lays out: its typed track list, or as many equal columns as
+/// [`resolve_grid_template_track_count`] finds.
+fn template_tracks(
+    template: &GridTemplate,
+    inherited_track_count: Option<usize>,
+    custom_properties: &[(&str, &str)],
+) -> TrackList {
+    let tracks = match template {
+        GridTemplate::CustomProperty(name) => lookup_custom_property_value(name, custom_properties)
+            .and_then(|value| TrackList::parse(value).ok()),
+        template => template.track_list(),
+    };
+    tracks.unwrap_or_else(|| {
+        let count =
+            resolve_grid_template_track_count(template, inherited_track_count, custom_properties);
+        TrackList::equal(u16::try_from(count).unwrap_or(u16::MAX))
+    })
 }
 
 /// Create an iced layout for a typed `grid-template-columns` value.
+///
+/// Items fill the columns row by row. Columns are sized like CSS grid tracks, so
+/// arbitrary templates such as `grid-cols-[240px_1fr_auto]` or
+/// `grid-cols-[repeat(auto-fill,minmax(12rem,1fr))]` work; each row is as tall as its
+/// tallest item, and the others stretch to match. [`styled_grid`] also places items by
+/// their `col-*` and `row-*` spans.
 pub fn grid_template_columns_layout<'a, Message: Clone + 'a>(
     items: Vec<super::common::Element<'a, Message>>,
     template: GridTemplate,
@@ -209,9 +187,385 @@ pub fn grid_template_columns_layout_with_context<'a, Message: Clone + 'a>(
     inherited_track_count: Option<usize>,
     custom_properties: &[(&str, &str)],
 ) -> super::common::Element<'a, Message> {
-    let track_count =
-        resolve_grid_template_track_count(&template, inherited_track_count, custom_properties);
-    build_grid_template_columns_layout(items, track_count, gap)
+    StyledGrid::with_tracks(
+        items,
+        template_tracks(&template, inherited_track_count, custom_properties),
+    )
+    .gap(spacing_to_px(gap))
+    .width(Length::Fill)
+    .into()
+}
+
+/// A CSS grid: items are placed on the tracks of the `grid-cols-*` and `grid-rows-*`
+/// templates of a style by their `col-*` and `row-*` placement and auto-placement.
+///
+/// Tracks are sized like CSS grid tracks, rows past the `grid-rows-*` template (or
+/// every row without one) are `auto`, and items spanning several tracks widen the
+/// content-sized ones. `grid-flow-col` fills columns instead of rows and the `dense`
+/// flows backfill holes left by spanning items. Gaps come from `gap-*`, and items
+/// stretch to the height of their area unless `items-start`, `items-center` or
+/// `items-end` is set.
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let bento = Style::new()
+///     .grid_cols_count(3)
+///     .grid_flow(GridFlow::Dense)
+///     .gap(Spacing::S4);
+/// let hero = Style::new().col_span(2).row_span(2);
+/// let wide = Style::new().col_span_full();
+///
+/// let dashboard: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::styled_grid(&bento)
+///         .push(iced_widget::text("Revenue"), &hero)
+///         .push(iced_widget::text("Users"), &Style::new())
+///         .push(iced_widget::text("Churn"), &Style::new())
+///         .push(iced_widget::text("Activity"), &wide)
+///         .width(iced_core::Length::Fill)
+///         .into();
+/// # let _ = dashboard;
+/// ```
+pub struct StyledGrid<'a, Message, Theme = iced_core::Theme, Renderer = iced_widget::Renderer> {
+    elements: Vec<super::common::Element<'a, Message, Theme, Renderer>>,
+    placements: Vec<(GridPlacement, GridPlacement)>,
+    columns: TrackList,
+    rows: TrackList,
+    flow: GridFlow,
+    column_gap: f32,
+    row_gap: f32,
+    align: Option<AlignItems>,
+    width: Length,
+    height: Length,
+}
+
+/// Create a [`StyledGrid`] from the grid container settings of `style`.
+pub fn styled_grid<'a, Message, Theme, Renderer>(
+    style: &Style,
+) -> StyledGrid<'a, Message, Theme, Renderer> {
+    let grid = style.grid_container().cloned().unwrap_or_default();
+    let tracks = |template: Option<&GridTemplate>| {
+        template.map_or_else(TrackList::new, |template| {
+            template_tracks(template, None, &[])
+        })
+    };
+    let gap = grid.gap_value().map_or(0.0, spacing_to_px);
+
+    let mut styled = StyledGrid::with_tracks(Vec::new(), tracks(grid.columns_value()));
+    styled.rows = tracks(grid.rows_value());
+    styled.flow = grid.flow_value().unwrap_or_default();
+    styled.column_gap = grid.col_gap_value().map_or(gap, spacing_to_px);
+    styled.row_gap = grid.row_gap_value().map_or(gap, spacing_to_px);
+    styled.align = grid.align_value();
+    styled
+}
+
+impl<'a, Message, Theme, Renderer> StyledGrid<'a, Message, Theme, Renderer> {
+    /// Grid placing `elements` one per cell, row by row, on the `columns` tracks.
+    pub(crate) fn with_tracks(
+        elements: Vec<super::common::Element<'a, Message, Theme, Renderer>>,
+        columns: TrackList,
+    ) -> Self {
+        Self {
+            placements: vec![Default::default(); elements.len()],
+            elements,
+            columns,
+            rows: TrackList::new(),
+            flow: GridFlow::Row,
+            column_gap: 0.0,
+            row_gap: 0.0,
+            align: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Add an item placed by the `col-*` and `row-*` placement of `style`.
+    ///
+    /// Only placement is read from `style`; wrap `child` in a styled container to
+    /// paint it.
+    pub fn push(
+        mut self,
+        child: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+        style: &Style,
+    ) -> Self {
+        self.elements.push(child.into());
+        self.placements.push((
+            style.grid_column_value().unwrap_or_default(),
+            style.grid_row_value().unwrap_or_default(),
+        ));
+        self
+    }
+
+    pub(crate) fn gap(mut self, gap: f32) -> Self {
+        self.column_gap = gap;
+        self.row_gap = gap;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+/// Number of explicit tracks in `tracks`; a list without tracks has none.
+fn explicit_track_count(tracks: &TrackList, available: f32, gap: f32) -> usize {
+    if tracks.tracks().is_empty() {
+        0
+    } else {
+        tracks.track_count(available, gap)
+    }
+}
+
+/// Offset and size of the `span` tracks from `start` in `tracks`.
+fn span_bounds(tracks: &[(f32, f32)], start: usize, span: usize) -> (f32, f32) {
+    let first = tracks.get(start).copied().unwrap_or_default();
+    let last = tracks
+        .get(start + span.max(1) - 1)
+        .copied()
+        .unwrap_or(first);
+    (first.0, (last.0 + last.1 - first.0).max(0.0))
+}
+
+impl<Message, Theme, Renderer> AdvancedWidget<Message, Theme, Renderer>
+    for StyledGrid<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements);
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        let column_gap = sanitize_gap(self.column_gap);
+        let row_gap = sanitize_gap(self.row_gap);
+        // Rows only share out the height when it is definite.
+        let available_height = if self.height == Length::Shrink {
+            f32::INFINITY
+        } else {
+            max.height
+        };
+        let elements = &mut self.elements;
+        let children = &mut tree.children;
+        let count = elements.len().min(children.len());
+
+        let areas = place_grid_items(
+            &self.placements[..count.min(self.placements.len())],
+            explicit_track_count(&self.columns, max.width, column_gap),
+            explicit_track_count(&self.rows, available_height, row_gap),
+            self.flow,
+        );
+
+        let mut layout_item = |index: usize, limits: &Limits| {
+            elements[index]
+                .as_widget_mut()
+                .layout(&mut children[index], renderer, limits)
+        };
+
+        let column_spans: Vec<_> = areas
+            .iter()
+            .map(|area| (area.column(), area.column_span()))
+            .collect();
+        let columns = self
+            .columns
+            .resolve_spans(max.width, column_gap, &column_spans, |index| {
+                // Laying an item out at no width and at any width measures how narrow it
+                // wraps and how wide it grows.
+                let mut measure = |max_width: f32| {
+                    let limits = Limits::new(Size::ZERO, Size::new(max_width, max.height));
+                    let width = layout_item(index, &limits).size().width;
+                    if width.is_finite() { width } else { 0.0 }
+                };
+                (measure(0.0), measure(f32::INFINITY))
+            });
+
+        let heights: Vec<f32> = areas
+            .iter()
+            .enumerate()
+            .map(|(index, area)| {
+                let (_, width) = span_bounds(&columns, area.column(), area.column_span());
+                let limits = Limits::new(Size::new(width, 0.0), Size::new(width, max.height));
+                let height = layout_item(index, &limits).size().height;
+                if height.is_finite() { height } else { 0.0 }
+            })
+            .collect();
+        let row_spans: Vec<_> = areas
+            .iter()
+            .map(|area| (area.row(), area.row_span()))
+            .collect();
+        let rows = self
+            .rows
+            .resolve_spans(available_height, row_gap, &row_spans, |index| {
+                (heights[index], heights[index])
+            });
+
+        let nodes = areas
+            .iter()
+            .enumerate()
+            .map(|(index, area)| {
+                let (x, width) = span_bounds(&columns, area.column(), area.column_span());
+                let (y, height) = span_bounds(&rows, area.row(), area.row_span());
+                let stretch = !matches!(
+                    self.align,
+                    Some(AlignItems::Start | AlignItems::Center | AlignItems::End)
+                );
+                let min_height = if stretch { height } else { 0.0 };
+                let limits = Limits::new(Size::new(width, min_height), Size::new(width, height));
+                let mut node = layout_item(index, &limits);
+                let free = (height - node.size().height).max(0.0);
+                let offset = match self.align {
+                    Some(AlignItems::Center) => free / 2.0,
+                    Some(AlignItems::End) => free,
+                    _ => 0.0,
+                };
+                node.move_to_mut(Point::new(x, y + offset));
+                node
+            })
+            .collect();
+
+        let extent = |tracks: &[(f32, f32)]| tracks.last().map_or(0.0, |&(at, size)| at + size);
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(extent(&columns), extent(&rows)),
+        );
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced_core::Event,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((state, layout), child) in tree
+            .children
+            .iter_mut()
+            .zip(layout.children())
+            .zip(self.elements.iter_mut())
+        {
+            child.as_widget_mut().update(
+                state, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for ((state, layout), child) in tree
+            .children
+            .iter_mut()
+            .zip(layout.children())
+            .zip(self.elements.iter_mut())
+        {
+            operation.container(None, layout.bounds());
+            operation.traverse(&mut |operation| {
+                child
+                    .as_widget_mut()
+                    .operate(state, layout, renderer, operation);
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: AdvancedLayout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.elements,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StyledGrid<'a, Message, Theme, Renderer>>
+    for super::common::Element<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+    Message: 'a,
+{
+    fn from(grid: StyledGrid<'a, Message, Theme, Renderer>) -> Self {
+        super::common::Element::new(grid)
+    }
 }
 
 struct ColumnsFlow<'a, Message, Theme = iced_core::Theme, Renderer = iced_widget::Renderer> {
@@ -0,0 +1,530 @@
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self as advanced_layout, Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::widget::operation::{self, Operation};
+use iced_core::widget::{Tree, tree};
+use iced_core::{
+    Border, Clipboard, Event, Length, Padding, Rectangle, Shell, Size, Vector,
+    Widget as AdvancedWidget, mouse, overlay, touch,
+};
+use iced_widget::graphics::geometry;
+use twill_core::style::{InteractionState, Style};
+use twill_core::tokens::{SemanticThemeVars, ThemeVariant};
+
+use super::blur::BlurRenderer;
+use super::container::{BoxVisuals, paint_border};
+use super::conversions::{SemanticThemeSource, to_interaction, to_style_padding};
+use super::transform::{to_transformation, untransformed};
+
+/// A container that tracks hover, press and keyboard focus itself and paints the
+/// matching state layers of its [`Style`].
+///
+/// Every event re-resolves the style with [`Style::resolve_for`], so `hover`, `active`,
+/// `focus`, `focus_visible` and `disabled` layers (plus any caller flags passed through
+/// [`TwillWidget::state`]) change background, border, radius, shadow, ring, outline,
+/// inset shadow, text color and cursor without separate per-state styles, so a
+/// `focus_visible` ring appears while the widget has keyboard focus. Each state is
+/// painted like [`styled_container`](super::styled_container) paints it: dashed,
+/// dotted and double borders, and sides of different widths, follow the radius. The
+/// `translate-*` and `scale-*` transforms of the current layers move what is drawn and
+/// where the pointer hits, as in [`Transformed`](super::Transformed).
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+/// }
+///
+/// let style = Style::interactive()
+///     .p(Spacing::S2)
+///     .bg(Color::slate(Scale::S100))
+///     .hover(|s| s.bg(Color::slate(Scale::S200)))
+///     .active(|s| s.bg(Color::slate(Scale::S300)));
+///
+/// let button: twill_iced::TwillWidget<'_, Message> =
+///     twill_iced::twill_widget(iced_widget::text("Save"), &style).on_press(Message::Save);
+/// # let _ = button;
+/// ```
+pub struct TwillWidget<'a, Message, Theme = iced_core::Theme, Renderer = iced_widget::Renderer> {
+    content: super::common::Element<'a, Message, Theme, Renderer>,
+    style: Style,
+    state: InteractionState,
+    on_press: Option<Message>,
+    semantic_theme: &'a dyn SemanticThemeSource,
+    variant: ThemeVariant,
+    width: Length,
+    height: Length,
+}
+
+/// Create a [`TwillWidget`] around `content`.
+pub fn twill_widget<'a, Message, Theme, Renderer>(
+    content: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+    style: &Style,
+) -> TwillWidget<'a, Message, Theme, Renderer> {
+    TwillWidget::new(content, style)
+}
+
+impl<'a, Message, Theme, Renderer> TwillWidget<'a, Message, Theme, Renderer> {
+    pub fn new(
+        content: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+        style: &Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            style: style.clone(),
+            state: InteractionState::new(),
+            on_press: None,
+            semantic_theme: SemanticThemeVars::shadcn_neutral(),
+            variant: ThemeVariant::Light,
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Publish `message` when the widget is clicked, tapped, or activated with Enter or
+    /// Space while focused. Pressable widgets take part in keyboard focus traversal.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Caller-owned flags such as `selected`, `checked`, `open`, `disabled` or
+    /// `data-*`/`aria-*` attributes. Hover, press and focus are tracked by the widget
+    /// and override the same flags here.
+    pub fn state(mut self, state: InteractionState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Shorthand for setting the `disabled` flag; disabled widgets never publish.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.state = self.state.disabled(disabled);
+        self
+    }
+
+    /// Resolve semantic colors through `theme` for `variant` instead of the light
+    /// shadcn neutral theme.
+    pub fn semantic_theme(
+        mut self,
+        theme: &'a dyn SemanticThemeSource,
+        variant: ThemeVariant,
+    ) -> Self {
+        self.semantic_theme = theme;
+        self.variant = variant;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    fn resolved(&self, state: &State) -> Style {
+        self.style.resolve_for(
+            &self
+                .state
+                .clone()
+                .hovered(state.is_hovered)
+                .pressed(state.is_pressed)
+                .focused(state.is_focused)
+                .focus_visible(state.is_focus_visible),
+        )
+    }
+
+    fn padding(&self, state: &State) -> iced_core::Padding {
+        self.resolved(state)
+            .padding_value()
+            .map_or(iced_core::Padding::ZERO, |padding| {
+                to_style_padding(*padding, &[])
+            })
+    }
+
+    fn is_pressable(&self) -> bool {
+        self.on_press.is_some() && !self.state.is_disabled()
+    }
+}
+
+/// Interaction flags tracked in the widget [`Tree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_hovered: bool,
+    is_pressed: bool,
+    is_focused: bool,
+    is_focus_visible: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focus arriving through an operation comes from keyboard traversal.
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.is_focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_focus_visible = false;
+        self.is_pressed = false;
+    }
+}
+
+impl<Message, Theme, Renderer> AdvancedWidget<Message, Theme, Renderer>
+    for TwillWidget<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: geometry::Renderer + BlurRenderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let padding = self.padding(tree.state.downcast_ref::<State>());
+        let Some(child_state) = tree.children.first_mut() else {
+            return Node::new(limits.resolve(self.width, self.height, Size::ZERO));
+        };
+
+        advanced_layout::padded(limits, self.width, self.height, padding, |limits| {
+            self.content
+                .as_widget_mut()
+                .layout(child_state, renderer, limits)
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let resolved = self.resolved(tree.state.downcast_ref::<State>());
+        let visuals = BoxVisuals::new(&resolved, self.semantic_theme, self.variant);
+        let quad_border = visuals.has_quad_border();
+        // Everything over the background: the border, decorations, content and outline.
+        let paint_box = |renderer: &mut Renderer, cursor: mouse::Cursor, viewport: &Rectangle| {
+            let border = Border {
+                radius: visuals.border_radius,
+                width: if quad_border {
+                    visuals.border_widths.top
+                } else {
+                    0.0
+                },
+                color: visuals.border_color,
+            };
+            if visuals.background.is_some() || border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border,
+                        ..renderer::Quad::default()
+                    },
+                    visuals.background.unwrap_or(iced_core::Color::TRANSPARENT),
+                );
+            }
+            // Dashed, dotted and double borders, and sides of different widths.
+            if !quad_border && visuals.border_widths != Padding::ZERO {
+                let mut frame = geometry::Frame::new(renderer, bounds.size());
+                paint_border(
+                    &mut frame,
+                    bounds.size(),
+                    visuals.border_style,
+                    visuals.border_widths,
+                    visuals.border_radius,
+                    visuals.border_color,
+                );
+                let border = frame.into_geometry();
+                renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                    renderer.draw_geometry(border);
+                });
+            }
+            visuals.decorations.draw_under(
+                renderer,
+                bounds,
+                visuals.border_radius,
+                visuals.border_widths,
+                style.text_color,
+            );
+
+            if let (Some(child_state), Some(child_layout)) =
+                (tree.children.first(), layout.children().next())
+            {
+                let child_style = renderer::Style {
+                    text_color: visuals.text_color.unwrap_or(style.text_color),
+                };
+                self.content.as_widget().draw(
+                    child_state,
+                    renderer,
+                    theme,
+                    &child_style,
+                    child_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+            visuals.decorations.draw_outline(
+                renderer,
+                bounds,
+                visuals.border_radius,
+                style.text_color,
+            );
+        };
+        let paint = |renderer: &mut Renderer, cursor: mouse::Cursor, viewport: &Rectangle| {
+            for shadow in visuals.shadows.iter().copied() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: visuals.border_radius,
+                            ..Border::default()
+                        },
+                        shadow,
+                        ..renderer::Quad::default()
+                    },
+                    iced_core::Color::TRANSPARENT,
+                );
+            }
+            match visuals.decorations.blurred_background() {
+                Some(background) => {
+                    background.draw(renderer, bounds, visuals.border_radius);
+                    // A shader blur is drawn after the quads of its layer.
+                    renderer.with_layer(*viewport, |renderer| {
+                        paint_box(renderer, cursor, viewport);
+                    });
+                }
+                None => paint_box(renderer, cursor, viewport),
+            }
+        };
+
+        match to_transformation(&resolved, bounds) {
+            Some(transformation) => {
+                let (cursor, viewport) = untransformed(Some(transformation), cursor, viewport);
+                renderer.with_transformation(transformation, |renderer| {
+                    paint(renderer, cursor, &viewport);
+                });
+            }
+            None => paint(renderer, cursor, viewport),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let (cursor, viewport) = untransformed(
+            to_transformation(
+                &self.resolved(tree.state.downcast_ref::<State>()),
+                layout.bounds(),
+            ),
+            cursor,
+            viewport,
+        );
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            self.content.as_widget_mut().update(
+                child_state,
+                event,
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &viewport,
+            );
+        }
+
+        let bounds = layout.bounds();
+        let pressable = self.is_pressable();
+        let disabled = self.state.is_disabled();
+        let state = tree.state.downcast_mut::<State>();
+        let before = *state;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(bounds) && !shell.is_event_captured() && !disabled {
+                    state.is_pressed = true;
+                    state.is_focused = pressable;
+                    state.is_focus_visible = false;
+                    if pressable {
+                        shell.capture_event();
+                    }
+                } else if !cursor.is_over(bounds) {
+                    state.is_focused = false;
+                    state.is_focus_visible = false;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+                if pressable
+                    && cursor.is_over(bounds)
+                    && let Some(message) = &self.on_press
+                {
+                    shell.publish(message.clone());
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused && pressable => {
+                state.is_pressed = true;
+                if let Some(message) = &self.on_press {
+                    shell.publish(message.clone());
+                }
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                state.is_pressed = false;
+            }
+            _ => {}
+        }
+        state.is_hovered = cursor.is_over(bounds);
+
+        let after = *state;
+        if after != before {
+            shell.request_redraw();
+            if self.padding(&before) != self.padding(&after) {
+                shell.invalidate_layout();
+            }
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.is_pressable() {
+            operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+        }
+
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            operation.container(None, layout.bounds());
+            operation.traverse(&mut |operation| {
+                self.content.as_widget_mut().operate(
+                    child_state,
+                    child_layout,
+                    renderer,
+                    operation,
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let resolved = self.resolved(tree.state.downcast_ref::<State>());
+        let (cursor, viewport) = untransformed(
+            to_transformation(&resolved, layout.bounds()),
+            cursor,
+            viewport,
+        );
+        let viewport = &viewport;
+        let child =
+            match (tree.children.first(), layout.children().next()) {
+                (Some(child_state), Some(child_layout)) => self
+                    .content
+                    .as_widget()
+                    .mouse_interaction(child_state, child_layout, cursor, viewport, renderer),
+                _ => mouse::Interaction::default(),
+            };
+        if child != mouse::Interaction::default() || !cursor.is_over(layout.bounds()) {
+            return child;
+        }
+
+        resolved.cursor_value().map_or(child, to_interaction)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: AdvancedLayout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        else {
+            return None;
+        };
+
+        self.content.as_widget_mut().overlay(
+            child_state,
+            child_layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TwillWidget<'a, Message, Theme, Renderer>>
+    for super::common::Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: geometry::Renderer + BlurRenderer + 'a,
+{
+    fn from(widget: TwillWidget<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
@@ -0,0 +1,374 @@
+use iced_core::layout::{Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    Clipboard, Event, Length, Point, Rectangle, Shell, Size, Vector, Widget as AdvancedWidget,
+    mouse, overlay,
+};
+use twill_core::style::Style;
+use twill_core::utilities::{Inset, InsetValue, Position};
+
+/// A relatively positioned container whose layers are placed by their `position`,
+/// `top`/`right`/`bottom`/`left` insets and `z_index`.
+///
+/// The content is laid out in flow and sets the size of the stack, which acts as the
+/// containing block of every layer (like a `relative` element in CSS). Layers are
+/// drawn above the content in ascending `z_index` order; `auto` counts as `0` and ties
+/// keep push order. Events reach the top-most layer first.
+///
+/// - `absolute` (and `fixed`) layers are offset from the stack edges by their insets.
+///   With both `left` and `right` set the layer stretches between them; fractions
+///   resolve against the stack size.
+/// - `relative` layers are shifted from the top-left corner by their insets.
+/// - Other layers sit at the top-left corner and ignore insets.
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let badge = Style::new()
+///     .position(Position::Absolute)
+///     .top(InsetValue::neg_scale(Spacing::S1))
+///     .right(InsetValue::neg_scale(Spacing::S1))
+///     .z_index(ZIndex::S10);
+///
+/// let avatar: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::positioned_stack(iced_widget::text("Inbox"))
+///         .push(iced_widget::text("3"), &badge)
+///         .into();
+/// # let _ = avatar;
+/// ```
+pub struct PositionedStack<'a, Message, Theme = iced_core::Theme, Renderer = iced_widget::Renderer>
+{
+    content: super::common::Element<'a, Message, Theme, Renderer>,
+    layers: Vec<(i16, super::common::Element<'a, Message, Theme, Renderer>)>,
+    width: Length,
+    height: Length,
+    clip: bool,
+}
+
+/// Create a [`PositionedStack`] around in-flow `content`.
+pub fn positioned_stack<'a, Message, Theme, Renderer>(
+    content: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+) -> PositionedStack<'a, Message, Theme, Renderer> {
+    PositionedStack {
+        content: content.into(),
+        layers: Vec::new(),
+        width: Length::Shrink,
+        height: Length::Shrink,
+        clip: false,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> PositionedStack<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    /// Add a layer placed by the `position`, inset and `z_index` of `style`.
+    ///
+    /// Only placement is read from `style`; wrap `child` in a styled container to
+    /// paint it.
+    pub fn push(
+        mut self,
+        child: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+        style: &Style,
+    ) -> Self {
+        let z_index = style
+            .z_index_value()
+            .and_then(|z_index| z_index.value())
+            .unwrap_or(0);
+        let layer = Placed {
+            content: child.into(),
+            placement: Placement::from_style(style),
+        };
+        self.layers
+            .push((z_index, super::common::Element::new(layer)));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Clip layers that extend past the stack bounds (`overflow-hidden`).
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PositionedStack<'a, Message, Theme, Renderer>>
+    for super::common::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(stack: PositionedStack<'a, Message, Theme, Renderer>) -> Self {
+        let PositionedStack {
+            content,
+            mut layers,
+            width,
+            height,
+            clip,
+        } = stack;
+        // Stable, so equal z-indices keep push order.
+        layers.sort_by_key(|(z_index, _)| *z_index);
+
+        iced_widget::Stack::with_children(
+            std::iter::once(content).chain(layers.into_iter().map(|(_, layer)| layer)),
+        )
+        .width(width)
+        .height(height)
+        .clip(clip)
+        .into()
+    }
+}
+
+/// Placement read from a layer style.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Placement {
+    position: Position,
+    inset: Inset,
+}
+
+impl Placement {
+    fn from_style(style: &Style) -> Self {
+        Self {
+            position: style.position_value().unwrap_or(Position::Static),
+            inset: style.inset_value().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Limits for the layer content inside a containing block of `size`.
+    fn limits(&self, size: Size) -> Limits {
+        match self.position {
+            Position::Absolute | Position::Fixed => {
+                let (left, right) = self.horizontal(size.width);
+                let (top, bottom) = self.vertical(size.height);
+                let available = |start: Option<f32>, end: Option<f32>, length: f32| {
+                    (length - start.unwrap_or(0.0) - end.unwrap_or(0.0)).max(0.0)
+                };
+                let width = available(left, right, size.width);
+                let height = available(top, bottom, size.height);
+                let stretch = |start: Option<f32>, end: Option<f32>, length: f32| {
+                    if start.is_some() && end.is_some() {
+                        length
+                    } else {
+                        0.0
+                    }
+                };
+
+                Limits::new(
+                    Size::new(stretch(left, right, width), stretch(top, bottom, height)),
+                    Size::new(width, height),
+                )
+            }
+            _ => Limits::new(Size::ZERO, size),
+        }
+    }
+
+    /// Top-left corner of content sized `content` inside a containing block of `size`.
+    fn origin(&self, size: Size, content: Size) -> Point {
+        match self.position {
+            Position::Absolute | Position::Fixed => {
+                let (left, right) = self.horizontal(size.width);
+                let (top, bottom) = self.vertical(size.height);
+
+                Point::new(
+                    place_on_axis(left, right, size.width, content.width),
+                    place_on_axis(top, bottom, size.height, content.height),
+                )
+            }
+            Position::Relative => {
+                let shift = |start: Option<f32>, end: Option<f32>| match (start, end) {
+                    (Some(start), _) => start,
+                    (None, Some(end)) => -end,
+                    (None, None) => 0.0,
+                };
+                let (left, right) = self.horizontal(size.width);
+                let (top, bottom) = self.vertical(size.height);
+
+                Point::new(shift(left, right), shift(top, bottom))
+            }
+            Position::Static | Position::Sticky => Point::ORIGIN,
+        }
+    }
+
+    fn horizontal(&self, width: f32) -> (Option<f32>, Option<f32>) {
+        let side = |value: Option<InsetValue>| value.and_then(|value| value.to_px(width));
+        (side(self.inset.left_side()), side(self.inset.right_side()))
+    }
+
+    fn vertical(&self, height: f32) -> (Option<f32>, Option<f32>) {
+        let side = |value: Option<InsetValue>| value.and_then(|value| value.to_px(height));
+        (side(self.inset.top_side()), side(self.inset.bottom_side()))
+    }
+}
+
+/// Start of content `extent` long on an axis `length` long, from its start/end insets.
+fn place_on_axis(start: Option<f32>, end: Option<f32>, length: f32, extent: f32) -> f32 {
+    match (start, end) {
+        (Some(start), _) => start,
+        (None, Some(end)) => length - end - extent,
+        (None, None) => 0.0,
+    }
+}
+
+/// A stack layer covering the containing block, with its content placed inside.
+struct Placed<'a, Message, Theme, Renderer> {
+    content: super::common::Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+}
+
+impl<Message, Theme, Renderer> AdvancedWidget<Message, Theme, Renderer>
+    for Placed<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.max();
+        let Some(child_state) = tree.children.first_mut() else {
+            return Node::new(size);
+        };
+
+        let node = self.content.as_widget_mut().layout(
+            child_state,
+            renderer,
+            &self.placement.limits(size),
+        );
+        let origin = self.placement.origin(size, node.size());
+
+        Node::with_children(size, vec![node.move_to(origin)])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first(), layout.children().next())
+        {
+            self.content.as_widget().draw(
+                child_state,
+                renderer,
+                theme,
+                style,
+                child_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            self.content.as_widget_mut().update(
+                child_state,
+                event,
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        {
+            self.content
+                .as_widget_mut()
+                .operate(child_state, child_layout, renderer, operation);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (tree.children.first(), layout.children().next()) {
+            (Some(child_state), Some(child_layout)) => self.content.as_widget().mouse_interaction(
+                child_state,
+                child_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: AdvancedLayout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (Some(child_state), Some(child_layout)) =
+            (tree.children.first_mut(), layout.children().next())
+        else {
+            return None;
+        };
+
+        self.content.as_widget_mut().overlay(
+            child_state,
+            child_layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
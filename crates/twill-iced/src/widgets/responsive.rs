@@ -0,0 +1,51 @@
+use iced_core::renderer;
+use iced_widget::Responsive;
+use twill_core::style::{ResolveContext, Style};
+
+/// Build content from `style` resolved for the width the widget is laid out at.
+///
+/// On every layout the available width is the viewport of
+/// [`Style::resolve_breakpoints`], and `view` receives the result, so `sm`/`md`/`lg`
+/// layers follow the window instead of a fixed breakpoint while state layers stay for
+/// [`twill_widget`](crate::twill_widget). Like [`iced_widget::Responsive`], the widget
+/// fills the available space; set `.width(...)`/`.height(...)` on the result to change
+/// that.
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let sidebar = Style::new().w(Spacing::S12).lg(|style| style.w(Spacing::S64));
+///
+/// let layout: iced_widget::Responsive<'_, ()> = twill_iced::responsive(&sidebar, |style| {
+///     twill_iced::styled_container(iced_widget::text("Navigation").into(), &style).into()
+/// });
+/// # let _ = layout;
+/// ```
+pub fn responsive<'a, Message, Theme, Renderer>(
+    style: &Style,
+    view: impl Fn(Style) -> super::common::Element<'a, Message, Theme, Renderer> + 'a,
+) -> Responsive<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    responsive_with_context(style, ResolveContext::new(), view)
+}
+
+/// Build content like [`responsive`], matching breakpoints against the layout width with
+/// the root font size of `context`.
+pub fn responsive_with_context<'a, Message, Theme, Renderer>(
+    style: &Style,
+    context: ResolveContext<'a>,
+    view: impl Fn(Style) -> super::common::Element<'a, Message, Theme, Renderer> + 'a,
+) -> Responsive<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    let style = style.clone();
+    Responsive::new(move |size| {
+        view(style.resolve_breakpoints(&ResolveContext {
+            viewport_width_px: size.width,
+            ..context.clone()
+        }))
+    })
+}
@@ -1,9 +1,13 @@
 use super::*;
 use crate::TextDirection;
 use iced_core::Length;
+use iced_core::widget::Tree;
+use iced_core::{Event, Point, Rectangle, Shell, Size, Widget, clipboard, layout, mouse};
+use iced_widget::graphics::cache::{Cached, Group};
+use iced_widget::graphics::geometry;
 use twill_core::prelude::*;
-use twill_core::tokens::AspectRatio;
 use twill_core::tokens::Scale;
+use twill_core::tokens::{AspectRatio, Blur, Cursor};
 
 #[test]
 fn test_color_conversion() {
@@ -534,6 +538,98 @@ fn test_grid_template_columns_layout_builds() {
     );
 }
 
+#[test]
+fn test_track_grid_sizes_columns_from_the_template() {
+    let space = |width: f32, height: f32| iced_widget::Space::new().width(width).height(height);
+    let columns =
+        |tracks: &str, items: Vec<iced_core::Element<'static, (), iced_core::Theme, ()>>| {
+            let style = Style::new()
+                .grid_cols(tracks.parse::<TrackList>().unwrap())
+                .gap(Spacing::S2_5);
+            let grid = items.into_iter().fold(styled_grid(&style), |grid, item| {
+                grid.push(item, &Style::new())
+            });
+            grid_bounds(grid.width(Length::Fill), Size::new(600.0, 400.0))
+        };
+
+    let dashboard = columns(
+        "240px 1fr auto",
+        vec![
+            space(100.0, 20.0).into(),
+            space(100.0, 30.0).into(),
+            space(80.0, 10.0).into(),
+            space(50.0, 5.0).into(),
+        ],
+    );
+    assert_eq!(
+        dashboard[1],
+        Rectangle::new(Point::new(250.0, 0.0), Size::new(260.0, 30.0))
+    );
+    // Items stretch to the height of their row.
+    assert_eq!(
+        dashboard[2],
+        Rectangle::new(Point::new(520.0, 0.0), Size::new(80.0, 30.0))
+    );
+    // The fourth item wraps below the tallest item of the first row.
+    assert_eq!(
+        dashboard[3],
+        Rectangle::new(Point::new(0.0, 40.0), Size::new(240.0, 5.0))
+    );
+
+    let cards = columns(
+        "repeat(auto-fit, minmax(100px, 1fr))",
+        vec![space(0.0, 20.0).into(), space(0.0, 20.0).into()],
+    );
+    assert_eq!(
+        cards,
+        vec![
+            Rectangle::new(Point::ORIGIN, Size::new(295.0, 20.0)),
+            Rectangle::new(Point::new(305.0, 0.0), Size::new(295.0, 20.0)),
+        ]
+    );
+}
+
+#[test]
+fn test_styled_grid_places_spanning_items() {
+    let space = |height: f32| iced_widget::Space::new().height(height);
+    let bento = Style::new()
+        .grid_cols_count(3)
+        .grid_flow(GridFlow::Dense)
+        .gap(Spacing::S2_5);
+    let grid = styled_grid(&bento)
+        .push(space(0.0), &Style::new())
+        .push(space(50.0), &Style::new().col_span(2).row_span(2))
+        .push(space(20.0), &Style::new().col_span(2))
+        .push(space(10.0), &Style::new())
+        .push(space(10.0), &Style::new())
+        .width(Length::Fill);
+
+    assert_eq!(
+        grid_bounds(grid, Size::new(320.0, 400.0)),
+        vec![
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 15.0)),
+            Rectangle::new(Point::new(110.0, 0.0), Size::new(210.0, 50.0)),
+            Rectangle::new(Point::new(0.0, 60.0), Size::new(210.0, 20.0)),
+            // Dense flow backfills the cell left of the tall item, whose height is
+            // shared between the rows it spans.
+            Rectangle::new(Point::new(0.0, 25.0), Size::new(100.0, 25.0)),
+            Rectangle::new(Point::new(220.0, 60.0), Size::new(100.0, 20.0)),
+        ]
+    );
+}
+
+fn grid_bounds(grid: StyledGrid<'static, (), iced_core::Theme, ()>, max: Size) -> Vec<Rectangle> {
+    let mut grid: iced_core::Element<'_, (), iced_core::Theme, ()> = grid.into();
+    let mut tree = Tree::new(&grid);
+    let node = grid
+        .as_widget_mut()
+        .layout(&mut tree, &(), &layout::Limits::new(Size::ZERO, max));
+    layout::Layout::new(&node)
+        .children()
+        .map(|item| item.bounds())
+        .collect()
+}
+
 #[test]
 fn test_resolve_column_width_handles_tight_space() {
     let width = resolve_column_width(100.0, 3, 80.0);
@@ -657,6 +753,79 @@ fn test_gap_layout_builds() {
     let _: super::common::Element<'_, ()> = gap_y_layout(vec![], FlexDirection::Col, Spacing::S3);
 }
 
+#[test]
+fn test_styled_flex_layout_reads_space_and_divide_utilities() {
+    let list = Style::new()
+        .flex(FlexContainer::col().gap(Spacing::S4))
+        .space_y(Spacing::S2)
+        .divide_y(DivideWidth::S2)
+        .divide_x(DivideWidth::S0)
+        .divide_color(Color::slate(Scale::S200));
+
+    assert_eq!(styled_main_axis_gap(&list, FlexDirection::Col), Spacing::S2);
+    assert_eq!(styled_main_axis_gap(&list, FlexDirection::Row), Spacing::S4);
+    let light = |style: &Style, direction| {
+        divider_for_direction(
+            style,
+            direction,
+            SemanticThemeVars::shadcn_neutral(),
+            ThemeVariant::Light,
+        )
+    };
+    assert_eq!(
+        light(&list, FlexDirection::ColReverse),
+        Some(Divider {
+            width: 2.0,
+            color: Some(to_color(Color::slate(Scale::S200))),
+        })
+    );
+    assert_eq!(light(&list, FlexDirection::Row), None);
+    assert_eq!(
+        light(&Style::new().divide_x(DivideWidth::S1), FlexDirection::Row),
+        Some(Divider {
+            width: 1.0,
+            color: None,
+        })
+    );
+
+    let _: super::common::Element<'_, ()> = styled_flex_layout(
+        vec![
+            iced_widget::text("Edit").into(),
+            iced_widget::text("Delete").into(),
+        ],
+        FlexDirection::Col,
+        &list,
+    );
+}
+
+#[test]
+fn test_styled_flex_layout_resolves_divide_color_in_semantic_theme() {
+    let list = Style::new()
+        .divide_y(DivideWidth::S1)
+        .divide_color_token(BorderColor::semantic(SemanticColor::Border));
+    let theme = SemanticThemeVars::shadcn_neutral();
+    let divider = |variant| divider_for_direction(&list, FlexDirection::Col, theme, variant);
+
+    assert_eq!(
+        divider(ThemeVariant::Dark),
+        Some(Divider {
+            width: 1.0,
+            color: Some(to_color_value(
+                theme.resolve_dark_value(SemanticColor::Border).unwrap()
+            )),
+        })
+    );
+    assert_ne!(divider(ThemeVariant::Dark), divider(ThemeVariant::Light));
+
+    let _: super::common::Element<'_, ()> = styled_flex_layout_with_semantic_theme(
+        vec![iced_widget::text("Edit").into()],
+        FlexDirection::Col,
+        &list,
+        theme,
+        ThemeVariant::Dark,
+    );
+}
+
 #[test]
 fn test_justify_content_safe_mapping() {
     assert_eq!(
@@ -694,3 +863,903 @@ fn test_justify_content_layout_builds() {
         JustifyContent::Evenly,
     );
 }
+
+type TestWidget = TwillWidget<'static, &'static str, iced_core::Theme, QuadRecorder>;
+
+fn widget(style: &Style) -> TestWidget {
+    twill_widget(iced_widget::Space::new().width(20).height(20), style)
+}
+
+/// Send `events` with the cursor at `position`, returning published messages.
+fn send(
+    widget: &mut TestWidget,
+    tree: &mut Tree,
+    node: &layout::Node,
+    position: Point,
+    events: &[Event],
+) -> Vec<&'static str> {
+    let mut messages = Vec::new();
+    for event in events {
+        let mut shell = Shell::new(&mut messages);
+        widget.update(
+            tree,
+            event,
+            layout::Layout::new(node),
+            mouse::Cursor::Available(position),
+            &QuadRecorder::default(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+    }
+    messages
+}
+
+fn click() -> [Event; 2] {
+    [
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]
+}
+
+#[test]
+fn test_twill_widget_publishes_on_click_inside() {
+    let mut widget = widget(&Style::interactive().p(Spacing::S2)).on_press("pressed");
+    let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+    let node = widget.layout(
+        &mut tree,
+        &QuadRecorder::default(),
+        &layout::Limits::new(Size::ZERO, Size::INFINITE),
+    );
+
+    assert_eq!(node.size(), Size::new(36.0, 36.0));
+    assert_eq!(
+        send(
+            &mut widget,
+            &mut tree,
+            &node,
+            Point::new(10.0, 10.0),
+            &click()
+        ),
+        vec!["pressed"]
+    );
+    assert!(
+        send(
+            &mut widget,
+            &mut tree,
+            &node,
+            Point::new(50.0, 50.0),
+            &click()
+        )
+        .is_empty()
+    );
+
+    let mut disabled = widget.disabled(true);
+    assert!(
+        send(
+            &mut disabled,
+            &mut tree,
+            &node,
+            Point::new(10.0, 10.0),
+            &click()
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn test_twill_widget_applies_hover_layer() {
+    let style = Style::new()
+        .p(Spacing::S1)
+        .hover(|style| style.p(Spacing::S2).cursor(Cursor::Pointer));
+    let mut widget = widget(&style);
+    let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+    let limits = layout::Limits::new(Size::ZERO, Size::INFINITE);
+    let node = widget.layout(&mut tree, &QuadRecorder::default(), &limits);
+    let interaction = |widget: &TestWidget, tree: &Tree, node: &layout::Node, position| {
+        widget.mouse_interaction(
+            tree,
+            layout::Layout::new(node),
+            mouse::Cursor::Available(position),
+            &Rectangle::with_size(Size::INFINITE),
+            &QuadRecorder::default(),
+        )
+    };
+
+    let inside = Point::new(5.0, 5.0);
+    assert_eq!(node.size(), Size::new(28.0, 28.0));
+    assert_eq!(
+        interaction(&widget, &tree, &node, inside),
+        mouse::Interaction::None
+    );
+
+    let moved = Event::Mouse(mouse::Event::CursorMoved { position: inside });
+    let _ = send(&mut widget, &mut tree, &node, inside, &[moved]);
+    assert_eq!(
+        interaction(&widget, &tree, &node, inside),
+        mouse::Interaction::Pointer
+    );
+    assert_eq!(
+        widget
+            .layout(&mut tree, &QuadRecorder::default(), &limits)
+            .size(),
+        Size::new(36.0, 36.0)
+    );
+}
+
+#[test]
+fn test_twill_widget_strokes_dashed_outline_as_geometry() {
+    let draw = |outline_style| {
+        let style = Style::new().outline(BorderWidth::S2, outline_style, Color::red(Scale::S500));
+        let mut widget = widget(&style);
+        let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+        let mut renderer = QuadRecorder::default();
+        let node = widget.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::INFINITE),
+        );
+        widget.draw(
+            &tree,
+            &mut renderer,
+            &iced_core::Theme::Light,
+            &iced_core::renderer::Style {
+                text_color: iced_core::Color::BLACK,
+            },
+            layout::Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        renderer
+    };
+
+    let dashed = draw(OutlineStyle::Dashed);
+    assert!(dashed.0.is_empty());
+    assert_eq!(dashed.1, [vec![8.0, 5.0]]);
+    let dotted = draw(OutlineStyle::Dotted);
+    assert_eq!(dotted.1, [vec![1.0, 5.0]]);
+    let solid = draw(OutlineStyle::Solid);
+    assert_eq!(solid.0.len(), 1);
+    assert!(solid.1.is_empty());
+}
+
+#[test]
+fn test_twill_widget_paints_dashed_and_unequal_borders_as_geometry() {
+    let draw = |style: Style| {
+        let mut widget = widget(&style);
+        let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+        let mut renderer = QuadRecorder::default();
+        let node = widget.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::INFINITE),
+        );
+        widget.draw(
+            &tree,
+            &mut renderer,
+            &iced_core::Theme::Light,
+            &iced_core::renderer::Style {
+                text_color: iced_core::Color::BLACK,
+            },
+            layout::Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        renderer
+    };
+    let red = Color::red(Scale::S500);
+
+    let dashed = draw(Style::new().border(BorderWidth::S2, BorderStyle::Dashed, red));
+    assert!(dashed.0.is_empty());
+    assert_eq!(dashed.1, [vec![8.0, 5.0]]);
+
+    let unequal = draw(
+        Style::new()
+            .border(BorderWidth::S2, BorderStyle::Solid, red)
+            .border_t(BorderWidth::S4),
+    );
+    assert!(unequal.0.is_empty());
+    assert!(unequal.1.is_empty());
+    assert_eq!(unequal.2.len(), 1);
+
+    let solid = draw(Style::new().border(BorderWidth::S2, BorderStyle::Solid, red));
+    assert_eq!(solid.0.len(), 1);
+    assert_eq!(solid.0[0].1.width, 2.0);
+    assert!(solid.1.is_empty() && solid.2.is_empty());
+}
+
+#[test]
+fn test_responsive_resolves_style_for_layout_width() {
+    let style = Style::new()
+        .w(Spacing::S12)
+        .md(|style| style.w(Spacing::S24))
+        .lg(|style| style.w(Spacing::S64));
+    let seen = std::rc::Rc::new(std::cell::Cell::new(None));
+    let width_at = |max_width: f32| {
+        let seen_in_view = seen.clone();
+        let mut widget: iced_widget::Responsive<'_, (), iced_core::Theme, ()> =
+            responsive(&style, move |style| {
+                seen_in_view.set(style.width_value());
+                iced_widget::Space::new().into()
+            });
+        let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(max_width, 600.0));
+        let _ = widget.layout(&mut tree, &(), &limits);
+        seen.get()
+    };
+
+    assert_eq!(width_at(320.0), Some(Width::from(Spacing::S12)));
+    assert_eq!(width_at(800.0), Some(Width::from(Spacing::S24)));
+    assert_eq!(width_at(1280.0), Some(Width::from(Spacing::S64)));
+}
+
+#[test]
+fn test_responsive_with_context_scales_breakpoints_by_root_font_size() {
+    let style = Style::new().w(Spacing::S12).md(|style| {
+        style
+            .w(Spacing::S24)
+            .hover(|style| style.shadow(Shadow::Md))
+    });
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let resolved_at = |root_font_size: f32| {
+        let seen_in_view = seen.clone();
+        let context = twill_core::style::ResolveContext {
+            root_font_size,
+            ..twill_core::style::ResolveContext::new()
+        };
+        let mut widget: iced_widget::Responsive<'_, (), iced_core::Theme, ()> =
+            responsive_with_context(&style, context, move |style| {
+                *seen_in_view.borrow_mut() = Some(style);
+                iced_widget::Space::new().into()
+            });
+        let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+        let _ = widget.layout(&mut tree, &(), &limits);
+        seen.borrow_mut().take().unwrap()
+    };
+
+    let md = resolved_at(16.0);
+    assert_eq!(md.width_value(), Some(Width::from(Spacing::S24)));
+    // State layers stay for stateful widgets.
+    assert!(md.hover_style().is_some());
+    // 800px is below `md` (48rem) with a 20px root font size.
+    assert_eq!(
+        resolved_at(20.0).width_value(),
+        Some(Width::from(Spacing::S12))
+    );
+}
+
+#[test]
+fn test_positioned_stack_places_layers_by_inset_and_z_index() {
+    let space = |width: f32, height: f32| iced_widget::Space::new().width(width).height(height);
+    let badge = Style::new()
+        .position(Position::Absolute)
+        .top(InsetValue::neg_scale(Spacing::S1))
+        .right(InsetValue::neg_scale(Spacing::S1))
+        .z_index(ZIndex::S20);
+    let footer = Style::new()
+        .position(Position::Absolute)
+        .inset_x(Spacing::S2)
+        .bottom(Percentage::S1_4)
+        .z_index(ZIndex::S10);
+
+    let mut stack: iced_core::Element<'_, (), iced_core::Theme, ()> =
+        positioned_stack(space(100.0, 40.0))
+            .push(space(10.0, 10.0), &badge)
+            .push(space(5.0, 4.0).width(Length::Fill), &footer)
+            .into();
+    let mut tree = Tree::new(&stack);
+    let node = stack.as_widget_mut().layout(
+        &mut tree,
+        &(),
+        &layout::Limits::new(Size::ZERO, Size::INFINITE),
+    );
+    let layers: Vec<Rectangle> = layout::Layout::new(&node)
+        .children()
+        .map(|layer| {
+            layer
+                .children()
+                .next()
+                .map_or(layer.bounds(), |content| content.bounds())
+        })
+        .collect();
+
+    assert_eq!(node.size(), Size::new(100.0, 40.0));
+    // The footer has the lower z-index, so it is drawn before the badge.
+    assert_eq!(
+        layers[1],
+        Rectangle::new(Point::new(8.0, 26.0), Size::new(84.0, 4.0))
+    );
+    assert_eq!(
+        layers[2],
+        Rectangle::new(Point::new(94.0, -4.0), Size::new(10.0, 10.0))
+    );
+}
+
+/// Renderer that records the quads it is asked to fill, and the dash segments of each
+/// stroke and the color of each solid fill in the geometry it draws.
+#[derive(Default)]
+struct QuadRecorder(
+    Vec<(Rectangle, iced_core::Border, iced_core::Background)>,
+    Vec<Vec<f32>>,
+    Vec<iced_core::Color>,
+);
+
+impl geometry::Renderer for QuadRecorder {
+    type Geometry = StrokeRecorder;
+    type Frame = StrokeRecorder;
+
+    fn new_frame(&self, _bounds: Rectangle) -> Self::Frame {
+        StrokeRecorder::default()
+    }
+
+    fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.1.extend(geometry.0);
+        self.2.extend(geometry.1);
+    }
+}
+
+/// Geometry frame that records the dash segments of each stroke and the color of each
+/// solid fill.
+#[derive(Debug, Clone, Default)]
+struct StrokeRecorder(Vec<Vec<f32>>, Vec<iced_core::Color>);
+
+impl Cached for StrokeRecorder {
+    type Cache = Self;
+
+    fn load(cache: &Self::Cache) -> Self {
+        cache.clone()
+    }
+
+    fn cache(self, _group: Group, _previous: Option<Self::Cache>) -> Self::Cache {
+        self
+    }
+}
+
+impl geometry::frame::Backend for StrokeRecorder {
+    type Geometry = Self;
+
+    fn width(&self) -> f32 {
+        0.0
+    }
+
+    fn height(&self) -> f32 {
+        0.0
+    }
+
+    fn size(&self) -> Size {
+        Size::ZERO
+    }
+
+    fn center(&self) -> Point {
+        Point::ORIGIN
+    }
+
+    fn push_transform(&mut self) {}
+
+    fn pop_transform(&mut self) {}
+
+    fn translate(&mut self, _translation: iced_core::Vector) {}
+
+    fn rotate(&mut self, _angle: impl Into<iced_core::Radians>) {}
+
+    fn scale(&mut self, _scale: impl Into<f32>) {}
+
+    fn scale_nonuniform(&mut self, _scale: impl Into<iced_core::Vector>) {}
+
+    fn draft(&mut self, _clip_bounds: Rectangle) -> Self {
+        Self::default()
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.0.extend(frame.0);
+        self.1.extend(frame.1);
+    }
+
+    fn stroke<'a>(&mut self, _path: &geometry::Path, stroke: impl Into<geometry::Stroke<'a>>) {
+        self.0.push(stroke.into().line_dash.segments.to_vec());
+    }
+
+    fn stroke_rectangle<'a>(
+        &mut self,
+        _top_left: Point,
+        _size: Size,
+        stroke: impl Into<geometry::Stroke<'a>>,
+    ) {
+        self.0.push(stroke.into().line_dash.segments.to_vec());
+    }
+
+    fn stroke_text<'a>(
+        &mut self,
+        _text: impl Into<geometry::Text>,
+        _stroke: impl Into<geometry::Stroke<'a>>,
+    ) {
+    }
+
+    fn fill(&mut self, _path: &geometry::Path, fill: impl Into<geometry::Fill>) {
+        if let geometry::Style::Solid(color) = fill.into().style {
+            self.1.push(color);
+        }
+    }
+
+    fn fill_text(&mut self, _text: impl Into<geometry::Text>) {}
+
+    fn fill_rectangle(&mut self, _top_left: Point, _size: Size, _fill: impl Into<geometry::Fill>) {}
+
+    fn draw_image(&mut self, _bounds: Rectangle, _image: impl Into<geometry::Image>) {}
+
+    fn draw_svg(&mut self, _bounds: Rectangle, _svg: impl Into<geometry::Svg>) {}
+
+    fn into_geometry(self) -> Self::Geometry {
+        self
+    }
+}
+
+impl BlurRenderer for QuadRecorder {}
+
+impl iced_core::Renderer for QuadRecorder {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, _transformation: iced_core::Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn reset(&mut self, _new_bounds: Rectangle) {}
+
+    fn fill_quad(
+        &mut self,
+        quad: iced_core::renderer::Quad,
+        background: impl Into<iced_core::Background>,
+    ) {
+        self.0.push((quad.bounds, quad.border, background.into()));
+    }
+
+    fn allocate_image(
+        &mut self,
+        _handle: &iced_core::image::Handle,
+        callback: impl FnOnce(Result<iced_core::image::Allocation, iced_core::image::Error>)
+        + Send
+        + 'static,
+    ) {
+        callback(Err(iced_core::image::Error::Unsupported));
+    }
+}
+
+#[test]
+fn test_decorations_draw_ring_inset_shadow_and_outline() {
+    let style = Style::new()
+        .ring(RingWidth::S2, Color::blue(Scale::S500))
+        .inset_shadow(InsetShadow::Sm)
+        .outline(
+            BorderWidth::S1,
+            OutlineStyle::Solid,
+            Color::red(Scale::S500),
+        );
+    let decorations = decorations::Decorations::new(
+        &style,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    );
+    let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 40.0));
+    let radius = iced_core::border::Radius::from(6.0);
+    let widths = iced_core::Padding::new(1.0);
+    let mut renderer = QuadRecorder::default();
+    decorations.draw_under(
+        &mut renderer,
+        bounds,
+        radius,
+        widths,
+        iced_core::Color::BLACK,
+    );
+    decorations.draw_over(&mut renderer, bounds, radius, iced_core::Color::BLACK);
+
+    let [ring, inset_shadow, outline] = renderer.0.as_slice() else {
+        panic!("expected three quads, got {}", renderer.0.len());
+    };
+    assert_eq!(ring.0, bounds.expand(2.0));
+    assert_eq!(ring.1.width, 2.0);
+    assert_eq!(ring.1.radius.top_left, 8.0);
+    assert_eq!(ring.1.color, to_color(Color::blue(Scale::S500)));
+    assert_eq!(inset_shadow.0, bounds.shrink(1.0));
+    assert!(matches!(inset_shadow.2, iced_core::Background::Gradient(_)));
+    assert_eq!(outline.0, bounds.expand(1.0));
+    assert_eq!(outline.1.color, to_color(Color::red(Scale::S500)));
+
+    let current = decorations::Decorations::new(
+        &Style::new().ring_width(RingWidth::S1),
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    );
+    let mut renderer = QuadRecorder::default();
+    current.draw_under(
+        &mut renderer,
+        bounds,
+        radius,
+        widths,
+        iced_core::Color::WHITE,
+    );
+    assert_eq!(renderer.0[0].1.color, iced_core::Color::WHITE);
+}
+
+#[test]
+fn test_border_sides_and_corners_follow_the_style() {
+    let tab = Style::new()
+        .rounded_t(BorderRadius::Md)
+        .border_b(BorderWidth::S2)
+        .ring(RingWidth::S1, Color::blue(Scale::S500))
+        .inset_shadow(InsetShadow::Xs);
+    let radius = conversions::resolved_border_radius(&tab);
+    let widths = conversions::resolved_border_widths(&tab);
+
+    assert_eq!(
+        <[f32; 4]>::from(radius),
+        [6.0, 6.0, 0.0, 0.0],
+        "unset corners stay square"
+    );
+    assert_eq!(widths, iced_core::Padding::ZERO.bottom(2.0));
+    assert_eq!(
+        to_border_radii(BorderRadii::all(BorderRadius::Lg)),
+        iced_core::border::Radius::from(8.0)
+    );
+
+    let decorations = decorations::Decorations::new(
+        &tab,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    );
+    let bounds = Rectangle::new(Point::ORIGIN, Size::new(80.0, 32.0));
+    let mut renderer = QuadRecorder::default();
+    decorations.draw_under(
+        &mut renderer,
+        bounds,
+        radius,
+        widths,
+        iced_core::Color::BLACK,
+    );
+
+    let [ring, inset_shadow] = renderer.0.as_slice() else {
+        panic!("expected two quads, got {}", renderer.0.len());
+    };
+    assert_eq!(<[f32; 4]>::from(ring.1.radius), [7.0, 7.0, 0.0, 0.0]);
+    assert_eq!(
+        inset_shadow.0,
+        Rectangle::new(Point::ORIGIN, Size::new(80.0, 30.0))
+    );
+    assert_eq!(
+        <[f32; 4]>::from(inset_shadow.1.radius),
+        [6.0, 6.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+fn test_decorations_draw_gradients_inside_the_border() {
+    let banner = Style::new()
+        .bg_linear(LinearDirection::ToBr)
+        .gradient_from(Color::sky(Scale::S500))
+        .gradient_to(Color::indigo(Scale::S500))
+        .inset_shadow(InsetShadow::Xs);
+    let decorations = decorations::Decorations::new(
+        &banner,
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    );
+    let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 100.0));
+    let radius = iced_core::border::Radius::from(8.0);
+    let widths = iced_core::Padding::new(2.0);
+    let mut renderer = QuadRecorder::default();
+    decorations.draw_under(
+        &mut renderer,
+        bounds,
+        radius,
+        widths,
+        iced_core::Color::BLACK,
+    );
+
+    let [gradient, _inset_shadow] = renderer.0.as_slice() else {
+        panic!("expected two quads, got {}", renderer.0.len());
+    };
+    assert_eq!(gradient.0, bounds.shrink(2.0));
+    assert_eq!(gradient.1.radius.top_left, 6.0);
+    let iced_core::Background::Gradient(iced_core::Gradient::Linear(linear)) = gradient.2 else {
+        panic!("expected a linear gradient, got {:?}", gradient.2);
+    };
+    let stops: Vec<_> = linear.stops.iter().flatten().collect();
+    assert_eq!(stops.len(), 8);
+    // iced's gradient line stops at the edges, so its ends sit inside the CSS line.
+    assert_ne!(stops[0].color, to_color(Color::sky(Scale::S500)));
+
+    let resolved = twill_core::tokens::Gradient::new(GradientKind::Linear(LinearDirection::ToR))
+        .resolve(|_| Some(Color::sky(Scale::S500).compute()))
+        .expect("the gradient has a kind");
+    assert!(to_gradient(&resolved, Size::new(200.0, 100.0)).is_some());
+
+    let radial = decorations::Decorations::new(
+        &Style::new()
+            .bg_radial()
+            .gradient_from(Color::white())
+            .gradient_to(Color::black()),
+        SemanticThemeVars::shadcn_neutral(),
+        ThemeVariant::Light,
+    );
+    let mut renderer = QuadRecorder::default();
+    radial.draw_under(
+        &mut renderer,
+        bounds,
+        radius,
+        widths,
+        iced_core::Color::BLACK,
+    );
+    assert!(renderer.0.is_empty(), "radial gradients are not quads");
+    let bands = &renderer.2;
+    assert!(bands.len() > 8, "the gradient is painted in bands");
+    assert!(bands[0].r > 0.9 && bands.last().unwrap().r < 0.1);
+}
+
+#[test]
+fn test_blurred_backgrounds_fade_over_layered_quads() {
+    let glow = Style::new()
+        .bg(Color::blue(Scale::S500))
+        .blur(Blur::Sm)
+        .grayscale(100);
+    let theme = SemanticThemeVars::shadcn_neutral();
+    let decorations = decorations::Decorations::new(&glow, theme, ThemeVariant::Light);
+    assert!(decorations.blurs_background());
+
+    let bounds = Rectangle::new(Point::new(20.0, 20.0), Size::new(100.0, 60.0));
+    let mut renderer = QuadRecorder::default();
+    decorations.draw_under(
+        &mut renderer,
+        bounds,
+        iced_core::border::Radius::from(4.0),
+        iced_core::Padding::ZERO,
+        iced_core::Color::BLACK,
+    );
+    assert!(
+        renderer.0.is_empty(),
+        "the blur is drawn under the border box"
+    );
+
+    decorations
+        .blurred_background()
+        .expect("the background is blurred")
+        .draw(&mut renderer, bounds, iced_core::border::Radius::from(4.0));
+    assert!(renderer.0.len() > 8, "the edge is built from nested quads");
+    assert!(renderer.0[0].0.contains(renderer.0[1].0.center()));
+    assert!(renderer.0[0].0.width > renderer.0.last().unwrap().0.width);
+    for (_, _, background) in &renderer.0 {
+        let iced_core::Background::Color(color) = background else {
+            panic!("expected solid quads, got {background:?}");
+        };
+        assert!((color.r - color.g).abs() < 0.01 && (color.g - color.b).abs() < 0.01);
+    }
+
+    let frosted = Style::new()
+        .bg_arbitrary(ColorValue::from_rgb(255, 255, 255).with_alpha(0.2).into())
+        .backdrop_blur(Blur::Xl);
+    let background = filters::resolved_background(&frosted, theme, ThemeVariant::Light)
+        .expect("the style has a background");
+    assert!(background.a > 0.2);
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_shader_blur_uniforms_are_in_physical_pixels() {
+    let primitive = blur::BlurPrimitive::new(
+        Size::new(130.0, 90.0),
+        Rectangle::new(Point::new(15.0, 15.0), Size::new(100.0, 60.0)),
+        iced_core::border::Radius::from(4.0).top_right(6.0),
+        iced_core::Color::WHITE,
+        5.0,
+    );
+    let uniforms = primitive.uniforms(2.0);
+
+    assert_eq!(uniforms[..4], [1.0; 4]);
+    assert_eq!(uniforms[4..8], [30.0, 30.0, 230.0, 150.0]);
+    assert_eq!(uniforms[8..12], [8.0, 12.0, 8.0, 8.0]);
+    assert_eq!(uniforms[12..15], [260.0, 180.0, 10.0]);
+}
+
+#[test]
+fn test_transformations_translate_and_scale_around_the_origin() {
+    let bounds = Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 40.0));
+    let pressed = Style::new().scale(50);
+    let transformation = to_transformation(&pressed, bounds).expect("scale-50 is drawn");
+    assert_eq!(
+        Point::new(0.0, 0.0) * transformation,
+        Point::new(25.0, 10.0)
+    );
+    assert_eq!(
+        Point::new(50.0, 20.0) * transformation,
+        Point::new(50.0, 20.0)
+    );
+
+    let corner = pressed.origin_top_left().translate_x(InsetValue::Px(10.0));
+    let transformation = to_transformation(&corner, bounds).expect("the style moves");
+    assert_eq!(
+        Point::new(100.0, 40.0) * transformation,
+        Point::new(60.0, 20.0)
+    );
+
+    // iced transformations cannot rotate, skew or stretch one axis.
+    assert_eq!(to_transformation(&Style::new().rotate(45), bounds), None);
+    assert_eq!(to_transformation(&Style::new().scale_x(150), bounds), None);
+    assert_eq!(
+        to_transformation(&Style::new().transform_none(), bounds),
+        None
+    );
+}
+
+#[test]
+fn test_animations_rotate_icons_and_stop_when_finished() {
+    use iced_core::time::{Duration, Instant};
+
+    let icon = Style::new().animate(AnimationToken::Spin);
+    let start = Instant::now();
+    let spinner = Animation::from_style(&icon, start).expect("spin animates");
+    let quarter = spinner.apply(&icon, start + Duration::from_millis(250));
+    let Some(iced_core::Rotation::Floating(angle)) = to_rotation(&quarter) else {
+        panic!("expected a floating rotation");
+    };
+    assert!((angle.0 - std::f32::consts::FRAC_PI_2).abs() < 1e-4);
+    assert_eq!(to_rotation(&icon), None);
+    assert!(Animation::from_style(&Style::new(), start).is_none());
+
+    let fade = Animation::new(
+        Keyframes::new(TransitionDuration::Ms200, Easing::Linear)
+            .at(0, Keyframe::new().opacity(0.0))
+            .iterations(1),
+        start,
+    );
+    assert_eq!(
+        fade.sample(start + Duration::from_millis(100))
+            .opacity_value(),
+        Some(0.5)
+    );
+    assert!(!fade.is_running(start + Duration::from_millis(200)));
+    assert_eq!(fade.apply(&icon, start + Duration::from_secs(1)), icon);
+}
+
+#[test]
+fn test_styled_text_ellipsizes_to_the_longest_fitting_prefix() {
+    let chars_fit = |max: usize| move |text: &str| text.chars().count() <= max;
+    assert_eq!(ellipsize("quarterly-report.xlsx", chars_fit(8)), "quarter…");
+    // White space before the ellipsis is dropped, like in CSS.
+    assert_eq!(ellipsize("ab cdef", chars_fit(4)), "ab…");
+    assert_eq!(ellipsize("résumé", chars_fit(3)), "ré…");
+    assert_eq!(ellipsize("wide", chars_fit(0)), "…");
+
+    let layout = |style: &Style| to_wrapping(&twill_backend_common::text_layout(style));
+    assert_eq!(
+        layout(&Style::new().truncate()),
+        iced_core::text::Wrapping::None
+    );
+    assert_eq!(
+        layout(&Style::new().line_clamp(2).break_words()),
+        iced_core::text::Wrapping::WordOrGlyph
+    );
+    assert_eq!(
+        layout(&Style::new().break_all()),
+        iced_core::text::Wrapping::Glyph
+    );
+    assert_eq!(layout(&Style::new()), iced_core::text::Wrapping::Word);
+
+    let mut cell: iced_core::Element<'_, (), iced_core::Theme, ()> =
+        styled_text("  quarterly\n report.xlsx ", &Style::new().truncate())
+            .width(Length::Fill)
+            .into();
+    let mut tree = Tree::new(&cell);
+    let node = cell.as_widget_mut().layout(
+        &mut tree,
+        &(),
+        &layout::Limits::new(Size::ZERO, Size::new(120.0, 40.0)),
+    );
+    assert_eq!(node.size(), Size::new(120.0, 0.0));
+}
+
+#[test]
+fn test_styled_text_reports_transformed_text() {
+    struct Texts(Vec<String>);
+
+    impl iced_core::widget::Operation for Texts {
+        fn traverse(&mut self, _operate: &mut dyn FnMut(&mut dyn iced_core::widget::Operation)) {}
+
+        fn text(&mut self, _id: Option<&iced_core::widget::Id>, _bounds: Rectangle, text: &str) {
+            self.0.push(text.to_owned());
+        }
+    }
+
+    let mut texts = Texts(Vec::new());
+    for (content, style) in [
+        (
+            "  monthly\n revenue ",
+            Style::new().uppercase().whitespace(WhiteSpace::Normal),
+        ),
+        (
+            "(draft) q3 report",
+            Style::new().capitalize().line_through(),
+        ),
+        (
+            "Mixed Case",
+            Style::new().lowercase().font_weight(FontWeight::Bold),
+        ),
+    ] {
+        let mut label: iced_core::Element<'_, (), iced_core::Theme, ()> =
+            styled_text(content, &style).into();
+        let mut tree = Tree::new(&label);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0));
+        let node = label.as_widget_mut().layout(&mut tree, &(), &limits);
+        label
+            .as_widget_mut()
+            .operate(&mut tree, layout::Layout::new(&node), &(), &mut texts);
+    }
+
+    assert_eq!(
+        texts.0,
+        vec!["MONTHLY REVENUE", "(Draft) Q3 Report", "mixed case"]
+    );
+}
+
+#[test]
+fn test_styled_text_resolves_text_color_with_semantic_theme() {
+    let theme = SemanticThemeVars::shadcn_neutral();
+    let style = Style::new().text_color_token(TextColor::semantic(SemanticColor::Foreground));
+    let color = |variant| {
+        theme
+            .resolve_value(SemanticColor::Foreground, variant)
+            .map(to_color_value)
+    };
+
+    assert_eq!(
+        styled_text("Title", &style).color,
+        color(ThemeVariant::Light)
+    );
+    assert_eq!(
+        styled_text("Title", &style)
+            .semantic_theme(theme, ThemeVariant::Dark)
+            .color,
+        color(ThemeVariant::Dark)
+    );
+    assert_eq!(
+        styled_text("Title", &style)
+            .color(iced_core::Color::WHITE)
+            .semantic_theme(theme, ThemeVariant::Dark)
+            .color,
+        Some(iced_core::Color::WHITE)
+    );
+}
+
+#[test]
+fn test_to_font_picks_registered_face() {
+    let fonts = FontRegistry::new()
+        .register(
+            FontFamily::Sans,
+            FontFace::new("Inter", FontSource::bytes(b"regular")),
+        )
+        .register(
+            FontFamily::Sans,
+            FontFace::new("Inter", FontSource::bytes(b"bold")).weight(FontWeight::Bold),
+        )
+        .register(
+            "Display",
+            FontFace::new("Fraunces", FontSource::bytes(b"display")).italic(),
+        );
+
+    let semibold = to_font(&fonts, FontFamily::Sans, FontWeight::SemiBold, false).unwrap();
+    assert_eq!(semibold.family, iced_core::font::Family::Name("Inter"));
+    assert_eq!(semibold.weight, iced_core::font::Weight::Bold);
+
+    let display = to_font(&fonts, "Display", FontWeight::Normal, false).unwrap();
+    assert_eq!(display.family, iced_core::font::Family::Name("Fraunces"));
+    assert_eq!(display.style, iced_core::font::Style::Italic);
+
+    assert_eq!(
+        to_font(&fonts, FontFamily::Mono, FontWeight::Normal, false),
+        None
+    );
+    assert_eq!(
+        font_bytes(&fonts).unwrap(),
+        [&b"regular"[..], b"bold", b"display"]
+    );
+}
@@ -0,0 +1,466 @@
+use iced_core::layout::{self, Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::text::{self, Difference, LineHeight, Paragraph, Wrapping};
+use iced_core::widget::text::{Format, Style as TextStyle};
+use iced_core::widget::{Operation, Tree, tree};
+use iced_core::{
+    Color, Font, Length, Pixels, Point, Rectangle, Size, Vector, Widget as AdvancedWidget, mouse,
+};
+use twill_backend_common::{
+    TextLayout, display_text, line_height_multiplier, text_layout, text_shadow_layers,
+};
+use twill_core::style::Style;
+use twill_core::tokens::{
+    FontFamily, FontRegistry, FontWeight, SemanticThemeVars, TextColor, TextDecoration,
+    TextOverflow, ThemeVariant, WordBreak,
+};
+
+use super::conversions::{
+    SemanticThemeSource, resolve_text_color_token_with_semantic_theme, to_color_value,
+    to_font_family, to_font_weight, to_text_alignment,
+};
+use super::fonts::to_font;
+
+/// Text styled by the typography utilities of a [`Style`] and laid out by its
+/// `whitespace-*`, `break-*`, `line-clamp-*`, `truncate` and `text-ellipsis`/`text-clip`
+/// utilities.
+///
+/// `font-*`, `text-<size>`, `leading-*`, `text-<color>`, `text-<align>`, `uppercase`,
+/// `lowercase`, `capitalize`, `underline`, `overline`, `line-through` and
+/// `text-shadow-*` are applied; a font size without `leading-*` brings its own line
+/// height, like in Tailwind. iced text has no letter spacing, so `tracking-*` is not
+/// drawn, and iced cannot blur text, so each text-shadow layer is drawn sharp at its
+/// offset. `font-*` maps to iced's generic families unless [`StyledText::fonts`] picks a
+/// face the app registered, and semantic `text-*` colors resolve with the light shadcn
+/// neutral theme unless [`StyledText::semantic_theme`] names another.
+///
+/// White space collapses like in CSS, `whitespace-nowrap` keeps lines from wrapping and
+/// `break-all`/`break-words` let wrapped lines break inside words. Text cut off by a
+/// line clamp, or by the width of the widget when it does not wrap, ends in an ellipsis
+/// found by measuring shorter prefixes; `text-clip` cuts it at the edge instead:
+///
+/// ```rust
+/// use twill_core::prelude::*;
+///
+/// let heading = Style::new()
+///     .text_size(FontSize::S2xl)
+///     .font_weight(FontWeight::SemiBold)
+///     .text_color(Color::slate(Scale::S900));
+/// let file_name = Style::new().truncate();
+/// let excerpt = Style::new().line_clamp(3).break_words();
+///
+/// let title: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::styled_text("Quarterly reports", &heading).into();
+/// let cell: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::styled_text("quarterly-report-final-v2.xlsx", &file_name)
+///         .width(iced_core::Length::Fill)
+///         .into();
+/// let summary: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::styled_text("A long description of the file...", &excerpt).into();
+/// # let _ = (title, cell, summary);
+/// ```
+pub struct StyledText<'a> {
+    content: text::Fragment<'a>,
+    format: Format<Font>,
+    family: Option<FontFamily>,
+    weight: Option<FontWeight>,
+    layout: TextLayout,
+    pub(super) color: Option<Color>,
+    color_token: Option<TextColor>,
+    decoration: Option<TextDecoration>,
+    shadow: &'static [(f32, f32, f32)],
+}
+
+/// Create a [`StyledText`] showing `content` with the typography and text layout of
+/// `style`.
+pub fn styled_text<'a>(content: impl text::IntoFragment<'a>, style: &Style) -> StyledText<'a> {
+    let content = content.into_fragment();
+    let content = match display_text(&content, style) {
+        std::borrow::Cow::Borrowed(_) => content,
+        std::borrow::Cow::Owned(displayed) => text::Fragment::Owned(displayed),
+    };
+    let layout = text_layout(style);
+    let color_token = style.text_color_token_value();
+    let size = style
+        .font_size_value()
+        .and_then(|size| size.resolve_px(&[]))
+        .map(Pixels);
+
+    StyledText {
+        content,
+        format: Format {
+            size,
+            line_height: line_height_multiplier(style)
+                .map_or(LineHeight::default(), LineHeight::Relative),
+            align_x: style
+                .text_align_value()
+                .map_or(text::Alignment::Default, to_text_alignment),
+            wrapping: to_wrapping(&layout),
+            ..Format::default()
+        },
+        family: style.font_family_value(),
+        weight: style.font_weight_value(),
+        layout,
+        color: color_token
+            .and_then(|token| {
+                resolve_text_color_token_with_semantic_theme(
+                    token,
+                    SemanticThemeVars::shadcn_neutral(),
+                    ThemeVariant::Light,
+                )
+            })
+            .map(to_color_value),
+        color_token,
+        decoration: style
+            .text_decoration_value()
+            .filter(|&decoration| decoration != TextDecoration::None),
+        shadow: style.text_shadow_value().map_or(&[], text_shadow_layers),
+    }
+}
+
+/// The iced [`Wrapping`] of `layout`.
+pub(crate) fn to_wrapping(layout: &TextLayout) -> Wrapping {
+    if !layout.wrap {
+        return Wrapping::None;
+    }
+    match layout.word_break {
+        WordBreak::BreakAll => Wrapping::Glyph,
+        WordBreak::BreakWord => Wrapping::WordOrGlyph,
+        WordBreak::Normal | WordBreak::KeepAll => Wrapping::Word,
+    }
+}
+
+/// The longest prefix of `content` that still `fits` once it ends in an ellipsis.
+pub(crate) fn ellipsize(content: &str, mut fits: impl FnMut(&str) -> bool) -> String {
+    let ends: Vec<usize> = content
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain([content.len()])
+        .collect();
+    let shortened = |chars: usize| format!("{}…", content[..ends[chars]].trim_end());
+
+    // Keeping no characters always "fits"; keeping all of them is known not to.
+    let (mut fitting, mut overflowing) = (0, ends.len() - 1);
+    while overflowing - fitting > 1 {
+        let middle = fitting + (overflowing - fitting) / 2;
+        if fits(&shortened(middle)) {
+            fitting = middle;
+        } else {
+            overflowing = middle;
+        }
+    }
+    shortened(fitting)
+}
+
+/// The shown text, shaped as a single span so its line bounds can be decorated.
+struct State<P> {
+    paragraph: P,
+    content: String,
+}
+
+impl<P: Paragraph> State<P> {
+    fn update(&mut self, text: text::Text<&str, P::Font>) {
+        let spans = [text::Span::<(), P::Font>::new(text.content)];
+        if self.content != text.content {
+            text.content.clone_into(&mut self.content);
+            self.paragraph = P::with_spans(text.with_content(&spans[..]));
+            return;
+        }
+        match self.paragraph.compare(text.with_content(())) {
+            Difference::None => {}
+            Difference::Bounds => self.paragraph.resize(text.bounds),
+            Difference::Shape => self.paragraph = P::with_spans(text.with_content(&spans[..])),
+        }
+    }
+}
+
+impl StyledText<'_> {
+    /// Set the width of the text.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.format.width = width.into();
+        self
+    }
+
+    /// Set the height of the text.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.format.height = height.into();
+        self
+    }
+
+    /// Set the color of the text, overriding the `text-*` color of the style.
+    #[must_use]
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self.color_token = None;
+        self
+    }
+
+    /// Resolve the `text-*` color of the style through `theme` for `variant` instead of
+    /// the light shadcn neutral theme.
+    #[must_use]
+    pub fn semantic_theme<S: SemanticThemeSource + ?Sized>(
+        mut self,
+        theme: &S,
+        variant: ThemeVariant,
+    ) -> Self {
+        if let Some(token) = self.color_token {
+            self.color = resolve_text_color_token_with_semantic_theme(token, theme, variant)
+                .map(to_color_value);
+        }
+        self
+    }
+
+    /// Use the face of `fonts` nearest to the `font-*` family (`font-sans` by default)
+    /// and weight of the style, when the family has registered faces; see [`to_font`].
+    #[must_use]
+    pub fn fonts(mut self, fonts: &FontRegistry) -> Self {
+        let family = self.family.unwrap_or(FontFamily::Sans);
+        let weight = self.weight.unwrap_or(FontWeight::Normal);
+        if let Some(font) = to_font(fonts, family, weight, false) {
+            self.format.font = Some(font);
+        }
+        self
+    }
+
+    fn clips(&self) -> bool {
+        self.layout.overflow.is_some() || self.layout.max_lines.is_some()
+    }
+
+    fn font_size(&self, renderer: &impl text::Renderer) -> Pixels {
+        self.format.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// The registered font, or the default font of `renderer` with the family and
+    /// weight of the style.
+    fn font(&self, renderer: &impl text::Renderer<Font = Font>) -> Font {
+        if let Some(font) = self.format.font {
+            return font;
+        }
+        let mut font = renderer.default_font();
+        if let Some(family) = self.family {
+            font.family = to_font_family(family);
+        }
+        if let Some(weight) = self.weight {
+            font.weight = to_font_weight(weight);
+        }
+        font
+    }
+
+    /// The content shortened to what fits in `bounds`, ending in an ellipsis when the
+    /// layout asks for one.
+    fn fitted<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Option<String>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        if self.layout.overflow != Some(TextOverflow::Ellipsis) {
+            return None;
+        }
+        let size = self.font_size(renderer);
+        let font = self.font(renderer);
+        let measure = |content: &str, width: f32, wrapping: Wrapping| {
+            Renderer::Paragraph::with_text(text::Text {
+                content,
+                bounds: Size::new(width, f32::INFINITY),
+                size,
+                line_height: self.format.line_height,
+                font,
+                align_x: self.format.align_x,
+                align_y: self.format.align_y,
+                shaping: self.format.shaping,
+                wrapping,
+            })
+            .min_bounds()
+        };
+
+        if let Some(max_lines) = self.layout.max_lines.filter(|_| self.layout.wrap) {
+            let line_height = self.format.line_height.to_absolute(size).0;
+            if line_height <= 0.0 {
+                return None;
+            }
+            let lines = |content: &str| {
+                (measure(content, bounds.width, self.format.wrapping).height / line_height).round()
+                    as usize
+            };
+            return (lines(&self.content) > max_lines)
+                .then(|| ellipsize(&self.content, |content| lines(content) <= max_lines));
+        }
+        if self.layout.wrap {
+            return None;
+        }
+
+        let width = |line: &str| measure(line, f32::INFINITY, Wrapping::None).width;
+        let mut shortened = false;
+        let lines: Vec<_> = self
+            .content
+            .split('\n')
+            .map(|line| {
+                if width(line) <= bounds.width {
+                    return line.to_owned();
+                }
+                shortened = true;
+                ellipsize(line, |line| width(line) <= bounds.width)
+            })
+            .collect();
+        shortened.then(|| lines.join("\n"))
+    }
+
+    /// Draw the `underline`, `overline` or `line-through` of every line of `paragraph`.
+    fn draw_decoration<Renderer: text::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        position: Point,
+        color: Color,
+    ) {
+        let Some(decoration) = self.decoration else {
+            return;
+        };
+        let size = self.font_size(renderer).0;
+        let line_height = self.format.line_height.to_absolute(Pixels(size)).0;
+        // Lines are centered in their line box, so this is where the glyphs sit.
+        let baseline = size + (line_height - size) / 2.0;
+        let y = match decoration {
+            TextDecoration::Underline => baseline - size * 0.08,
+            TextDecoration::Overline => (line_height - size) / 2.0,
+            TextDecoration::LineThrough => baseline - size / 2.0,
+            TextDecoration::None => return,
+        };
+
+        for line in paragraph.span_bounds(0) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        line.position() + (position - Point::ORIGIN) + Vector::new(0.0, y),
+                        Size::new(line.width, 1.0),
+                    ),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> AdvancedWidget<Message, Theme, Renderer> for StyledText<'_>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            paragraph: Renderer::Paragraph::default(),
+            content: String::new(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.format.width, self.format.height)
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        layout::sized(limits, self.format.width, self.format.height, |limits| {
+            let bounds = limits.max();
+            let size = self.font_size(renderer);
+            let fitted = self.fitted(renderer, bounds);
+
+            state.update(text::Text {
+                content: fitted.as_deref().unwrap_or(&self.content),
+                bounds,
+                size,
+                line_height: self.format.line_height,
+                font: self.font(renderer),
+                align_x: self.format.align_x,
+                align_y: self.format.align_y,
+                shaping: self.format.shaping,
+                wrapping: self.format.wrapping,
+            });
+
+            let mut measured = state.paragraph.min_bounds();
+            if let Some(max_lines) = self.layout.max_lines {
+                let line_height = self.format.line_height.to_absolute(size).0;
+                measured.height = measured.height.min(max_lines as f32 * line_height);
+            }
+            measured
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let paragraph = &tree
+            .state
+            .downcast_ref::<State<Renderer::Paragraph>>()
+            .paragraph;
+        let bounds = layout.bounds();
+        let color = self.color.unwrap_or(style.text_color);
+        let draw = |renderer: &mut Renderer| {
+            // The first shadow layer is the top-most one, like in CSS.
+            let anchor = bounds.anchor(
+                paragraph.min_bounds(),
+                paragraph.align_x(),
+                paragraph.align_y(),
+            );
+            for &(offset, _, alpha) in self.shadow.iter().rev() {
+                renderer.fill_paragraph(
+                    paragraph,
+                    anchor + Vector::new(0.0, offset),
+                    Color {
+                        a: alpha * color.a,
+                        ..Color::BLACK
+                    },
+                    *viewport,
+                );
+            }
+            self.draw_decoration(renderer, paragraph, bounds.position(), color);
+            iced_core::widget::text::draw(
+                renderer,
+                style,
+                bounds,
+                paragraph,
+                TextStyle { color: Some(color) },
+                viewport,
+            );
+        };
+
+        if self.clips() {
+            renderer.with_layer(bounds, draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.text(None, layout.bounds(), &self.content);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StyledText<'a>>
+    for super::common::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(widget: StyledText<'a>) -> Self {
+        Self::new(widget)
+    }
+}
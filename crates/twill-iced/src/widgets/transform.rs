@@ -0,0 +1,240 @@
+use iced_core::layout::{Layout as AdvancedLayout, Limits, Node};
+use iced_core::renderer;
+use iced_core::widget::{Operation, Tree, tree};
+use iced_core::{
+    Clipboard, Event, Length, Radians, Rectangle, Rotation, Shell, Size, Transformation, Vector,
+    Widget as AdvancedWidget, mouse, overlay,
+};
+use twill_core::style::Style;
+use twill_core::utilities::{Transform, TransformOrigin};
+
+/// A wrapper that draws its content moved by the `translate-*` and `scale-*`
+/// transforms of a [`Style`], around its `origin-*`.
+///
+/// Like CSS transforms, the content keeps its layout size and position; only what is
+/// drawn moves, and the cursor is mapped back so the moved content is what receives
+/// hover and clicks. iced transformations are limited to translation and uniform
+/// scaling, so `rotate-*`, `skew-*` and a `scale-x-*`/`scale-y-*` pair that differs
+/// are not drawn. [`styled_container`](crate::styled_container) wraps itself in this
+/// widget when its style has a transform, and [`TwillWidget`](crate::TwillWidget)
+/// applies the transform of its current state layers itself:
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+///
+/// let lifted = Style::new().translate_y(InsetValue::neg_scale(Spacing::S1)).scale(105);
+///
+/// let card: iced_core::Element<'_, (), iced_core::Theme, iced_widget::Renderer> =
+///     twill_iced::transformed(iced_widget::text("Featured"), &lifted).into();
+/// # let _ = card;
+/// ```
+pub struct Transformed<'a, Message, Theme = iced_core::Theme, Renderer = iced_widget::Renderer> {
+    content: super::common::Element<'a, Message, Theme, Renderer>,
+    transform: Transform,
+    origin: TransformOrigin,
+}
+
+/// Create a [`Transformed`] wrapper drawing `content` with the transform of `style`.
+pub fn transformed<'a, Message, Theme, Renderer>(
+    content: impl Into<super::common::Element<'a, Message, Theme, Renderer>>,
+    style: &Style,
+) -> Transformed<'a, Message, Theme, Renderer> {
+    Transformed {
+        content: content.into(),
+        transform: style.transform_value().unwrap_or_default(),
+        origin: style.transform_origin_value().unwrap_or_default(),
+    }
+}
+
+/// The iced transformation of `style` for a widget at `bounds`, or `None` when it does
+/// not move anything iced can draw.
+pub(crate) fn to_transformation(style: &Style, bounds: Rectangle) -> Option<Transformation> {
+    let transform = style.transform_value()?;
+    transformation(
+        transform,
+        style.transform_origin_value().unwrap_or_default(),
+        bounds,
+    )
+}
+
+fn transformation(
+    transform: Transform,
+    origin: TransformOrigin,
+    bounds: Rectangle,
+) -> Option<Transformation> {
+    let (translate_x, translate_y) = transform.translation(bounds.width, bounds.height);
+    let scale = match transform.scale() {
+        (x, y) if x == y && x > 0.0 => x,
+        _ => 1.0,
+    };
+    if translate_x == 0.0 && translate_y == 0.0 && scale == 1.0 {
+        return None;
+    }
+
+    let (origin_x, origin_y) = origin.point(bounds.width, bounds.height);
+    let (origin_x, origin_y) = (bounds.x + origin_x, bounds.y + origin_y);
+    Some(
+        Transformation::translate(origin_x + translate_x, origin_y + translate_y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-origin_x, -origin_y),
+    )
+}
+
+/// The `rotate-*` transform of `style` as a floating [`Rotation`], for widgets that
+/// rotate what they draw themselves, such as `svg` and `image` (a spinning icon under
+/// `animate-spin`).
+pub fn to_rotation(style: &Style) -> Option<Rotation> {
+    let degrees = style.transform_value()?.rotate()?;
+    Some(Rotation::Floating(Radians(f32::from(degrees).to_radians())))
+}
+
+/// Map `cursor` and `viewport` into the untransformed space of content drawn with
+/// `transformation`.
+pub(super) fn untransformed(
+    transformation: Option<Transformation>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) -> (mouse::Cursor, Rectangle) {
+    match transformation {
+        Some(transformation) => {
+            let inverse = transformation.inverse();
+            (cursor * inverse, *viewport * inverse)
+        }
+        None => (cursor, *viewport),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Transformed<'a, Message, Theme, Renderer> {
+    fn transformation(&self, bounds: Rectangle) -> Option<Transformation> {
+        transformation(self.transform, self.origin, bounds)
+    }
+}
+
+impl<Message, Theme, Renderer> AdvancedWidget<Message, Theme, Renderer>
+    for Transformed<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget_mut().layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(transformation) = self.transformation(layout.bounds()) else {
+            return self
+                .content
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        };
+        let (cursor, viewport) = untransformed(Some(transformation), cursor, viewport);
+
+        renderer.with_transformation(transformation, |renderer| {
+            self.content
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let (cursor, viewport) =
+            untransformed(self.transformation(layout.bounds()), cursor, viewport);
+        self.content.as_widget_mut().update(
+            tree, event, layout, cursor, renderer, clipboard, shell, &viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: AdvancedLayout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (cursor, viewport) =
+            untransformed(self.transformation(layout.bounds()), cursor, viewport);
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, &viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: AdvancedLayout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: AdvancedLayout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Transformed<'a, Message, Theme, Renderer>>
+    for super::common::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(widget: Transformed<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
@@ -0,0 +1,59 @@
+//! Registering the fonts of a [`FontRegistry`] with Slint's font collection.
+
+use std::sync::Arc;
+
+use slint::fontique_07::fontique::{Blob, FontInfoOverride, FontStyle, FontWeight};
+use twill_core::tokens::{FontLoadError, FontRegistry};
+
+/// Register the fonts of `fonts` with the font collection Slint renders text with.
+///
+/// Each face is registered under the name of its family,
+/// [`RegisteredFamily::name`](twill_core::tokens::RegisteredFamily::name), with its
+/// weight and slant, so `.slint` files pick faces by `font-family` and `font-weight` and
+/// Slint matches the nearest weight:
+///
+/// ```slint
+/// Text { font-family: "twill-sans"; font-weight: 600; }
+/// ```
+///
+/// Register the fonts before creating the first component. This needs the `fonts`
+/// feature, which turns on Slint's `unstable-fontique-07` API.
+pub fn register_fonts(fonts: &FontRegistry) -> Result<(), FontLoadError> {
+    let mut collection = slint::fontique_07::shared_collection();
+    for (family, faces) in fonts.families() {
+        for face in faces {
+            let data = face.source().load()?;
+            let style = if face.is_italic() {
+                FontStyle::Italic
+            } else {
+                FontStyle::Normal
+            };
+            collection.register_fonts(
+                Blob::new(Arc::new(data)),
+                Some(FontInfoOverride {
+                    family_name: Some(family.name()),
+                    weight: Some(FontWeight::new(f32::from(face.weight_value().value()))),
+                    style: Some(style),
+                    ..FontInfoOverride::default()
+                }),
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{FontFace, FontFamily, FontSource};
+
+    #[test]
+    fn test_register_fonts_reports_unreadable_files() {
+        let fonts = FontRegistry::new().register(
+            FontFamily::Sans,
+            FontFace::new("Inter", FontSource::path("missing/Inter.ttf")),
+        );
+        let error = register_fonts(&fonts).unwrap_err();
+        assert_eq!(error.path(), std::path::Path::new("missing/Inter.ttf"));
+    }
+}
@@ -1,16 +1,26 @@
 //! Slint backend for twill.
 //!
-//! Provides color constants and utilities for Slint UI.
+//! Provides color constants and utilities for Slint UI, plus [`SlintStyle`] for binding a
+//! whole [`Style`](twill_core::style::Style) to the `TwillStyle` struct in [`TWILL_SLINT`].
 
 #![forbid(unsafe_code)]
 
 use twill_backend_common::ShadowColor;
 use twill_core::tokens::{
-    AspectRatio, Blur, BorderRadius, Color, Cursor, FontSize, FontWeight, Scale, SemanticColor,
-    SemanticThemeVars, Shadow, Spacing, ThemeVariant, TransitionDuration,
+    AspectRatio, Blur, BorderRadius, Color, ColorValue, Cursor, FontSize, FontWeight, Scale,
+    SemanticColor, SemanticThemeVars, Shadow, Spacing, ThemeVariant, TransitionDuration,
 };
 use twill_core::traits::ComputeValue;
 
+#[cfg(feature = "fonts")]
+mod fonts;
+mod style;
+
+#[cfg(feature = "fonts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fonts")))]
+pub use fonts::register_fonts;
+pub use style::{SLINT_UI_PATH, SlintStyle, TWILL_SLINT};
+
 mod private {
     pub trait Sealed {}
 }
@@ -220,13 +230,17 @@ pub fn to_shadow(shadow: Shadow) -> (f32, f32) {
 
 /// Convert twill Shadow to Slint values with color.
 pub fn to_shadow_with_color(shadow: Shadow, color: ShadowColor) -> (f32, f32, slint::Color) {
-    let (offset, blur) = to_shadow(shadow);
-    let mut value = match color {
+    let value = match color {
         ShadowColor::Default => Color::black(),
         ShadowColor::Explicit(color) => color,
     }
     .compute();
-    value = value.with_alpha(
+    shadow_with_color_value(shadow, value)
+}
+
+fn shadow_with_color_value(shadow: Shadow, value: ColorValue) -> (f32, f32, slint::Color) {
+    let (offset, blur) = to_shadow(shadow);
+    let value = value.with_alpha(
         value.alpha()
             * match shadow {
                 Shadow::None => 0.0,
@@ -0,0 +1,395 @@
+//! Whole-`Style` conversion into one bindable Slint value.
+
+use twill_backend_common::{filter_blur_shadow_radius, frosted_alpha};
+use twill_core::style::Style;
+use twill_core::tokens::{
+    BackgroundColor, BorderColor, BorderWidth, Color, ColorValue, SemanticColor,
+    SemanticThemeSource, SemanticThemeVars, ShadowColorToken, TextColor, ThemeVariant,
+};
+use twill_core::traits::ComputeValue;
+use twill_core::utilities::PaddingValue;
+
+use crate::{
+    shadow_with_color_value, to_font_size, to_font_weight, to_radius, to_slint_color_value,
+};
+
+/// Source of the `TwillStyle` struct and the `TwillBox`/`TwillText` components.
+///
+/// The same file ships as `ui/twill.slint` in this crate (see [`SLINT_UI_PATH`]) so it
+/// can be imported from `.slint` files compiled by `slint-build` or `slint!`.
+pub const TWILL_SLINT: &str = include_str!("../ui/twill.slint");
+
+/// Absolute path of `twill.slint`, for `slint_build::CompilerConfiguration::with_library_paths`.
+pub const SLINT_UI_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/ui/twill.slint");
+
+/// Every visual property of a [`Style`] that a Slint `Rectangle` or `Text` can bind.
+///
+/// Field names match the `TwillStyle` struct in [`TWILL_SLINT`]; use
+/// [`impl_slint_style!`](crate::impl_slint_style) to convert into the generated type.
+/// Lengths are logical pixels. Semantic colors resolve through the theme passed to
+/// [`SlintStyle::with_semantic_theme`], and unset colors fall back to transparent
+/// (or the theme foreground for text). Opacity is kept separate instead of being
+/// multiplied into the colors, matching Slint's `opacity` property.
+///
+/// Color filters (`grayscale`, `brightness-*`, ...) are applied to every color. Slint
+/// only blurs drop shadows, so a blurred background (`blur-*`) becomes an unoffset
+/// drop shadow of the background color over a transparent fill, replacing the box
+/// shadow. Slint cannot read what is drawn behind an element, so `backdrop-blur` makes
+/// the background more opaque instead.
+///
+/// ```rust
+/// use twill_core::prelude::core::*;
+/// use twill_slint::SlintStyle;
+///
+/// let style = Style::card()
+///     .p(Spacing::S4)
+///     .bg(Color::blue(Scale::S500))
+///     .shadow(Shadow::Md);
+/// let slint_style = SlintStyle::from_style(&style);
+///
+/// assert_eq!(slint_style.padding_left, 16.0);
+/// assert_eq!(slint_style.shadow_offset_y, 4.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlintStyle {
+    pub background: slint::Color,
+    pub border_width: f32,
+    pub border_color: slint::Color,
+    pub border_radius: f32,
+    pub padding_top: f32,
+    pub padding_right: f32,
+    pub padding_bottom: f32,
+    pub padding_left: f32,
+    pub shadow_offset_x: f32,
+    pub shadow_offset_y: f32,
+    pub shadow_blur: f32,
+    pub shadow_color: slint::Color,
+    pub text_color: slint::Color,
+    pub font_size: f32,
+    pub font_weight: i32,
+    pub opacity: f32,
+}
+
+impl Default for SlintStyle {
+    fn default() -> Self {
+        Self::from_style(&Style::new())
+    }
+}
+
+impl From<&Style> for SlintStyle {
+    fn from(style: &Style) -> Self {
+        Self::from_style(style)
+    }
+}
+
+impl SlintStyle {
+    /// Convert `style` using the light shadcn neutral theme for semantic colors.
+    pub fn from_style(style: &Style) -> Self {
+        Self::with_semantic_theme(
+            style,
+            SemanticThemeVars::shadcn_neutral(),
+            ThemeVariant::Light,
+        )
+    }
+
+    /// Convert `style`, resolving semantic colors through `theme` for `variant`.
+    pub fn with_semantic_theme<S>(style: &Style, theme: &S, variant: ThemeVariant) -> Self
+    where
+        S: SemanticThemeSource + ?Sized,
+    {
+        let style = style.resolved_theme(theme, variant);
+        let filter = style.filter_value().unwrap_or_default();
+        let foreground = theme
+            .resolve_value(SemanticColor::Foreground, variant)
+            .unwrap_or_else(|| Color::black().compute());
+        let text = style
+            .text_color_token_value()
+            .and_then(|token| text_color(token, foreground))
+            .unwrap_or(foreground);
+        let background = style
+            .background_color_value()
+            .and_then(|token| background_color(token, text))
+            .map(|background| {
+                let background = filter.apply(background);
+                match style.backdrop_blur_value() {
+                    Some(blur) => background.with_alpha(frosted_alpha(background.alpha(), blur)),
+                    None => background,
+                }
+            });
+        let blur = style
+            .blur_value()
+            .map(filter_blur_shadow_radius)
+            .filter(|blur| *blur > 0.0);
+
+        let padding = style.padding_value();
+        let side = |value: Option<PaddingValue>| value.map_or(0.0, padding_value_to_px);
+        let (shadow_offset_y, shadow_blur, shadow_color) = match (blur, background) {
+            (Some(blur), Some(background)) => (0.0, blur, to_slint_color_value(background)),
+            _ => style
+                .box_shadow_value()
+                .map_or((0.0, 0.0, slint::Color::default()), |shadow| {
+                    let color = style
+                        .shadow_color_token_value()
+                        .and_then(|token| shadow_color(token, text))
+                        .unwrap_or_else(|| Color::black().compute());
+                    shadow_with_color_value(shadow, filter.apply(color))
+                }),
+        };
+
+        Self {
+            background: background
+                .filter(|_| blur.is_none())
+                .map_or_else(slint::Color::default, to_slint_color_value),
+            border_width: style.border_width_value().map_or(0.0, border_width_px),
+            border_color: style
+                .border_color_token_value()
+                .and_then(|token| border_color(token, text))
+                .map_or_else(slint::Color::default, |color| {
+                    to_slint_color_value(filter.apply(color))
+                }),
+            border_radius: style.border_radius_value().map_or(0.0, to_radius),
+            padding_top: side(padding.and_then(|p| p.top_side())),
+            padding_right: side(padding.and_then(|p| p.right_side())),
+            padding_bottom: side(padding.and_then(|p| p.bottom_side())),
+            padding_left: side(padding.and_then(|p| p.left_side())),
+            shadow_offset_x: 0.0,
+            shadow_offset_y,
+            shadow_blur,
+            shadow_color,
+            text_color: to_slint_color_value(filter.apply(text)),
+            font_size: style.font_size_value().map_or(16.0, to_font_size),
+            font_weight: style.font_weight_value().map_or(400, to_font_weight),
+            opacity: style.opacity_value().map_or(1.0, |opacity| {
+                if opacity.is_finite() {
+                    opacity.clamp(0.0, 1.0)
+                } else {
+                    1.0
+                }
+            }),
+        }
+    }
+}
+
+/// Implement `From<SlintStyle>` for the Rust struct that Slint generates from
+/// `TwillStyle` (exported from your `.slint` file or `slint!` block).
+///
+/// ```rust,ignore
+/// slint::include_modules!();
+/// twill_slint::impl_slint_style!(TwillStyle);
+///
+/// window.set_card(SlintStyle::from_style(&card_style).into());
+/// ```
+#[macro_export]
+macro_rules! impl_slint_style {
+    ($target:ty) => {
+        impl ::core::convert::From<$crate::SlintStyle> for $target {
+            fn from(style: $crate::SlintStyle) -> Self {
+                Self {
+                    background: style.background,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                    border_radius: style.border_radius,
+                    padding_top: style.padding_top,
+                    padding_right: style.padding_right,
+                    padding_bottom: style.padding_bottom,
+                    padding_left: style.padding_left,
+                    shadow_offset_x: style.shadow_offset_x,
+                    shadow_offset_y: style.shadow_offset_y,
+                    shadow_blur: style.shadow_blur,
+                    shadow_color: style.shadow_color,
+                    text_color: style.text_color,
+                    font_size: style.font_size,
+                    font_weight: style.font_weight,
+                    opacity: style.opacity,
+                }
+            }
+        }
+    };
+}
+
+fn padding_value_to_px(value: PaddingValue) -> f32 {
+    let px = match value {
+        PaddingValue::Scale(spacing) => spacing.to_px().map_or(0.0, |px| px as f32),
+        PaddingValue::Px(px) => px,
+        PaddingValue::Rem(rem) => rem * 16.0,
+        PaddingValue::Var(_) => 0.0,
+    };
+    if px.is_finite() { px.max(0.0) } else { 0.0 }
+}
+
+fn border_width_px(width: BorderWidth) -> f32 {
+    match width {
+        BorderWidth::S0 => 0.0,
+        BorderWidth::S1 => 1.0,
+        BorderWidth::S2 => 2.0,
+        BorderWidth::S4 => 4.0,
+        BorderWidth::S8 => 8.0,
+    }
+}
+
+/// Resolve a color token whose semantic colors were already replaced by the theme.
+macro_rules! resolve_color_token {
+    ($name:ident, $token:ident) => {
+        fn $name(token: $token, current: ColorValue) -> Option<ColorValue> {
+            match token {
+                $token::Current => Some(current),
+                $token::Transparent => Some(ColorValue::TRANSPARENT),
+                $token::Palette(color) => Some(color.compute()),
+                $token::Arbitrary(value) => Some(value.into()),
+                $token::Inherit | $token::Semantic(_) | $token::CustomProperty(_) => None,
+            }
+        }
+    };
+}
+
+resolve_color_token!(background_color, BackgroundColor);
+resolve_color_token!(border_color, BorderColor);
+resolve_color_token!(shadow_color, ShadowColorToken);
+resolve_color_token!(text_color, TextColor);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twill_core::tokens::{Blur, BorderRadius, BorderStyle, FontWeight, Scale, Shadow, Spacing};
+
+    /// Same shape as the struct `slint!` generates from `TwillStyle`.
+    struct TwillStyle {
+        background: slint::Color,
+        border_width: f32,
+        border_color: slint::Color,
+        border_radius: f32,
+        padding_top: f32,
+        padding_right: f32,
+        padding_bottom: f32,
+        padding_left: f32,
+        shadow_offset_x: f32,
+        shadow_offset_y: f32,
+        shadow_blur: f32,
+        shadow_color: slint::Color,
+        text_color: slint::Color,
+        font_size: f32,
+        font_weight: i32,
+        opacity: f32,
+    }
+
+    crate::impl_slint_style!(TwillStyle);
+
+    #[test]
+    fn test_slint_style_from_style() {
+        let style = Style::new()
+            .p(Spacing::S4)
+            .px(Spacing::S2)
+            .bg(Color::blue(Scale::S500))
+            .border(
+                BorderWidth::S2,
+                BorderStyle::Solid,
+                Color::blue(Scale::S700),
+            )
+            .rounded(BorderRadius::Lg)
+            .shadow(Shadow::Md)
+            .font_weight(FontWeight::Bold)
+            .opacity(0.5);
+        let slint_style = SlintStyle::from_style(&style);
+
+        assert_eq!(
+            slint_style.background,
+            to_slint_color_value(Color::blue(Scale::S500).compute())
+        );
+        assert_eq!(slint_style.border_width, 2.0);
+        assert_eq!(slint_style.border_radius, 8.0);
+        assert_eq!(slint_style.padding_top, 16.0);
+        assert_eq!(slint_style.padding_left, 8.0);
+        assert_eq!(slint_style.shadow_offset_y, 4.0);
+        assert_eq!(slint_style.font_size, 16.0);
+        assert_eq!(slint_style.font_weight, 700);
+        assert_eq!(slint_style.opacity, 0.5);
+    }
+
+    #[test]
+    fn test_slint_style_resolves_semantic_theme() {
+        let theme = SemanticThemeVars::shadcn_neutral();
+        let style = Style::new()
+            .background_token(BackgroundColor::semantic(SemanticColor::Primary))
+            .text_color_token(TextColor::semantic(SemanticColor::PrimaryForeground));
+        let dark = SlintStyle::with_semantic_theme(&style, theme, ThemeVariant::Dark);
+
+        let expected =
+            |token| to_slint_color_value(theme.resolve_value(token, ThemeVariant::Dark).unwrap());
+        assert_eq!(dark.background, expected(SemanticColor::Primary));
+        assert_eq!(dark.text_color, expected(SemanticColor::PrimaryForeground));
+        assert_eq!(
+            SlintStyle::default().text_color,
+            to_slint_color_value(
+                theme
+                    .resolve_value(SemanticColor::Foreground, ThemeVariant::Light)
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_slint_style_applies_filters_and_blur() {
+        let disabled = SlintStyle::from_style(
+            &Style::new()
+                .bg(Color::blue(Scale::S500))
+                .text_color(Color::red(Scale::S600))
+                .grayscale(100),
+        );
+        let gray =
+            |color: slint::Color| color.red() == color.green() && color.green() == color.blue();
+        assert!(gray(disabled.background));
+        assert!(gray(disabled.text_color));
+
+        let glow = SlintStyle::from_style(
+            &Style::new()
+                .bg(Color::violet(Scale::S500))
+                .shadow(Shadow::Lg)
+                .blur(Blur::Xl),
+        );
+        assert_eq!(glow.background, slint::Color::default());
+        assert_eq!(glow.shadow_blur, 48.0);
+        assert_eq!(glow.shadow_offset_y, 0.0);
+        assert_eq!(
+            glow.shadow_color,
+            to_slint_color_value(Color::violet(Scale::S500).compute())
+        );
+
+        let glass = SlintStyle::from_style(
+            &Style::new()
+                .background_color_token(BackgroundColor::Arbitrary(
+                    ColorValue::from_rgb(255, 255, 255).with_alpha(0.2).into(),
+                ))
+                .backdrop_blur(Blur::Xl),
+        );
+        // Half of the remaining transparency is filled in.
+        assert_eq!(glass.background.alpha(), 153);
+    }
+
+    #[test]
+    fn test_slint_style_binds_generated_struct() {
+        let style = SlintStyle::from_style(&Style::new().p(Spacing::S3));
+        let card: TwillStyle = style.into();
+
+        let round_trip = SlintStyle {
+            background: card.background,
+            border_width: card.border_width,
+            border_color: card.border_color,
+            border_radius: card.border_radius,
+            padding_top: card.padding_top,
+            padding_right: card.padding_right,
+            padding_bottom: card.padding_bottom,
+            padding_left: card.padding_left,
+            shadow_offset_x: card.shadow_offset_x,
+            shadow_offset_y: card.shadow_offset_y,
+            shadow_blur: card.shadow_blur,
+            shadow_color: card.shadow_color,
+            text_color: card.text_color,
+            font_size: card.font_size,
+            font_weight: card.font_weight,
+            opacity: card.opacity,
+        };
+        assert_eq!(round_trip, style);
+        assert_eq!(round_trip.padding_top, 12.0);
+        assert_eq!(TWILL_SLINT.matches("export").count(), 3);
+    }
+}
@@ -36,4 +36,21 @@ fn main() {
             .aria_attr_style(AriaAttr::Selected)
             .and_then(Style::font_weight_value)
     );
+
+    println!("== resolved for an interaction snapshot ==");
+    let hovered_open = style.resolve_for(
+        &InteractionState::new()
+            .hovered(true)
+            .data_attr(DataState::Open),
+    );
+    println!(
+        "hover + data[state=open]: opacity {:?}, shadow {:?}",
+        hovered_open.opacity_value(),
+        hovered_open.box_shadow_value()
+    );
+    let disabled = style.resolve_for(&InteractionState::new().hovered(true).disabled(true));
+    println!(
+        "hovered while disabled: opacity {:?}",
+        disabled.opacity_value()
+    );
 }
@@ -26,4 +26,19 @@ fn main() {
             resolved.box_shadow_value()
         );
     }
+
+    println!("== available widths ==");
+    for width in [360.0, 800.0, 1100.0, 1600.0] {
+        let context = ResolveContext {
+            viewport_width_px: width,
+            ..ResolveContext::new()
+        };
+        let resolved = style.resolve_breakpoints(&context);
+        println!(
+            "{width}px -> {:?}: width={:?}, padding={:?}",
+            context.active_breakpoints().last(),
+            resolved.width_value(),
+            resolved.padding_value()
+        );
+    }
 }
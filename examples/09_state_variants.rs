@@ -4,7 +4,9 @@ mod egui_demo_common;
 #[cfg(feature = "egui")]
 use eframe::egui;
 #[cfg(feature = "egui")]
-use egui_demo_common::{apply_theme, example_shell, merge_state, run_demo, style_card};
+use egui_demo_common::{apply_theme, example_shell, run_demo, style_card};
+#[cfg(feature = "egui")]
+use twill::backends::egui::styled_frame;
 #[cfg(feature = "egui")]
 use twill::prelude::core::*;
 
@@ -79,7 +81,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Hover",
-                        merge_state(&base, base.hover_style()),
+                        base.resolve_for(&InteractionState::new().hovered(true)),
                         "Preview of a hover override layered on top of the base style.",
                         vec![format!(
                             "hover bg: {:?}",
@@ -88,7 +90,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Focus Visible",
-                        merge_state(&base, base.focus_visible_style()),
+                        base.resolve_for(&InteractionState::new().focus_visible(true)),
                         "Focus ring stays local to the state layer instead of leaking into the base surface.",
                         vec![format!(
                             "ring: {:?}",
@@ -97,7 +99,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Disabled",
-                        merge_state(&base, base.disabled_style()),
+                        base.resolve_for(&InteractionState::new().disabled(true)),
                         "Disabled keeps the same structure, only muting the resolved opacity.",
                         vec![format!(
                             "opacity: {:?}",
@@ -106,7 +108,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Selected",
-                        merge_state(&base, base.selected_style()),
+                        base.resolve_for(&InteractionState::new().selected(true)),
                         "Selection is expressed as a local border variant.",
                         vec![format!(
                             "border: {:?}",
@@ -115,7 +117,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Checked",
-                        merge_state(&base, base.checked_style()),
+                        base.resolve_for(&InteractionState::new().checked(true)),
                         "Checked changes both fill and foreground in one typed override.",
                         vec![format!(
                             "checked bg: {:?}",
@@ -124,7 +126,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Open",
-                        merge_state(&base, base.open_style()),
+                        base.resolve_for(&InteractionState::new().open(true)),
                         "Open state makes the card feel lifted without changing its structural tokens.",
                         vec![format!(
                             "shadow: {:?}",
@@ -133,7 +135,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "Closed",
-                        merge_state(&base, base.closed_style()),
+                        base.resolve_for(&InteractionState::new().open(false)),
                         "Closed can dim the same surface while preserving layout and spacing.",
                         vec![format!(
                             "opacity: {:?}",
@@ -142,7 +144,9 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "data-side=left",
-                        merge_state(&base, base.data_attr_style(DataAttr::pair("side", "left"))),
+                        base.resolve_for(
+                            &InteractionState::new().data_attr(DataAttr::pair("side", "left")),
+                        ),
                         "Typed data hooks let downstream widgets keep their own semantic state names.",
                         vec![format!(
                             "radius: {:?}",
@@ -152,7 +156,7 @@ impl eframe::App for StateVariantsApp {
                     ),
                     (
                         "aria-invalid",
-                        merge_state(&base, base.aria_attr_style(AriaAttr::Invalid)),
+                        base.resolve_for(&InteractionState::new().aria_attr(AriaAttr::Invalid)),
                         "ARIA hooks make error and selection states share the same layered model.",
                         vec![format!(
                             "outline: {:?}",
@@ -162,6 +166,11 @@ impl eframe::App for StateVariantsApp {
                     ),
                 ];
 
+                styled_frame(ui, &base, |ui| {
+                    ui.label("Live surface: hover, press or tab to it to pick a state layer.");
+                });
+                ui.add_space(12.0);
+
                 ui.columns(2, |columns| {
                     for (index, (title, style, body, facts)) in cards.into_iter().enumerate() {
                         let column = &mut columns[index % 2];
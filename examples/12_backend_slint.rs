@@ -4,25 +4,47 @@ mod common;
 #[cfg(feature = "slint")]
 use common::interactive_style;
 #[cfg(feature = "slint")]
-use twill::backends::slint::{self, ToSlint};
+use twill::backends::slint::{self as twill_slint, ToSlint};
 #[cfg(feature = "slint")]
 use twill::prelude::{arbitrary::*, core::*, theme::*};
 #[cfg(feature = "slint")]
 use twill::tokens::Cursor;
 
+#[cfg(feature = "slint")]
+slint::slint! {
+    import { TwillStyle, TwillBox, TwillText } from "../crates/twill-slint/ui/twill.slint";
+
+    export component StyledCard inherits Window {
+        in property <TwillStyle> card;
+
+        TwillBox {
+            twill: root.card;
+
+            TwillText {
+                twill: root.card;
+                text: "Bound from one SlintStyle";
+            }
+        }
+    }
+}
+
+#[cfg(feature = "slint")]
+twill_slint::impl_slint_style!(TwillStyle);
+
 #[cfg(feature = "slint")]
 fn main() {
     let color = Color::emerald(Scale::S500).to_slint();
     let arbitrary =
-        slint::to_slint_color_value(ColorValueToken::from_rgba8(56, 189, 248, 180).into());
+        twill_slint::to_slint_color_value(ColorValueToken::from_rgba8(56, 189, 248, 180).into());
     let spacing = Spacing::S4.to_slint();
     let radius = BorderRadius::Lg.to_slint();
-    let semantic = slint::to_semantic_color(SemanticColor::Primary, ThemeVariant::Dark);
+    let semantic = twill_slint::to_semantic_color(SemanticColor::Primary, ThemeVariant::Dark);
     let cursor = Cursor::Pointer.to_slint();
     let shadow = interactive_style()
         .data_attr_style(DataState::Open)
         .and_then(Style::box_shadow_value)
-        .map(slint::to_shadow);
+        .map(twill_slint::to_shadow);
+    let card: TwillStyle = twill_slint::SlintStyle::from_style(&interactive_style()).into();
 
     println!("== slint adapter ==");
     println!("brand color: {:?}", color);
@@ -32,6 +54,10 @@ fn main() {
     println!("semantic primary (dark): {:?}", semantic);
     println!("cursor: {:?}", cursor.twill_cursor());
     println!("shadow: {:?}", shadow);
+    println!(
+        "card: bg {:?}, padding {:?}, radius {:?}",
+        card.background, card.padding_left, card.border_radius
+    );
 }
 
 #[cfg(not(feature = "slint"))]
@@ -164,7 +164,7 @@ impl eframe::App for ShowcaseApp {
                     );
                 });
                 ui.add_space(8.0);
-                interactive_style().to_egui().show(ui, |ui| {
+                twill_egui::styled_frame(ui, &interactive_style(), |ui| {
                     ui.label(RichText::new("Interactive states").strong());
                     ui.label("Hover, focus-visible, disabled, data-state, and aria-state live next to the base style.");
                     ui.label("Open state adds a larger shadow; focus-visible adds a ring.");
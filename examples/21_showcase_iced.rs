@@ -7,7 +7,7 @@ use common::{
     semantic_summary, themed_surface_style, token_palette,
 };
 #[cfg(feature = "iced")]
-use iced::widget::{button, column, container, row, scrollable, text};
+use iced::widget::{column, container, row, scrollable, text};
 #[cfg(feature = "iced")]
 use iced::{Element, Fill, Theme};
 #[cfg(feature = "iced")]
@@ -82,7 +82,8 @@ impl ShowcaseApp {
 
         let topbar = row![
             text("Twill 0.3.x layered examples").size(28),
-            button("Toggle semantic theme").on_press(Message::ToggleTheme),
+            twill_iced::twill_widget(text("Toggle semantic theme"), &toggle_button_style())
+                .on_press(Message::ToggleTheme),
         ]
         .spacing(16);
 
@@ -132,6 +133,22 @@ impl ShowcaseApp {
             &shadow_preview,
         )
         .into();
+        let badge_style = Style::new()
+            .px(Spacing::S2)
+            .py(Spacing::S0_5)
+            .rounded(BorderRadius::Full)
+            .bg(Color::rose(Scale::S500))
+            .position(Position::Absolute)
+            .top(InsetValue::neg_scale(Spacing::S2))
+            .right(InsetValue::neg_scale(Spacing::S2))
+            .z_index(ZIndex::S10);
+        let badge = styled_container::<Message>(
+            text("New").size(12).color(iced::Color::WHITE).into(),
+            &badge_style,
+        );
+        let shadow_card: Element<'_, Message> = twill_iced::positioned_stack(shadow_card)
+            .push(badge, &badge_style)
+            .into();
 
         let spacing_preview = surface.clone().padding(Padding::all(Spacing::S6));
         let spacing_ink = Self::text_color(&spacing_preview, self.default_body_color());
@@ -232,7 +249,7 @@ impl ShowcaseApp {
         let states: Element<'_, Message> = {
             let style = interactive_style();
             let text_color = Self::text_color(&style, self.default_body_color());
-            styled_container::<Message>(
+            twill_iced::twill_widget(
                 column![
                     text("Interactive states").size(22).color(text_color),
                     text("Hover, focus-visible, disabled, data-state, and aria-state live next to the base style.")
@@ -240,8 +257,7 @@ impl ShowcaseApp {
                     text("Open state adds a larger shadow; focus-visible adds a ring.")
                         .color(text_color)
                 ]
-                .spacing(8)
-                .into(),
+                .spacing(8),
                 &style,
             )
             .into()
@@ -340,5 +356,18 @@ impl ShowcaseApp {
     }
 }
 
+#[cfg(feature = "iced")]
+fn toggle_button_style() -> Style {
+    Style::interactive()
+        .px(Spacing::S4)
+        .py(Spacing::S2)
+        .rounded(BorderRadius::Md)
+        .bg(Color::slate(Scale::S900))
+        .text_color(Color::white())
+        .hover(|style| style.bg(Color::slate(Scale::S700)))
+        .active(|style| style.bg(Color::slate(Scale::S800)))
+        .focus_visible(|style| style.ring(RingWidth::S2, Color::blue(Scale::S400)))
+}
+
 #[cfg(not(feature = "iced"))]
 fn main() {}
@@ -81,10 +81,6 @@ pub fn style_card(ui: &mut egui::Ui, style: &Style, title: &str, body: &str, fac
     });
 }
 
-pub fn merge_state(base: &Style, nested: Option<&Style>) -> Style {
-    base.clone().merged(nested.cloned().unwrap_or_default())
-}
-
 pub fn semantic_surface(variant: ThemeVariant) -> Style {
     let theme = SemanticThemeVars::shadcn_neutral();
     Style::new()